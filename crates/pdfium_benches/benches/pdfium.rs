@@ -0,0 +1,90 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use pdfium_benches::{DOCUMENTS, DPIS};
+use pdfium_rs::{BitmapFormat, Library};
+
+fn open_document(c: &mut Criterion) {
+    let library = Library::init().unwrap();
+    let mut group = c.benchmark_group("open_document");
+
+    for &(name, bytes) in DOCUMENTS {
+        group.bench_with_input(BenchmarkId::from_parameter(name), bytes, |b, bytes| {
+            b.iter(|| library.document_from_bytes(black_box(bytes)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn load_page(c: &mut Criterion) {
+    let library = Library::init().unwrap();
+    let mut group = c.benchmark_group("load_page");
+
+    for &(name, bytes) in DOCUMENTS {
+        let document = library.document_from_bytes(bytes).unwrap();
+        group.bench_function(BenchmarkId::new("uncached", name), |b| {
+            b.iter(|| document.page(black_box(0)).unwrap())
+        });
+
+        let document = library.document_from_bytes(bytes).unwrap();
+        document.set_page_cache_capacity(1);
+        group.bench_function(BenchmarkId::new("cached", name), |b| {
+            b.iter(|| document.page(black_box(0)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn render_page(c: &mut Criterion) {
+    let library = Library::init().unwrap();
+
+    for &(name, bytes) in DOCUMENTS {
+        let document = library.document_from_bytes(bytes).unwrap();
+        let page = document.page(0).unwrap();
+        let mut group = c.benchmark_group(format!("render_page/{}", name));
+
+        for &dpi in DPIS {
+            let width = (page.width() * dpi as f32 / 72.0).round() as usize;
+            let height = (page.height() * dpi as f32 / 72.0).round() as usize;
+            let stride = width * BitmapFormat::BGRA.bytes_per_pixel();
+            let mut pixels = vec![0; stride * height];
+
+            group.bench_function(BenchmarkId::from_parameter(dpi), |b| {
+                b.iter(|| {
+                    let mut bitmap = library
+                        .bitmap_from_external_buffer(
+                            width,
+                            height,
+                            stride,
+                            BitmapFormat::BGRA,
+                            &mut pixels,
+                        )
+                        .unwrap();
+                    bitmap.fill_rect(0, 0, width as i32, height as i32, 0xFFFF_FFFF);
+                    page.render_to(&mut bitmap);
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+fn extract_text(c: &mut Criterion) {
+    let library = Library::init().unwrap();
+    let mut group = c.benchmark_group("extract_text");
+
+    for &(name, bytes) in DOCUMENTS {
+        let document = library.document_from_bytes(bytes).unwrap();
+        group.bench_function(BenchmarkId::new("new_page", name), |b| {
+            b.iter(|| document.page(black_box(0)).unwrap().text().unwrap())
+        });
+
+        // The text page is kept with the page after the first iteration
+        let page = document.page(0).unwrap();
+        group.bench_function(BenchmarkId::new("same_page", name), |b| {
+            b.iter(|| page.text().unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, open_document, load_page, render_page, extract_text);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+//! The documents the benchmarks of `pdfium_rs` are run with, the benchmarks are in `benches/`.
+//!
+//! Run them with `cargo bench -p pdfium_benches`, Criterion keeps the results of the last run in
+//! `target/criterion` and compares the next run with them.
+
+/// The test assets that have the common kinds of content, with their names.
+pub static DOCUMENTS: &[(&str, &[u8])] = &[
+    // Text with embedded fonts
+    ("dummy", include_bytes!("../../../test_assets/dummy.pdf")),
+    // Vector graphics
+    ("paths", include_bytes!("../../../test_assets/paths.pdf")),
+    // A page that is an image
+    (
+        "scanned",
+        include_bytes!("../../../test_assets/scanned.pdf"),
+    ),
+    // Annotations that are drawn with the page
+    (
+        "annotations",
+        include_bytes!("../../../test_assets/annotations.pdf"),
+    ),
+    // A structure tree, like the documents of office applications
+    ("tagged", include_bytes!("../../../test_assets/tagged.pdf")),
+];
+
+/// The resolutions pages are rendered at, from a thumbnail to print quality.
+pub static DPIS: &[u32] = &[36, 72, 150, 300];
@@ -1,12 +1,52 @@
 extern crate bindgen;
 
 use std::env;
-use std::path::PathBuf;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// How to fix a build that can't find PDFium.
+const HELP: &str =
+    "Install PDFium, set `PDFIUM_LIB_DIR` and `PDFIUM_INCLUDE_DIR` to the directories \
+    of its library and headers or enable the `download-prebuilt` feature";
 
 fn main() {
+    let dynamic = env::var_os("CARGO_FEATURE_DYNAMIC").is_some();
+    let linking = match env::var_os("CARGO_FEATURE_STATIC") {
+        Some(_) if dynamic => panic!("The `static` and `dynamic` features can't be used together"),
+        Some(_) => Linking::Static,
+        None if dynamic => Linking::Runtime,
+        None => Linking::Shared,
+    };
+    let target = env::var("TARGET").unwrap();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+
+    let pdfium = find_pdfium(&target, &target_os, linking);
+
     // Tell cargo to tell rustc to link the system libpdfium
-    // shared library.
-    println!("cargo:rustc-link-lib=pdfium");
+    // shared library, with the `dynamic` feature it's loaded at runtime instead.
+    if linking != Linking::Runtime {
+        for lib_dir in &pdfium.lib_dirs {
+            println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        }
+
+        let name = link_name(&target_os, pdfium.lib_dirs.first(), linking);
+        match linking {
+            Linking::Static => println!("cargo:rustc-link-lib=static={}", name),
+            _ => println!("cargo:rustc-link-lib={}", name),
+        }
+
+        for lib in &pdfium.libs {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
+
+        if linking == Linking::Static {
+            for lib in static_dependencies(&target_os) {
+                println!("cargo:rustc-link-lib={}", lib);
+            }
+        }
+    }
 
     // Tell cargo to invalidate the built crate whenever the wrapper changes
     println!("cargo:rerun-if-changed=wrapper.h");
@@ -14,21 +54,675 @@ fn main() {
     // The bindgen::Builder is the main entry point
     // to bindgen, and lets you build up options for
     // the resulting bindings.
-    let bindings = bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default()
         // The input header we would like to generate
         // bindings for.
-        .header("wrapper.h")
+        .header("wrapper.h");
+
+    for include_dir in &pdfium.include_dirs {
+        builder = builder.clang_arg(format!("-I{}", include_dir.display()));
+    }
+
+    // Parse the headers for the target when cross compiling, so the types have its sizes.
+    // The sysroot of the target can be passed with `BINDGEN_EXTRA_CLANG_ARGS`.
+    if target != env::var("HOST").unwrap() {
+        builder = builder.clang_arg(format!("--target={}", target));
+    }
+
+    // Declare the V8 parts of the headers, they are only available in PDFium builds with V8.
+    if env::var_os("CARGO_FEATURE_V8").is_some() {
+        builder = builder.clang_arg("-DPDF_ENABLE_V8");
+    }
+
+    // Declare the XFA parts of the headers, they are only available in PDFium builds with XFA.
+    if env::var_os("CARGO_FEATURE_XFA").is_some() {
+        builder = builder.clang_arg("-DPDF_ENABLE_XFA");
+    }
+
+    let bindings = builder
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         // Finish the builder and generate the bindings.
         .generate()
-        // Unwrap the Result and panic on failure.
-        .expect("Unable to generate bindings");
+        // Unwrap the Result and panic on failure, the headers are usually missing.
+        .unwrap_or_else(|_| {
+            panic!(
+                "Unable to generate bindings from the headers of PDFium. {}",
+                HELP
+            )
+        });
+
+    let bindings = bindings.to_string();
+    emit_optional_functions(&bindings);
+
+    // Record the version for `Library::get_pdfium_version`
+    if let Some(version) = &pdfium.version {
+        println!("cargo:rustc-env=PDFIUM_VERSION={}", version);
+    }
+
+    let bindings = match dynamic {
+        true => dynamic_bindings(&bindings),
+        false => bindings,
+    };
 
     // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings!");
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
+    fs::write(&out_path, bindings).expect("Couldn't write bindings!");
+}
+
+/// How PDFium is linked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Linking {
+    /// The shared library, by default.
+    Shared,
+    /// The static library, with the `static` feature.
+    Static,
+    /// Not linked, it's loaded at runtime with the `dynamic` feature.
+    Runtime,
+}
+
+/// The directories and libraries of PDFium, empty to use the directories of the system.
+#[derive(Debug, Default)]
+struct Pdfium {
+    lib_dirs: Vec<PathBuf>,
+    include_dirs: Vec<PathBuf>,
+    /// The libraries PDFium depends on, from pkg-config.
+    libs: Vec<String>,
+    /// The version, like `5135` for the Chromium build of a release of pdfium-binaries.
+    version: Option<String>,
+}
+
+/// Find the directories of the library and headers.
+///
+/// They are set with `PDFIUM_LIB_DIR` and `PDFIUM_INCLUDE_DIR`, or the variables with the target
+/// like `PDFIUM_LIB_DIR_aarch64_unknown_linux_gnu` when cross compiling to multiple targets.
+/// With the `download-prebuilt` feature the directories that aren't set are downloaded,
+/// otherwise PDFium is found with pkg-config if neither is set.
+/// The version is set with `PDFIUM_VERSION`, otherwise it's the version of the download or pkg-config.
+fn find_pdfium(target: &str, target_os: &str, linking: Linking) -> Pdfium {
+    let mut lib_dir = target_var("PDFIUM_LIB_DIR", target).map(PathBuf::from);
+    let mut include_dir = target_var("PDFIUM_INCLUDE_DIR", target).map(PathBuf::from);
+    let mut version =
+        target_var("PDFIUM_VERSION", target).map(|version| version.to_string_lossy().into_owned());
+
+    if env::var_os("CARGO_FEATURE_DOWNLOAD_PREBUILT").is_some() {
+        if lib_dir.is_none() && linking == Linking::Static {
+            panic!(
+                "pdfium-binaries only has shared libraries, set `PDFIUM_LIB_DIR` to the directory \
+                of a static PDFium or disable the `static` feature"
+            );
+        }
+
+        if lib_dir.is_none() || include_dir.is_none() {
+            let prebuilt = download_prebuilt(target_os);
+            version = version.or_else(|| prebuilt_version(&prebuilt));
+            lib_dir = lib_dir.or_else(|| Some(prebuilt.join("lib")));
+            include_dir = include_dir.or_else(|| Some(prebuilt.join("include")));
+        }
+    } else if lib_dir.is_none() && include_dir.is_none() {
+        if let Some(mut pdfium) = pkg_config(target, linking) {
+            pdfium.version = version.or(pdfium.version);
+            return pdfium;
+        }
+    }
+
+    // The library is not needed when it's loaded at runtime
+    if let (Some(lib_dir), false) = (&lib_dir, linking == Linking::Runtime) {
+        let files = library_files(target_os, linking);
+
+        if !files.iter().any(|file| lib_dir.join(file).is_file()) {
+            panic!(
+                "`{}` is not in {}. {}",
+                files.join("` or `"),
+                lib_dir.display(),
+                HELP
+            );
+        }
+    }
+
+    if let Some(include_dir) = &include_dir {
+        if !include_dir.join("fpdfview.h").is_file() {
+            panic!("`fpdfview.h` is not in {}. {}", include_dir.display(), HELP);
+        }
+    }
+
+    Pdfium {
+        lib_dirs: lib_dir.into_iter().collect(),
+        include_dirs: include_dir.into_iter().collect(),
+        libs: Vec::new(),
+        version,
+    }
+}
+
+/// Find PDFium with the `pdfium.pc` file of pkg-config, `None` if pkg-config or the file is missing.
+///
+/// The program can be changed with `PKG_CONFIG`. When cross compiling it's only used if
+/// `PKG_CONFIG_ALLOW_CROSS` is set, since it finds the libraries of the host by default.
+fn pkg_config(target: &str, linking: Linking) -> Option<Pdfium> {
+    for name in &["PKG_CONFIG", "PKG_CONFIG_PATH", "PKG_CONFIG_ALLOW_CROSS"] {
+        println!("cargo:rerun-if-env-changed={}", name);
+    }
+
+    if target != env::var("HOST").unwrap() && env::var_os("PKG_CONFIG_ALLOW_CROSS").is_none() {
+        return None;
+    }
+
+    let program = env::var_os("PKG_CONFIG").unwrap_or_else(|| "pkg-config".into());
+    let mut command = Command::new(&program);
+    if linking == Linking::Static {
+        command.arg("--static");
+    }
+    let output = command
+        .args(["--cflags", "--libs", "pdfium"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let mut pdfium = Pdfium::default();
+    for flag in String::from_utf8_lossy(&output.stdout).split_whitespace() {
+        if let Some(lib_dir) = flag.strip_prefix("-L") {
+            pdfium.lib_dirs.push(lib_dir.into());
+        } else if let Some(include_dir) = flag.strip_prefix("-I") {
+            pdfium.include_dirs.push(include_dir.into());
+        } else if let Some(lib) = flag.strip_prefix("-l") {
+            // PDFium itself is linked by the name of the target
+            if lib != "pdfium" {
+                pdfium.libs.push(lib.to_string());
+            }
+        }
+    }
+
+    pdfium.version = Command::new(&program)
+        .args(["--modversion", "pdfium"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|version| !version.is_empty());
+
+    Some(pdfium)
+}
+
+/// The value of `NAME_<target>` or `NAME`, the target with underscores like `aarch64_unknown_linux_gnu`.
+fn target_var(name: &str, target: &str) -> Option<OsString> {
+    let target_name = format!("{}_{}", name, target.replace('-', "_"));
+    println!("cargo:rerun-if-env-changed={}", target_name);
+    println!("cargo:rerun-if-env-changed={}", name);
+
+    env::var_os(target_name).or_else(|| env::var_os(name))
+}
+
+/// The files of the library that is linked for the target.
+fn library_files(target_os: &str, linking: Linking) -> &'static [&'static str] {
+    match (target_os, linking) {
+        ("windows", Linking::Static) => &["pdfium.lib"],
+        (_, Linking::Static) => &["libpdfium.a"],
+        // The import library of the DLL, `pdfium.dll.lib` in the builds of pdfium-binaries
+        ("windows", _) => &["pdfium.dll.lib", "pdfium.lib"],
+        ("macos", _) | ("ios", _) => &["libpdfium.dylib"],
+        _ => &["libpdfium.so"],
+    }
+}
+
+/// The name of the library for `rustc-link-lib`.
+fn link_name(target_os: &str, lib_dir: Option<&PathBuf>, linking: Linking) -> &'static str {
+    match lib_dir {
+        Some(lib_dir)
+            if target_os == "windows"
+                && linking == Linking::Shared
+                && lib_dir.join("pdfium.dll.lib").is_file() =>
+        {
+            "pdfium.dll"
+        }
+        _ => "pdfium",
+    }
+}
+
+/// The libraries a static PDFium needs, its C++ runtime and the libraries of the system it uses.
+///
+/// The C++ runtime is the one of the platform's compiler, it can be changed with `PDFIUM_CXX_RUNTIME`
+/// like `c++` for a PDFium that was built with libc++ on Linux, or set to nothing to not link one.
+fn static_dependencies(target_os: &str) -> Vec<String> {
+    println!("cargo:rerun-if-env-changed=PDFIUM_CXX_RUNTIME");
+
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    let runtime = match (target_os, target_env.as_str()) {
+        ("macos", _) | ("ios", _) => Some("c++"),
+        ("android", _) => Some("c++_shared"),
+        // MSVC links its runtime by itself
+        ("windows", "msvc") => None,
+        _ => Some("stdc++"),
+    };
+    let runtime = match env::var("PDFIUM_CXX_RUNTIME") {
+        Ok(runtime) => Some(runtime).filter(|runtime| !runtime.is_empty()),
+        Err(_) => runtime.map(String::from),
+    };
+
+    let system: &[&str] = match target_os {
+        "macos" | "ios" => &["framework=CoreFoundation", "framework=CoreGraphics"],
+        "windows" => &["gdi32", "user32", "advapi32"],
+        _ => &[],
+    };
+
+    runtime
+        .into_iter()
+        .chain(system.iter().map(|lib| lib.to_string()))
+        .collect()
+}
+
+/// The Chromium build number of the pdfium-binaries release that is downloaded, the bindings are generated
+/// from its headers. Another release can be downloaded by setting `PDFIUM_PREBUILT_VERSION` and `PDFIUM_PREBUILT_SHA256`.
+const PREBUILT_VERSION: &str = "5135";
+
+/// The SHA-256 of the archives of the `PREBUILT_VERSION` release, by the names of the archives.
+///
+/// An archive that isn't listed is only used if `PDFIUM_PREBUILT_SHA256` is set to its checksum.
+const PREBUILT_SHA256: &[(&str, &str)] = &[];
+
+/// Download and extract the build of pdfium-binaries for the target, returns the directory with its
+/// `include` and `lib` directories.
+///
+/// The archive is checked against its SHA-256 before it's extracted, from `PREBUILT_SHA256` or
+/// `PDFIUM_PREBUILT_SHA256`, which has to be set when `PDFIUM_PREBUILT_VERSION` is.
+///
+/// The download is kept in `OUT_DIR`, so it's only downloaded again after `cargo clean` or
+/// when the version changes. It needs `curl` and `tar`, which are included in Windows 10 and later,
+/// and `sha256sum`, `shasum` on macOS or `certutil` on Windows.
+fn download_prebuilt(target_os: &str) -> PathBuf {
+    println!("cargo:rerun-if-env-changed=PDFIUM_PREBUILT_VERSION");
+    println!("cargo:rerun-if-env-changed=PDFIUM_PREBUILT_SHA256");
+
+    let asset = prebuilt_asset(target_os);
+    let checksum = env::var("PDFIUM_PREBUILT_SHA256").ok();
+    let version = match env::var("PDFIUM_PREBUILT_VERSION") {
+        Ok(version) if checksum.is_none() => panic!(
+            "Set `PDFIUM_PREBUILT_SHA256` to the SHA-256 of {} of the {} release of pdfium-binaries",
+            asset, version
+        ),
+        Ok(version) => version,
+        Err(_) => PREBUILT_VERSION.to_string(),
+    };
+    let checksum = checksum
+        .or_else(|| {
+            PREBUILT_SHA256
+                .iter()
+                .find(|&&(name, _)| name == asset)
+                .map(|&(_, checksum)| checksum.to_string())
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "There is no SHA-256 of {} of the {} release of pdfium-binaries, set `PDFIUM_PREBUILT_SHA256` to it",
+                asset, version
+            )
+        })
+        .to_ascii_lowercase();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let directory = out_dir.join("pdfium").join(&version);
+
+    if directory.join("include").join("fpdfview.h").exists() {
+        return directory;
+    }
+
+    let url = format!(
+        "https://github.com/bblanchon/pdfium-binaries/releases/download/chromium%2F{}/{}",
+        version, asset
+    );
+    let archive = out_dir.join(&asset);
+
+    run(
+        Command::new("curl")
+            .args(["--fail", "--location", "--silent", "--show-error"])
+            .arg("--output")
+            .arg(&archive)
+            .arg(&url),
+        &format!("download PDFium from {}", url),
+    );
+
+    let actual = sha256(&archive);
+    if actual != checksum {
+        let _ = fs::remove_file(&archive);
+        panic!(
+            "The SHA-256 of {} is {}, but it should be {}",
+            url, actual, checksum
+        );
+    }
+
+    // Extract to another directory first, so an interrupted build doesn't leave a partial download
+    let partial = out_dir.join("pdfium").join(format!("{}.partial", version));
+    let _ = fs::remove_dir_all(&partial);
+    fs::create_dir_all(&partial).expect("Couldn't create the PDFium directory!");
+    run(
+        Command::new("tar")
+            .arg("-xzf")
+            .arg(&archive)
+            .arg("-C")
+            .arg(&partial),
+        &format!("extract {}", archive.display()),
+    );
+
+    let _ = fs::remove_dir_all(&directory);
+    fs::rename(&partial, &directory).expect("Couldn't move the PDFium directory!");
+    let _ = fs::remove_file(&archive);
+
+    directory
+}
+
+/// The SHA-256 of a file in lowercase hex, with the checksum tool of the system.
+fn sha256(file: &Path) -> String {
+    let mut command = match env::consts::OS {
+        "windows" => Command::new("certutil"),
+        "macos" => Command::new("shasum"),
+        _ => Command::new("sha256sum"),
+    };
+    match env::consts::OS {
+        "windows" => command.arg("-hashfile").arg(file).arg("SHA256"),
+        "macos" => command.args(["-a", "256"]).arg(file),
+        _ => command.arg(file),
+    };
+    let program = Path::new(command.get_program()).display().to_string();
+
+    let output = match command.output() {
+        Ok(output) if output.status.success() => output,
+        Ok(output) => panic!(
+            "Failed to check the SHA-256 of {}, `{}` {}",
+            file.display(),
+            program,
+            output.status
+        ),
+        Err(error) => panic!(
+            "Failed to check the SHA-256 of {}, couldn't run `{}`: {}. Install it or disable the `download-prebuilt` feature",
+            file.display(),
+            program,
+            error
+        ),
+    };
+
+    // `sha256sum` and `shasum` print the checksum before the file, `certutil` on a line of its own
+    // that has spaces between the bytes on older versions of Windows
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| match env::consts::OS {
+            "windows" => line.replace(' ', ""),
+            _ => line
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        })
+        .find(|checksum| checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(|checksum| checksum.to_ascii_lowercase())
+        .unwrap_or_else(|| {
+            panic!(
+                "`{}` didn't print the SHA-256 of {}",
+                program,
+                file.display()
+            )
+        })
+}
+
+/// The Chromium build of a download, from the `BUILD=5135` line of its `VERSION` file.
+fn prebuilt_version(prebuilt: &Path) -> Option<String> {
+    let file = fs::read_to_string(prebuilt.join("VERSION")).ok()?;
+
+    file.lines()
+        .find_map(|line| line.strip_prefix("BUILD="))
+        .map(|build| build.trim().to_string())
+}
+
+/// The name of the pdfium-binaries archive for the target, the V8 builds also include XFA.
+fn prebuilt_asset(target_os: &str) -> String {
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+
+    let arch = match arch.as_str() {
+        "x86_64" => "x64",
+        "x86" => "x86",
+        "aarch64" => "arm64",
+        "arm" => "arm",
+        arch => unsupported_target(target_os, arch),
+    };
+    let platform = match target_os {
+        "linux" if target_env == "musl" => "linux-musl",
+        "linux" => "linux",
+        "macos" => "mac",
+        "windows" => "win",
+        "android" => "android",
+        os => unsupported_target(os, arch),
+    };
+    let v8 = match env::var_os("CARGO_FEATURE_V8") {
+        Some(_) => "v8-",
+        None => "",
+    };
+
+    format!("pdfium-{}{}-{}.tgz", v8, platform, arch)
+}
+
+fn unsupported_target(target_os: &str, arch: &str) -> ! {
+    panic!(
+        "There is no prebuilt PDFium for {} on {}, disable the `download-prebuilt` feature and install PDFium",
+        arch, target_os
+    )
+}
+
+/// Run a command of the build, panics with what failed if it doesn't succeed.
+fn run(command: &mut Command, action: &str) {
+    let program = Path::new(command.get_program()).display().to_string();
+
+    match command.status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => panic!("Failed to {}, `{}` {}", action, program, status),
+        Err(error) => panic!(
+            "Failed to {}, couldn't run `{}`: {}. Install it or disable the `download-prebuilt` feature",
+            action, program, error
+        ),
+    }
+}
+
+/// The functions of PDFium, other functions of the headers are from the C library.
+const PREFIXES: &[&str] = &["FPDF", "FORM_", "FSDK_"];
+
+/// Replace the `extern` declarations of the PDFium functions with functions that call the function
+/// of the library that is loaded at runtime, so the bindings are used the same way with both kinds of linking.
+///
+/// The names of the functions are listed in `FUNCTIONS`, without the optional functions, for `load_library` to check.
+fn dynamic_bindings(bindings: &str) -> String {
+    let mut output = String::new();
+    let mut functions = Vec::new();
+    let mut rest = bindings;
+
+    while let Some((start, marker)) = find_extern_block(rest) {
+        output.push_str(&rest[..start]);
+        let body_start = start + marker.len();
+        let body_end = body_start + matching(&rest[body_start..], '{', '}');
+
+        for item in split_top_level(&rest[body_start..body_end], ';') {
+            let item = item.trim();
+
+            match function_wrapper(item) {
+                Some((name, wrapper)) => {
+                    if !OPTIONAL_FUNCTIONS.contains(&name.as_str()) {
+                        functions.push(format!("\"{}\"", name));
+                    }
+                    output.push_str(&wrapper);
+                }
+                None if item.is_empty() => {}
+                None => output.push_str(&format!("extern \"C\" {{\n    {};\n}}\n", item)),
+            }
+        }
+
+        rest = &rest[body_end + 1..];
+    }
+    output.push_str(rest);
+    output.push_str(&format!(
+        "pub(crate) static FUNCTIONS: &[&str] = &[{}];\n",
+        functions.join(", ")
+    ));
+
+    output
+}
+
+/// Find the next `extern "C" {` block, bindgen puts every function in its own block.
+fn find_extern_block(source: &str) -> Option<(usize, &'static str)> {
+    ["unsafe extern \"C\" {", "extern \"C\" {"]
+        .iter()
+        .filter_map(|&marker| Some((source.find(marker)?, marker)))
+        .min_by_key(|&(start, marker)| (start, std::cmp::Reverse(marker.len())))
+}
+
+/// The name and wrapper of a function declaration like `pub fn FPDF_GetLastError() -> c_ulong`,
+/// `None` for anything else like statics and functions of the C library.
+fn function_wrapper(item: &str) -> Option<(String, String)> {
+    let fn_start = item.find("pub fn ")?;
+    let attributes = &item[..fn_start];
+    let signature = &item[fn_start + "pub fn ".len()..];
+
+    let name_end = signature.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    let name = &signature[..name_end];
+    if !PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+        return None;
+    }
+
+    let params_start = name_end + signature[name_end..].find('(')? + 1;
+    let params_end = params_start + matching(&signature[params_start..], '(', ')');
+    let output = signature[params_end + 1..].trim();
+
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for param in split_top_level(&signature[params_start..params_end], ',') {
+        let param = param.trim();
+        if param.is_empty() {
+            continue;
+        }
+        // Variadic functions can't be called through a function pointer type
+        let colon = param.find(':')?;
+        names.push(param[..colon].trim().to_string());
+        types.push(param[colon + 1..].trim().to_string());
+    }
+
+    let output = if output.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", output)
+    };
+    let params: Vec<_> = names
+        .iter()
+        .zip(&types)
+        .map(|(name, param_type)| format!("{}: {}", name, param_type))
+        .collect();
+
+    let wrapper = format!(
+        "{attributes}pub unsafe fn {name}({params}){output} {{\n    \
+        static SYMBOL: crate::dynamic::Symbol = crate::dynamic::Symbol::new(b\"{name}\\0\");\n    \
+        let function = ::std::mem::transmute::<usize, unsafe extern \"C\" fn({types}){output}>(SYMBOL.address());\n    \
+        function({names})\n}}\n",
+        attributes = attributes,
+        name = name,
+        params = params.join(", "),
+        output = output,
+        types = types.join(", "),
+        names = names.join(", "),
+    );
+
+    Some((name.to_string(), wrapper))
+}
+
+/// The length of `source` until the `close` that matches an `open` before it, skipping over string literals.
+fn matching(source: &str, open: char, close: char) -> usize {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in source.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == open {
+            depth += 1;
+        } else if c == close {
+            if depth == 0 {
+                return index;
+            }
+            depth -= 1;
+        }
+    }
+
+    source.len()
+}
+
+/// Split `source` at `separator`s that are not in brackets or string literals.
+fn split_top_level(source: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = ' ';
+    let mut start = 0;
+
+    for (index, c) in source.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else {
+            match c {
+                '"' => in_string = true,
+                '(' | '[' | '{' | '<' => depth += 1,
+                // The `>` of `->` doesn't close a bracket
+                '>' if previous == '-' => {}
+                ')' | ']' | '}' | '>' => depth -= 1,
+                _ if c == separator && depth == 0 => {
+                    parts.push(&source[start..index]);
+                    start = index + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        previous = c;
+    }
+    parts.push(&source[start..]);
+
+    parts
+}
+
+/// The functions that are missing from older builds of PDFium, see `Capability`.
+const OPTIONAL_FUNCTIONS: &[&str] = &["FPDF_MovePages", "FPDFPageObj_GetRotatedBounds"];
+
+/// Set `pdfium_function = "NAME"` for the optional functions that are declared by the headers,
+/// so the calls to functions that are missing can be left out.
+fn emit_optional_functions(bindings: &str) {
+    let values: Vec<_> = OPTIONAL_FUNCTIONS
+        .iter()
+        .map(|function| format!("\"{}\"", function))
+        .collect();
+    println!(
+        "cargo:rustc-check-cfg=cfg(pdfium_function, values({}))",
+        values.join(", ")
+    );
+
+    for function in OPTIONAL_FUNCTIONS {
+        let declaration = format!("pub fn {}", function);
+        // Without rustfmt bindgen puts a space before the parameters
+        let declared = bindings.match_indices(&declaration).any(|(start, _)| {
+            bindings[start + declaration.len()..]
+                .trim_start()
+                .starts_with('(')
+        });
+
+        if declared {
+            println!("cargo:rustc-cfg=pdfium_function=\"{}\"", function);
+        }
+    }
 }
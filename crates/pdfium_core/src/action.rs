@@ -0,0 +1,315 @@
+use crate::{bindings, get_string, BookmarkHandle, DestinationHandle, DocumentHandle, Library};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the action of a bookmark, what happens when it's clicked.
+    ///
+    /// Returns `None` if the bookmark has no action, use [`Library::get_bookmark_destination`] then.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    ///
+    /// let action_handle = library.get_bookmark_action(&bookmark_handle);
+    /// assert!(action_handle.is_none());
+    /// ```
+    pub fn get_bookmark_action<'data, 'library>(
+        &'library self,
+        bookmark: &BookmarkHandle<'data, '_>,
+    ) -> Option<ActionHandle<'data, 'library>> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDFBookmark_GetAction(bookmark.handle.as_ptr()) });
+
+        handle.map(ActionHandle::new)
+    }
+
+    /// Get the type of an action.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{ActionType, Library};
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let first = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let chapter = library
+    ///     .get_next_bookmark_sibling(&document_handle, &first)
+    ///     .unwrap();
+    /// let section = library
+    ///     .get_first_bookmark_child(&document_handle, Some(&chapter))
+    ///     .unwrap();
+    /// let action_handle = library.get_bookmark_action(&section).unwrap();
+    ///
+    /// let action_type = library.get_action_type(&action_handle);
+    /// assert_eq!(action_type, ActionType::GoTo);
+    /// ```
+    pub fn get_action_type(&self, action: &ActionHandle) -> ActionType {
+        let action_type = unsafe { bindings::FPDFAction_GetType(action.handle.as_ptr()) };
+
+        ActionType::from_i32(action_type as i32).unwrap_or(ActionType::Unsupported)
+    }
+
+    /// Get the destination of an action with the [`ActionType::GoTo`] or [`ActionType::RemoteGoTo`] type.
+    ///
+    /// For [`ActionType::RemoteGoTo`] `document` has to be the document at [`Library::get_action_file_path`].
+    ///
+    /// Returns `None` if the action has a different type or the destination is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let first = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let chapter = library
+    ///     .get_next_bookmark_sibling(&document_handle, &first)
+    ///     .unwrap();
+    /// let section = library
+    ///     .get_first_bookmark_child(&document_handle, Some(&chapter))
+    ///     .unwrap();
+    /// let action_handle = library.get_bookmark_action(&section).unwrap();
+    ///
+    /// let destination_handle = library
+    ///     .get_action_destination(&document_handle, &action_handle)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     library.get_destination_page_index(&document_handle, &destination_handle),
+    ///     Some(1)
+    /// );
+    /// ```
+    pub fn get_action_destination<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        action: &ActionHandle,
+    ) -> Option<DestinationHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFAction_GetDest(document.handle.as_ptr(), action.handle.as_ptr())
+        });
+
+        handle.map(DestinationHandle::new)
+    }
+
+    /// Get the file path of an action with the [`ActionType::Launch`] or [`ActionType::RemoteGoTo`] type.
+    ///
+    /// Returns an empty string if the action has a different type.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// for _ in 0..3 {
+    ///     bookmark_handle = library
+    ///         .get_next_bookmark_sibling(&document_handle, &bookmark_handle)
+    ///         .unwrap();
+    /// }
+    /// let action_handle = library.get_bookmark_action(&bookmark_handle).unwrap();
+    ///
+    /// let file_path = library.get_action_file_path(&action_handle);
+    /// assert_eq!(file_path, "notes.txt");
+    /// ```
+    pub fn get_action_file_path(&self, action: &ActionHandle) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDFAction_GetFilePath(action.handle.as_ptr(), buffer as _, length as _) as _
+        })
+    }
+
+    /// Get the URI of an action with the [`ActionType::Uri`] type.
+    ///
+    /// Returns an empty string if the action has a different type.
+    /// Invalid UTF-8 in the URI is replaced with `U+FFFD`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let first = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let chapter = library
+    ///     .get_next_bookmark_sibling(&document_handle, &first)
+    ///     .unwrap();
+    /// let section_1 = library
+    ///     .get_first_bookmark_child(&document_handle, Some(&chapter))
+    ///     .unwrap();
+    /// let section_2 = library
+    ///     .get_next_bookmark_sibling(&document_handle, &section_1)
+    ///     .unwrap();
+    /// let action_handle = library.get_bookmark_action(&section_2).unwrap();
+    ///
+    /// let uri = library.get_action_uri_path(&document_handle, &action_handle);
+    /// assert_eq!(uri, "https://example.com/section");
+    /// ```
+    pub fn get_action_uri_path(&self, document: &DocumentHandle, action: &ActionHandle) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDFAction_GetURIPath(
+                document.handle.as_ptr(),
+                action.handle.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+}
+
+/// Safe handle to PDFium Action, what happens when a bookmark or link is clicked.
+///
+/// Created using [`Library::get_bookmark_action`] and [`Library::get_link_action`].
+///
+/// The action is owned by its document so nothing is freed when the handle is dropped.
+pub struct ActionHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_action_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(ActionHandle: Sync, Send);
+
+impl ActionHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_action_t__>) -> Self {
+        ActionHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// The type of an action.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActionType {
+    /// An action type that PDFium doesn't support, like JavaScript.
+    Unsupported = bindings::PDFACTION_UNSUPPORTED as i32,
+    /// Go to a destination in the same document.
+    GoTo = bindings::PDFACTION_GOTO as i32,
+    /// Go to a destination in another document.
+    RemoteGoTo = bindings::PDFACTION_REMOTEGOTO as i32,
+    /// Open a URI, like a web page.
+    Uri = bindings::PDFACTION_URI as i32,
+    /// Launch an application or open a file.
+    Launch = bindings::PDFACTION_LAUNCH as i32,
+    /// Go to a destination in an embedded document.
+    EmbeddedGoTo = bindings::PDFACTION_EMBEDDEDGOTO as i32,
+}
+
+impl ActionType {
+    fn from_i32(number: i32) -> Option<ActionType> {
+        match number {
+            x if x == ActionType::Unsupported as i32 => Some(ActionType::Unsupported),
+            x if x == ActionType::GoTo as i32 => Some(ActionType::GoTo),
+            x if x == ActionType::RemoteGoTo as i32 => Some(ActionType::RemoteGoTo),
+            x if x == ActionType::Uri as i32 => Some(ActionType::Uri),
+            x if x == ActionType::Launch as i32 => Some(ActionType::Launch),
+            x if x == ActionType::EmbeddedGoTo as i32 => Some(ActionType::EmbeddedGoTo),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+
+    #[test]
+    fn bookmark_actions() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(OUTLINE_PDF, None).unwrap();
+
+        let introduction = library.get_first_bookmark_child(&document, None).unwrap();
+        assert!(library.get_bookmark_action(&introduction).is_none());
+
+        let chapter_1 = library
+            .get_next_bookmark_sibling(&document, &introduction)
+            .unwrap();
+        let section_1 = library
+            .get_first_bookmark_child(&document, Some(&chapter_1))
+            .unwrap();
+        let action = library.get_bookmark_action(&section_1).unwrap();
+        assert_eq!(library.get_action_type(&action), ActionType::GoTo);
+        let destination = library.get_action_destination(&document, &action).unwrap();
+        assert_eq!(
+            library.get_destination_page_index(&document, &destination),
+            Some(1)
+        );
+        assert_eq!(library.get_action_file_path(&action), "");
+        assert_eq!(library.get_action_uri_path(&document, &action), "");
+
+        let section_2 = library
+            .get_next_bookmark_sibling(&document, &section_1)
+            .unwrap();
+        let action = library.get_bookmark_action(&section_2).unwrap();
+        assert_eq!(library.get_action_type(&action), ActionType::Uri);
+        assert_eq!(
+            library.get_action_uri_path(&document, &action),
+            "https://example.com/section"
+        );
+        assert!(library.get_action_destination(&document, &action).is_none());
+
+        let chapter_2 = library
+            .get_next_bookmark_sibling(&document, &chapter_1)
+            .unwrap();
+        let appendix = library
+            .get_first_bookmark_child(&document, Some(&chapter_2))
+            .unwrap();
+        let action = library.get_bookmark_action(&appendix).unwrap();
+        assert_eq!(library.get_action_type(&action), ActionType::RemoteGoTo);
+        assert_eq!(library.get_action_file_path(&action), "other.pdf");
+
+        let notes = library
+            .get_next_bookmark_sibling(&document, &chapter_2)
+            .unwrap();
+        let action = library.get_bookmark_action(&notes).unwrap();
+        assert_eq!(library.get_action_type(&action), ActionType::Launch);
+        assert_eq!(library.get_action_file_path(&action), "notes.txt");
+    }
+}
@@ -0,0 +1,2854 @@
+use crate::geometry::get_points;
+use crate::{
+    bindings, get_wide_string, to_byte_string, to_result, to_wide_string, Color, FormHandle,
+    Library, OwnedPageObjectHandle, PageHandle, PdfiumError, Point, QuadPoints, Rect,
+};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get number of annotations in the page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_count = library.get_annotation_count(&page_handle);
+    /// assert!(annotation_count > 0);
+    /// ```
+    pub fn get_annotation_count(&self, page: &PageHandle) -> usize {
+        let count = unsafe { bindings::FPDFPage_GetAnnotCount(page.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get an annotation in the page.
+    ///
+    /// `index` 0 for the first annotation.
+    ///
+    /// Will return `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0);
+    /// assert!(annotation_handle.is_some());
+    /// ```
+    pub fn get_annotation<'page, 'library>(
+        &'library self,
+        page: &'page PageHandle,
+        index: usize,
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFPage_GetAnnot(page.handle.as_ptr(), index as i32)
+        });
+
+        handle.map(AnnotationHandle::new)
+    }
+
+    /// Create a new annotation and append it to the page.
+    ///
+    /// Use [`Library::set_annotation_rect`] to position the new annotation.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium doesn't support creating annotations of `subtype`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationSubtype, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library
+    ///     .create_annotation(&mut page_handle, AnnotationSubtype::Stamp)
+    ///     .unwrap();
+    /// assert_eq!(library.get_annotation_subtype(&annotation_handle), AnnotationSubtype::Stamp);
+    /// ```
+    pub fn create_annotation<'page, 'library>(
+        &'library self,
+        page: &'page mut PageHandle,
+        subtype: AnnotationSubtype,
+    ) -> Result<AnnotationHandle<'page, 'library>, PdfiumError> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFPage_CreateAnnot(page.handle.as_ptr(), subtype as _)
+        });
+
+        handle
+            .map(AnnotationHandle::new)
+            .ok_or(PdfiumError::Unknown)
+    }
+
+    /// Remove the annotation at `index` from a page.
+    ///
+    /// The indices of the annotations after it are decreased by one.
+    /// The annotation is only removed from the document when it's saved.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let count = library.get_annotation_count(&page_handle);
+    ///
+    /// library.remove_annotation(&mut page_handle, 0).unwrap();
+    /// assert_eq!(library.get_annotation_count(&page_handle), count - 1);
+    /// ```
+    pub fn remove_annotation(
+        &self,
+        page: &mut PageHandle,
+        index: usize,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe { bindings::FPDFPage_RemoveAnnot(page.handle.as_ptr(), index as _) })
+    }
+
+    /// Get the subtype of an annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationSubtype, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    /// let subtype = library.get_annotation_subtype(&annotation_handle);
+    /// assert_eq!(subtype, AnnotationSubtype::Text);
+    /// ```
+    pub fn get_annotation_subtype(&self, annotation: &AnnotationHandle) -> AnnotationSubtype {
+        let subtype = unsafe { bindings::FPDFAnnot_GetSubtype(annotation.handle.as_ptr()) };
+
+        AnnotationSubtype::from_i32(subtype).unwrap_or(AnnotationSubtype::Unknown)
+    }
+
+    /// Get the rectangle of an annotation.
+    ///
+    /// The rectangle is in page space and defines where the annotation is drawn.
+    ///
+    /// Will return `None` if the annotation has no rectangle.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Rect};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let rect = library.get_annotation_rect(&annotation_handle);
+    /// assert_eq!(rect, Some(Rect { left: 100.0, bottom: 100.0, right: 200.0, top: 200.0 }));
+    /// ```
+    pub fn get_annotation_rect(&self, annotation: &AnnotationHandle) -> Option<Rect> {
+        let mut rect = Rect::default().to_fs_rectf();
+
+        to_result(unsafe { bindings::FPDFAnnot_GetRect(annotation.handle.as_ptr(), &mut rect) })
+            .ok()
+            .map(|_| Rect::from_fs_rectf(&rect))
+    }
+
+    /// Set the rectangle of an annotation.
+    ///
+    /// Annotations with an appearance stream are drawn stretched to fill the new rectangle.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): Unable to set the rectangle.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Rect};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let rect = Rect { left: 300.0, bottom: 300.0, right: 350.0, top: 350.0 };
+    /// assert!(library.set_annotation_rect(&mut annotation_handle, &rect).is_ok());
+    /// ```
+    pub fn set_annotation_rect(
+        &self,
+        annotation: &mut AnnotationHandle,
+        rect: &Rect,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDFAnnot_SetRect(annotation.handle.as_ptr(), &rect.to_fs_rectf())
+        })
+    }
+
+    /// Get a color of an annotation.
+    ///
+    /// If the annotation doesn't specify the color, the default is yellow for highlight annotations and black for all others.
+    ///
+    /// Will return `None` if the annotation has an appearance stream, the colors of its
+    /// appearance stream's page objects are used instead.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationColorType, Color, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let color = library.get_annotation_color(&annotation_handle, AnnotationColorType::Stroke);
+    /// assert_eq!(color, Some(Color::rgb(0, 0, 255)));
+    /// ```
+    pub fn get_annotation_color(
+        &self,
+        annotation: &AnnotationHandle,
+        color_type: AnnotationColorType,
+    ) -> Option<Color> {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_GetColor(
+                annotation.handle.as_ptr(),
+                color_type as _,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )
+        })
+        .ok()
+        .map(|_| Color {
+            r: r as u8,
+            g: g as u8,
+            b: b as u8,
+            a: a as u8,
+        })
+    }
+
+    /// Set a color of an annotation.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation has an appearance stream.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationColorType, Color, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let result = library.set_annotation_color(
+    ///     &mut annotation_handle,
+    ///     AnnotationColorType::Interior,
+    ///     &Color::rgb(0, 255, 0),
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_annotation_color(
+        &self,
+        annotation: &mut AnnotationHandle,
+        color_type: AnnotationColorType,
+        color: &Color,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDFAnnot_SetColor(
+                annotation.handle.as_ptr(),
+                color_type as _,
+                color.r as _,
+                color.g as _,
+                color.b as _,
+                color.a as _,
+            )
+        })
+    }
+
+    /// Get number of strokes in the ink list of an ink annotation.
+    ///
+    /// Returns `0` if the annotation is not an ink annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    /// assert_eq!(library.get_annotation_ink_list_count(&annotation_handle), 2);
+    /// ```
+    pub fn get_annotation_ink_list_count(&self, annotation: &AnnotationHandle) -> usize {
+        unsafe { bindings::FPDFAnnot_GetInkListCount(annotation.handle.as_ptr()) as usize }
+    }
+
+    /// Get the points of a stroke in the ink list of an ink annotation.
+    ///
+    /// `index` 0 for the first stroke.
+    ///
+    /// Returns an empty list if `index` is out of bounds or the annotation is not an ink annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    ///
+    /// let points = library.get_annotation_ink_list_path(&annotation_handle, 1);
+    /// assert_eq!(points, [Point { x: 40.0, y: 40.0 }, Point { x: 50.0, y: 50.0 }]);
+    /// ```
+    pub fn get_annotation_ink_list_path(
+        &self,
+        annotation: &AnnotationHandle,
+        index: usize,
+    ) -> Vec<Point> {
+        get_points(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetInkListPath(
+                annotation.handle.as_ptr(),
+                index as _,
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Add a stroke to the ink list of an ink annotation.
+    ///
+    /// Returns the index of the new stroke.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation is not an ink annotation.
+    /// - [`Unknown`](PdfiumError::Unknown): `points` is empty.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    ///
+    /// let points = [Point { x: 10.0, y: 50.0 }, Point { x: 50.0, y: 10.0 }];
+    /// let index = library.add_annotation_ink_stroke(&mut annotation_handle, &points);
+    /// assert_eq!(index, Ok(2));
+    /// ```
+    pub fn add_annotation_ink_stroke(
+        &self,
+        annotation: &mut AnnotationHandle,
+        points: &[Point],
+    ) -> Result<usize, PdfiumError> {
+        let points: Vec<_> = points.iter().map(|point| point.to_fs_pointf()).collect();
+
+        let index = unsafe {
+            bindings::FPDFAnnot_AddInkStroke(
+                annotation.handle.as_ptr(),
+                points.as_ptr(),
+                points.len() as _,
+            )
+        };
+
+        if index >= 0 {
+            Ok(index as usize)
+        } else {
+            Err(PdfiumError::Unknown)
+        }
+    }
+
+    /// Remove all strokes from the ink list of an ink annotation.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation is not an ink annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    ///
+    /// assert!(library.remove_annotation_ink_list(&mut annotation_handle).is_ok());
+    /// assert_eq!(library.get_annotation_ink_list_count(&annotation_handle), 0);
+    /// ```
+    pub fn remove_annotation_ink_list(
+        &self,
+        annotation: &mut AnnotationHandle,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe { bindings::FPDFAnnot_RemoveInkList(annotation.handle.as_ptr()) })
+    }
+
+    /// Get the flags of an annotation.
+    ///
+    /// See the [`annotation_flags`](crate::annotation_flags) module for the meaning of the flags.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{annotation_flags, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let flags = library.get_annotation_flags(&annotation_handle);
+    /// assert_eq!(flags, annotation_flags::PRINT);
+    /// ```
+    pub fn get_annotation_flags(&self, annotation: &AnnotationHandle) -> i32 {
+        unsafe { bindings::FPDFAnnot_GetFlags(annotation.handle.as_ptr()) }
+    }
+
+    /// Set the flags of an annotation, replacing all existing flags.
+    ///
+    /// See the [`annotation_flags`](crate::annotation_flags) module for the meaning of the flags.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to set the flags.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{annotation_flags, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// // Only show the annotation when printing
+    /// let flags = annotation_flags::PRINT | annotation_flags::NO_VIEW;
+    /// assert!(library.set_annotation_flags(&mut annotation_handle, flags).is_ok());
+    /// ```
+    pub fn set_annotation_flags(
+        &self,
+        annotation: &mut AnnotationHandle,
+        flags: i32,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe { bindings::FPDFAnnot_SetFlags(annotation.handle.as_ptr(), flags) })
+    }
+
+    /// Get an appearance stream of an annotation.
+    ///
+    /// Returns an empty string if the annotation doesn't have an appearance stream for `mode`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AppearanceMode, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let appearance = library.get_annotation_appearance(&annotation_handle, AppearanceMode::Normal);
+    /// assert_eq!(appearance, "");
+    /// ```
+    pub fn get_annotation_appearance(
+        &self,
+        annotation: &AnnotationHandle,
+        mode: AppearanceMode,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetAP(annotation.handle.as_ptr(), mode as _, buffer, length as _)
+                as _
+        })
+    }
+
+    /// Set an appearance stream of an annotation.
+    ///
+    /// `appearance` is the content stream used to draw the annotation when it is in `mode`.
+    /// If `appearance` is `None` the appearance stream for `mode` is removed,
+    /// removing the [`Normal`](AppearanceMode::Normal) appearance stream removes all appearance streams.
+    ///
+    /// Some viewers only draw annotations with an appearance stream,
+    /// so annotations created by PDFium should be given at least a [`Normal`](AppearanceMode::Normal) appearance stream.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to set the appearance stream.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AppearanceMode, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let appearance = "0 0 1 rg 0 0 100 100 re f";
+    /// let result = library.set_annotation_appearance(
+    ///     &mut annotation_handle,
+    ///     AppearanceMode::Normal,
+    ///     Some(appearance),
+    /// );
+    /// assert!(result.is_ok());
+    /// assert_eq!(
+    ///     library.get_annotation_appearance(&annotation_handle, AppearanceMode::Normal),
+    ///     appearance
+    /// );
+    /// ```
+    pub fn set_annotation_appearance(
+        &self,
+        annotation: &mut AnnotationHandle,
+        mode: AppearanceMode,
+        appearance: Option<&str>,
+    ) -> Result<(), PdfiumError> {
+        let appearance = appearance.map(to_wide_string);
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_SetAP(
+                annotation.handle.as_ptr(),
+                mode as _,
+                appearance
+                    .as_ref()
+                    .map_or(std::ptr::null(), |appearance| appearance.as_ptr()),
+            )
+        })
+    }
+
+    /// Get number of page objects in an annotation.
+    ///
+    /// Only ink and stamp annotations expose their page objects, `0` is returned for all other annotations.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    /// assert_eq!(library.get_annotation_object_count(&annotation_handle), 0);
+    /// ```
+    pub fn get_annotation_object_count(&self, annotation: &AnnotationHandle) -> usize {
+        let count = unsafe { bindings::FPDFAnnot_GetObjectCount(annotation.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Append a page object to an annotation.
+    ///
+    /// Ownership of the page object is transferred to the annotation and the annotation's
+    /// appearance stream is regenerated from its page objects.
+    /// The page object is positioned in page space, like objects inserted into the page.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation is not an ink or stamp annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationSubtype, Library, Matrix, Rect};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let rect = Rect { left: 100.0, bottom: 100.0, right: 200.0, top: 150.0 };
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library
+    ///     .set_page_object_matrix(&mut image_object_handle, &Matrix::from_rect(&rect))
+    ///     .unwrap();
+    ///
+    /// let mut annotation_handle = library
+    ///     .create_annotation(&mut page_handle, AnnotationSubtype::Stamp)
+    ///     .unwrap();
+    /// library.set_annotation_rect(&mut annotation_handle, &rect).unwrap();
+    ///
+    /// let result = library.append_annotation_object(&mut annotation_handle, image_object_handle);
+    /// assert!(result.is_ok());
+    /// assert_eq!(library.get_annotation_object_count(&annotation_handle), 1);
+    /// ```
+    pub fn append_annotation_object(
+        &self,
+        annotation: &mut AnnotationHandle,
+        object: OwnedPageObjectHandle,
+    ) -> Result<(), PdfiumError> {
+        let object = ManuallyDrop::new(object);
+
+        let result = to_result(unsafe {
+            bindings::FPDFAnnot_AppendObject(annotation.handle.as_ptr(), object.handle.as_ptr())
+        });
+
+        if result.is_err() {
+            // The annotation didn't take ownership of the object.
+            drop(ManuallyDrop::into_inner(object));
+        }
+
+        result
+    }
+
+    /// Get the start and end points of a line annotation.
+    ///
+    /// Returns `None` if the annotation is not a line annotation.
+    ///
+    /// PDFium doesn't expose the line ending styles (`/LE`) of line annotations.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 5).unwrap();
+    ///
+    /// let line = library.get_annotation_line(&annotation_handle);
+    /// assert_eq!(line, Some((Point { x: 50.0, y: 50.0 }, Point { x: 150.0, y: 150.0 })));
+    /// ```
+    pub fn get_annotation_line(&self, annotation: &AnnotationHandle) -> Option<(Point, Point)> {
+        let mut start = Point::default().to_fs_pointf();
+        let mut end = Point::default().to_fs_pointf();
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_GetLine(annotation.handle.as_ptr(), &mut start, &mut end)
+        })
+        .ok()
+        .map(|_| (Point::from_fs_pointf(&start), Point::from_fs_pointf(&end)))
+    }
+
+    /// Get the vertices of a polygon or polyline annotation.
+    ///
+    /// Returns an empty list if the annotation is not a polygon or polyline annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 6).unwrap();
+    ///
+    /// let vertices = library.get_annotation_vertices(&annotation_handle);
+    /// assert_eq!(vertices[0], Point { x: 300.0, y: 300.0 });
+    /// ```
+    pub fn get_annotation_vertices(&self, annotation: &AnnotationHandle) -> Vec<Point> {
+        get_points(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetVertices(annotation.handle.as_ptr(), buffer, length as _) as _
+        })
+    }
+
+    /// Get the font size of an annotation with variable text, like free text annotations and form fields.
+    ///
+    /// A font size of `0` means the font is auto-sized to fit the annotation's rect.
+    ///
+    /// Returns `None` if the annotation doesn't have variable text.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 8).unwrap();
+    ///
+    /// let font_size = library.get_annotation_font_size(&form_handle, &annotation_handle);
+    /// assert_eq!(font_size, Some(12.0));
+    /// ```
+    pub fn get_annotation_font_size(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> Option<f32> {
+        let mut font_size = 0.0;
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_GetFontSize(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                &mut font_size,
+            )
+        })
+        .ok()
+        .map(|_| font_size)
+    }
+
+    /// Get the font color of an annotation with variable text, like free text annotations and form fields.
+    ///
+    /// The returned color is always opaque.
+    ///
+    /// Returns `None` if the annotation doesn't have variable text or doesn't specify a font color.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Color, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 8).unwrap();
+    ///
+    /// let color = library.get_annotation_font_color(&form_handle, &annotation_handle);
+    /// assert_eq!(color, Some(Color::rgb(0, 0, 255)));
+    /// ```
+    pub fn get_annotation_font_color(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> Option<Color> {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_GetFontColor(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                &mut r,
+                &mut g,
+                &mut b,
+            )
+        })
+        .ok()
+        .map(|_| Color::rgb(r as u8, g as u8, b as u8))
+    }
+
+    /// Set the font color of an annotation with variable text, like free text annotations and form fields.
+    ///
+    /// The color is changed in the default appearance (`DA`) of the annotation. For form fields the appearance stream
+    /// is not regenerated, see [`Library::set_annotation_font_size`].
+    ///
+    /// The alpha of `color` is ignored.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation doesn't have its own default appearance,
+    ///   because it doesn't have variable text or because it's inherited from its field or the form.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Color, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 8).unwrap();
+    ///
+    /// let result = library.set_annotation_font_color(
+    ///     &form_handle,
+    ///     &mut annotation_handle,
+    ///     &Color::rgb(255, 0, 0),
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// Change the text color of a form field:
+    /// ```
+    /// use pdfium_core::{Color, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let result = library.set_annotation_font_color(
+    ///     &form_handle,
+    ///     &mut annotation_handle,
+    ///     &Color::rgb(255, 0, 0),
+    /// );
+    /// assert!(result.is_ok());
+    /// assert_eq!(
+    ///     library.get_annotation_string_value(&annotation_handle, "DA").unwrap(),
+    ///     "/Helv 12 Tf 1 0 0 rg"
+    /// );
+    /// ```
+    pub fn set_annotation_font_color(
+        &self,
+        form: &FormHandle,
+        annotation: &mut AnnotationHandle,
+        color: &Color,
+    ) -> Result<(), PdfiumError> {
+        // PDFium only supports free text annotations, and also updates their appearance.
+        if self.get_annotation_subtype(annotation) == AnnotationSubtype::FreeText {
+            return to_result(unsafe {
+                bindings::FPDFAnnot_SetFontColor(
+                    form.handle.as_ptr(),
+                    annotation.handle.as_ptr(),
+                    color.r as _,
+                    color.g as _,
+                    color.b as _,
+                )
+            });
+        }
+
+        self.edit_annotation_default_appearance(annotation, |operands| {
+            // Remove the current color operator with its operands.
+            if let Some(index) = operands
+                .iter()
+                .position(|x| x == "g" || x == "rg" || x == "k")
+            {
+                let count = match operands[index].as_str() {
+                    "g" => 1,
+                    "rg" => 3,
+                    _ => 4,
+                };
+                operands.drain(index.saturating_sub(count)..=index);
+            }
+
+            for component in [color.r, color.g, color.b] {
+                operands.push((f32::from(component) / 255.0).to_string());
+            }
+            operands.push("rg".to_string());
+            Some(())
+        })
+    }
+
+    /// Set the font size in the default appearance (`DA`) of an annotation with variable text, like form fields.
+    ///
+    /// A font size of `0` means the font is auto-sized to fit the annotation's rect.
+    ///
+    /// The annotation's appearance stream is not regenerated. For form fields, remove the normal appearance with
+    /// [`Library::set_annotation_appearance`], PDFium then regenerates it when the page is loaded with
+    /// [`Library::form_on_after_load_page`].
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation doesn't have its own default appearance with a font,
+    ///   for example because it's inherited from its field or the form.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let result = library.set_annotation_font_size(&mut annotation_handle, 8.0);
+    /// assert!(result.is_ok());
+    /// assert_eq!(
+    ///     library.get_annotation_string_value(&annotation_handle, "DA").unwrap(),
+    ///     "/Helv 8 Tf 0 0 1 rg"
+    /// );
+    /// ```
+    pub fn set_annotation_font_size(
+        &self,
+        annotation: &mut AnnotationHandle,
+        font_size: f32,
+    ) -> Result<(), PdfiumError> {
+        self.edit_annotation_default_appearance(annotation, |operands| {
+            let index = operands.iter().position(|x| x == "Tf")?;
+            *operands.get_mut(index.checked_sub(1)?)? = font_size.to_string();
+            Some(())
+        })
+    }
+
+    /// Change the operators and operands of the default appearance (`DA`) of an annotation.
+    ///
+    /// `edit` returns `None` if the default appearance can't be changed.
+    fn edit_annotation_default_appearance(
+        &self,
+        annotation: &mut AnnotationHandle,
+        edit: impl FnOnce(&mut Vec<String>) -> Option<()>,
+    ) -> Result<(), PdfiumError> {
+        let default_appearance = self
+            .get_annotation_string_value(annotation, "DA")
+            .ok_or(PdfiumError::Unknown)?;
+
+        let mut operands: Vec<String> = default_appearance
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+        edit(&mut operands).ok_or(PdfiumError::Unknown)?;
+
+        self.set_annotation_string_value(annotation, "DA", &operands.join(" "))
+    }
+
+    /// Get the index of an annotation in its page.
+    ///
+    /// Returns `None` if the annotation is not in `page`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 2).unwrap();
+    ///
+    /// let index = library.get_annotation_index(&page_handle, &annotation_handle);
+    /// assert_eq!(index, Some(2));
+    /// ```
+    pub fn get_annotation_index(
+        &self,
+        page: &PageHandle,
+        annotation: &AnnotationHandle,
+    ) -> Option<usize> {
+        let index = unsafe {
+            bindings::FPDFPage_GetAnnotIndex(page.handle.as_ptr(), annotation.handle.as_ptr())
+        };
+
+        Some(index as usize).filter(|_| index >= 0)
+    }
+
+    /// Get the annotation that an annotation is a reply to.
+    ///
+    /// Returns `None` if the annotation is not a reply.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let reply_handle = library.get_annotation(&page_handle, 2).unwrap();
+    ///
+    /// let comment_handle = library.get_annotation_in_reply_to(&reply_handle).unwrap();
+    /// assert_eq!(library.get_annotation_index(&page_handle, &comment_handle), Some(0));
+    /// ```
+    pub fn get_annotation_in_reply_to<'page, 'library>(
+        &'library self,
+        annotation: &AnnotationHandle<'page, '_>,
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        self.get_linked_annotation(annotation, b"IRT\0")
+    }
+
+    /// Get the popup annotation that displays the text of an annotation.
+    ///
+    /// Returns `None` if the annotation doesn't have a popup.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationSubtype, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let popup_handle = library.get_annotation_popup(&annotation_handle).unwrap();
+    /// assert_eq!(library.get_annotation_subtype(&popup_handle), AnnotationSubtype::Popup);
+    /// ```
+    pub fn get_annotation_popup<'page, 'library>(
+        &'library self,
+        annotation: &AnnotationHandle<'page, '_>,
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        self.get_linked_annotation(annotation, b"Popup\0")
+    }
+
+    /// Get the annotation that a popup annotation displays the text of.
+    ///
+    /// Returns `None` if the annotation is not a popup annotation or doesn't have a parent.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let popup_handle = library.get_annotation(&page_handle, 1).unwrap();
+    ///
+    /// let parent_handle = library.get_annotation_parent(&popup_handle).unwrap();
+    /// assert_eq!(library.get_annotation_index(&page_handle, &parent_handle), Some(0));
+    /// ```
+    pub fn get_annotation_parent<'page, 'library>(
+        &'library self,
+        annotation: &AnnotationHandle<'page, '_>,
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        self.get_linked_annotation(annotation, b"Parent\0")
+    }
+
+    /// `key` has to be nul terminated.
+    fn get_linked_annotation<'page, 'library>(
+        &'library self,
+        annotation: &AnnotationHandle<'page, '_>,
+        key: &[u8],
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFAnnot_GetLinkedAnnot(annotation.handle.as_ptr(), key.as_ptr() as _)
+        });
+
+        handle.map(AnnotationHandle::new)
+    }
+
+    /// Get the border of an annotation.
+    ///
+    /// Returns `None` if the annotation doesn't specify a border.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationBorder, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let border = library.get_annotation_border(&annotation_handle).unwrap();
+    /// assert_eq!(border.width, 2.0);
+    /// ```
+    pub fn get_annotation_border(&self, annotation: &AnnotationHandle) -> Option<AnnotationBorder> {
+        let mut border = AnnotationBorder::default();
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_GetBorder(
+                annotation.handle.as_ptr(),
+                &mut border.horizontal_radius,
+                &mut border.vertical_radius,
+                &mut border.width,
+            )
+        })
+        .ok()
+        .map(|_| border)
+    }
+
+    /// Set the border of an annotation.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to set the border.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationBorder, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// let border = AnnotationBorder { horizontal_radius: 4.0, vertical_radius: 4.0, width: 1.5 };
+    /// assert!(library.set_annotation_border(&mut annotation_handle, &border).is_ok());
+    /// ```
+    pub fn set_annotation_border(
+        &self,
+        annotation: &mut AnnotationHandle,
+        border: &AnnotationBorder,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDFAnnot_SetBorder(
+                annotation.handle.as_ptr(),
+                border.horizontal_radius,
+                border.vertical_radius,
+                border.width,
+            )
+        })
+    }
+
+    /// Get number of quadrilaterals covering the text of a text markup annotation, like a highlight annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 9).unwrap();
+    /// assert_eq!(library.get_annotation_attachment_points_count(&annotation_handle), 1);
+    /// ```
+    pub fn get_annotation_attachment_points_count(&self, annotation: &AnnotationHandle) -> usize {
+        unsafe { bindings::FPDFAnnot_CountAttachmentPoints(annotation.handle.as_ptr()) }
+    }
+
+    /// Get a quadrilateral covering the text of a text markup annotation.
+    ///
+    /// `index` 0 for the first quadrilateral.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, QuadPoints, Rect};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 9).unwrap();
+    ///
+    /// let quad_points = library.get_annotation_attachment_points(&annotation_handle, 0);
+    /// let rect = Rect { left: 72.0, bottom: 715.0, right: 200.0, top: 745.0 };
+    /// assert_eq!(quad_points, Some(QuadPoints::from_rect(&rect)));
+    /// ```
+    pub fn get_annotation_attachment_points(
+        &self,
+        annotation: &AnnotationHandle,
+        index: usize,
+    ) -> Option<QuadPoints> {
+        let mut quad_points = QuadPoints::default().to_fs_quadpointsf();
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_GetAttachmentPoints(
+                annotation.handle.as_ptr(),
+                index,
+                &mut quad_points,
+            )
+        })
+        .ok()
+        .map(|_| QuadPoints::from_fs_quadpointsf(&quad_points))
+    }
+
+    /// Append a quadrilateral covering text to a text markup annotation.
+    ///
+    /// The annotation's rect is not updated, use [`Library::set_annotation_rect`] to make it cover all quadrilaterals.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation is not a text markup or link annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, QuadPoints, Rect};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 9).unwrap();
+    ///
+    /// let rect = Rect { left: 72.0, bottom: 680.0, right: 200.0, top: 710.0 };
+    /// let result = library.append_annotation_attachment_points(
+    ///     &mut annotation_handle,
+    ///     &QuadPoints::from_rect(&rect),
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn append_annotation_attachment_points(
+        &self,
+        annotation: &mut AnnotationHandle,
+        quad_points: &QuadPoints,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDFAnnot_AppendAttachmentPoints(
+                annotation.handle.as_ptr(),
+                &quad_points.to_fs_quadpointsf(),
+            )
+        })
+    }
+
+    /// Get the JavaScript run on an event of a form field annotation.
+    ///
+    /// Returns an empty string if the annotation doesn't have JavaScript for `event`.
+    ///
+    /// PDFium only exposes the events in [`AnnotationActionEvent`], not mouse and focus events.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationActionEvent, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let javascript = library.get_annotation_action_javascript(
+    ///     &form_handle,
+    ///     &annotation_handle,
+    ///     AnnotationActionEvent::KeyStroke,
+    /// );
+    /// assert_eq!(javascript, "AFSpecial_Keystroke(0);");
+    /// ```
+    pub fn get_annotation_action_javascript(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+        event: AnnotationActionEvent,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetFormAdditionalActionJavaScript(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                event as _,
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the fully qualified name of the form field of a widget annotation.
+    ///
+    /// The names of the parent fields are joined with periods, like `"address.street"`.
+    ///
+    /// Returns an empty string if the annotation is not a widget annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let name = library.get_annotation_form_field_name(&form_handle, &annotation_handle);
+    /// assert_eq!(name, "name");
+    /// ```
+    pub fn get_annotation_form_field_name(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetFormFieldName(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the alternate name of the form field of a widget annotation, which is meant to be shown to the user.
+    ///
+    /// Returns an empty string if the field has no alternate name.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let alternate_name =
+    ///     library.get_annotation_form_field_alternate_name(&form_handle, &annotation_handle);
+    /// assert_eq!(alternate_name, "Your name");
+    /// ```
+    pub fn get_annotation_form_field_alternate_name(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetFormFieldAlternateName(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the value of the form field of a widget annotation.
+    ///
+    /// Check boxes and radio buttons have the name of their selected state as value, `"Off"` if they are not selected.
+    ///
+    /// Returns an empty string if the field has no value.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let value = library.get_annotation_form_field_value(&form_handle, &annotation_handle);
+    /// assert_eq!(value, "Alice");
+    /// ```
+    pub fn get_annotation_form_field_value(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetFormFieldValue(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the export value of the check box or radio button of a widget annotation, the value its field has when the widget is checked.
+    ///
+    /// Returns an empty string if the annotation is not a check box or radio button.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 1).unwrap();
+    ///
+    /// let value = library.get_annotation_form_field_export_value(&form_handle, &annotation_handle);
+    /// assert_eq!(value, "Yes");
+    /// ```
+    pub fn get_annotation_form_field_export_value(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetFormFieldExportValue(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the type of the form field of a widget annotation.
+    ///
+    /// Returns [`FormFieldType::Unknown`] if the annotation is not a widget annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{FormFieldType, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let field_type = library.get_annotation_form_field_type(&form_handle, &annotation_handle);
+    /// assert_eq!(field_type, FormFieldType::TextField);
+    /// ```
+    pub fn get_annotation_form_field_type(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> FormFieldType {
+        let field_type = unsafe {
+            bindings::FPDFAnnot_GetFormFieldType(form.handle.as_ptr(), annotation.handle.as_ptr())
+        };
+
+        FormFieldType::from_i32(field_type).unwrap_or(FormFieldType::Unknown)
+    }
+
+    /// Get the flags of the form field of a widget annotation.
+    ///
+    /// See [`form_field_flags`] for the flags.
+    ///
+    /// Returns [`form_field_flags::NONE`] if the annotation is not a widget annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{form_field_flags, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 5).unwrap();
+    ///
+    /// let flags = library.get_annotation_form_field_flags(&form_handle, &annotation_handle);
+    /// assert!(flags & form_field_flags::READ_ONLY != 0);
+    /// ```
+    pub fn get_annotation_form_field_flags(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> i32 {
+        unsafe {
+            bindings::FPDFAnnot_GetFormFieldFlags(form.handle.as_ptr(), annotation.handle.as_ptr())
+        }
+    }
+
+    /// Check if the check box or radio button of a widget annotation is checked.
+    ///
+    /// Returns `false` if the annotation is not a check box or radio button.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let agree = library.get_annotation(&page_handle, 1).unwrap();
+    /// let blue = library.get_annotation(&page_handle, 3).unwrap();
+    ///
+    /// assert!(!library.is_annotation_checked(&form_handle, &agree));
+    /// assert!(library.is_annotation_checked(&form_handle, &blue));
+    /// ```
+    pub fn is_annotation_checked(&self, form: &FormHandle, annotation: &AnnotationHandle) -> bool {
+        unsafe {
+            bindings::FPDFAnnot_IsChecked(form.handle.as_ptr(), annotation.handle.as_ptr()) != 0
+        }
+    }
+
+    /// Get the number of options of the combo box or list box of a widget annotation.
+    ///
+    /// Returns `None` if the annotation is not a combo box or list box.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    ///
+    /// let count = library.get_annotation_option_count(&form_handle, &annotation_handle);
+    /// assert_eq!(count, Some(3));
+    /// ```
+    pub fn get_annotation_option_count(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+    ) -> Option<usize> {
+        let count = unsafe {
+            bindings::FPDFAnnot_GetOptionCount(form.handle.as_ptr(), annotation.handle.as_ptr())
+        };
+
+        if count >= 0 {
+            Some(count as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Get the label of an option of the combo box or list box of a widget annotation.
+    ///
+    /// Returns `None` if the annotation is not a combo box or list box, or `index` is out of range.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    ///
+    /// let label = library.get_annotation_option_label(&form_handle, &annotation_handle, 1);
+    /// assert_eq!(label.as_deref(), Some("Germany"));
+    /// ```
+    pub fn get_annotation_option_label(
+        &self,
+        form: &FormHandle,
+        annotation: &AnnotationHandle,
+        index: usize,
+    ) -> Option<String> {
+        if index >= self.get_annotation_option_count(form, annotation)? {
+            return None;
+        }
+
+        Some(get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetOptionLabel(
+                form.handle.as_ptr(),
+                annotation.handle.as_ptr(),
+                index as _,
+                buffer,
+                length as _,
+            ) as _
+        }))
+    }
+
+    /// Check if the dictionary of an annotation has `key`, like `A` for an action or `AA` for additional actions.
+    ///
+    /// Returns `false` if `key` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// assert!(library.has_annotation_key(&annotation_handle, "Contents"));
+    /// assert!(!library.has_annotation_key(&annotation_handle, "AA"));
+    /// ```
+    pub fn has_annotation_key(&self, annotation: &AnnotationHandle, key: &str) -> bool {
+        match to_byte_string(key) {
+            Ok(key) => unsafe {
+                bindings::FPDFAnnot_HasKey(annotation.handle.as_ptr(), key.as_ptr()) != 0
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Get a string value from the dictionary of an annotation, like `Contents` or `T` (the author).
+    ///
+    /// Returns `None` if the annotation doesn't have `key` or `key` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let contents = library.get_annotation_string_value(&annotation_handle, "Contents");
+    /// assert_eq!(contents.as_deref(), Some("Comment"));
+    /// ```
+    pub fn get_annotation_string_value(
+        &self,
+        annotation: &AnnotationHandle,
+        key: &str,
+    ) -> Option<String> {
+        if !self.has_annotation_key(annotation, key) {
+            return None;
+        }
+        let key = to_byte_string(key).ok()?;
+
+        Some(get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAnnot_GetStringValue(
+                annotation.handle.as_ptr(),
+                key.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        }))
+    }
+
+    /// Set a string value in the dictionary of an annotation, like `Contents` or `T` (the author).
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `key` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let result = library.set_annotation_string_value(&mut annotation_handle, "T", "Carol");
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_annotation_string_value(
+        &self,
+        annotation: &mut AnnotationHandle,
+        key: &str,
+        value: &str,
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+        let value = to_wide_string(value);
+
+        to_result(unsafe {
+            bindings::FPDFAnnot_SetStringValue(
+                annotation.handle.as_ptr(),
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        })
+    }
+}
+
+/// The subtype of an annotation.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnnotationSubtype {
+    /// Unknown subtype.
+    Unknown = bindings::FPDF_ANNOT_UNKNOWN as i32,
+    /// Sticky note.
+    Text = bindings::FPDF_ANNOT_TEXT as i32,
+    /// Hypertext link.
+    Link = bindings::FPDF_ANNOT_LINK as i32,
+    /// Text drawn directly on the page.
+    FreeText = bindings::FPDF_ANNOT_FREETEXT as i32,
+    /// Straight line.
+    Line = bindings::FPDF_ANNOT_LINE as i32,
+    /// Rectangle.
+    Square = bindings::FPDF_ANNOT_SQUARE as i32,
+    /// Ellipse.
+    Circle = bindings::FPDF_ANNOT_CIRCLE as i32,
+    /// Closed polygon.
+    Polygon = bindings::FPDF_ANNOT_POLYGON as i32,
+    /// Open polygon.
+    Polyline = bindings::FPDF_ANNOT_POLYLINE as i32,
+    /// Highlighted text.
+    Highlight = bindings::FPDF_ANNOT_HIGHLIGHT as i32,
+    /// Underlined text.
+    Underline = bindings::FPDF_ANNOT_UNDERLINE as i32,
+    /// Text with a jagged underline.
+    Squiggly = bindings::FPDF_ANNOT_SQUIGGLY as i32,
+    /// Struck out text.
+    StrikeOut = bindings::FPDF_ANNOT_STRIKEOUT as i32,
+    /// Rubber stamp.
+    Stamp = bindings::FPDF_ANNOT_STAMP as i32,
+    /// Text insertion mark.
+    Caret = bindings::FPDF_ANNOT_CARET as i32,
+    /// Freehand drawing.
+    Ink = bindings::FPDF_ANNOT_INK as i32,
+    /// Pop-up window of another annotation.
+    Popup = bindings::FPDF_ANNOT_POPUP as i32,
+    /// Embedded file.
+    FileAttachment = bindings::FPDF_ANNOT_FILEATTACHMENT as i32,
+    /// Sound clip.
+    Sound = bindings::FPDF_ANNOT_SOUND as i32,
+    /// Movie clip.
+    Movie = bindings::FPDF_ANNOT_MOVIE as i32,
+    /// Interactive form field.
+    Widget = bindings::FPDF_ANNOT_WIDGET as i32,
+    /// Media clip.
+    Screen = bindings::FPDF_ANNOT_SCREEN as i32,
+    /// Printer's mark.
+    PrinterMark = bindings::FPDF_ANNOT_PRINTERMARK as i32,
+    /// Trap network.
+    TrapNet = bindings::FPDF_ANNOT_TRAPNET as i32,
+    /// Watermark.
+    Watermark = bindings::FPDF_ANNOT_WATERMARK as i32,
+    /// 3D artwork.
+    ThreeD = bindings::FPDF_ANNOT_THREED as i32,
+    /// Rich media.
+    RichMedia = bindings::FPDF_ANNOT_RICHMEDIA as i32,
+    /// XFA form field.
+    XfaWidget = bindings::FPDF_ANNOT_XFAWIDGET as i32,
+    /// Content marked for redaction.
+    Redact = bindings::FPDF_ANNOT_REDACT as i32,
+}
+
+impl AnnotationSubtype {
+    fn from_i32(number: i32) -> Option<AnnotationSubtype> {
+        match number {
+            x if x == AnnotationSubtype::Unknown as i32 => Some(AnnotationSubtype::Unknown),
+            x if x == AnnotationSubtype::Text as i32 => Some(AnnotationSubtype::Text),
+            x if x == AnnotationSubtype::Link as i32 => Some(AnnotationSubtype::Link),
+            x if x == AnnotationSubtype::FreeText as i32 => Some(AnnotationSubtype::FreeText),
+            x if x == AnnotationSubtype::Line as i32 => Some(AnnotationSubtype::Line),
+            x if x == AnnotationSubtype::Square as i32 => Some(AnnotationSubtype::Square),
+            x if x == AnnotationSubtype::Circle as i32 => Some(AnnotationSubtype::Circle),
+            x if x == AnnotationSubtype::Polygon as i32 => Some(AnnotationSubtype::Polygon),
+            x if x == AnnotationSubtype::Polyline as i32 => Some(AnnotationSubtype::Polyline),
+            x if x == AnnotationSubtype::Highlight as i32 => Some(AnnotationSubtype::Highlight),
+            x if x == AnnotationSubtype::Underline as i32 => Some(AnnotationSubtype::Underline),
+            x if x == AnnotationSubtype::Squiggly as i32 => Some(AnnotationSubtype::Squiggly),
+            x if x == AnnotationSubtype::StrikeOut as i32 => Some(AnnotationSubtype::StrikeOut),
+            x if x == AnnotationSubtype::Stamp as i32 => Some(AnnotationSubtype::Stamp),
+            x if x == AnnotationSubtype::Caret as i32 => Some(AnnotationSubtype::Caret),
+            x if x == AnnotationSubtype::Ink as i32 => Some(AnnotationSubtype::Ink),
+            x if x == AnnotationSubtype::Popup as i32 => Some(AnnotationSubtype::Popup),
+            x if x == AnnotationSubtype::FileAttachment as i32 => {
+                Some(AnnotationSubtype::FileAttachment)
+            }
+            x if x == AnnotationSubtype::Sound as i32 => Some(AnnotationSubtype::Sound),
+            x if x == AnnotationSubtype::Movie as i32 => Some(AnnotationSubtype::Movie),
+            x if x == AnnotationSubtype::Widget as i32 => Some(AnnotationSubtype::Widget),
+            x if x == AnnotationSubtype::Screen as i32 => Some(AnnotationSubtype::Screen),
+            x if x == AnnotationSubtype::PrinterMark as i32 => Some(AnnotationSubtype::PrinterMark),
+            x if x == AnnotationSubtype::TrapNet as i32 => Some(AnnotationSubtype::TrapNet),
+            x if x == AnnotationSubtype::Watermark as i32 => Some(AnnotationSubtype::Watermark),
+            x if x == AnnotationSubtype::ThreeD as i32 => Some(AnnotationSubtype::ThreeD),
+            x if x == AnnotationSubtype::RichMedia as i32 => Some(AnnotationSubtype::RichMedia),
+            x if x == AnnotationSubtype::XfaWidget as i32 => Some(AnnotationSubtype::XfaWidget),
+            x if x == AnnotationSubtype::Redact as i32 => Some(AnnotationSubtype::Redact),
+            _ => None,
+        }
+    }
+}
+
+/// The colors of an annotation.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AnnotationColorType {
+    /// The color of the annotation's border, or of the annotation itself if it is drawn without a border.
+    Stroke = 0,
+    /// The color used to fill the annotation's interior.
+    Interior = 1,
+}
+
+pub mod annotation_flags {
+    //! Annotation flags used by [`get_annotation_flags`](crate::Library::get_annotation_flags)
+    //! and [`set_annotation_flags`](crate::Library::set_annotation_flags), as defined in ISO 32000-1:2008, table 165.
+    //! They are combined with bit-wise OR.
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::annotation_flags::*;
+    //!
+    //! // Print the annotation, but don't display it
+    //! let flags = PRINT | NO_VIEW;
+    //! assert!(flags & HIDDEN == 0);
+    //! ```
+
+    use crate::bindings;
+
+    /// No flags.
+    pub const NONE: i32 = bindings::FPDF_ANNOT_FLAG_NONE as i32;
+
+    /// Don't display the annotation if its subtype is not supported.
+    pub const INVISIBLE: i32 = bindings::FPDF_ANNOT_FLAG_INVISIBLE as i32;
+
+    /// Neither display nor print the annotation.
+    pub const HIDDEN: i32 = bindings::FPDF_ANNOT_FLAG_HIDDEN as i32;
+
+    /// Print the annotation when the page is printed.
+    pub const PRINT: i32 = bindings::FPDF_ANNOT_FLAG_PRINT as i32;
+
+    /// Don't scale the annotation's appearance with the page's zoom.
+    pub const NO_ZOOM: i32 = bindings::FPDF_ANNOT_FLAG_NOZOOM as i32;
+
+    /// Don't rotate the annotation's appearance with the page's rotation.
+    pub const NO_ROTATE: i32 = bindings::FPDF_ANNOT_FLAG_NOROTATE as i32;
+
+    /// Don't display the annotation on screen, it may still be printed.
+    pub const NO_VIEW: i32 = bindings::FPDF_ANNOT_FLAG_NOVIEW as i32;
+
+    /// Don't let the user interact with the annotation.
+    pub const READ_ONLY: i32 = bindings::FPDF_ANNOT_FLAG_READONLY as i32;
+
+    /// Don't let the user delete the annotation or change its properties.
+    pub const LOCKED: i32 = bindings::FPDF_ANNOT_FLAG_LOCKED as i32;
+
+    /// Invert [`NO_VIEW`] for certain events, such as the mouse hovering over the annotation.
+    pub const TOGGLE_NO_VIEW: i32 = bindings::FPDF_ANNOT_FLAG_TOGGLENOVIEW as i32;
+}
+
+/// The events of a form field annotation that can run JavaScript.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AnnotationActionEvent {
+    /// Run when the user types into the field or changes the selection of a choice field.
+    KeyStroke = bindings::FPDF_ANNOT_AACTION_KEY_STROKE as i32,
+    /// Run before the field's value is displayed, to format it.
+    Format = bindings::FPDF_ANNOT_AACTION_FORMAT as i32,
+    /// Run when the field's value changes, to check it.
+    Validate = bindings::FPDF_ANNOT_AACTION_VALIDATE as i32,
+    /// Run when the value of another field changes, to recalculate the field's value.
+    Calculate = bindings::FPDF_ANNOT_AACTION_CALCULATE as i32,
+}
+
+/// The type of a form field.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FormFieldType {
+    /// Unknown type, or the annotation is not a widget annotation.
+    Unknown = bindings::FPDF_FORMFIELD_UNKNOWN as i32,
+    /// Button without a value, like a submit button.
+    PushButton = bindings::FPDF_FORMFIELD_PUSHBUTTON as i32,
+    /// Button that can be toggled on and off.
+    CheckBox = bindings::FPDF_FORMFIELD_CHECKBOX as i32,
+    /// One of a group of buttons of which only one can be selected.
+    RadioButton = bindings::FPDF_FORMFIELD_RADIOBUTTON as i32,
+    /// Drop down list of options, which may allow entering text.
+    ComboBox = bindings::FPDF_FORMFIELD_COMBOBOX as i32,
+    /// Scrollable list of options.
+    ListBox = bindings::FPDF_FORMFIELD_LISTBOX as i32,
+    /// Field to enter text.
+    TextField = bindings::FPDF_FORMFIELD_TEXTFIELD as i32,
+    /// Field for a digital signature.
+    Signature = bindings::FPDF_FORMFIELD_SIGNATURE as i32,
+}
+
+impl FormFieldType {
+    pub(crate) fn from_i32(number: i32) -> Option<FormFieldType> {
+        match number {
+            x if x == FormFieldType::Unknown as i32 => Some(FormFieldType::Unknown),
+            x if x == FormFieldType::PushButton as i32 => Some(FormFieldType::PushButton),
+            x if x == FormFieldType::CheckBox as i32 => Some(FormFieldType::CheckBox),
+            x if x == FormFieldType::RadioButton as i32 => Some(FormFieldType::RadioButton),
+            x if x == FormFieldType::ComboBox as i32 => Some(FormFieldType::ComboBox),
+            x if x == FormFieldType::ListBox as i32 => Some(FormFieldType::ListBox),
+            x if x == FormFieldType::TextField as i32 => Some(FormFieldType::TextField),
+            x if x == FormFieldType::Signature as i32 => Some(FormFieldType::Signature),
+            _ => None,
+        }
+    }
+}
+
+pub mod form_field_flags {
+    //! Form field flags returned by [`get_annotation_form_field_flags`](crate::Library::get_annotation_form_field_flags),
+    //! as defined in ISO 32000-1:2008, tables 221, 228 and 230.
+    //! They are combined with bit-wise OR.
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::form_field_flags::*;
+    //!
+    //! let flags = READ_ONLY | REQUIRED;
+    //! assert!(flags & NO_EXPORT == 0);
+    //! ```
+
+    use crate::bindings;
+
+    /// No flags.
+    pub const NONE: i32 = bindings::FPDF_FORMFLAG_NONE as i32;
+
+    /// The user may not change the value of the field.
+    pub const READ_ONLY: i32 = bindings::FPDF_FORMFLAG_READONLY as i32;
+
+    /// The field must have a value when the form is submitted.
+    pub const REQUIRED: i32 = bindings::FPDF_FORMFLAG_REQUIRED as i32;
+
+    /// The field is not exported when the form is submitted.
+    pub const NO_EXPORT: i32 = bindings::FPDF_FORMFLAG_NOEXPORT as i32;
+
+    /// The text field may contain multiple lines of text.
+    pub const TEXT_MULTILINE: i32 = bindings::FPDF_FORMFLAG_TEXT_MULTILINE as i32;
+
+    /// The text field is a password field, its text is not displayed.
+    pub const TEXT_PASSWORD: i32 = bindings::FPDF_FORMFLAG_TEXT_PASSWORD as i32;
+
+    /// The choice field is a combo box, otherwise it is a list box.
+    pub const CHOICE_COMBO: i32 = bindings::FPDF_FORMFLAG_CHOICE_COMBO as i32;
+
+    /// The combo box allows entering a custom value.
+    pub const CHOICE_EDIT: i32 = bindings::FPDF_FORMFLAG_CHOICE_EDIT as i32;
+
+    /// More than one option of the choice field may be selected.
+    pub const CHOICE_MULTI_SELECT: i32 = bindings::FPDF_FORMFLAG_CHOICE_MULTI_SELECT as i32;
+}
+
+/// The border of an annotation.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnnotationBorder {
+    /// The horizontal radius of the border's rounded corners.
+    pub horizontal_radius: f32,
+    /// The vertical radius of the border's rounded corners.
+    pub vertical_radius: f32,
+    /// The width of the border, `0` for no border.
+    pub width: f32,
+}
+
+/// The appearance modes of an annotation, each with its own appearance stream.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AppearanceMode {
+    /// Used when the user isn't interacting with the annotation.
+    Normal = bindings::FPDF_ANNOT_APPEARANCEMODE_NORMAL as i32,
+    /// Used when the mouse hovers over the annotation.
+    Rollover = bindings::FPDF_ANNOT_APPEARANCEMODE_ROLLOVER as i32,
+    /// Used when the mouse button is pressed on the annotation.
+    Down = bindings::FPDF_ANNOT_APPEARANCEMODE_DOWN as i32,
+}
+
+/// Safe handle to PDFium Annotation.
+///
+/// Created using [`Library::get_annotation`].
+///
+/// Annotation is closed when handle is dropped. The annotation itself stays in its page.
+pub struct AnnotationHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_annotation_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(AnnotationHandle: Sync, Send);
+
+impl AnnotationHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_annotation_t__>) -> Self {
+        AnnotationHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+impl Drop for AnnotationHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FPDFPage_CloseAnnot(self.handle.as_ptr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+
+    #[test]
+    fn annotations() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let count = library.get_annotation_count(&page);
+        let subtypes: Vec<_> = (0..count)
+            .map(|index| library.get_annotation(&page, index).unwrap())
+            .map(|annotation| library.get_annotation_subtype(&annotation))
+            .collect();
+
+        assert_eq!(
+            subtypes,
+            [
+                AnnotationSubtype::Text,
+                AnnotationSubtype::Popup,
+                AnnotationSubtype::Text,
+                AnnotationSubtype::Square,
+                AnnotationSubtype::Ink,
+                AnnotationSubtype::Line,
+                AnnotationSubtype::Polygon,
+                AnnotationSubtype::Link,
+                AnnotationSubtype::FreeText,
+                AnnotationSubtype::Highlight,
+            ]
+        );
+
+        assert!(library.get_annotation(&page, count).is_none());
+    }
+
+    #[test]
+    fn no_annotations() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        assert_eq!(library.get_annotation_count(&page), 0);
+        assert!(library.get_annotation(&page, 0).is_none());
+    }
+
+    #[test]
+    fn remove_annotation() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+
+        library.remove_annotation(&mut page, 3).unwrap();
+        assert_eq!(library.get_annotation_count(&page), 9);
+
+        let annotation = library.get_annotation(&page, 3).unwrap();
+        assert_eq!(
+            library.get_annotation_subtype(&annotation),
+            AnnotationSubtype::Ink
+        );
+        drop(annotation);
+
+        assert!(library.remove_annotation(&mut page, 9).is_err());
+        assert_eq!(library.get_annotation_count(&page), 9);
+    }
+
+    #[test]
+    fn rect() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut annotation = library.get_annotation(&page, 3).unwrap();
+
+        let rect = Rect {
+            left: 100.0,
+            bottom: 100.0,
+            right: 200.0,
+            top: 200.0,
+        };
+        assert_eq!(library.get_annotation_rect(&annotation), Some(rect));
+
+        let rect = Rect {
+            left: 10.0,
+            bottom: 20.0,
+            right: 30.0,
+            top: 40.0,
+        };
+        library.set_annotation_rect(&mut annotation, &rect).unwrap();
+        assert_eq!(library.get_annotation_rect(&annotation), Some(rect));
+    }
+
+    #[test]
+    fn colors() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut annotation = library.get_annotation(&page, 3).unwrap();
+
+        assert_eq!(
+            library.get_annotation_color(&annotation, AnnotationColorType::Stroke),
+            Some(Color::rgb(0, 0, 255))
+        );
+        assert_eq!(
+            library.get_annotation_color(&annotation, AnnotationColorType::Interior),
+            Some(Color::rgb(255, 255, 0))
+        );
+
+        let color = Color {
+            r: 10,
+            g: 20,
+            b: 30,
+            a: 128,
+        };
+        library
+            .set_annotation_color(&mut annotation, AnnotationColorType::Stroke, &color)
+            .unwrap();
+        assert_eq!(
+            library.get_annotation_color(&annotation, AnnotationColorType::Stroke),
+            Some(color)
+        );
+    }
+
+    #[test]
+    fn ink_list() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut annotation = library.get_annotation(&page, 4).unwrap();
+
+        assert_eq!(library.get_annotation_ink_list_count(&annotation), 2);
+        assert_eq!(
+            library.get_annotation_ink_list_path(&annotation, 0),
+            [
+                Point { x: 10.0, y: 10.0 },
+                Point { x: 20.0, y: 20.0 },
+                Point { x: 30.0, y: 10.0 },
+            ]
+        );
+        assert!(library
+            .get_annotation_ink_list_path(&annotation, 2)
+            .is_empty());
+
+        library.remove_annotation_ink_list(&mut annotation).unwrap();
+        assert_eq!(library.get_annotation_ink_list_count(&annotation), 0);
+
+        let stroke = [Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }];
+        assert_eq!(
+            library.add_annotation_ink_stroke(&mut annotation, &stroke),
+            Ok(0)
+        );
+        assert_eq!(library.get_annotation_ink_list_path(&annotation, 0), stroke);
+        assert!(library
+            .add_annotation_ink_stroke(&mut annotation, &[])
+            .is_err());
+
+        let square = library.get_annotation(&page, 3).unwrap();
+        assert_eq!(library.get_annotation_ink_list_count(&square), 0);
+    }
+
+    #[test]
+    fn flags() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let mut annotation = library.get_annotation(&page, 3).unwrap();
+        assert_eq!(
+            library.get_annotation_flags(&annotation),
+            annotation_flags::PRINT
+        );
+
+        let flags = annotation_flags::PRINT | annotation_flags::LOCKED;
+        library
+            .set_annotation_flags(&mut annotation, flags)
+            .unwrap();
+        assert_eq!(library.get_annotation_flags(&annotation), flags);
+
+        let annotation = library.get_annotation(&page, 4).unwrap();
+        assert_eq!(
+            library.get_annotation_flags(&annotation),
+            annotation_flags::NONE
+        );
+    }
+
+    #[test]
+    fn appearance() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut annotation = library.get_annotation(&page, 3).unwrap();
+
+        assert_eq!(
+            library.get_annotation_appearance(&annotation, AppearanceMode::Normal),
+            ""
+        );
+
+        let normal = "0 0 1 RG 0 0 100 100 re S";
+        let rollover = "1 0 0 RG 0 0 100 100 re S";
+        library
+            .set_annotation_appearance(&mut annotation, AppearanceMode::Normal, Some(normal))
+            .unwrap();
+        library
+            .set_annotation_appearance(&mut annotation, AppearanceMode::Rollover, Some(rollover))
+            .unwrap();
+        assert_eq!(
+            library.get_annotation_appearance(&annotation, AppearanceMode::Normal),
+            normal
+        );
+        assert_eq!(
+            library.get_annotation_appearance(&annotation, AppearanceMode::Rollover),
+            rollover
+        );
+        assert_eq!(
+            library.get_annotation_appearance(&annotation, AppearanceMode::Down),
+            ""
+        );
+
+        library
+            .set_annotation_appearance(&mut annotation, AppearanceMode::Normal, None)
+            .unwrap();
+        assert_eq!(
+            library.get_annotation_appearance(&annotation, AppearanceMode::Rollover),
+            ""
+        );
+    }
+
+    #[test]
+    fn stamp() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+
+        let rect = Rect {
+            left: 100.0,
+            bottom: 100.0,
+            right: 200.0,
+            top: 150.0,
+        };
+        let mut image = library.create_image_object(&document).unwrap();
+        library
+            .set_page_object_matrix(&mut image, &crate::Matrix::from_rect(&rect))
+            .unwrap();
+
+        let mut annotation = library
+            .create_annotation(&mut page, AnnotationSubtype::Stamp)
+            .unwrap();
+        library.set_annotation_rect(&mut annotation, &rect).unwrap();
+        library
+            .append_annotation_object(&mut annotation, image)
+            .unwrap();
+        assert_eq!(library.get_annotation_object_count(&annotation), 1);
+        drop(annotation);
+
+        assert_eq!(library.get_annotation_count(&page), 1);
+
+        let mut square = library
+            .create_annotation(&mut page, AnnotationSubtype::Square)
+            .unwrap();
+        let image = library.create_image_object(&document).unwrap();
+        assert!(library
+            .append_annotation_object(&mut square, image)
+            .is_err());
+    }
+
+    #[test]
+    fn line() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let line = library.get_annotation(&page, 5).unwrap();
+        assert_eq!(
+            library.get_annotation_line(&line),
+            Some((Point { x: 50.0, y: 50.0 }, Point { x: 150.0, y: 150.0 }))
+        );
+
+        let square = library.get_annotation(&page, 3).unwrap();
+        assert_eq!(library.get_annotation_line(&square), None);
+    }
+
+    #[test]
+    fn vertices() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let polygon = library.get_annotation(&page, 6).unwrap();
+        assert_eq!(
+            library.get_annotation_vertices(&polygon),
+            [
+                Point { x: 300.0, y: 300.0 },
+                Point { x: 400.0, y: 300.0 },
+                Point { x: 350.0, y: 400.0 },
+            ]
+        );
+
+        let line = library.get_annotation(&page, 5).unwrap();
+        assert!(library.get_annotation_vertices(&line).is_empty());
+    }
+
+    #[test]
+    fn font() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut free_text = library.get_annotation(&page, 8).unwrap();
+
+        assert_eq!(
+            library.get_annotation_font_size(&form, &free_text),
+            Some(12.0)
+        );
+        assert_eq!(
+            library.get_annotation_font_color(&form, &free_text),
+            Some(Color::rgb(0, 0, 255))
+        );
+
+        library
+            .set_annotation_font_color(&form, &mut free_text, &Color::rgb(10, 20, 30))
+            .unwrap();
+        assert_eq!(
+            library.get_annotation_font_color(&form, &free_text),
+            Some(Color::rgb(10, 20, 30))
+        );
+
+        let mut square = library.get_annotation(&page, 3).unwrap();
+        assert!(library
+            .set_annotation_font_color(&form, &mut square, &Color::rgb(10, 20, 30))
+            .is_err());
+    }
+
+    #[test]
+    fn linked_annotations() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let comment = library.get_annotation(&page, 0).unwrap();
+        let popup = library.get_annotation_popup(&comment).unwrap();
+        assert_eq!(library.get_annotation_index(&page, &popup), Some(1));
+        assert!(library.get_annotation_in_reply_to(&comment).is_none());
+
+        let parent = library.get_annotation_parent(&popup).unwrap();
+        assert_eq!(library.get_annotation_index(&page, &parent), Some(0));
+
+        let reply = library.get_annotation(&page, 2).unwrap();
+        let in_reply_to = library.get_annotation_in_reply_to(&reply).unwrap();
+        assert_eq!(library.get_annotation_index(&page, &in_reply_to), Some(0));
+        assert!(library.get_annotation_popup(&reply).is_none());
+
+        let other_document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let other_page = library.load_page(&other_document, 0).unwrap();
+        assert_eq!(library.get_annotation_index(&other_page, &comment), None);
+    }
+
+    #[test]
+    fn border() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut annotation = library.get_annotation(&page, 3).unwrap();
+
+        assert_eq!(
+            library.get_annotation_border(&annotation),
+            Some(AnnotationBorder {
+                horizontal_radius: 0.0,
+                vertical_radius: 0.0,
+                width: 2.0,
+            })
+        );
+
+        let border = AnnotationBorder {
+            horizontal_radius: 3.0,
+            vertical_radius: 5.0,
+            width: 0.5,
+        };
+        library
+            .set_annotation_border(&mut annotation, &border)
+            .unwrap();
+        assert_eq!(library.get_annotation_border(&annotation), Some(border));
+    }
+
+    #[test]
+    fn attachment_points() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut highlight = library.get_annotation(&page, 9).unwrap();
+
+        assert_eq!(
+            library.get_annotation_attachment_points_count(&highlight),
+            1
+        );
+        assert!(library
+            .get_annotation_attachment_points(&highlight, 1)
+            .is_none());
+
+        let quad_points = QuadPoints::from_rect(&Rect {
+            left: 10.0,
+            bottom: 20.0,
+            right: 30.0,
+            top: 40.0,
+        });
+        library
+            .append_annotation_attachment_points(&mut highlight, &quad_points)
+            .unwrap();
+        assert_eq!(
+            library.get_annotation_attachment_points_count(&highlight),
+            2
+        );
+        assert_eq!(
+            library.get_annotation_attachment_points(&highlight, 1),
+            Some(quad_points)
+        );
+
+        let mut square = library.get_annotation(&page, 3).unwrap();
+        assert!(library
+            .append_annotation_attachment_points(&mut square, &quad_points)
+            .is_err());
+    }
+
+    #[test]
+    fn action_javascript() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let name = library.get_annotation(&page, 0).unwrap();
+
+        assert_eq!(
+            library.get_annotation_action_javascript(
+                &form,
+                &name,
+                AnnotationActionEvent::KeyStroke
+            ),
+            "AFSpecial_Keystroke(0);"
+        );
+        assert_eq!(
+            library.get_annotation_action_javascript(&form, &name, AnnotationActionEvent::Format),
+            "event.value = event.value.toUpperCase();"
+        );
+        assert_eq!(
+            library.get_annotation_action_javascript(&form, &name, AnnotationActionEvent::Validate),
+            ""
+        );
+    }
+
+    #[test]
+    fn string_values() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut annotation = library.get_annotation(&page, 0).unwrap();
+
+        assert_eq!(
+            library
+                .get_annotation_string_value(&annotation, "T")
+                .as_deref(),
+            Some("Alice")
+        );
+        assert_eq!(
+            library.get_annotation_string_value(&annotation, "Subj"),
+            None
+        );
+        assert_eq!(
+            library.get_annotation_string_value(&annotation, "T\0"),
+            None
+        );
+
+        library
+            .set_annotation_string_value(&mut annotation, "Subj", "Review")
+            .unwrap();
+        assert_eq!(
+            library
+                .get_annotation_string_value(&annotation, "Subj")
+                .as_deref(),
+            Some("Review")
+        );
+        assert!(library
+            .set_annotation_string_value(&mut annotation, "T\0", "Carol")
+            .is_err());
+    }
+
+    #[test]
+    fn form_fields() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let name = library.get_annotation(&page, 0).unwrap();
+        assert_eq!(library.get_annotation_form_field_name(&form, &name), "name");
+        assert_eq!(
+            library.get_annotation_form_field_alternate_name(&form, &name),
+            "Your name"
+        );
+        assert_eq!(
+            library.get_annotation_form_field_value(&form, &name),
+            "Alice"
+        );
+        assert_eq!(
+            library.get_annotation_form_field_type(&form, &name),
+            FormFieldType::TextField
+        );
+        assert_eq!(
+            library.get_annotation_form_field_flags(&form, &name),
+            form_field_flags::NONE
+        );
+
+        let agree = library.get_annotation(&page, 1).unwrap();
+        assert_eq!(
+            library.get_annotation_form_field_type(&form, &agree),
+            FormFieldType::CheckBox
+        );
+        assert_eq!(
+            library.get_annotation_form_field_value(&form, &agree),
+            "Off"
+        );
+
+        let blue = library.get_annotation(&page, 3).unwrap();
+        assert_eq!(
+            library.get_annotation_form_field_name(&form, &blue),
+            "color"
+        );
+        assert_eq!(
+            library.get_annotation_form_field_type(&form, &blue),
+            FormFieldType::RadioButton
+        );
+        assert_eq!(
+            library.get_annotation_form_field_value(&form, &blue),
+            "Blue"
+        );
+
+        let country = library.get_annotation(&page, 4).unwrap();
+        assert_eq!(
+            library.get_annotation_form_field_type(&form, &country),
+            FormFieldType::ComboBox
+        );
+        assert_eq!(
+            library.get_annotation_form_field_flags(&form, &country),
+            form_field_flags::CHOICE_COMBO
+        );
+
+        let id = library.get_annotation(&page, 5).unwrap();
+        assert_eq!(
+            library.get_annotation_form_field_flags(&form, &id),
+            form_field_flags::READ_ONLY | form_field_flags::REQUIRED
+        );
+    }
+
+    #[test]
+    fn default_appearance() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let mut name = library.get_annotation(&page, 0).unwrap();
+        library.set_annotation_font_size(&mut name, 8.5).unwrap();
+        library
+            .set_annotation_font_color(&form, &mut name, &Color::rgb(255, 0, 0))
+            .unwrap();
+        assert_eq!(
+            library.get_annotation_string_value(&name, "DA").unwrap(),
+            "/Helv 8.5 Tf 1 0 0 rg"
+        );
+
+        assert_eq!(library.get_annotation_font_size(&form, &name), Some(8.5));
+        assert_eq!(
+            library.get_annotation_font_color(&form, &name),
+            Some(Color::rgb(255, 0, 0))
+        );
+
+        // The default appearance of "agree" is inherited from the form
+        let mut agree = library.get_annotation(&page, 1).unwrap();
+        assert_eq!(
+            library.set_annotation_font_size(&mut agree, 8.0),
+            Err(PdfiumError::Unknown)
+        );
+    }
+
+    #[test]
+    fn form_field_options() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let name = library.get_annotation(&page, 0).unwrap();
+        assert_eq!(library.get_annotation_option_count(&form, &name), None);
+        assert!(!library.is_annotation_checked(&form, &name));
+
+        let red = library.get_annotation(&page, 2).unwrap();
+        let blue = library.get_annotation(&page, 3).unwrap();
+        assert_eq!(
+            library.get_annotation_form_field_export_value(&form, &red),
+            "Red"
+        );
+        assert_eq!(
+            library.get_annotation_form_field_export_value(&form, &blue),
+            "Blue"
+        );
+        assert_eq!(
+            library.get_annotation_form_field_export_value(&form, &name),
+            ""
+        );
+        assert!(!library.is_annotation_checked(&form, &red));
+        assert!(library.is_annotation_checked(&form, &blue));
+
+        let country = library.get_annotation(&page, 4).unwrap();
+        let labels: Vec<_> = (0..4)
+            .map(|index| library.get_annotation_option_label(&form, &country, index))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                Some("France".to_string()),
+                Some("Germany".to_string()),
+                Some("Japan".to_string()),
+                None
+            ]
+        );
+    }
+}
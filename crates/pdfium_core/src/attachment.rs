@@ -0,0 +1,531 @@
+use crate::{
+    bindings, get_wide_string, to_byte_string, to_result, to_wide_string, DocumentHandle, Library,
+    PdfiumError,
+};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+impl Library {
+    /// Get the number of files embedded in a document, the entries of its `EmbeddedFiles` name tree.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(library.get_attachment_count(&document_handle), 2);
+    /// ```
+    pub fn get_attachment_count(&self, document: &DocumentHandle) -> usize {
+        let count = unsafe { bindings::FPDFDoc_GetAttachmentCount(document.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a file embedded in a document.
+    ///
+    /// `index` 0 for the first attachment.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.get_attachment(&document_handle, 0);
+    /// assert!(attachment_handle.is_some());
+    /// ```
+    pub fn get_attachment<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        index: usize,
+    ) -> Option<AttachmentHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFDoc_GetAttachment(document.handle.as_ptr(), index as _)
+        });
+
+        handle.map(|handle| AttachmentHandle::new(handle, document.handle))
+    }
+
+    /// Get the name of an attachment, its key in the `EmbeddedFiles` name tree, usually the file name.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.get_attachment(&document_handle, 0).unwrap();
+    ///
+    /// let name = library.get_attachment_name(&attachment_handle);
+    /// assert_eq!(name, "factur-x.xml");
+    /// ```
+    pub fn get_attachment_name(&self, attachment: &AttachmentHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAttachment_GetName(attachment.handle.as_ptr(), buffer, length as _) as _
+        })
+    }
+
+    /// Get the MIME type of an attachment, the `Subtype` entry of its file stream, like `text/xml`.
+    ///
+    /// Returns an empty string if the attachment doesn't have a MIME type.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.get_attachment(&document_handle, 0).unwrap();
+    ///
+    /// let mime_type = library.get_attachment_mime_type(&attachment_handle);
+    /// assert_eq!(mime_type, "text/xml");
+    /// ```
+    pub fn get_attachment_mime_type(&self, attachment: &AttachmentHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAttachment_GetSubtype(attachment.handle.as_ptr(), buffer, length as _)
+                as _
+        })
+    }
+
+    /// Check if the parameters of an attachment have an entry, like `Size`, `CheckSum`, `CreationDate` or `ModDate`.
+    ///
+    /// Returns `false` if `key` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.get_attachment(&document_handle, 0).unwrap();
+    ///
+    /// assert!(library.attachment_has_key(&attachment_handle, "CheckSum"));
+    /// assert!(!library.attachment_has_key(&attachment_handle, "Mac"));
+    /// ```
+    pub fn attachment_has_key(&self, attachment: &AttachmentHandle, key: &str) -> bool {
+        let key = match to_byte_string(key) {
+            Ok(key) => key,
+            Err(_) => return false,
+        };
+
+        unsafe { bindings::FPDFAttachment_HasKey(attachment.handle.as_ptr(), key.as_ptr()) != 0 }
+    }
+
+    /// Get a string entry of the parameters of an attachment, like `CreationDate` or `ModDate`.
+    ///
+    /// Dates are in the PDF date format, like `D:20240105120000+01'00'`.
+    /// The `CheckSum` entry, an MD5 hash of the file data, is returned as hexadecimal digits.
+    ///
+    /// Returns an empty string if the parameters don't have the entry, the entry is not a string or
+    /// `key` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.get_attachment(&document_handle, 0).unwrap();
+    ///
+    /// let date = library.get_attachment_string_value(&attachment_handle, "CreationDate");
+    /// assert_eq!(date, "D:20240105120000+01'00'");
+    /// ```
+    pub fn get_attachment_string_value(&self, attachment: &AttachmentHandle, key: &str) -> String {
+        let key = match to_byte_string(key) {
+            Ok(key) => key,
+            Err(_) => return String::new(),
+        };
+
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFAttachment_GetStringValue(
+                attachment.handle.as_ptr(),
+                key.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the data of an embedded file, with all stream filters applied.
+    ///
+    /// Returns `None` if the attachment doesn't have a readable file stream.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.get_attachment(&document_handle, 1).unwrap();
+    ///
+    /// let data = library.get_attachment_file(&attachment_handle);
+    /// assert_eq!(data.as_deref(), Some(&b"Notes\n"[..]));
+    /// ```
+    pub fn get_attachment_file(&self, attachment: &AttachmentHandle) -> Option<Vec<u8>> {
+        let mut length = 0;
+
+        let success = unsafe {
+            bindings::FPDFAttachment_GetFile(
+                attachment.handle.as_ptr(),
+                ptr::null_mut(),
+                0,
+                &mut length,
+            )
+        };
+
+        if success == 0 {
+            return None;
+        }
+
+        let mut buffer = vec![0u8; length as usize];
+
+        if !buffer.is_empty() {
+            unsafe {
+                bindings::FPDFAttachment_GetFile(
+                    attachment.handle.as_ptr(),
+                    buffer.as_mut_ptr() as _,
+                    length,
+                    &mut length,
+                )
+            };
+        }
+
+        Some(buffer)
+    }
+
+    /// Add an empty embedded file to a document, use [`Library::set_attachment_file`] to set its data.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `name` is empty or the document already has an attachment with that name.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let mut document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let attachment_handle = library.add_attachment(&mut document_handle, "data.csv");
+    /// assert!(attachment_handle.is_ok());
+    /// ```
+    pub fn add_attachment<'data, 'library>(
+        &'library self,
+        document: &'data mut DocumentHandle,
+        name: &str,
+    ) -> Result<AttachmentHandle<'data, 'library>, PdfiumError> {
+        let name = to_wide_string(name);
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFDoc_AddAttachment(document.handle.as_ptr(), name.as_ptr())
+        });
+
+        handle
+            .map(|handle| AttachmentHandle::new(handle, document.handle))
+            .ok_or(PdfiumError::Unknown)
+    }
+
+    /// Remove an embedded file from a document.
+    ///
+    /// The file is only removed from the `EmbeddedFiles` name tree, its data stays in the document
+    /// until it's saved with [`SaveMode::NoIncremental`](crate::SaveMode::NoIncremental).
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let mut document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let result = library.delete_attachment(&mut document_handle, 1);
+    /// assert!(result.is_ok());
+    /// assert_eq!(library.get_attachment_count(&document_handle), 1);
+    /// ```
+    pub fn delete_attachment(
+        &self,
+        document: &mut DocumentHandle,
+        index: usize,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDFDoc_DeleteAttachment(document.handle.as_ptr(), index as _)
+        })
+    }
+
+    /// Set the data of an embedded file.
+    ///
+    /// The `CreationDate` and `CheckSum` parameters are updated, all other parameters are removed.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `contents` is larger than `i32::MAX` bytes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let mut document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    /// let mut attachment_handle = library
+    ///     .add_attachment(&mut document_handle, "data.csv")
+    ///     .unwrap();
+    ///
+    /// let result = library.set_attachment_file(&mut attachment_handle, b"a,b\n1,2\n");
+    /// assert!(result.is_ok());
+    /// assert_eq!(
+    ///     library.get_attachment_file(&attachment_handle).as_deref(),
+    ///     Some(&b"a,b\n1,2\n"[..])
+    /// );
+    /// ```
+    pub fn set_attachment_file(
+        &self,
+        attachment: &mut AttachmentHandle,
+        contents: &[u8],
+    ) -> Result<(), PdfiumError> {
+        if contents.len() >= i32::MAX as usize {
+            return Err(PdfiumError::Unknown);
+        }
+
+        to_result(unsafe {
+            bindings::FPDFAttachment_SetFile(
+                attachment.handle.as_ptr(),
+                attachment.document.as_ptr(),
+                contents.as_ptr() as _,
+                contents.len() as _,
+            )
+        })
+    }
+
+    /// Set a string entry of the parameters of an attachment, like `ModDate`.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `key` contains a nul character or the attachment has no file,
+    ///   set one with [`Library::set_attachment_file`] first.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ATTACHMENTS_PDF, None)
+    ///     .unwrap();
+    /// let mut attachment_handle = library.get_attachment(&document_handle, 0).unwrap();
+    ///
+    /// let result = library.set_attachment_string_value(
+    ///     &mut attachment_handle,
+    ///     "ModDate",
+    ///     "D:20240201000000Z",
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_attachment_string_value(
+        &self,
+        attachment: &mut AttachmentHandle,
+        key: &str,
+        value: &str,
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+        let value = to_wide_string(value);
+
+        to_result(unsafe {
+            bindings::FPDFAttachment_SetStringValue(
+                attachment.handle.as_ptr(),
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        })
+    }
+}
+
+/// Safe handle to PDFium Attachment, a file embedded in a document.
+///
+/// Created using [`Library::get_attachment`] and [`Library::add_attachment`].
+///
+/// The attachment is owned by its document so nothing is freed when the handle is dropped.
+pub struct AttachmentHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_attachment_t__>,
+    /// The document that owns the attachment, needed to change its file.
+    document: NonNull<bindings::fpdf_document_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(AttachmentHandle: Sync, Send);
+
+impl AttachmentHandle<'_, '_> {
+    pub(crate) fn new(
+        handle: NonNull<bindings::fpdf_attachment_t__>,
+        document: NonNull<bindings::fpdf_document_t__>,
+    ) -> Self {
+        AttachmentHandle {
+            handle,
+            document,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static ATTACHMENTS_PDF: &[u8] = include_bytes!("../../../test_assets/attachments.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn attachments() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ATTACHMENTS_PDF, None)
+            .unwrap();
+
+        assert_eq!(library.get_attachment_count(&document), 2);
+        assert!(library.get_attachment(&document, 2).is_none());
+
+        let invoice = library.get_attachment(&document, 0).unwrap();
+        assert_eq!(library.get_attachment_name(&invoice), "factur-x.xml");
+        assert_eq!(library.get_attachment_mime_type(&invoice), "text/xml");
+        assert!(library.attachment_has_key(&invoice, "Size"));
+        assert_eq!(
+            library
+                .get_attachment_string_value(&invoice, "CheckSum")
+                .to_lowercase(),
+            "723ee463091d40193468e909c02ad5a9"
+        );
+        assert_eq!(
+            library.get_attachment_string_value(&invoice, "ModDate"),
+            "D:20240106093000Z"
+        );
+        // Not a string
+        assert_eq!(library.get_attachment_string_value(&invoice, "Size"), "");
+        let data = library.get_attachment_file(&invoice).unwrap();
+        assert!(data.starts_with(b"<?xml"));
+        assert_eq!(data.len(), 73);
+
+        let notes = library.get_attachment(&document, 1).unwrap();
+        assert_eq!(library.get_attachment_name(&notes), "notes.txt");
+        assert_eq!(library.get_attachment_mime_type(&notes), "");
+        assert!(!library.attachment_has_key(&notes, "CheckSum"));
+        assert_eq!(library.get_attachment_string_value(&notes, "CheckSum"), "");
+
+        let dummy = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_attachment_count(&dummy), 0);
+    }
+
+    #[test]
+    fn add_and_delete_attachments() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let mut document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        let mut attachment = library.add_attachment(&mut document, "data.csv").unwrap();
+        library
+            .set_attachment_file(&mut attachment, b"a,b\n")
+            .unwrap();
+        library
+            .set_attachment_string_value(&mut attachment, "ModDate", "D:20240201000000Z")
+            .unwrap();
+        assert!(library.attachment_has_key(&attachment, "CheckSum"));
+        assert_eq!(
+            library.get_attachment_string_value(&attachment, "ModDate"),
+            "D:20240201000000Z"
+        );
+
+        // Names have to be unique
+        assert!(library.add_attachment(&mut document, "data.csv").is_err());
+        assert!(library.add_attachment(&mut document, "").is_err());
+
+        let mut bytes = Vec::new();
+        library
+            .save_document(&document, &mut bytes, crate::SaveMode::NoIncremental)
+            .unwrap();
+        let mut saved = library.load_document_from_bytes(&bytes, None).unwrap();
+        let attachment = library.get_attachment(&saved, 0).unwrap();
+        assert_eq!(library.get_attachment_name(&attachment), "data.csv");
+        assert_eq!(
+            library.get_attachment_file(&attachment).as_deref(),
+            Some(&b"a,b\n"[..])
+        );
+
+        library.delete_attachment(&mut saved, 0).unwrap();
+        assert_eq!(library.get_attachment_count(&saved), 0);
+        assert!(library.delete_attachment(&mut saved, 0).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{bindings, get_wide_string, DocumentHandle, Library};
+use static_assertions::assert_not_impl_any;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+impl Library {
+    /// Get the first child of a bookmark in the outline of a document.
+    ///
+    /// `parent` `None` gets the first top level bookmark.
+    ///
+    /// Returns `None` if the bookmark has no children.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// assert_eq!(library.get_bookmark_title(&bookmark_handle), "Introduction");
+    /// ```
+    pub fn get_first_bookmark_child<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        parent: Option<&BookmarkHandle>,
+    ) -> Option<BookmarkHandle<'data, 'library>> {
+        let parent = parent.map_or(ptr::null_mut(), |parent| parent.handle.as_ptr());
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFBookmark_GetFirstChild(document.handle.as_ptr(), parent)
+        });
+
+        handle.map(BookmarkHandle::new)
+    }
+
+    /// Get the next bookmark with the same parent in the outline of a document.
+    ///
+    /// Returns `None` if `bookmark` is the last child of its parent.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let first = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let second = library
+    ///     .get_next_bookmark_sibling(&document_handle, &first)
+    ///     .unwrap();
+    /// assert_eq!(library.get_bookmark_title(&second), "Chapter 1");
+    /// ```
+    pub fn get_next_bookmark_sibling<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        bookmark: &BookmarkHandle,
+    ) -> Option<BookmarkHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFBookmark_GetNextSibling(
+                document.handle.as_ptr(),
+                bookmark.handle.as_ptr(),
+            )
+        });
+
+        handle.map(BookmarkHandle::new)
+    }
+
+    /// Get the title of a bookmark, the text shown in the outline.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    ///
+    /// let title = library.get_bookmark_title(&bookmark_handle);
+    /// assert_eq!(title, "Introduction");
+    /// ```
+    pub fn get_bookmark_title(&self, bookmark: &BookmarkHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFBookmark_GetTitle(bookmark.handle.as_ptr(), buffer as _, length as _) as _
+        })
+    }
+
+    /// Get the number of visible descendants of a bookmark, the `Count` entry of its outline item.
+    ///
+    /// A negative count means the bookmark is closed, so its children are hidden by default.
+    /// Returns `0` if the bookmark has no children.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let first = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let chapter = library
+    ///     .get_next_bookmark_sibling(&document_handle, &first)
+    ///     .unwrap();
+    ///
+    /// let count = library.get_bookmark_count(&chapter);
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn get_bookmark_count(&self, bookmark: &BookmarkHandle) -> i32 {
+        unsafe { bindings::FPDFBookmark_GetCount(bookmark.handle.as_ptr()) }
+    }
+}
+
+/// Safe handle to PDFium Bookmark, an item of the outline of a document.
+///
+/// Created using [`Library::get_first_bookmark_child`] and [`Library::get_next_bookmark_sibling`].
+///
+/// The bookmark is owned by its document so nothing is freed when the handle is dropped.
+/// The handle can't outlive the document:
+/// ```compile_fail
+/// use pdfium_core::Library;
+/// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+///
+/// let library = Library::init_library().unwrap();
+/// let document_handle = library
+///     .load_document_from_bytes(OUTLINE_PDF, None)
+///     .unwrap();
+///
+/// let bookmark_handle = library.get_first_bookmark_child(&document_handle, None);
+/// drop(document_handle);
+/// drop(bookmark_handle);
+/// ```
+#[derive(Clone)]
+pub struct BookmarkHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_bookmark_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(BookmarkHandle: Sync, Send);
+
+/// Handles are equal if they refer to the same bookmark,
+/// which can be used to detect cycles in broken outlines.
+impl PartialEq for BookmarkHandle<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Eq for BookmarkHandle<'_, '_> {}
+
+impl Hash for BookmarkHandle<'_, '_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.handle.hash(state);
+    }
+}
+
+impl BookmarkHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_bookmark_t__>) -> Self {
+        BookmarkHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+
+    fn children(
+        library: &Library,
+        document: &DocumentHandle,
+        parent: Option<&BookmarkHandle>,
+    ) -> Vec<(String, i32)> {
+        let mut children = Vec::new();
+        let mut child = library.get_first_bookmark_child(document, parent);
+
+        while let Some(bookmark) = child {
+            children.push((
+                library.get_bookmark_title(&bookmark),
+                library.get_bookmark_count(&bookmark),
+            ));
+            child = library.get_next_bookmark_sibling(document, &bookmark);
+        }
+
+        children
+    }
+
+    #[test]
+    fn bookmarks() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(OUTLINE_PDF, None).unwrap();
+
+        assert_eq!(
+            children(&library, &document, None),
+            [
+                ("Introduction".to_string(), 0),
+                ("Chapter 1".to_string(), 2),
+                ("Chapter 2".to_string(), -1),
+                ("Notes".to_string(), 0)
+            ]
+        );
+
+        let introduction = library.get_first_bookmark_child(&document, None).unwrap();
+        let chapter_1 = library
+            .get_next_bookmark_sibling(&document, &introduction)
+            .unwrap();
+        let chapter_2 = library
+            .get_next_bookmark_sibling(&document, &chapter_1)
+            .unwrap();
+        assert_eq!(
+            children(&library, &document, Some(&chapter_1)),
+            [
+                ("Section 1.1".to_string(), 0),
+                ("Section 1.2".to_string(), 0)
+            ]
+        );
+        assert_eq!(
+            children(&library, &document, Some(&chapter_2)),
+            [("Appendix".to_string(), 0)]
+        );
+        assert!(library
+            .get_first_bookmark_child(&document, Some(&introduction))
+            .is_none());
+        assert!(introduction == library.get_first_bookmark_child(&document, None).unwrap());
+        assert!(introduction != chapter_1);
+
+        let dummy = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert!(library.get_first_bookmark_child(&dummy, None).is_none());
+    }
+}
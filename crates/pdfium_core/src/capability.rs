@@ -0,0 +1,137 @@
+use crate::{Library, PdfiumError};
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(test)]
+thread_local! {
+    /// A capability the tests treat as missing, to check the functions that need it without an older PDFium.
+    static MISSING: Cell<Option<Capability>> = const { Cell::new(None) };
+}
+
+/// A part of the API that is missing from older builds of PDFium.
+///
+/// The functions that use a capability fail with [`PdfiumError::Unsupported`] if PDFium doesn't have it.
+/// The headers are checked when building the crate, and with the `dynamic` feature the loaded library
+/// is checked as well since it can be older than the headers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Capability {
+    /// Moving pages within a document, used by [`Library::move_pages`].
+    MovePages,
+    /// The rotated bounds of page objects, used by [`Library::get_page_object_rotated_bounds`].
+    RotatedBounds,
+}
+
+impl Capability {
+    /// The function of PDFium the capability needs, it's looked up in the library that is loaded.
+    #[cfg(feature = "dynamic")]
+    fn function(self) -> &'static str {
+        match self {
+            Capability::MovePages => "FPDF_MovePages",
+            Capability::RotatedBounds => "FPDFPageObj_GetRotatedBounds",
+        }
+    }
+
+    /// Check if the headers declare the function, set by the build script.
+    fn is_declared(self) -> bool {
+        match self {
+            Capability::MovePages => cfg!(pdfium_function = "FPDF_MovePages"),
+            Capability::RotatedBounds => cfg!(pdfium_function = "FPDFPageObj_GetRotatedBounds"),
+        }
+    }
+}
+
+impl Library {
+    /// Check if PDFium has a capability.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Capability, Library};
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// if library.has_capability(Capability::MovePages) {
+    ///     // The pages of documents can be moved
+    /// }
+    /// ```
+    pub fn has_capability(&self, capability: Capability) -> bool {
+        #[cfg(test)]
+        if MISSING.with(Cell::get) == Some(capability) {
+            return false;
+        }
+
+        #[cfg(feature = "dynamic")]
+        {
+            capability.is_declared() && crate::dynamic::has_function(capability.function())
+        }
+
+        #[cfg(not(feature = "dynamic"))]
+        {
+            capability.is_declared()
+        }
+    }
+
+    /// Fail with [`PdfiumError::Unsupported`] if PDFium doesn't have a capability.
+    pub(crate) fn require_capability(&self, capability: Capability) -> Result<(), PdfiumError> {
+        match self.has_capability(capability) {
+            true => Ok(()),
+            false => Err(PdfiumError::Unsupported),
+        }
+    }
+
+    /// Get the version of PDFium the crate was built with, it's the number of the Chromium build
+    /// for releases of pdfium-binaries, like `5135`.
+    ///
+    /// Will return `None` if the version is unknown. It's found with pkg-config or in the download of the
+    /// `download-prebuilt` feature, and can be set with the `PDFIUM_VERSION` environment variable when building.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// if let Some(version) = library.get_pdfium_version() {
+    ///     println!("PDFium {}", version);
+    /// }
+    /// ```
+    pub fn get_pdfium_version(&self) -> Option<&'static str> {
+        option_env!("PDFIUM_VERSION").filter(|version| !version.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn missing_capabilities() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let source = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut document = library.create_document().unwrap();
+        library
+            .import_pages(&mut document, &source, Some(&[0, 0]), 0)
+            .unwrap();
+        let page = library.load_page(&source, 0).unwrap();
+        let object = library.get_page_object(&page, 0).unwrap();
+
+        MISSING.with(|missing| missing.set(Some(Capability::MovePages)));
+        assert!(!library.has_capability(Capability::MovePages));
+        assert_eq!(
+            library.move_pages(&mut document, &[1], 0),
+            Err(PdfiumError::Unsupported)
+        );
+
+        MISSING.with(|missing| missing.set(Some(Capability::RotatedBounds)));
+        assert!(!library.has_capability(Capability::RotatedBounds));
+        assert_eq!(
+            library.get_page_object_rotated_bounds(&object).err(),
+            Some(PdfiumError::Unsupported)
+        );
+
+        MISSING.with(|missing| missing.set(None));
+    }
+}
@@ -0,0 +1,371 @@
+use crate::{
+    bindings, to_result, Library, Matrix, PageHandle, PageObjectHandle, PathSegmentHandle,
+};
+use crate::{PdfiumError, Rect};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the clip path of a page object.
+    ///
+    /// Will return `None` if the page object is not clipped.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 1).unwrap();
+    /// let clip_path_handle = library.get_page_object_clip_path(&object_handle);
+    /// assert!(clip_path_handle.is_some());
+    /// ```
+    pub fn get_page_object_clip_path<'data, 'library>(
+        &'library self,
+        page_object: &'data PageObjectHandle,
+    ) -> Option<ClipPathHandle<'data, 'library>> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDFPageObj_GetClipPath(page_object.handle.as_ptr()) });
+
+        handle.map(ClipPathHandle::new)
+    }
+
+    /// Get number of paths inside a clip path.
+    ///
+    /// The clip region is the intersection of all of its paths.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 1).unwrap();
+    /// let clip_path_handle = library.get_page_object_clip_path(&object_handle).unwrap();
+    /// assert_eq!(library.get_clip_path_path_count(&clip_path_handle), 1);
+    /// ```
+    pub fn get_clip_path_path_count(&self, clip_path: &ClipPathHandle) -> usize {
+        let count = unsafe { bindings::FPDFClipPath_CountPaths(clip_path.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get number of segments inside one path of a clip path.
+    ///
+    /// `path_index` 0 for the first path.
+    ///
+    /// Returns `0` if `path_index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 1).unwrap();
+    /// let clip_path_handle = library.get_page_object_clip_path(&object_handle).unwrap();
+    /// assert!(library.get_clip_path_segment_count(&clip_path_handle, 0) > 0);
+    /// ```
+    pub fn get_clip_path_segment_count(
+        &self,
+        clip_path: &ClipPathHandle,
+        path_index: usize,
+    ) -> usize {
+        let count = unsafe {
+            bindings::FPDFClipPath_CountPathSegments(clip_path.handle.as_ptr(), path_index as i32)
+        };
+
+        count.max(0) as usize
+    }
+
+    /// Get a segment of one path of a clip path.
+    ///
+    /// `path_index` and `segment_index` 0 for the first path and segment.
+    ///
+    /// Will return `None` if `path_index` or `segment_index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PathSegmentType};
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 1).unwrap();
+    /// let clip_path_handle = library.get_page_object_clip_path(&object_handle).unwrap();
+    /// let segment_handle = library.get_clip_path_segment(&clip_path_handle, 0, 0).unwrap();
+    /// assert_eq!(library.get_path_segment_type(&segment_handle), PathSegmentType::MoveTo);
+    /// ```
+    pub fn get_clip_path_segment<'data, 'library>(
+        &'library self,
+        clip_path: &'data ClipPathHandle,
+        path_index: usize,
+        segment_index: usize,
+    ) -> Option<PathSegmentHandle<'data, 'library>> {
+        let handle = unsafe {
+            bindings::FPDFClipPath_GetPathSegment(
+                clip_path.handle.as_ptr(),
+                path_index as i32,
+                segment_index as i32,
+            )
+        };
+
+        PathSegmentHandle::new(handle)
+    }
+
+    /// Create a new clip path containing a rectangle.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): Unable to allocate the clip path.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Rect};
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let rect = Rect { left: 0.0, bottom: 0.0, right: 100.0, top: 100.0 };
+    /// let clip_path_handle = library.create_clip_path(&rect).unwrap();
+    /// assert_eq!(library.get_clip_path_path_count(&clip_path_handle), 1);
+    /// ```
+    pub fn create_clip_path<'library>(
+        &'library self,
+        rect: &Rect,
+    ) -> Result<OwnedClipPathHandle<'static, 'library>, PdfiumError> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_CreateClipPath(rect.left, rect.bottom, rect.right, rect.top)
+        });
+
+        handle
+            .map(|handle| OwnedClipPathHandle(ClipPathHandle::new(handle)))
+            .ok_or(PdfiumError::Unknown)
+    }
+
+    /// Clip the content of a page.
+    ///
+    /// The clip path is inserted before the page's content so the content outside of it becomes invisible.
+    /// The clip path is copied, so it can be dropped afterwards.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Rect};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let rect = Rect { left: 0.0, bottom: 0.0, right: 100.0, top: 100.0 };
+    /// let clip_path_handle = library.create_clip_path(&rect).unwrap();
+    /// library.insert_clip_path(&mut page_handle, &clip_path_handle);
+    /// ```
+    pub fn insert_clip_path(&self, page: &mut PageHandle, clip_path: &ClipPathHandle) {
+        page.changed();
+
+        unsafe {
+            bindings::FPDFPage_InsertClipPath(page.handle.as_ptr(), clip_path.handle.as_ptr());
+        }
+    }
+
+    /// Transform and clip the content of a page.
+    ///
+    /// `matrix` is applied to all of the page's content, which is then clipped to `clip`.
+    /// At least one of them must be given.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): Neither `matrix` nor `clip` is given.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Matrix, Rect};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// // Scale the page to half its size and only keep the bottom left corner.
+    /// let matrix = Matrix { a: 0.5, d: 0.5, ..Matrix::IDENTITY };
+    /// let clip = Rect { left: 0.0, bottom: 0.0, right: 100.0, top: 100.0 };
+    /// let result = library.transform_page_with_clip(&mut page_handle, Some(&matrix), Some(&clip));
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn transform_page_with_clip(
+        &self,
+        page: &mut PageHandle,
+        matrix: Option<&Matrix>,
+        clip: Option<&Rect>,
+    ) -> Result<(), PdfiumError> {
+        page.changed();
+
+        let matrix = matrix.map(|matrix| matrix.to_fs_matrix());
+        let clip = clip.map(|clip| clip.to_fs_rectf());
+
+        to_result(unsafe {
+            bindings::FPDFPage_TransFormWithClip(
+                page.handle.as_ptr(),
+                matrix.as_ref().map_or(std::ptr::null(), |matrix| matrix),
+                clip.as_ref().map_or(std::ptr::null(), |clip| clip),
+            )
+        })
+    }
+}
+
+/// Safe handle to PDFium Clip Path.
+///
+/// Created using [`Library::get_page_object_clip_path`].
+///
+/// The clip path is owned by its page object so nothing is freed when the handle is dropped.
+/// See [`OwnedClipPathHandle`] for clip paths that don't belong to a page object.
+pub struct ClipPathHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_clippath_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(ClipPathHandle: Sync, Send);
+
+impl ClipPathHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_clippath_t__>) -> Self {
+        ClipPathHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// Safe handle to PDFium Clip Path that doesn't belong to a page object.
+///
+/// Created using [`Library::create_clip_path`].
+///
+/// Derefs to [`ClipPathHandle`] so it can be used with any function that takes a clip path.
+///
+/// Clip path is destroyed when handle is dropped.
+pub struct OwnedClipPathHandle<'a, 'b>(ClipPathHandle<'a, 'b>);
+
+assert_not_impl_any!(OwnedClipPathHandle: Sync, Send);
+
+impl Drop for OwnedClipPathHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FPDF_DestroyClipPath(self.0.handle.as_ptr());
+        }
+    }
+}
+
+impl<'a, 'b> Deref for OwnedClipPathHandle<'a, 'b> {
+    type Target = ClipPathHandle<'a, 'b>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PathSegmentType, TEST_LOCK};
+
+    static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+
+    #[test]
+    fn page_object_clip_path() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(PATHS_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let unclipped = library.get_page_object(&page, 0).unwrap();
+        assert!(library.get_page_object_clip_path(&unclipped).is_none());
+
+        let clipped = library.get_page_object(&page, 1).unwrap();
+        let clip_path = library.get_page_object_clip_path(&clipped).unwrap();
+        assert_eq!(library.get_clip_path_path_count(&clip_path), 1);
+
+        let segment_count = library.get_clip_path_segment_count(&clip_path, 0);
+        assert!(segment_count >= 4);
+        assert_eq!(library.get_clip_path_segment_count(&clip_path, 1), 0);
+
+        let first = library.get_clip_path_segment(&clip_path, 0, 0).unwrap();
+        assert_eq!(
+            library.get_path_segment_type(&first),
+            PathSegmentType::MoveTo
+        );
+        assert_eq!(library.get_path_segment_point(&first), Some((50.0, 400.0)));
+
+        assert!(library
+            .get_clip_path_segment(&clip_path, 0, segment_count)
+            .is_none());
+    }
+
+    #[test]
+    fn clip_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(PATHS_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+
+        let rect = Rect {
+            left: 0.0,
+            bottom: 0.0,
+            right: 100.0,
+            top: 100.0,
+        };
+
+        {
+            let clip_path = library.create_clip_path(&rect).unwrap();
+            library.insert_clip_path(&mut page, &clip_path);
+        }
+
+        assert!(library
+            .transform_page_with_clip(&mut page, None, Some(&rect))
+            .is_ok());
+        assert!(library
+            .transform_page_with_clip(&mut page, None, None)
+            .is_err());
+    }
+}
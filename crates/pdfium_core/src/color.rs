@@ -0,0 +1,30 @@
+/// A color with an alpha channel.
+///
+/// Each channel ranges from 0 to 255. An alpha of 255 is fully opaque.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+    /// Alpha channel.
+    pub a: u8,
+}
+
+impl Color {
+    /// An opaque color.
+    ///
+    /// ## Example
+    /// ```
+    /// use pdfium_core::Color;
+    ///
+    /// let red = Color::rgb(255, 0, 0);
+    /// assert_eq!(red, Color { r: 255, g: 0, b: 0, a: 255 });
+    /// ```
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b, a: 255 }
+    }
+}
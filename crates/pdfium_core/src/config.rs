@@ -0,0 +1,231 @@
+use crate::system_font::SystemFontInfo;
+use crate::{bindings, cstr, CharsetFont, Library, INITIALIZED};
+#[cfg(feature = "v8")]
+use std::ffi::c_void;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::path::PathBuf;
+use std::ptr;
+
+/// The configuration of the PDFium library, used by [`Library::init_library_with_config`].
+///
+/// ## Examples
+/// ```
+/// use pdfium_core::{Library, LibraryConfig};
+///
+/// let config = LibraryConfig {
+///     font_paths: vec!["/usr/share/fonts/noto".into()],
+///     ..Default::default()
+/// };
+///
+/// let library = Library::init_library_with_config(config);
+/// assert!(library.is_some());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryConfig {
+    /// The directories that are searched for fonts, instead of the directories of the system.
+    ///
+    /// The directories of the system are used if it's empty.
+    /// Depending on the platform PDFium might ignore them, like on Windows and macOS where
+    /// the fonts are found with the font APIs of the system.
+    pub font_paths: Vec<PathBuf>,
+    /// Fonts that are added to the fonts PDFium finds, so they can be used for their character set.
+    ///
+    /// The fonts have to be one of the fonts that PDFium finds, like a font in the font paths,
+    /// see [`Library::get_default_ttf_map`] for the fonts PDFium uses by default.
+    pub installed_fonts: Vec<CharsetFont>,
+    /// The V8 isolate and platform of the application, PDFium creates its own isolate if it's `None`.
+    #[cfg(feature = "v8")]
+    pub v8: Option<V8Config>,
+}
+
+/// The parts of V8 the application shares with PDFium, for builds of PDFium with V8 or XFA support.
+///
+/// Created using [`V8Config::new`].
+#[cfg(feature = "v8")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V8Config {
+    isolate: *mut c_void,
+    embedder_slot: u32,
+    platform: *mut c_void,
+}
+
+#[cfg(feature = "v8")]
+impl V8Config {
+    /// Share a V8 isolate and platform with PDFium.
+    ///
+    /// `embedder_slot` is the slot of the isolate PDFium uses to store its data, 0 if the application doesn't use slots.
+    /// `platform` can be null to use the platform of the isolate.
+    ///
+    /// ## Safety
+    /// `isolate` must be a `v8::Isolate*` and `platform` a `v8::Platform*` or null,
+    /// both have to stay valid until the library is dropped.
+    pub unsafe fn new(isolate: *mut c_void, embedder_slot: u32, platform: *mut c_void) -> Self {
+        V8Config {
+            isolate,
+            embedder_slot,
+            platform,
+        }
+    }
+}
+
+/// A policy of the sandbox PDFium runs in, for [`Library::set_sandbox_policy`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SandboxPolicy {
+    /// Access to the time of the machine, like the current date for JavaScript and form fields.
+    ///
+    /// Without it PDFium uses a fixed time, so documents can't find the time or time zone of the machine.
+    MachineTimeAccess = bindings::FPDF_POLICY_MACHINETIME_ACCESS as isize,
+}
+
+/// The font paths of a config, PDFium keeps pointers to them until the library is destroyed.
+pub(crate) struct FontPaths {
+    _paths: Vec<CString>,
+    pointers: Vec<*const c_char>,
+}
+
+impl FontPaths {
+    /// Paths that can't be passed to PDFium, like paths with a nul byte, are skipped.
+    fn new(config: &LibraryConfig) -> Option<FontPaths> {
+        let paths: Vec<_> = config
+            .font_paths
+            .iter()
+            .filter_map(|path| cstr(path).ok())
+            .collect();
+
+        if paths.is_empty() {
+            return None;
+        }
+
+        let pointers = paths
+            .iter()
+            .map(|path| path.as_ptr())
+            .chain(Some(ptr::null()))
+            .collect();
+
+        Some(FontPaths {
+            _paths: paths,
+            pointers,
+        })
+    }
+}
+
+impl Library {
+    /// Initialize the PDFium library with a configuration.
+    ///
+    /// See [`Library::init_library`], which uses the default configuration.
+    ///
+    /// Will return `None` if the library is already initialized,
+    /// or with the `dynamic` feature if PDFium is not loaded.
+    pub fn init_library_with_config(config: LibraryConfig) -> Option<Library> {
+        let mut initialized = INITIALIZED.lock();
+
+        if *initialized {
+            return None;
+        }
+
+        #[cfg(feature = "dynamic")]
+        {
+            if !crate::dynamic::is_loaded() {
+                return None;
+            }
+        }
+
+        let mut font_paths = FontPaths::new(&config);
+
+        #[allow(unused_mut)]
+        let mut library_config = bindings::FPDF_LIBRARY_CONFIG_ {
+            version: 2,
+            m_pUserFontPaths: font_paths
+                .as_mut()
+                .map(|paths| paths.pointers.as_mut_ptr())
+                .unwrap_or_else(ptr::null_mut),
+            m_pIsolate: ptr::null_mut(),
+            m_v8EmbedderSlot: 0,
+            m_pPlatform: ptr::null_mut(),
+        };
+
+        #[cfg(feature = "v8")]
+        if let Some(v8) = config.v8 {
+            library_config.m_pIsolate = v8.isolate;
+            library_config.m_v8EmbedderSlot = v8.embedder_slot as _;
+            if !v8.platform.is_null() {
+                // The platform is read since version 3 of the config
+                library_config.version = 3;
+                library_config.m_pPlatform = v8.platform;
+            }
+        }
+        unsafe {
+            bindings::FPDF_InitLibraryWithConfig(&library_config);
+        }
+        // PDFium only uses a system font info that is set after it's initialized
+        let font_info = SystemFontInfo::install(&config.installed_fonts);
+        *initialized = true;
+
+        Some(Library {
+            _font_paths: font_paths,
+            _font_info: font_info,
+            not_send: PhantomData,
+        })
+    }
+}
+
+impl Library {
+    /// Enable or disable a policy of the sandbox, all policies are enabled by default.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, SandboxPolicy};
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// // Don't let documents find the time of the machine
+    /// library.set_sandbox_policy(SandboxPolicy::MachineTimeAccess, false);
+    /// ```
+    pub fn set_sandbox_policy(&self, policy: SandboxPolicy, enabled: bool) {
+        unsafe { bindings::FPDF_SetSandBoxPolicy(policy as _, enabled as _) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    #[test]
+    fn font_paths() {
+        assert!(FontPaths::new(&LibraryConfig::default()).is_none());
+
+        let config = LibraryConfig {
+            font_paths: vec!["/fonts".into(), "/in\0valid".into(), "/more/fonts".into()],
+            ..Default::default()
+        };
+        let paths = FontPaths::new(&config).unwrap();
+        assert_eq!(paths.pointers.len(), 3);
+        assert!(paths.pointers[2].is_null());
+    }
+
+    #[test]
+    fn init_library_with_config() {
+        let _guard = TEST_LOCK.lock();
+        let config = LibraryConfig {
+            font_paths: vec!["../../test_assets".into()],
+            ..Default::default()
+        };
+
+        let library = Library::init_library_with_config(config.clone());
+        assert!(library.is_some());
+        assert!(Library::init_library_with_config(config).is_none());
+        assert!(Library::init_library().is_none());
+    }
+
+    #[test]
+    fn set_sandbox_policy() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        library.set_sandbox_policy(SandboxPolicy::MachineTimeAccess, false);
+        library.set_sandbox_policy(SandboxPolicy::MachineTimeAccess, true);
+    }
+}
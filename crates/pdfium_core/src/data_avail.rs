@@ -0,0 +1,515 @@
+use crate::{bindings, catch_panic, DocumentHandle, Library, PageCache, PdfiumError};
+use static_assertions::assert_not_impl_any;
+use std::cell::{OnceCell, RefCell};
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::{c_int, c_uchar, c_ulong, c_void};
+use std::ptr::{self, NonNull};
+
+/// A document that is still being downloaded, the source of a [`DataAvailabilityHandle`].
+///
+/// PDFium asks for the parts it needs with [`add_segment`](DataSource::add_segment).
+/// The source usually shares its buffer with the download, like through a `RefCell`,
+/// so new data can be added while the handle is used.
+///
+/// A panic in a method is caught, it counts as data that isn't available.
+pub trait DataSource {
+    /// Check if all bytes from `offset` to `offset + size` have been downloaded.
+    fn is_data_available(&mut self, offset: usize, size: usize) -> bool;
+
+    /// Copy the bytes at `offset` into `buffer`.
+    ///
+    /// Returns `false` if the bytes have not been downloaded.
+    fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool;
+
+    /// Request the download of the bytes from `offset` to `offset + size`.
+    ///
+    /// Requests can overlap with each other and with bytes that have already been downloaded.
+    fn add_segment(&mut self, offset: usize, size: usize);
+}
+
+impl Library {
+    /// Create an availability provider for a document that is still being downloaded,
+    /// to open it and load pages as soon as their data is available.
+    ///
+    /// `length` is the size of the whole document in bytes.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to create the availability provider.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{DataSource, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// struct Downloaded(&'static [u8]);
+    ///
+    /// impl DataSource for Downloaded {
+    ///     fn is_data_available(&mut self, _offset: usize, _size: usize) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+    ///         buffer.copy_from_slice(&self.0[offset..offset + buffer.len()]);
+    ///         true
+    ///     }
+    ///
+    ///     fn add_segment(&mut self, _offset: usize, _size: usize) {}
+    /// }
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let availability_handle = library
+    ///     .create_data_availability(Downloaded(DUMMY_PDF), DUMMY_PDF.len())
+    ///     .unwrap();
+    ///
+    /// let result = library.is_document_available(&availability_handle);
+    /// assert_eq!(result, Ok(true));
+    /// ```
+    pub fn create_data_availability<'data, 'library>(
+        &'library self,
+        source: impl DataSource + 'data,
+        length: usize,
+    ) -> Result<DataAvailabilityHandle<'data, 'library>, PdfiumError> {
+        let file_avail = Box::into_raw(Box::new(FileAvail {
+            file_avail: bindings::FX_FILEAVAIL {
+                version: 1,
+                IsDataAvail: Some(is_data_available),
+            },
+            file_access: bindings::FPDF_FILEACCESS {
+                m_FileLen: length as c_ulong,
+                m_GetBlock: Some(get_block),
+                m_Param: ptr::null_mut(),
+            },
+            source: Box::new(source),
+        }));
+
+        // PDFium keeps pointers to both interfaces, so they stay in the box until the provider is destroyed
+        let handle = unsafe {
+            (*file_avail).file_access.m_Param = file_avail.cast();
+
+            NonNull::new(bindings::FPDFAvail_Create(
+                &mut (*file_avail).file_avail,
+                &mut (*file_avail).file_access,
+            ))
+        };
+
+        match handle {
+            Some(handle) => Ok(DataAvailabilityHandle {
+                handle,
+                file_avail: unsafe { NonNull::new_unchecked(file_avail) },
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            }),
+            None => {
+                drop(unsafe { Box::from_raw(file_avail) });
+                Err(PdfiumError::Unknown)
+            }
+        }
+    }
+
+    /// Check if a document is linearized, so its first page can be loaded before the rest is downloaded.
+    ///
+    /// Returns `None` if less than 1 KiB of the document is available or the document is smaller than that.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{DataSource, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # struct Downloaded(&'static [u8]);
+    /// # impl DataSource for Downloaded {
+    /// #     fn is_data_available(&mut self, _offset: usize, _size: usize) -> bool { true }
+    /// #     fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+    /// #         buffer.copy_from_slice(&self.0[offset..offset + buffer.len()]);
+    /// #         true
+    /// #     }
+    /// #     fn add_segment(&mut self, _offset: usize, _size: usize) {}
+    /// # }
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let availability_handle = library
+    ///     .create_data_availability(Downloaded(DUMMY_PDF), DUMMY_PDF.len())
+    ///     .unwrap();
+    ///
+    /// let linearized = library.is_linearized(&availability_handle);
+    /// assert_eq!(linearized, Some(false));
+    /// ```
+    pub fn is_linearized(&self, availability: &DataAvailabilityHandle) -> Option<bool> {
+        match unsafe { bindings::FPDFAvail_IsLinearized(availability.handle.as_ptr()) } {
+            x if x == bindings::PDF_LINEARIZED as c_int => Some(true),
+            x if x == bindings::PDF_NOT_LINEARIZED as c_int => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Check if enough of the document is available to open it with [`Library::get_available_document`].
+    ///
+    /// If it's not, the missing parts are requested from the [`DataSource`].
+    /// Call this again when new data arrives, until it returns `true`.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium can't tell if the data is available.
+    ///
+    /// ## Examples
+    /// See [`Library::create_data_availability`].
+    pub fn is_document_available(
+        &self,
+        availability: &DataAvailabilityHandle,
+    ) -> Result<bool, PdfiumError> {
+        let mut hints = DownloadHints::new(availability.file_avail);
+
+        to_availability(unsafe {
+            bindings::FPDFAvail_IsDocAvail(availability.handle.as_ptr(), hints.as_mut_ptr())
+        })
+    }
+
+    /// Open the document of an availability provider, once [`Library::is_document_available`] returns `true`.
+    ///
+    /// Pages can only be loaded when they are available, see [`Library::is_page_available`].
+    ///
+    /// See the [`load_document`](Library::load_document) function for more details and the errors.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{DataSource, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # struct Downloaded(&'static [u8]);
+    /// # impl DataSource for Downloaded {
+    /// #     fn is_data_available(&mut self, _offset: usize, _size: usize) -> bool { true }
+    /// #     fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+    /// #         buffer.copy_from_slice(&self.0[offset..offset + buffer.len()]);
+    /// #         true
+    /// #     }
+    /// #     fn add_segment(&mut self, _offset: usize, _size: usize) {}
+    /// # }
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let availability_handle = library
+    ///     .create_data_availability(Downloaded(DUMMY_PDF), DUMMY_PDF.len())
+    ///     .unwrap();
+    ///
+    /// let document_handle = library
+    ///     .get_available_document(&availability_handle, None)
+    ///     .unwrap();
+    /// assert_eq!(library.get_page_count(&document_handle), 1);
+    /// ```
+    pub fn get_available_document<'data, 'library>(
+        &'library self,
+        availability: &'data DataAvailabilityHandle,
+        password: Option<&CStr>,
+    ) -> Result<DocumentHandle<'data, 'library>, PdfiumError> {
+        let password = password.map(|x| x.as_ptr()).unwrap_or_else(ptr::null);
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFAvail_GetDocument(availability.handle.as_ptr(), password)
+        });
+
+        handle
+            .map(|handle| DocumentHandle {
+                handle,
+                _file_read: None,
+                pages: PageCache::default(),
+                form: OnceCell::new(),
+                jpegs: RefCell::default(),
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or_else(|| self.last_error())
+    }
+
+    /// Get the index of the first page that becomes available, 0 for most documents.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(library.get_first_available_page(&document_handle), 0);
+    /// ```
+    pub fn get_first_available_page(&self, document: &DocumentHandle) -> usize {
+        let index = unsafe { bindings::FPDFAvail_GetFirstPageNum(document.handle.as_ptr()) };
+
+        index.max(0) as usize
+    }
+
+    /// Check if enough of a page is available to load it.
+    ///
+    /// If it's not, the missing parts are requested from the [`DataSource`].
+    /// Call this again when new data arrives, until it returns `true`.
+    ///
+    /// The document has to be opened with [`Library::get_available_document`] first.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The document was not opened or `index` is out of bounds.
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium can't tell if the data is available.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{DataSource, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # struct Downloaded(&'static [u8]);
+    /// # impl DataSource for Downloaded {
+    /// #     fn is_data_available(&mut self, _offset: usize, _size: usize) -> bool { true }
+    /// #     fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+    /// #         buffer.copy_from_slice(&self.0[offset..offset + buffer.len()]);
+    /// #         true
+    /// #     }
+    /// #     fn add_segment(&mut self, _offset: usize, _size: usize) {}
+    /// # }
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let availability_handle = library
+    ///     .create_data_availability(Downloaded(DUMMY_PDF), DUMMY_PDF.len())
+    ///     .unwrap();
+    ///
+    /// let document_handle = library
+    ///     .get_available_document(&availability_handle, None)
+    ///     .unwrap();
+    ///
+    /// let result = library.is_page_available(&availability_handle, 0);
+    /// assert_eq!(result, Ok(true));
+    /// ```
+    pub fn is_page_available(
+        &self,
+        availability: &DataAvailabilityHandle,
+        index: usize,
+    ) -> Result<bool, PdfiumError> {
+        let mut hints = DownloadHints::new(availability.file_avail);
+
+        to_availability(unsafe {
+            bindings::FPDFAvail_IsPageAvail(
+                availability.handle.as_ptr(),
+                index as _,
+                hints.as_mut_ptr(),
+            )
+        })
+    }
+}
+
+fn to_availability(result: c_int) -> Result<bool, PdfiumError> {
+    match result {
+        x if x == bindings::PDF_DATA_AVAIL as c_int => Ok(true),
+        x if x == bindings::PDF_DATA_NOTAVAIL as c_int => Ok(false),
+        _ => Err(PdfiumError::Unknown),
+    }
+}
+
+/// Safe handle to PDFium Data Availability, a provider that checks which parts of a document are downloaded.
+///
+/// Created using [`Library::create_data_availability`].
+///
+/// Data availability is destroyed when handle is dropped, documents opened with it have to be closed before.
+pub struct DataAvailabilityHandle<'a, 'b> {
+    handle: NonNull<bindings::fpdf_avail_t__>,
+    file_avail: NonNull<FileAvail<'a>>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(DataAvailabilityHandle: Sync, Send);
+
+impl Drop for DataAvailabilityHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FPDFAvail_Destroy(self.handle.as_ptr());
+            drop(Box::from_raw(self.file_avail.as_ptr()));
+        }
+    }
+}
+
+/// The `FX_FILEAVAIL` and `FPDF_FILEACCESS` of a [`DataSource`].
+///
+/// `file_avail` has to be the first field, PDFium passes a pointer to it to the callback.
+#[repr(C)]
+struct FileAvail<'a> {
+    file_avail: bindings::FX_FILEAVAIL,
+    file_access: bindings::FPDF_FILEACCESS,
+    source: Box<dyn DataSource + 'a>,
+}
+
+/// A `FX_DOWNLOADHINTS` that passes the requests to the [`DataSource`] of `file_avail`.
+///
+/// `hints` has to be the first field, PDFium passes a pointer to it to the callback.
+#[repr(C)]
+struct DownloadHints<'a> {
+    hints: bindings::FX_DOWNLOADHINTS,
+    file_avail: NonNull<FileAvail<'a>>,
+}
+
+impl<'a> DownloadHints<'a> {
+    fn new(file_avail: NonNull<FileAvail<'a>>) -> Self {
+        DownloadHints {
+            hints: bindings::FX_DOWNLOADHINTS {
+                version: 1,
+                AddSegment: Some(add_segment),
+            },
+            file_avail,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut bindings::FX_DOWNLOADHINTS {
+        (self as *mut Self).cast()
+    }
+}
+
+unsafe extern "C" fn is_data_available(
+    this: *mut bindings::FX_FILEAVAIL,
+    offset: usize,
+    size: usize,
+) -> bindings::FPDF_BOOL {
+    let file_avail = &mut *this.cast::<FileAvail>();
+
+    catch_panic(0, || file_avail.source.is_data_available(offset, size) as _)
+}
+
+unsafe extern "C" fn get_block(
+    param: *mut c_void,
+    position: c_ulong,
+    buffer: *mut c_uchar,
+    size: c_ulong,
+) -> c_int {
+    let file_avail = &mut *param.cast::<FileAvail>();
+    let buffer = if size == 0 {
+        &mut []
+    } else {
+        std::slice::from_raw_parts_mut(buffer, size as usize)
+    };
+
+    catch_panic(0, || {
+        file_avail.source.read_block(position as usize, buffer) as c_int
+    })
+}
+
+unsafe extern "C" fn add_segment(
+    this: *mut bindings::FX_DOWNLOADHINTS,
+    offset: usize,
+    size: usize,
+) {
+    let hints = &mut *this.cast::<DownloadHints>();
+    let file_avail = &mut *hints.file_avail.as_ptr();
+
+    catch_panic((), || file_avail.source.add_segment(offset, size));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+    use std::cell::RefCell;
+    use std::ops::Range;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    /// A download of `DUMMY_PDF` where only `downloaded` is available.
+    struct Download<'a> {
+        downloaded: Range<usize>,
+        requested: &'a RefCell<Vec<Range<usize>>>,
+    }
+
+    impl DataSource for Download<'_> {
+        fn is_data_available(&mut self, offset: usize, size: usize) -> bool {
+            self.downloaded.start <= offset && offset + size <= self.downloaded.end
+        }
+
+        fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+            if !self.is_data_available(offset, buffer.len()) {
+                return false;
+            }
+
+            buffer.copy_from_slice(&DUMMY_PDF[offset..offset + buffer.len()]);
+            true
+        }
+
+        fn add_segment(&mut self, offset: usize, size: usize) {
+            self.requested.borrow_mut().push(offset..offset + size);
+        }
+    }
+
+    #[test]
+    fn data_availability() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let requested = RefCell::new(Vec::new());
+
+        let nothing = Download {
+            downloaded: 0..0,
+            requested: &requested,
+        };
+        let availability = library
+            .create_data_availability(nothing, DUMMY_PDF.len())
+            .unwrap();
+        assert_eq!(library.is_linearized(&availability), None);
+        assert_eq!(library.is_document_available(&availability), Ok(false));
+        assert!(!requested.borrow().is_empty());
+        drop(availability);
+
+        let everything = Download {
+            downloaded: 0..DUMMY_PDF.len(),
+            requested: &requested,
+        };
+        let availability = library
+            .create_data_availability(everything, DUMMY_PDF.len())
+            .unwrap();
+        assert_eq!(library.is_linearized(&availability), Some(false));
+        assert_eq!(library.is_document_available(&availability), Ok(true));
+
+        let document = library.get_available_document(&availability, None).unwrap();
+        assert_eq!(library.get_first_available_page(&document), 0);
+        assert_eq!(library.is_page_available(&availability, 0), Ok(true));
+        assert!(library.load_page(&document, 0).is_ok());
+    }
+
+    /// A source that panics in every method.
+    struct Panicking;
+
+    impl DataSource for Panicking {
+        fn is_data_available(&mut self, _: usize, _: usize) -> bool {
+            panic!("is_data_available")
+        }
+
+        fn read_block(&mut self, _: usize, _: &mut [u8]) -> bool {
+            panic!("read_block")
+        }
+
+        fn add_segment(&mut self, _: usize, _: usize) {
+            panic!("add_segment")
+        }
+    }
+
+    #[test]
+    fn panicking_source() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        // The panics are caught and the data is not available
+        let availability = library
+            .create_data_availability(Panicking, DUMMY_PDF.len())
+            .unwrap();
+        assert_eq!(library.is_document_available(&availability), Ok(false));
+        assert!(library.get_available_document(&availability, None).is_err());
+    }
+}
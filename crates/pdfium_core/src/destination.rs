@@ -0,0 +1,360 @@
+use crate::{bindings, BookmarkHandle, DocumentHandle, Library};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the destination of a bookmark, the place in the document it jumps to.
+    ///
+    /// Named destinations are resolved with the name tree of the document.
+    ///
+    /// Returns `None` if the bookmark has no destination, like when it has an action instead.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    ///
+    /// let destination_handle = library.get_bookmark_destination(&document_handle, &bookmark_handle);
+    /// assert!(destination_handle.is_some());
+    /// ```
+    pub fn get_bookmark_destination<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        bookmark: &BookmarkHandle,
+    ) -> Option<DestinationHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFBookmark_GetDest(document.handle.as_ptr(), bookmark.handle.as_ptr())
+        });
+
+        handle.map(DestinationHandle::new)
+    }
+
+    /// Get the index of the page a destination is on.
+    ///
+    /// Returns `None` if the destination doesn't point to a page of the document.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let destination_handle = library
+    ///     .get_bookmark_destination(&document_handle, &bookmark_handle)
+    ///     .unwrap();
+    ///
+    /// let page_index = library.get_destination_page_index(&document_handle, &destination_handle);
+    /// assert_eq!(page_index, Some(0));
+    /// ```
+    pub fn get_destination_page_index(
+        &self,
+        document: &DocumentHandle,
+        destination: &DestinationHandle,
+    ) -> Option<usize> {
+        let index = unsafe {
+            bindings::FPDFDest_GetDestPageIndex(
+                document.handle.as_ptr(),
+                destination.handle.as_ptr(),
+            )
+        };
+
+        if index < 0 {
+            None
+        } else {
+            Some(index as usize)
+        }
+    }
+
+    /// Get how a destination displays its page, with the parameters of the view.
+    ///
+    /// The parameters are the numbers after the view mode in the destination array,
+    /// like `[top]` for [`DestinationViewMode::FitH`] or `[left, bottom, right, top]` for [`DestinationViewMode::FitR`].
+    /// Null parameters are `0`, use [`Library::get_destination_location`] to tell them apart for [`DestinationViewMode::Xyz`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{DestinationViewMode, Library};
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let first = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let chapter = library
+    ///     .get_next_bookmark_sibling(&document_handle, &first)
+    ///     .unwrap();
+    /// let destination_handle = library
+    ///     .get_bookmark_destination(&document_handle, &chapter)
+    ///     .unwrap();
+    ///
+    /// let (mode, parameters) = library.get_destination_view(&destination_handle);
+    /// assert_eq!(mode, DestinationViewMode::FitH);
+    /// assert_eq!(parameters, [500.0]);
+    /// ```
+    pub fn get_destination_view(
+        &self,
+        destination: &DestinationHandle,
+    ) -> (DestinationViewMode, Vec<f32>) {
+        let mut count = 0;
+        let mut parameters = [0.0; 4];
+
+        let mode = unsafe {
+            bindings::FPDFDest_GetView(
+                destination.handle.as_ptr(),
+                &mut count,
+                parameters.as_mut_ptr(),
+            )
+        };
+
+        let mode =
+            DestinationViewMode::from_i32(mode as i32).unwrap_or(DestinationViewMode::Unknown);
+        let count = (count as usize).min(parameters.len());
+
+        (mode, parameters[..count].to_vec())
+    }
+
+    /// Get the location and zoom of a destination with the [`DestinationViewMode::Xyz`] view mode.
+    ///
+    /// Returns `None` if the destination has a different view mode.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let bookmark_handle = library
+    ///     .get_first_bookmark_child(&document_handle, None)
+    ///     .unwrap();
+    /// let destination_handle = library
+    ///     .get_bookmark_destination(&document_handle, &bookmark_handle)
+    ///     .unwrap();
+    ///
+    /// let location = library.get_destination_location(&destination_handle).unwrap();
+    /// assert_eq!(location.x, Some(72.0));
+    /// assert_eq!(location.y, Some(720.0));
+    /// ```
+    pub fn get_destination_location(
+        &self,
+        destination: &DestinationHandle,
+    ) -> Option<DestinationLocation> {
+        let mut has_x = 0;
+        let mut has_y = 0;
+        let mut has_zoom = 0;
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut zoom = 0.0;
+
+        let success = unsafe {
+            bindings::FPDFDest_GetLocationInPage(
+                destination.handle.as_ptr(),
+                &mut has_x,
+                &mut has_y,
+                &mut has_zoom,
+                &mut x,
+                &mut y,
+                &mut zoom,
+            )
+        };
+
+        if success == 0 {
+            return None;
+        }
+
+        Some(DestinationLocation {
+            x: Some(x).filter(|_| has_x != 0),
+            y: Some(y).filter(|_| has_y != 0),
+            zoom: Some(zoom).filter(|_| has_zoom != 0),
+        })
+    }
+}
+
+/// Safe handle to PDFium Destination, a place in a document that bookmarks and links jump to.
+///
+/// Created using [`Library::get_bookmark_destination`].
+///
+/// The destination is owned by its document so nothing is freed when the handle is dropped.
+pub struct DestinationHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_dest_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(DestinationHandle: Sync, Send);
+
+impl DestinationHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_dest_t__>) -> Self {
+        DestinationHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// How a destination displays its page, the second entry of the destination array.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DestinationViewMode {
+    /// The destination doesn't specify a view.
+    Unknown = bindings::PDFDEST_VIEW_UNKNOWN_MODE as i32,
+    /// Position the top left corner of the window at a point, with a zoom.
+    Xyz = bindings::PDFDEST_VIEW_XYZ as i32,
+    /// Fit the whole page in the window.
+    Fit = bindings::PDFDEST_VIEW_FIT as i32,
+    /// Fit the width of the page, with the top of the window at a y-coordinate.
+    FitH = bindings::PDFDEST_VIEW_FITH as i32,
+    /// Fit the height of the page, with the left side of the window at a x-coordinate.
+    FitV = bindings::PDFDEST_VIEW_FITV as i32,
+    /// Fit a rectangle of the page in the window.
+    FitR = bindings::PDFDEST_VIEW_FITR as i32,
+    /// Fit the bounding box of the page content in the window.
+    FitB = bindings::PDFDEST_VIEW_FITB as i32,
+    /// Fit the width of the bounding box of the page content, with the top of the window at a y-coordinate.
+    FitBH = bindings::PDFDEST_VIEW_FITBH as i32,
+    /// Fit the height of the bounding box of the page content, with the left side of the window at a x-coordinate.
+    FitBV = bindings::PDFDEST_VIEW_FITBV as i32,
+}
+
+impl DestinationViewMode {
+    fn from_i32(number: i32) -> Option<DestinationViewMode> {
+        match number {
+            x if x == DestinationViewMode::Unknown as i32 => Some(DestinationViewMode::Unknown),
+            x if x == DestinationViewMode::Xyz as i32 => Some(DestinationViewMode::Xyz),
+            x if x == DestinationViewMode::Fit as i32 => Some(DestinationViewMode::Fit),
+            x if x == DestinationViewMode::FitH as i32 => Some(DestinationViewMode::FitH),
+            x if x == DestinationViewMode::FitV as i32 => Some(DestinationViewMode::FitV),
+            x if x == DestinationViewMode::FitR as i32 => Some(DestinationViewMode::FitR),
+            x if x == DestinationViewMode::FitB as i32 => Some(DestinationViewMode::FitB),
+            x if x == DestinationViewMode::FitBH as i32 => Some(DestinationViewMode::FitBH),
+            x if x == DestinationViewMode::FitBV as i32 => Some(DestinationViewMode::FitBV),
+            _ => None,
+        }
+    }
+}
+
+/// The location and zoom of a destination with the [`DestinationViewMode::Xyz`] view mode.
+///
+/// Created using [`Library::get_destination_location`].
+/// Null values, which keep the current value of the viewer, are `None`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DestinationLocation {
+    /// The x-coordinate of the left side of the window.
+    pub x: Option<f32>,
+    /// The y-coordinate of the top of the window.
+    pub y: Option<f32>,
+    /// The zoom factor, `1` shows the page at its actual size.
+    pub zoom: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+
+    #[test]
+    fn bookmark_destination() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(OUTLINE_PDF, None).unwrap();
+
+        let page_index = |bookmark: &BookmarkHandle| {
+            library
+                .get_bookmark_destination(&document, bookmark)
+                .map(|destination| library.get_destination_page_index(&document, &destination))
+        };
+
+        let introduction = library.get_first_bookmark_child(&document, None).unwrap();
+        assert_eq!(page_index(&introduction), Some(Some(0)));
+
+        let chapter_1 = library
+            .get_next_bookmark_sibling(&document, &introduction)
+            .unwrap();
+        assert_eq!(page_index(&chapter_1), Some(Some(1)));
+
+        // A named destination
+        let chapter_2 = library
+            .get_next_bookmark_sibling(&document, &chapter_1)
+            .unwrap();
+        assert_eq!(page_index(&chapter_2), Some(Some(2)));
+
+        // Has a go to action instead of a destination
+        let section = library
+            .get_first_bookmark_child(&document, Some(&chapter_1))
+            .unwrap();
+        assert_eq!(page_index(&section), None);
+    }
+
+    #[test]
+    fn destination_view() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(OUTLINE_PDF, None).unwrap();
+
+        let introduction = library.get_first_bookmark_child(&document, None).unwrap();
+        let destination = library
+            .get_bookmark_destination(&document, &introduction)
+            .unwrap();
+        let (mode, parameters) = library.get_destination_view(&destination);
+        assert_eq!(mode, DestinationViewMode::Xyz);
+        assert_eq!(parameters[..2], [72.0, 720.0]);
+        let location = library.get_destination_location(&destination).unwrap();
+        assert_eq!((location.x, location.y), (Some(72.0), Some(720.0)));
+
+        let chapter_1 = library
+            .get_next_bookmark_sibling(&document, &introduction)
+            .unwrap();
+        let destination = library
+            .get_bookmark_destination(&document, &chapter_1)
+            .unwrap();
+        assert_eq!(
+            library.get_destination_view(&destination),
+            (DestinationViewMode::FitH, vec![500.0])
+        );
+        assert!(library.get_destination_location(&destination).is_none());
+
+        let chapter_2 = library
+            .get_next_bookmark_sibling(&document, &chapter_1)
+            .unwrap();
+        let destination = library
+            .get_bookmark_destination(&document, &chapter_2)
+            .unwrap();
+        assert_eq!(
+            library.get_destination_view(&destination),
+            (DestinationViewMode::Fit, vec![])
+        );
+    }
+}
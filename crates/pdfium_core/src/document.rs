@@ -0,0 +1,586 @@
+use crate::{
+    bindings, get_bytes, get_string, get_wide_string, to_byte_string, to_result, to_wide_string,
+    DocumentHandle, Library, PdfiumError,
+};
+
+impl Library {
+    /// Get an entry of the document information dictionary of a document.
+    ///
+    /// `tag` is the key of the entry, one of `Title`, `Author`, `Subject`, `Keywords`, `Creator`, `Producer`,
+    /// `CreationDate` or `ModDate`. Dates are in the PDF date format, like `D:20070223175637+02'00'`.
+    ///
+    /// Returns an empty string if the document doesn't have the entry or `tag` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let title = library.get_meta_text(&document_handle, "Title");
+    /// assert_eq!(title, "Dummy PDF file");
+    /// ```
+    pub fn get_meta_text(&self, document: &DocumentHandle, tag: &str) -> String {
+        let tag = match to_byte_string(tag) {
+            Ok(tag) => tag,
+            Err(_) => return String::new(),
+        };
+
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_GetMetaText(
+                document.handle.as_ptr(),
+                tag.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the PDF version of a document, from its header, times ten. For example `14` for PDF 1.4.
+    ///
+    /// Returns `None` if the document has no header, because it wasn't loaded from a file.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let version = library.get_file_version(&document_handle);
+    /// assert_eq!(version, Some(14));
+    /// ```
+    pub fn get_file_version(&self, document: &DocumentHandle) -> Option<i32> {
+        let mut version = 0;
+
+        let success =
+            unsafe { bindings::FPDF_GetFileVersion(document.handle.as_ptr(), &mut version) };
+
+        if success != 0 {
+            Some(version)
+        } else {
+            None
+        }
+    }
+
+    /// Get the permission flags of a document, see [`permission_flags`].
+    ///
+    /// Returns all flags if the document is not encrypted or was loaded with the owner password.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{permission_flags, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let permissions = library.get_document_permissions(&document_handle);
+    /// assert!(permissions & permission_flags::PRINT != 0);
+    /// ```
+    pub fn get_document_permissions(&self, document: &DocumentHandle) -> u32 {
+        unsafe { bindings::FPDF_GetDocPermissions(document.handle.as_ptr()) as u32 }
+    }
+
+    /// Get the permission flags a document grants to users, see [`permission_flags`].
+    ///
+    /// Unlike [`Library::get_document_permissions`] these are the user permissions, even if the document
+    /// was loaded with the owner password. Returns all flags if the document is not encrypted.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{permission_flags, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let permissions = library.get_document_user_permissions(&document_handle);
+    /// assert!(permissions & permission_flags::COPY != 0);
+    /// ```
+    pub fn get_document_user_permissions(&self, document: &DocumentHandle) -> u32 {
+        unsafe { bindings::FPDF_GetDocUserPermissions(document.handle.as_ptr()) as u32 }
+    }
+
+    /// Get the revision of the standard security handler of an encrypted document, like `4` for AES-128 encryption.
+    ///
+    /// Returns `None` if the document is not encrypted.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// use std::ffi::CString;
+    /// # static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../../../test_assets/password.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let password = CString::new("test").unwrap();
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PASSWORD_PDF, Some(&password))
+    ///     .unwrap();
+    ///
+    /// let revision = library.get_security_handler_revision(&document_handle);
+    /// assert_eq!(revision, Some(4));
+    /// ```
+    pub fn get_security_handler_revision(&self, document: &DocumentHandle) -> Option<i32> {
+        let revision =
+            unsafe { bindings::FPDF_GetSecurityHandlerRevision(document.handle.as_ptr()) };
+
+        if revision >= 0 {
+            Some(revision)
+        } else {
+            None
+        }
+    }
+
+    /// Get how a document wants to be displayed when it's opened, see [`PageMode`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PageMode};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_mode = library.get_page_mode(&document_handle);
+    /// assert_eq!(page_mode, PageMode::UseNone);
+    /// ```
+    pub fn get_page_mode(&self, document: &DocumentHandle) -> PageMode {
+        let page_mode = unsafe { bindings::FPDFDoc_GetPageMode(document.handle.as_ptr()) };
+
+        PageMode::from_i32(page_mode).unwrap_or(PageMode::Unknown)
+    }
+
+    /// Get a name entry of the viewer preferences of a document, like `Direction` or `ViewArea`.
+    ///
+    /// Returns `None` if the viewer preferences don't have `key`, its value is not a name,
+    /// or `key` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let direction = library.get_viewer_preference_name(&document_handle, "Direction");
+    /// assert_eq!(direction.as_deref(), Some("R2L"));
+    /// ```
+    pub fn get_viewer_preference_name(
+        &self,
+        document: &DocumentHandle,
+        key: &str,
+    ) -> Option<String> {
+        let key = to_byte_string(key).ok()?;
+
+        let name = get_string(|buffer, length| unsafe {
+            bindings::FPDF_VIEWERREF_GetName(
+                document.handle.as_ptr(),
+                key.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        });
+
+        Some(name).filter(|name| !name.is_empty())
+    }
+
+    /// Get a file identifier of a document, the `ID` entry of its trailer.
+    ///
+    /// The identifier is a byte string, usually an MD5 hash.
+    ///
+    /// Returns `None` if the document doesn't have file identifiers.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{FileIdentifierType, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let identifier = library
+    ///     .get_file_identifier(&document_handle, FileIdentifierType::Permanent)
+    ///     .unwrap();
+    /// assert_eq!(identifier.len(), 16);
+    /// ```
+    pub fn get_file_identifier(
+        &self,
+        document: &DocumentHandle,
+        identifier_type: FileIdentifierType,
+    ) -> Option<Vec<u8>> {
+        let mut identifier = get_bytes(|buffer, length| unsafe {
+            bindings::FPDF_GetFileIdentifier(
+                document.handle.as_ptr(),
+                identifier_type as _,
+                buffer as _,
+                length as _,
+            ) as _
+        });
+
+        // Remove the nul terminator
+        identifier.pop()?;
+
+        Some(identifier)
+    }
+
+    /// Check if a document is a tagged PDF, which has a structure tree describing the logical structure of its content.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// assert!(library.is_document_tagged(&document_handle));
+    /// ```
+    pub fn is_document_tagged(&self, document: &DocumentHandle) -> bool {
+        unsafe { bindings::FPDFCatalog_IsTagged(document.handle.as_ptr()) != 0 }
+    }
+
+    /// Get the natural language of a document, the `Lang` entry of its catalog, like `en-US`.
+    ///
+    /// Returns an empty string if the document doesn't have a language.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let language = library.get_document_language(&document_handle);
+    /// assert_eq!(language, "en-US");
+    /// ```
+    pub fn get_document_language(&self, document: &DocumentHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFCatalog_GetLanguage(document.handle.as_ptr(), buffer, length as _) as _
+        })
+    }
+
+    /// Set the natural language of a document, which screen readers use to pronounce its text.
+    ///
+    /// `language` is a language tag, like `en-US`.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): the document has no catalog.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let mut document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let result = library.set_document_language(&mut document_handle, "de-DE");
+    /// assert!(result.is_ok());
+    /// assert_eq!(library.get_document_language(&document_handle), "de-DE");
+    /// ```
+    pub fn set_document_language(
+        &self,
+        document: &mut DocumentHandle,
+        language: &str,
+    ) -> Result<(), PdfiumError> {
+        let language = to_wide_string(language);
+
+        to_result(unsafe {
+            bindings::FPDFCatalog_SetLanguage(document.handle.as_ptr(), language.as_ptr())
+        })
+    }
+}
+
+/// Which of the two file identifiers of a document to get.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FileIdentifierType {
+    /// The identifier of the original document, which doesn't change when the document is changed.
+    Permanent = bindings::FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT as i32,
+    /// The identifier of the current version of the document, which changes when the document is changed.
+    Changing = bindings::FPDF_FILEIDTYPE_FILEIDTYPE_CHANGING as i32,
+}
+
+/// How a document wants to be displayed when it's opened, the `PageMode` entry of its catalog.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PageMode {
+    /// The entry has an unknown value.
+    Unknown = bindings::PAGEMODE_UNKNOWN,
+    /// Show only the pages. This is the default.
+    UseNone = bindings::PAGEMODE_USENONE as i32,
+    /// Show the document outline next to the pages.
+    UseOutlines = bindings::PAGEMODE_USEOUTLINES as i32,
+    /// Show page thumbnails next to the pages.
+    UseThumbs = bindings::PAGEMODE_USETHUMBS as i32,
+    /// Show the pages in full screen mode, without menu bar and other controls.
+    FullScreen = bindings::PAGEMODE_FULLSCREEN as i32,
+    /// Show the optional content group panel next to the pages.
+    UseOc = bindings::PAGEMODE_USEOC as i32,
+    /// Show the attachments panel next to the pages.
+    UseAttachments = bindings::PAGEMODE_USEATTACHMENTS as i32,
+}
+
+impl PageMode {
+    fn from_i32(number: i32) -> Option<PageMode> {
+        match number {
+            x if x == PageMode::Unknown as i32 => Some(PageMode::Unknown),
+            x if x == PageMode::UseNone as i32 => Some(PageMode::UseNone),
+            x if x == PageMode::UseOutlines as i32 => Some(PageMode::UseOutlines),
+            x if x == PageMode::UseThumbs as i32 => Some(PageMode::UseThumbs),
+            x if x == PageMode::FullScreen as i32 => Some(PageMode::FullScreen),
+            x if x == PageMode::UseOc as i32 => Some(PageMode::UseOc),
+            x if x == PageMode::UseAttachments as i32 => Some(PageMode::UseAttachments),
+            _ => None,
+        }
+    }
+}
+
+pub mod permission_flags {
+    //! Permission flags of encrypted documents, returned by
+    //! [`get_document_permissions`](crate::Library::get_document_permissions), as defined in ISO 32000-1:2008, table 22.
+    //! They are combined with bit-wise OR.
+    //!
+    //! PDFium doesn't enforce permissions, viewers are expected to respect them.
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::permission_flags::*;
+    //!
+    //! // A document that can be printed and read, but not changed
+    //! let flags = PRINT | PRINT_HIGH_QUALITY | EXTRACT_FOR_ACCESSIBILITY;
+    //! assert!(flags & MODIFY == 0);
+    //! ```
+
+    /// Print the document, possibly in a degraded quality if [`PRINT_HIGH_QUALITY`] is missing.
+    pub const PRINT: u32 = 1 << 2;
+
+    /// Change the document in ways not covered by [`ANNOTATE`], [`FILL_FORMS`] and [`ASSEMBLE`].
+    pub const MODIFY: u32 = 1 << 3;
+
+    /// Copy or extract text and graphics.
+    pub const COPY: u32 = 1 << 4;
+
+    /// Add or change annotations and fill in form fields.
+    pub const ANNOTATE: u32 = 1 << 5;
+
+    /// Fill in existing form fields, even if [`ANNOTATE`] is missing.
+    pub const FILL_FORMS: u32 = 1 << 8;
+
+    /// Extract text and graphics for accessibility.
+    pub const EXTRACT_FOR_ACCESSIBILITY: u32 = 1 << 9;
+
+    /// Insert, rotate or delete pages and create bookmarks and thumbnails, even if [`MODIFY`] is missing.
+    pub const ASSEMBLE: u32 = 1 << 10;
+
+    /// Print the document in full quality.
+    pub const PRINT_HIGH_QUALITY: u32 = 1 << 11;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+    use std::ffi::CString;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../../../test_assets/password.pdf");
+    static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+
+    #[test]
+    fn meta_text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        assert_eq!(
+            library.get_meta_text(&document, "Author"),
+            "Evangelos Vlachogiannis"
+        );
+        assert_eq!(
+            library.get_meta_text(&document, "CreationDate"),
+            "D:20070223175637+02'00'"
+        );
+        assert_eq!(library.get_meta_text(&document, "Subject"), "");
+        assert_eq!(library.get_meta_text(&document, "Ti\0tle"), "");
+    }
+
+    #[test]
+    fn file_version() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_file_version(&document), Some(14));
+
+        let forms = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        assert_eq!(library.get_file_version(&forms), Some(17));
+    }
+
+    #[test]
+    fn document_permissions() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_document_permissions(&document), u32::MAX);
+        assert_eq!(library.get_document_user_permissions(&document), u32::MAX);
+
+        let password = CString::new("test").unwrap();
+        let encrypted = library
+            .load_document_from_bytes(DUMMY_PASSWORD_PDF, Some(&password))
+            .unwrap();
+        // The document allows everything, -4 in its encryption dictionary
+        assert_eq!(
+            library.get_document_user_permissions(&encrypted),
+            0xFFFF_FFFC
+        );
+    }
+
+    #[test]
+    fn security_handler_revision() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_security_handler_revision(&document), None);
+
+        let password = CString::new("test").unwrap();
+        let encrypted = library
+            .load_document_from_bytes(DUMMY_PASSWORD_PDF, Some(&password))
+            .unwrap();
+        assert_eq!(library.get_security_handler_revision(&encrypted), Some(4));
+    }
+
+    #[test]
+    fn page_mode() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        assert_eq!(library.get_page_mode(&document), PageMode::UseNone);
+
+        assert_eq!(PageMode::from_i32(3), Some(PageMode::FullScreen));
+        assert_eq!(PageMode::from_i32(-1), Some(PageMode::Unknown));
+        assert_eq!(PageMode::from_i32(6), None);
+    }
+
+    #[test]
+    fn viewer_preferences() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+
+        assert_eq!(
+            library
+                .get_viewer_preference_name(&document, "ViewArea")
+                .as_deref(),
+            Some("CropBox")
+        );
+        assert_eq!(
+            library.get_viewer_preference_name(&document, "Duplex"),
+            None
+        );
+
+        let dummy = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(
+            library.get_viewer_preference_name(&dummy, "Direction"),
+            None
+        );
+    }
+
+    #[test]
+    fn file_identifier() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let identifier = [
+            0xF7, 0xD7, 0x7B, 0x3D, 0x22, 0xB9, 0xF9, 0x28, 0x29, 0xD4, 0x9F, 0xF5, 0xD7, 0x8B,
+            0x8F, 0x28,
+        ];
+        assert_eq!(
+            library.get_file_identifier(&document, FileIdentifierType::Permanent),
+            Some(identifier.to_vec())
+        );
+        assert_eq!(
+            library.get_file_identifier(&document, FileIdentifierType::Changing),
+            Some(identifier.to_vec())
+        );
+
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        assert_eq!(
+            library.get_file_identifier(&document, FileIdentifierType::Permanent),
+            None
+        );
+    }
+
+    #[test]
+    fn tagged() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        let document = library.load_document_from_bytes(TAGGED_PDF, None).unwrap();
+        assert!(library.is_document_tagged(&document));
+
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert!(!library.is_document_tagged(&document));
+    }
+
+    #[test]
+    fn language() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        let mut document = library.load_document_from_bytes(TAGGED_PDF, None).unwrap();
+        assert_eq!(library.get_document_language(&document), "en-US");
+        library
+            .set_document_language(&mut document, "fr-CA")
+            .unwrap();
+        assert_eq!(library.get_document_language(&document), "fr-CA");
+
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_document_language(&document), "");
+    }
+}
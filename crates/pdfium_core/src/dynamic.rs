@@ -0,0 +1,170 @@
+//! Loading PDFium at runtime, with the `dynamic` feature.
+//!
+//! The functions of the bindings are looked up in the loaded library on their first call.
+
+use parking_lot::{const_mutex, Mutex};
+use std::ffi::OsStr;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static LIBRARY: Mutex<Option<libloading::Library>> = const_mutex(None);
+
+/// The functions that are called to initialize the library, so a file that isn't PDFium fails to load.
+static REQUIRED_FUNCTIONS: &[&str] = &[
+    "FPDF_InitLibraryWithConfig",
+    "FPDF_DestroyLibrary",
+    "FPDF_GetLastError",
+    "FPDF_LoadMemDocument64",
+    "FPDF_CloseDocument",
+];
+
+/// Load the PDFium library from `path`, like `/opt/app/libpdfium.so`, with the `dynamic` feature.
+///
+/// It has to be called before [`Library::init_library`](crate::Library::init_library), which returns `None`
+/// until PDFium is loaded. The library stays loaded until the process exits.
+///
+/// ## Errors
+/// - The file can't be loaded as a library, the message of the system is in the error.
+/// - A function that is required to initialize PDFium is missing from the library, an error of kind [`io::ErrorKind::NotFound`].
+/// - Other functions of the bindings are missing from the library, like with an older build of PDFium,
+///   an error of kind [`io::ErrorKind::NotFound`] that lists the missing functions.
+/// - PDFium is already loaded, an error of kind [`io::ErrorKind::AlreadyExists`].
+///
+/// The functions of a [`Capability`](crate::Capability) may be missing, the functions of the crate that use them
+/// fail with [`PdfiumError::Unsupported`](crate::PdfiumError::Unsupported) instead.
+///
+/// ## Examples
+/// ```no_run
+/// use pdfium_core::Library;
+///
+/// pdfium_core::load_library("/opt/app/libpdfium.so").unwrap();
+///
+/// let library = Library::init_library().unwrap();
+/// ```
+pub fn load_library(path: impl AsRef<OsStr>) -> io::Result<()> {
+    let path = path.as_ref();
+    let mut loaded = LIBRARY.lock();
+
+    if loaded.is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "PDFium is already loaded",
+        ));
+    }
+
+    let library = libloading::Library::new(path)?;
+
+    for function in REQUIRED_FUNCTIONS {
+        if !has_symbol(&library, function) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "{} is not PDFium, it doesn't have the function {}",
+                    path.to_string_lossy(),
+                    function
+                ),
+            ));
+        }
+    }
+
+    let missing: Vec<_> = crate::bindings::FUNCTIONS
+        .iter()
+        .copied()
+        .filter(|function| !has_symbol(&library, function))
+        .collect();
+    if !missing.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "{} is missing the functions {}, it might be too old",
+                path.to_string_lossy(),
+                missing.join(", ")
+            ),
+        ));
+    }
+
+    *loaded = Some(library);
+    Ok(())
+}
+
+/// Check if PDFium is loaded with [`load_library`].
+pub(crate) fn is_loaded() -> bool {
+    LIBRARY.lock().is_some()
+}
+
+/// Check if the loaded library has a function, `false` if PDFium is not loaded.
+pub(crate) fn has_function(name: &str) -> bool {
+    match &*LIBRARY.lock() {
+        Some(library) => has_symbol(library, name),
+        None => false,
+    }
+}
+
+fn has_symbol(library: &libloading::Library, name: &str) -> bool {
+    let name = format!("{}\0", name);
+
+    unsafe { library.get::<unsafe extern "C" fn()>(name.as_bytes()) }.is_ok()
+}
+
+/// A function of the loaded library, generated for every function of the bindings.
+pub(crate) struct Symbol {
+    /// The name, with a nul byte at the end.
+    name: &'static [u8],
+    address: AtomicUsize,
+}
+
+impl Symbol {
+    pub(crate) const fn new(name: &'static [u8]) -> Self {
+        Symbol {
+            name,
+            address: AtomicUsize::new(0),
+        }
+    }
+
+    /// The address of the function.
+    ///
+    /// ## Panics
+    /// Panics if PDFium is not loaded or the function is missing from the library. [`load_library`] checks
+    /// that the library has all functions but the ones of capabilities, which are only called after
+    /// [`Library::require_capability`](crate::Library::require_capability).
+    pub(crate) fn address(&self) -> usize {
+        let address = self.address.load(Ordering::Relaxed);
+        if address != 0 {
+            return address;
+        }
+
+        let name = String::from_utf8_lossy(&self.name[..self.name.len() - 1]);
+        let library = LIBRARY.lock();
+        let library = library.as_ref().unwrap_or_else(|| {
+            panic!(
+                "PDFium is not loaded, call `load_library` before `{}`",
+                name
+            )
+        });
+
+        let function =
+            unsafe { library.get::<unsafe extern "C" fn()>(self.name) }.unwrap_or_else(|_| {
+                panic!(
+                    "the loaded PDFium doesn't have the function `{}`, it might be too old",
+                    name
+                )
+            });
+
+        let address = *function as usize;
+        self.address.store(address, Ordering::Relaxed);
+
+        address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_library() {
+        let error = super::load_library("/missing/libpdfium.so").unwrap_err();
+        assert_ne!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert!(!is_loaded());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{bindings, catch_panic};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::os::raw::{c_int, c_uchar, c_ulong, c_void};
+
+/// Create a `FPDF_FILEACCESS` that reads from `buffer`.
+///
+/// PDFium only reads blocks inside `m_FileLen`, so the start of `buffer` is enough for the callback.
+/// `buffer` must outlive every use of the file access by PDFium.
+pub(crate) fn from_bytes(buffer: &[u8]) -> bindings::FPDF_FILEACCESS {
+    bindings::FPDF_FILEACCESS {
+        m_FileLen: buffer.len() as c_ulong,
+        m_GetBlock: Some(get_block_from_bytes),
+        m_Param: buffer.as_ptr() as *mut c_void,
+    }
+}
+
+unsafe extern "C" fn get_block_from_bytes(
+    param: *mut c_void,
+    position: c_ulong,
+    buffer: *mut c_uchar,
+    size: c_ulong,
+) -> c_int {
+    std::ptr::copy_nonoverlapping(
+        (param as *const u8).add(position as usize),
+        buffer,
+        size as usize,
+    );
+
+    1
+}
+
+pub(crate) trait ReadSeek: Read + Seek {}
+
+impl<T: Read + Seek> ReadSeek for T {}
+
+/// The `m_Param` of a `FPDF_FILEACCESS` that reads from a [`Read`] and [`Seek`].
+///
+/// PDFium reads from it until the document is closed, so it's boxed and owned by the document handle.
+pub(crate) struct FileRead<'a> {
+    reader: Box<dyn ReadSeek + 'a>,
+}
+
+impl<'a> FileRead<'a> {
+    pub(crate) fn new(reader: impl Read + Seek + 'a) -> Box<Self> {
+        Box::new(FileRead {
+            reader: Box::new(reader),
+        })
+    }
+
+    /// Create a `FPDF_FILEACCESS` for the whole reader, it's only valid as long as `self` doesn't move.
+    ///
+    /// Fails if the length of the reader can't be found.
+    pub(crate) fn file_access(&mut self) -> io::Result<bindings::FPDF_FILEACCESS> {
+        let length = self.reader.seek(SeekFrom::End(0))?;
+
+        Ok(bindings::FPDF_FILEACCESS {
+            m_FileLen: length as c_ulong,
+            m_GetBlock: Some(get_block_from_reader),
+            m_Param: (self as *mut Self).cast(),
+        })
+    }
+}
+
+unsafe extern "C" fn get_block_from_reader(
+    param: *mut c_void,
+    position: c_ulong,
+    buffer: *mut c_uchar,
+    size: c_ulong,
+) -> c_int {
+    let file_read = &mut *param.cast::<FileRead>();
+    let buffer = if size == 0 {
+        &mut []
+    } else {
+        std::slice::from_raw_parts_mut(buffer, size as usize)
+    };
+
+    catch_panic(0, || {
+        let result = file_read
+            .reader
+            .seek(SeekFrom::Start(position as _))
+            .and_then(|_| file_read.reader.read_exact(buffer));
+
+        result.is_ok() as c_int
+    })
+}
+
+/// A `FPDF_FILEWRITE` that writes to a [`Write`].
+///
+/// `file_write` has to be the first field, PDFium passes a pointer to it to the callback.
+#[repr(C)]
+pub(crate) struct FileWrite<'a> {
+    file_write: bindings::FPDF_FILEWRITE,
+    writer: &'a mut dyn Write,
+}
+
+impl<'a> FileWrite<'a> {
+    pub(crate) fn new(writer: &'a mut dyn Write) -> Self {
+        FileWrite {
+            file_write: bindings::FPDF_FILEWRITE {
+                version: 1,
+                WriteBlock: Some(write_block),
+            },
+            writer,
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut bindings::FPDF_FILEWRITE {
+        (self as *mut Self).cast()
+    }
+}
+
+unsafe extern "C" fn write_block(
+    this: *mut bindings::FPDF_FILEWRITE,
+    data: *const c_void,
+    size: c_ulong,
+) -> c_int {
+    let file_write = &mut *this.cast::<FileWrite>();
+
+    let data = if size == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data as *const u8, size as usize)
+    };
+
+    catch_panic(0, || file_write.writer.write_all(data).is_ok() as c_int)
+}
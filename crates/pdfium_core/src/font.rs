@@ -0,0 +1,461 @@
+use crate::{bindings, get_bytes, get_string, Library, PathSegmentHandle};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the base name of a font.
+    ///
+    /// For subset fonts the name includes the subset prefix, for example `"BAAAAA+Arial-BoldMT"`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let name = library.get_font_base_name(&font_handle);
+    /// assert_eq!(name, "BAAAAA+Arial-BoldMT");
+    /// ```
+    pub fn get_font_base_name(&self, font: &FontHandle) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDFFont_GetBaseFontName(font.handle.as_ptr(), buffer as _, length as _) as _
+        })
+    }
+
+    /// Get the family name of a font, for example `"Arial"`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let name = library.get_font_family_name(&font_handle);
+    /// assert!(!name.is_empty());
+    /// ```
+    pub fn get_font_family_name(&self, font: &FontHandle) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDFFont_GetFamilyName(font.handle.as_ptr(), buffer as _, length as _) as _
+        })
+    }
+
+    /// Get the data of a font program, for example a TrueType file.
+    ///
+    /// The data is decoded, so all stream filters are already applied.
+    /// If the font is not embedded this is the data of the font PDFium uses as a substitute.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let data = library.get_font_data(&font_handle);
+    /// assert!(!data.is_empty());
+    /// ```
+    pub fn get_font_data(&self, font: &FontHandle) -> Vec<u8> {
+        get_bytes(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDFFont_GetFontData(
+                    font.handle.as_ptr(),
+                    buffer,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            if success != 0 {
+                out_length as _
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Check if the font program of a font is embedded in the document.
+    ///
+    /// Fonts that are not embedded are replaced by a similar system font when rendered.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// assert!(library.get_font_is_embedded(&font_handle));
+    /// ```
+    pub fn get_font_is_embedded(&self, font: &FontHandle) -> bool {
+        unsafe { bindings::FPDFFont_GetIsEmbedded(font.handle.as_ptr()) == 1 }
+    }
+
+    /// Get the descriptor flags of a font.
+    ///
+    /// See [`font_flags`](crate::font_flags) for the meaning of each flag.
+    ///
+    /// Will return `None` if the flags can't be read.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let flags = library.get_font_flags(&font_handle);
+    /// assert!(flags.is_some());
+    /// ```
+    pub fn get_font_flags(&self, font: &FontHandle) -> Option<i32> {
+        let flags = unsafe { bindings::FPDFFont_GetFlags(font.handle.as_ptr()) };
+
+        Some(flags).filter(|flags| *flags != -1)
+    }
+
+    /// Get the weight of a font.
+    ///
+    /// Typical values are `400` for normal and `700` for bold fonts.
+    ///
+    /// Will return `None` if the weight can't be read.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let weight = library.get_font_weight(&font_handle);
+    /// assert!(weight.is_some());
+    /// ```
+    pub fn get_font_weight(&self, font: &FontHandle) -> Option<i32> {
+        let weight = unsafe { bindings::FPDFFont_GetWeight(font.handle.as_ptr()) };
+
+        Some(weight).filter(|weight| *weight != -1)
+    }
+
+    /// Get the italic angle of a font.
+    ///
+    /// The angle is in degrees counterclockwise from vertical, so fonts that slope to the right have a negative angle.
+    ///
+    /// Will return `None` if the angle can't be read.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let angle = library.get_font_italic_angle(&font_handle);
+    /// assert_eq!(angle, Some(0));
+    /// ```
+    pub fn get_font_italic_angle(&self, font: &FontHandle) -> Option<i32> {
+        let mut angle = 0;
+
+        let success =
+            unsafe { bindings::FPDFFont_GetItalicAngle(font.handle.as_ptr(), &mut angle) };
+
+        Some(angle).filter(|_| success != 0)
+    }
+
+    /// Get the outline of the glyph for `character`.
+    ///
+    /// `font_size` is the size of the font, the outline's coordinates are scaled accordingly.
+    ///
+    /// Will return `None` if the font has no glyph for `character`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let glyph_path_handle = library.get_font_glyph_path(&font_handle, 'D', 16.0);
+    /// assert!(glyph_path_handle.is_some());
+    /// ```
+    pub fn get_font_glyph_path<'data, 'library>(
+        &'library self,
+        font: &'data FontHandle,
+        character: char,
+        font_size: f32,
+    ) -> Option<GlyphPathHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFFont_GetGlyphPath(font.handle.as_ptr(), character as u32, font_size)
+        } as *mut _);
+
+        handle.map(|handle| GlyphPathHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        })
+    }
+
+    /// Get number of segments inside a glyph path.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let glyph_path_handle = library.get_font_glyph_path(&font_handle, 'D', 16.0).unwrap();
+    /// assert!(library.get_glyph_path_segment_count(&glyph_path_handle) > 0);
+    /// ```
+    pub fn get_glyph_path_segment_count(&self, glyph_path: &GlyphPathHandle) -> usize {
+        let count =
+            unsafe { bindings::FPDFGlyphPath_CountGlyphSegments(glyph_path.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a segment of a glyph path.
+    ///
+    /// `index` 0 for the first segment.
+    ///
+    /// Will return `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PathSegmentType};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let font_handle = library.get_text_object_font(&text_object_handle).unwrap();
+    ///
+    /// let glyph_path_handle = library.get_font_glyph_path(&font_handle, 'D', 16.0).unwrap();
+    /// let segment_handle = library.get_glyph_path_segment(&glyph_path_handle, 0).unwrap();
+    /// assert_eq!(library.get_path_segment_type(&segment_handle), PathSegmentType::MoveTo);
+    /// ```
+    pub fn get_glyph_path_segment<'data, 'library>(
+        &'library self,
+        glyph_path: &'data GlyphPathHandle,
+        index: usize,
+    ) -> Option<PathSegmentHandle<'data, 'library>> {
+        let handle = unsafe {
+            bindings::FPDFGlyphPath_GetGlyphPathSegment(glyph_path.handle.as_ptr(), index as i32)
+        };
+
+        PathSegmentHandle::new(handle)
+    }
+}
+
+/// Safe handle to PDFium Font.
+///
+/// Created using [`Library::get_text_object_font`].
+///
+/// The font is owned by its document so nothing is freed when the handle is dropped.
+pub struct FontHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_font_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(FontHandle: Sync, Send);
+
+impl FontHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_font_t__>) -> Self {
+        FontHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// Safe handle to PDFium Glyph Path.
+///
+/// Created using [`Library::get_font_glyph_path`].
+///
+/// The glyph path is owned by its font so nothing is freed when the handle is dropped.
+pub struct GlyphPathHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_glyphpath_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(GlyphPathHandle: Sync, Send);
+
+pub mod font_flags {
+    //! Font descriptor flags returned by [`get_font_flags`](crate::Library::get_font_flags), as defined in ISO 32000-1:2008, table 123.
+    //! They are combined with bit-wise OR.
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::font_flags::*;
+    //!
+    //! let flags = SERIF | ITALIC;
+    //! assert!(flags & ITALIC != 0);
+    //! ```
+
+    /// All glyphs have the same width.
+    pub const FIXED_PITCH: i32 = 1;
+
+    /// Glyphs have serifs.
+    pub const SERIF: i32 = 1 << 1;
+
+    /// Font contains glyphs outside the Adobe standard Latin character set.
+    pub const SYMBOLIC: i32 = 1 << 2;
+
+    /// Glyphs resemble cursive handwriting.
+    pub const SCRIPT: i32 = 1 << 3;
+
+    /// Font uses the Adobe standard Latin character set or a subset of it.
+    pub const NON_SYMBOLIC: i32 = 1 << 5;
+
+    /// Glyphs have dominant vertical strokes that are slanted.
+    pub const ITALIC: i32 = 1 << 6;
+
+    /// Font contains no lowercase letters.
+    pub const ALL_CAP: i32 = 1 << 16;
+
+    /// Lowercase letters are drawn as smaller uppercase letters.
+    pub const SMALL_CAP: i32 = 1 << 17;
+
+    /// Bold glyphs are drawn with extra thickness even at small sizes.
+    pub const FORCE_BOLD: i32 = 1 << 18;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PathSegmentType, TEST_LOCK};
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn font_info() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let text = library.get_page_object(&page, 0).unwrap();
+        let font = library.get_text_object_font(&text).unwrap();
+
+        assert!(library.get_font_family_name(&font).contains("Arial"));
+        assert_eq!(library.get_font_italic_angle(&font), Some(0));
+        assert!(library.get_font_is_embedded(&font));
+
+        let flags = library.get_font_flags(&font).unwrap();
+        assert_eq!(flags & font_flags::ITALIC, 0);
+
+        // The embedded font is a TrueType file.
+        let data = library.get_font_data(&font);
+        assert_eq!(&data[..4], &[0x00, 0x01, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn glyph_path() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let text = library.get_page_object(&page, 0).unwrap();
+        let font = library.get_text_object_font(&text).unwrap();
+
+        let glyph_path = library.get_font_glyph_path(&font, 'D', 16.0).unwrap();
+        let count = library.get_glyph_path_segment_count(&glyph_path);
+        assert!(count > 0);
+
+        let first = library.get_glyph_path_segment(&glyph_path, 0).unwrap();
+        assert_eq!(
+            library.get_path_segment_type(&first),
+            PathSegmentType::MoveTo
+        );
+
+        for index in 0..count {
+            let segment = library.get_glyph_path_segment(&glyph_path, index).unwrap();
+            let (x, y) = library.get_path_segment_point(&segment).unwrap();
+            assert!((0.0..=16.0).contains(&x));
+            assert!((-16.0..=16.0).contains(&y));
+        }
+
+        assert!(library.get_glyph_path_segment(&glyph_path, count).is_none());
+    }
+}
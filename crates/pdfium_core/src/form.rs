@@ -0,0 +1,1335 @@
+use crate::{
+    bindings, AnnotationHandle, BitmapHandle, DocumentHandle, FormFieldType, Library, PageHandle,
+    PageOrientation, PdfiumError, Point, Rect,
+};
+use crate::{catch_panic, get_wide_string, to_result, to_wide_string};
+use static_assertions::assert_not_impl_any;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the type of the interactive form of a document.
+    ///
+    /// Documents with XFA forms can not be filled or rendered correctly, because PDFium is built without XFA support.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{FormType, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_type = library.get_form_type(&document_handle);
+    /// assert_eq!(form_type, FormType::AcroForm);
+    /// ```
+    pub fn get_form_type(&self, document: &DocumentHandle) -> FormType {
+        let form_type = unsafe { bindings::FPDF_GetFormType(document.handle.as_ptr()) };
+
+        FormType::from_i32(form_type).unwrap_or(FormType::None)
+    }
+
+    /// Initialize the form fill environment of a document.
+    ///
+    /// The form fill environment is needed to access the interactive form and the variable text of annotations.
+    /// All notifications of the environment are ignored, use [`Library::init_form_fill_environment_with_callbacks`] to receive them.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to initialize the form fill environment.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle);
+    /// assert!(form_handle.is_ok());
+    /// ```
+    pub fn init_form_fill_environment<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+    ) -> Result<FormHandle<'data, 'library>, PdfiumError> {
+        self.init_form_fill_environment_with_callbacks(document, NoCallbacks)
+    }
+
+    /// Initialize the form fill environment of a document with callbacks.
+    ///
+    /// PDFium notifies `callbacks` about changes of the form, for example when an area of a page has to be redrawn.
+    /// See [`FormFillCallbacks`] for all notifications.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to initialize the form fill environment.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{FormFillCallbacks, Library, Rect};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// struct Invalidated(Vec<Rect>);
+    ///
+    /// impl FormFillCallbacks for Invalidated {
+    ///     fn invalidate(&mut self, rect: Rect) {
+    ///         self.0.push(rect);
+    ///     }
+    /// }
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle =
+    ///     library.init_form_fill_environment_with_callbacks(&document_handle, Invalidated(Vec::new()));
+    /// assert!(form_handle.is_ok());
+    /// ```
+    pub fn init_form_fill_environment_with_callbacks<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        callbacks: impl FormFillCallbacks + 'data,
+    ) -> Result<FormHandle<'data, 'library>, PdfiumError> {
+        let (handle, form_info) = init_form(document, callbacks)?;
+
+        Ok(FormHandle {
+            handle,
+            form_info: Some(form_info),
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        })
+    }
+
+    /// Get the form fill environment of a document like [`Library::init_form_fill_environment`],
+    /// but initialize it only on the first call and keep it until the document is closed.
+    ///
+    /// Initializing the environment is expensive, so use this when forms are rendered or filled repeatedly.
+    /// All notifications of the environment are ignored. The environment is exited by [`Library::load_xfa`].
+    ///
+    /// ## Errors
+    /// The same errors as [`Library::init_form_fill_environment`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// // The environment is only initialized once
+    /// for _ in 0..3 {
+    ///     let form_handle = library.init_cached_form_fill_environment(&document_handle);
+    ///     assert!(form_handle.is_ok());
+    /// }
+    /// ```
+    pub fn init_cached_form_fill_environment<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+    ) -> Result<FormHandle<'data, 'library>, PdfiumError> {
+        let handle = match document.form.get() {
+            Some(form) => form.handle,
+            None => {
+                let (handle, form_info) = init_form(document, NoCallbacks)?;
+                document
+                    .form
+                    .get_or_init(|| CachedForm { handle, form_info })
+                    .handle
+            }
+        };
+
+        Ok(FormHandle {
+            handle,
+            form_info: None,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        })
+    }
+
+    /// Tell the form fill environment that a page was loaded.
+    ///
+    /// Forms on a page only react to the form fill environment while the returned handle is alive.
+    /// The form fill environment is told that the page is closed when the handle is dropped.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// ```
+    pub fn form_on_after_load_page<'a, 'library>(
+        &'library self,
+        form: &'a FormHandle,
+        page: &'a PageHandle,
+    ) -> FormPageHandle<'a, 'library> {
+        unsafe {
+            bindings::FORM_OnAfterLoadPage(page.handle.as_ptr(), form.handle.as_ptr());
+        }
+
+        FormPageHandle {
+            form: form.handle,
+            page: page.handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+
+    /// Get the type of the form field at a point of a page.
+    ///
+    /// `point` is in page space.
+    ///
+    /// Returns `None` if there is no form field at `point`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{FormFieldType, Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let field_type = library.get_form_field_type_at_point(
+    ///     &form_handle,
+    ///     &page_handle,
+    ///     &Point { x: 150.0, y: 710.0 },
+    /// );
+    /// assert_eq!(field_type, Some(FormFieldType::TextField));
+    /// ```
+    pub fn get_form_field_type_at_point(
+        &self,
+        form: &FormHandle,
+        page: &PageHandle,
+        point: &Point,
+    ) -> Option<FormFieldType> {
+        let field_type = unsafe {
+            bindings::FPDFPage_HasFormFieldAtPoint(
+                form.handle.as_ptr(),
+                page.handle.as_ptr(),
+                point.x as f64,
+                point.y as f64,
+            )
+        };
+
+        FormFieldType::from_i32(field_type)
+    }
+
+    /// Get the z-order of the form field at a point of a page.
+    ///
+    /// `point` is in page space. Form fields with a higher z-order are drawn on top of those with a lower one.
+    ///
+    /// Returns `None` if there is no form field at `point`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let z_order = library.get_form_field_z_order_at_point(
+    ///     &form_handle,
+    ///     &page_handle,
+    ///     &Point { x: 150.0, y: 710.0 },
+    /// );
+    /// assert_eq!(z_order, Some(0));
+    /// ```
+    pub fn get_form_field_z_order_at_point(
+        &self,
+        form: &FormHandle,
+        page: &PageHandle,
+        point: &Point,
+    ) -> Option<usize> {
+        let z_order = unsafe {
+            bindings::FPDFPage_FormFieldZOrderAtPoint(
+                form.handle.as_ptr(),
+                page.handle.as_ptr(),
+                point.x as f64,
+                point.y as f64,
+            )
+        };
+
+        if z_order < 0 {
+            None
+        } else {
+            Some(z_order as usize)
+        }
+    }
+
+    /// Get the widget annotation of the form field at a point of a page.
+    ///
+    /// `point` is in page space. If widgets overlap, the one on top is returned.
+    ///
+    /// Returns `None` if there is no form field at `point`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let annotation_handle = library
+    ///     .get_form_field_annotation_at_point(&form_handle, &page_handle, &Point { x: 150.0, y: 710.0 })
+    ///     .unwrap();
+    /// assert_eq!(library.get_annotation_index(&page_handle, &annotation_handle), Some(0));
+    /// ```
+    pub fn get_form_field_annotation_at_point<'page, 'library>(
+        &'library self,
+        form: &FormHandle,
+        page: &'page PageHandle,
+        point: &Point,
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFAnnot_GetFormFieldAtPoint(
+                form.handle.as_ptr(),
+                page.handle.as_ptr(),
+                &point.to_fs_pointf(),
+            )
+        });
+
+        handle.map(AnnotationHandle::new)
+    }
+
+    /// Render the form fields of a page to a device independent bitmap.
+    ///
+    /// Form fields are drawn with their current values on top of the bitmap, so the page should be rendered with
+    /// [`Library::render_page_to_bitmap`] first, using the same position, size and orientation.
+    ///
+    /// `x`, `y`, `width`, `height`, `orientation` and `flags` work like they do for [`Library::render_page_to_bitmap`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{rendering_flags, BitmapFormat, Library, PageOrientation};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// let width = library.get_page_width(&page_handle) as i32;
+    /// let height = library.get_page_height(&page_handle) as i32;
+    ///
+    /// let mut bitmap_handle = library
+    ///     .create_bitmap(width as usize, height as usize, BitmapFormat::BGRA)
+    ///     .unwrap();
+    ///
+    /// library.render_page_to_bitmap(
+    ///     &mut bitmap_handle,
+    ///     &page_handle,
+    ///     0,
+    ///     0,
+    ///     width,
+    ///     height,
+    ///     PageOrientation::Normal,
+    ///     rendering_flags::ANNOTATIONS,
+    /// );
+    /// library.render_form_to_bitmap(
+    ///     &mut bitmap_handle,
+    ///     &form_page_handle,
+    ///     0,
+    ///     0,
+    ///     width,
+    ///     height,
+    ///     PageOrientation::Normal,
+    ///     rendering_flags::ANNOTATIONS,
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_form_to_bitmap(
+        &self,
+        bitmap: &mut BitmapHandle,
+        form_page: &FormPageHandle,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        orientation: PageOrientation,
+        flags: i32,
+    ) {
+        unsafe {
+            bindings::FPDF_FFLDraw(
+                form_page.form.as_ptr(),
+                bitmap.handle.as_ptr(),
+                form_page.page.as_ptr(),
+                x,
+                y,
+                width,
+                height,
+                orientation as i32,
+                flags,
+            );
+        }
+    }
+
+    /// Give the keyboard focus to a form field annotation, like the user clicking on it.
+    ///
+    /// Text fields with the focus can be edited with [`Library::form_select_all_text`] and [`Library::form_replace_selection`].
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The annotation can't get the focus, for example because it's not a form field
+    ///   or its page wasn't loaded with [`Library::form_on_after_load_page`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// let result = library.set_form_focused_annotation(&form_page_handle, &annotation_handle);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_form_focused_annotation(
+        &self,
+        form_page: &FormPageHandle,
+        annotation: &AnnotationHandle,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FORM_SetFocusedAnnot(form_page.form.as_ptr(), annotation.handle.as_ptr())
+        })
+    }
+
+    /// Remove the keyboard focus from the focused form field, which commits its new value.
+    ///
+    /// The value runs through the field's format and validate JavaScript and its appearance is regenerated.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): No form field has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    ///
+    /// let result = library.form_kill_focus(&form_page_handle);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn form_kill_focus(&self, form_page: &FormPageHandle) -> Result<(), PdfiumError> {
+        to_result(unsafe { bindings::FORM_ForceToKillFocus(form_page.form.as_ptr()) })
+    }
+
+    /// Select all text of the focused text field.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): No text field on the page has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    ///
+    /// let result = library.form_select_all_text(&form_page_handle);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn form_select_all_text(&self, form_page: &FormPageHandle) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FORM_SelectAllText(form_page.form.as_ptr(), form_page.page.as_ptr())
+        })
+    }
+
+    /// Replace the selected text of the focused text field with `text`, like the user typing it.
+    ///
+    /// Inserts `text` at the cursor if nothing is selected and does nothing if no text field on the page has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// library.form_select_all_text(&form_page_handle).unwrap();
+    /// library.form_replace_selection(&form_page_handle, "Bob");
+    /// library.form_kill_focus(&form_page_handle).unwrap();
+    ///
+    /// let value = library.get_annotation_form_field_value(&form_handle, &annotation_handle);
+    /// assert_eq!(value, "Bob");
+    /// ```
+    pub fn form_replace_selection(&self, form_page: &FormPageHandle, text: &str) {
+        let text = to_wide_string(text);
+
+        unsafe {
+            bindings::FORM_ReplaceSelection(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                text.as_ptr(),
+            );
+        }
+    }
+
+    /// Get the selected text of the focused form field, for example to copy it to the clipboard.
+    ///
+    /// Returns an empty string if nothing is selected or no form field on the page has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// library.form_select_all_text(&form_page_handle).unwrap();
+    ///
+    /// let text = library.get_form_selected_text(&form_page_handle);
+    /// assert_eq!(text, "Alice");
+    /// ```
+    pub fn get_form_selected_text(&self, form_page: &FormPageHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FORM_GetSelectedText(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the whole text of the focused form field, including changes that are not committed yet.
+    ///
+    /// Returns an empty string if no form field on the page has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    ///
+    /// let text = library.get_form_focused_text(&form_page_handle);
+    /// assert_eq!(text, "Alice");
+    /// ```
+    pub fn get_form_focused_text(&self, form_page: &FormPageHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FORM_GetFocusedText(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+    /// Select or deselect an option of the focused combo box or list box, like the user clicking on it.
+    ///
+    /// Selecting an option of a combo box or single select list box deselects the other options.
+    /// The new value is committed when the form field loses the focus, for example with [`Library::form_kill_focus`].
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): No combo box or list box on the page has the focus, or `index` is out of range.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 4).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// library.form_set_index_selected(&form_page_handle, 2, true).unwrap();
+    /// library.form_kill_focus(&form_page_handle).unwrap();
+    ///
+    /// let value = library.get_annotation_form_field_value(&form_handle, &annotation_handle);
+    /// assert_eq!(value, "Japan");
+    /// ```
+    pub fn form_set_index_selected(
+        &self,
+        form_page: &FormPageHandle,
+        index: usize,
+        selected: bool,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FORM_SetIndexSelected(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                index as _,
+                selected as _,
+            )
+        })
+    }
+
+    /// Check if the focused form field has a change that can be undone with [`Library::form_undo`].
+    ///
+    /// Returns `false` if no form field on the page has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// assert!(!library.form_can_undo(&form_page_handle));
+    ///
+    /// library.form_replace_selection(&form_page_handle, "Bob");
+    /// assert!(library.form_can_undo(&form_page_handle));
+    /// ```
+    pub fn form_can_undo(&self, form_page: &FormPageHandle) -> bool {
+        unsafe { bindings::FORM_CanUndo(form_page.form.as_ptr(), form_page.page.as_ptr()) != 0 }
+    }
+
+    /// Check if the focused form field has an undone change that can be redone with [`Library::form_redo`].
+    ///
+    /// Returns `false` if no form field on the page has the focus.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// library.form_replace_selection(&form_page_handle, "Bob");
+    /// assert!(!library.form_can_redo(&form_page_handle));
+    ///
+    /// library.form_undo(&form_page_handle).unwrap();
+    /// assert!(library.form_can_redo(&form_page_handle));
+    /// ```
+    pub fn form_can_redo(&self, form_page: &FormPageHandle) -> bool {
+        unsafe { bindings::FORM_CanRedo(form_page.form.as_ptr(), form_page.page.as_ptr()) != 0 }
+    }
+
+    /// Undo the last change of the focused form field, like the user pressing Ctrl+Z.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): No form field on the page has the focus or there is nothing to undo.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// library.form_replace_selection(&form_page_handle, "Bob");
+    ///
+    /// let result = library.form_undo(&form_page_handle);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn form_undo(&self, form_page: &FormPageHandle) -> Result<(), PdfiumError> {
+        to_result(unsafe { bindings::FORM_Undo(form_page.form.as_ptr(), form_page.page.as_ptr()) })
+    }
+
+    /// Redo the last undone change of the focused form field, like the user pressing Ctrl+Y.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): No form field on the page has the focus or there is nothing to redo.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library.set_form_focused_annotation(&form_page_handle, &annotation_handle).unwrap();
+    /// library.form_replace_selection(&form_page_handle, "Bob");
+    /// library.form_undo(&form_page_handle).unwrap();
+    ///
+    /// let result = library.form_redo(&form_page_handle);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn form_redo(&self, form_page: &FormPageHandle) -> Result<(), PdfiumError> {
+        to_result(unsafe { bindings::FORM_Redo(form_page.form.as_ptr(), form_page.page.as_ptr()) })
+    }
+}
+
+/// The type of the interactive form of a document.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FormType {
+    /// The document has no interactive form.
+    None = bindings::FORMTYPE_NONE as i32,
+    /// The document has an AcroForm.
+    AcroForm = bindings::FORMTYPE_ACRO_FORM as i32,
+    /// The document has an XFA form that replaces the pages.
+    XfaFull = bindings::FORMTYPE_XFA_FULL as i32,
+    /// The document has an XFA form that is drawn on top of the pages.
+    XfaForeground = bindings::FORMTYPE_XFA_FOREGROUND as i32,
+}
+
+impl FormType {
+    fn from_i32(number: i32) -> Option<FormType> {
+        match number {
+            x if x == FormType::None as i32 => Some(FormType::None),
+            x if x == FormType::AcroForm as i32 => Some(FormType::AcroForm),
+            x if x == FormType::XfaFull as i32 => Some(FormType::XfaFull),
+            x if x == FormType::XfaForeground as i32 => Some(FormType::XfaForeground),
+            _ => None,
+        }
+    }
+
+    /// Whether the form is an XFA form.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::FormType;
+    ///
+    /// assert!(FormType::XfaFull.is_xfa());
+    /// assert!(!FormType::AcroForm.is_xfa());
+    /// ```
+    pub fn is_xfa(self) -> bool {
+        matches!(self, FormType::XfaFull | FormType::XfaForeground)
+    }
+}
+
+/// Notifications of a form fill environment.
+///
+/// Every method does nothing by default, so only the needed notifications have to be implemented.
+/// Coordinates are in page space of the page the notification is about.
+///
+/// The methods are called from within PDFium, a panic in any of them is caught and ignored.
+pub trait FormFillCallbacks {
+    /// An area of a page has to be redrawn.
+    fn invalidate(&mut self, rect: Rect) {
+        let _ = rect;
+    }
+
+    /// Text in an area of a page was selected.
+    fn output_selected_rect(&mut self, rect: Rect) {
+        let _ = rect;
+    }
+
+    /// The cursor should change its shape.
+    ///
+    /// `cursor_type` is one of the `FXCT_*` values of PDFium, for example `0` for the arrow and `1` for the text cursor.
+    fn set_cursor(&mut self, cursor_type: i32) {
+        let _ = cursor_type;
+    }
+
+    /// The content of the form changed.
+    fn on_change(&mut self) {}
+
+    /// A named action, like `"NextPage"`, should be executed.
+    fn execute_named_action(&mut self, name: &str) {
+        let _ = name;
+    }
+
+    /// A text field gained or lost the focus.
+    fn set_text_field_focus(&mut self, value: &str, is_focus: bool) {
+        let _ = (value, is_focus);
+    }
+
+    /// A URI should be opened.
+    fn do_uri_action(&mut self, uri: &str) {
+        let _ = uri;
+    }
+
+    /// The viewer should go to a page.
+    fn do_go_to_action(&mut self, page_index: usize) {
+        let _ = page_index;
+    }
+}
+
+/// Initialize a form fill environment, PDFium keeps a pointer to the returned form fill info until it's exited.
+fn init_form<'data>(
+    document: &DocumentHandle,
+    callbacks: impl FormFillCallbacks + 'data,
+) -> Result<FormEnvironment<'data>, PdfiumError> {
+    // Callbacks that are not set are never called, PDFium checks every callback before calling it.
+    let mut info: bindings::FPDF_FORMFILLINFO = unsafe { std::mem::zeroed() };
+    info.version = 1;
+    info.FFI_Invalidate = Some(invalidate);
+    info.FFI_OutputSelectedRect = Some(output_selected_rect);
+    info.FFI_SetCursor = Some(set_cursor);
+    info.FFI_OnChange = Some(on_change);
+    info.FFI_ExecuteNamedAction = Some(execute_named_action);
+    info.FFI_SetTextFieldFocus = Some(set_text_field_focus);
+    info.FFI_DoURIAction = Some(do_uri_action);
+    info.FFI_DoGoToAction = Some(do_go_to_action);
+
+    let form_info = Box::into_raw(Box::new(FormFillInfo {
+        info,
+        callbacks: Box::new(callbacks),
+    }));
+
+    let handle = NonNull::new(unsafe {
+        bindings::FPDFDOC_InitFormFillEnvironment(document.handle.as_ptr(), form_info.cast())
+    });
+
+    match handle {
+        Some(handle) => Ok((handle, unsafe { NonNull::new_unchecked(form_info) })),
+        None => {
+            drop(unsafe { Box::from_raw(form_info) });
+            Err(PdfiumError::Unknown)
+        }
+    }
+}
+
+type FormEnvironment<'a> = (
+    NonNull<bindings::fpdf_form_handle_t__>,
+    NonNull<FormFillInfo<'a>>,
+);
+
+/// Exit a form fill environment and free its form fill info.
+unsafe fn exit_form(
+    handle: NonNull<bindings::fpdf_form_handle_t__>,
+    form_info: NonNull<FormFillInfo>,
+) {
+    bindings::FPDFDOC_ExitFormFillEnvironment(handle.as_ptr());
+    drop(Box::from_raw(form_info.as_ptr()));
+}
+
+/// The form fill environment of [`Library::init_cached_form_fill_environment`], exited when the document is closed.
+pub(crate) struct CachedForm {
+    handle: NonNull<bindings::fpdf_form_handle_t__>,
+    form_info: NonNull<FormFillInfo<'static>>,
+}
+
+impl Drop for CachedForm {
+    fn drop(&mut self) {
+        unsafe { exit_form(self.handle, self.form_info) }
+    }
+}
+
+struct NoCallbacks;
+
+impl FormFillCallbacks for NoCallbacks {}
+
+// `info` has to be the first field, PDFium passes a pointer to it to every callback.
+#[repr(C)]
+struct FormFillInfo<'a> {
+    info: bindings::FPDF_FORMFILLINFO,
+    callbacks: Box<dyn FormFillCallbacks + 'a>,
+}
+
+unsafe fn callbacks<'a>(this: *mut bindings::FPDF_FORMFILLINFO) -> &'a mut dyn FormFillCallbacks {
+    &mut *(*this.cast::<FormFillInfo>()).callbacks
+}
+
+fn to_rect(left: f64, top: f64, right: f64, bottom: f64) -> Rect {
+    Rect {
+        left: left as f32,
+        bottom: bottom as f32,
+        right: right as f32,
+        top: top as f32,
+    }
+}
+
+unsafe fn to_str<'a>(string: bindings::FPDF_BYTESTRING) -> std::borrow::Cow<'a, str> {
+    if string.is_null() {
+        Default::default()
+    } else {
+        CStr::from_ptr(string).to_string_lossy()
+    }
+}
+
+unsafe extern "C" fn invalidate(
+    this: *mut bindings::FPDF_FORMFILLINFO,
+    _page: bindings::FPDF_PAGE,
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+) {
+    let callbacks = callbacks(this);
+    catch_panic((), || {
+        callbacks.invalidate(to_rect(left, top, right, bottom))
+    });
+}
+
+unsafe extern "C" fn output_selected_rect(
+    this: *mut bindings::FPDF_FORMFILLINFO,
+    _page: bindings::FPDF_PAGE,
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+) {
+    let callbacks = callbacks(this);
+    catch_panic((), || {
+        callbacks.output_selected_rect(to_rect(left, top, right, bottom))
+    });
+}
+
+unsafe extern "C" fn set_cursor(this: *mut bindings::FPDF_FORMFILLINFO, cursor_type: c_int) {
+    let callbacks = callbacks(this);
+    catch_panic((), || callbacks.set_cursor(cursor_type));
+}
+
+unsafe extern "C" fn on_change(this: *mut bindings::FPDF_FORMFILLINFO) {
+    let callbacks = callbacks(this);
+    catch_panic((), || callbacks.on_change());
+}
+
+unsafe extern "C" fn execute_named_action(
+    this: *mut bindings::FPDF_FORMFILLINFO,
+    name: bindings::FPDF_BYTESTRING,
+) {
+    let callbacks = callbacks(this);
+    catch_panic((), || callbacks.execute_named_action(&to_str(name)));
+}
+
+unsafe extern "C" fn set_text_field_focus(
+    this: *mut bindings::FPDF_FORMFILLINFO,
+    value: bindings::FPDF_WIDESTRING,
+    length: bindings::FPDF_DWORD,
+    is_focus: bindings::FPDF_BOOL,
+) {
+    let value = if value.is_null() {
+        String::new()
+    } else {
+        String::from_utf16_lossy(std::slice::from_raw_parts(value, length as usize))
+    };
+
+    let callbacks = callbacks(this);
+    catch_panic((), || callbacks.set_text_field_focus(&value, is_focus != 0));
+}
+
+unsafe extern "C" fn do_uri_action(
+    this: *mut bindings::FPDF_FORMFILLINFO,
+    uri: bindings::FPDF_BYTESTRING,
+) {
+    let callbacks = callbacks(this);
+    catch_panic((), || callbacks.do_uri_action(&to_str(uri)));
+}
+
+unsafe extern "C" fn do_go_to_action(
+    this: *mut bindings::FPDF_FORMFILLINFO,
+    page_index: c_int,
+    _zoom_mode: c_int,
+    _position: *mut f32,
+    _position_len: c_int,
+) {
+    let callbacks = callbacks(this);
+    catch_panic((), || callbacks.do_go_to_action(page_index.max(0) as usize));
+}
+
+/// Safe handle to PDFium Form Fill Environment.
+///
+/// Created using [`Library::init_form_fill_environment`], [`Library::init_form_fill_environment_with_callbacks`]
+/// or [`Library::init_cached_form_fill_environment`].
+///
+/// Form fill environment is exited when handle is dropped, unless it's kept with its document.
+pub struct FormHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_form_handle_t__>,
+    /// PDFium keeps a pointer to the form fill info until the environment is exited,
+    /// `None` for an environment that is kept with its document by [`Library::init_cached_form_fill_environment`].
+    form_info: Option<NonNull<FormFillInfo<'a>>>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(FormHandle: Sync, Send);
+
+impl Drop for FormHandle<'_, '_> {
+    fn drop(&mut self) {
+        if let Some(form_info) = self.form_info {
+            unsafe { exit_form(self.handle, form_info) }
+        }
+    }
+}
+
+/// Safe handle to a page loaded into a PDFium Form Fill Environment.
+///
+/// Created using [`Library::form_on_after_load_page`].
+///
+/// Form fill environment is told that the page is closed when handle is dropped.
+pub struct FormPageHandle<'a, 'b> {
+    pub(crate) form: NonNull<bindings::fpdf_form_handle_t__>,
+    pub(crate) page: NonNull<bindings::fpdf_page_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(FormPageHandle: Sync, Send);
+
+impl Drop for FormPageHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FORM_OnBeforeClosePage(self.page.as_ptr(), self.form.as_ptr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+    use std::cell::Cell;
+
+    static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    struct Dropped<'a>(&'a Cell<bool>);
+
+    impl FormFillCallbacks for Dropped<'_> {}
+
+    impl Drop for Dropped<'_> {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    #[test]
+    fn form_type() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        assert_eq!(library.get_form_type(&document), FormType::AcroForm);
+
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_form_type(&document), FormType::None);
+    }
+
+    #[test]
+    fn form_fill_environment() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+
+        let dropped = Cell::new(false);
+        let form = library
+            .init_form_fill_environment_with_callbacks(&document, Dropped(&dropped))
+            .unwrap();
+
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+        drop(form_page);
+        drop(page);
+
+        assert!(!dropped.get());
+        drop(form);
+        assert!(dropped.get());
+    }
+
+    #[test]
+    fn cached_form_fill_environment() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+
+        let form = library
+            .init_cached_form_fill_environment(&document)
+            .unwrap();
+        let again = library
+            .init_cached_form_fill_environment(&document)
+            .unwrap();
+        assert_eq!(form.handle, again.handle);
+        // The environment is kept when the handles are dropped
+        drop((form, again));
+
+        let form = library
+            .init_cached_form_fill_environment(&document)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        drop(library.form_on_after_load_page(&form, &page));
+        drop((page, form));
+        // The environment is exited with the document
+        drop(document);
+    }
+
+    #[test]
+    fn replace_text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+
+        let name = library.get_annotation(&page, 0).unwrap();
+        library
+            .set_form_focused_annotation(&form_page, &name)
+            .unwrap();
+        library.form_select_all_text(&form_page).unwrap();
+        library.form_replace_selection(&form_page, "Bob");
+        library.form_kill_focus(&form_page).unwrap();
+        assert_eq!(library.get_annotation_form_field_value(&form, &name), "Bob");
+    }
+
+    #[test]
+    fn hit_testing() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let country = Point { x: 150.0, y: 560.0 };
+        assert_eq!(
+            library.get_form_field_type_at_point(&form, &page, &country),
+            Some(FormFieldType::ComboBox)
+        );
+        assert_eq!(
+            library.get_form_field_z_order_at_point(&form, &page, &country),
+            Some(4)
+        );
+        let annotation = library
+            .get_form_field_annotation_at_point(&form, &page, &country)
+            .unwrap();
+        assert_eq!(library.get_annotation_index(&page, &annotation), Some(4));
+
+        let nothing = Point { x: 10.0, y: 10.0 };
+        assert_eq!(
+            library.get_form_field_type_at_point(&form, &page, &nothing),
+            None
+        );
+        assert_eq!(
+            library.get_form_field_z_order_at_point(&form, &page, &nothing),
+            None
+        );
+        assert!(library
+            .get_form_field_annotation_at_point(&form, &page, &nothing)
+            .is_none());
+    }
+
+    #[test]
+    fn selected_text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+
+        assert_eq!(library.get_form_focused_text(&form_page), "");
+        assert_eq!(library.get_form_selected_text(&form_page), "");
+
+        let name = library.get_annotation(&page, 0).unwrap();
+        library
+            .set_form_focused_annotation(&form_page, &name)
+            .unwrap();
+        library.form_select_all_text(&form_page).unwrap();
+        assert_eq!(library.get_form_selected_text(&form_page), "Alice");
+
+        library.form_replace_selection(&form_page, "Bob");
+        assert_eq!(library.get_form_selected_text(&form_page), "");
+        assert_eq!(library.get_form_focused_text(&form_page), "Bob");
+        // The value is only committed when the focus is removed
+        assert_eq!(
+            library.get_annotation_form_field_value(&form, &name),
+            "Alice"
+        );
+    }
+
+    #[test]
+    fn undo_redo() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+
+        assert!(!library.form_can_undo(&form_page));
+        assert!(library.form_undo(&form_page).is_err());
+
+        let name = library.get_annotation(&page, 0).unwrap();
+        library
+            .set_form_focused_annotation(&form_page, &name)
+            .unwrap();
+        library.form_select_all_text(&form_page).unwrap();
+        library.form_replace_selection(&form_page, "Bob");
+        assert!(library.form_can_undo(&form_page));
+        assert!(!library.form_can_redo(&form_page));
+
+        library.form_undo(&form_page).unwrap();
+        assert_eq!(library.get_form_focused_text(&form_page), "Alice");
+        assert!(library.form_can_redo(&form_page));
+
+        library.form_redo(&form_page).unwrap();
+        assert_eq!(library.get_form_focused_text(&form_page), "Bob");
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{bindings, FormHandle, FormPageHandle, Library};
+
+impl Library {
+    /// Run the document level JavaScript of a document.
+    ///
+    /// PDFium never runs document actions on its own, so viewers call this once after
+    /// [`Library::init_form_fill_environment`] if they trust the document.
+    /// Does nothing if the document has no document level JavaScript or PDFium was built without JavaScript support.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    ///
+    /// library.form_do_document_javascript_action(&form_handle);
+    /// ```
+    pub fn form_do_document_javascript_action(&self, form: &FormHandle) {
+        unsafe { bindings::FORM_DoDocumentJSAction(form.handle.as_ptr()) }
+    }
+
+    /// Run the open action of a document, for example to compute the values of form fields.
+    ///
+    /// PDFium never runs document actions on its own, so viewers call this once after
+    /// [`Library::init_form_fill_environment`] if they trust the document.
+    /// Does nothing if the document has no open action.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    ///
+    /// library.form_do_document_open_action(&form_handle);
+    /// ```
+    pub fn form_do_document_open_action(&self, form: &FormHandle) {
+        unsafe { bindings::FORM_DoDocumentOpenAction(form.handle.as_ptr()) }
+    }
+
+    /// Run an additional action of a document, like the one for [`DocumentAction::WillSave`] before saving it.
+    ///
+    /// Does nothing if the document has no additional action for `action`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{DocumentAction, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    ///
+    /// library.form_do_document_additional_action(&form_handle, DocumentAction::WillSave);
+    /// ```
+    pub fn form_do_document_additional_action(&self, form: &FormHandle, action: DocumentAction) {
+        unsafe { bindings::FORM_DoDocumentAAction(form.handle.as_ptr(), action as i32) }
+    }
+
+    /// Run an additional action of a page, like the one for [`PageAction::Open`] after it is shown.
+    ///
+    /// Does nothing if the page has no additional action for `action`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PageAction};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// library.form_do_page_additional_action(&form_page_handle, PageAction::Open);
+    /// ```
+    pub fn form_do_page_additional_action(&self, form_page: &FormPageHandle, action: PageAction) {
+        unsafe {
+            bindings::FORM_DoPageAAction(
+                form_page.page.as_ptr(),
+                form_page.form.as_ptr(),
+                action as i32,
+            )
+        }
+    }
+}
+
+/// The additional actions of a document, run with [`Library::form_do_document_additional_action`].
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DocumentAction {
+    /// Before the document is closed.
+    WillClose = bindings::FPDFDOC_AACTION_WC as i32,
+    /// Before the document is saved.
+    WillSave = bindings::FPDFDOC_AACTION_WS as i32,
+    /// After the document is saved.
+    DidSave = bindings::FPDFDOC_AACTION_DS as i32,
+    /// Before the document is printed.
+    WillPrint = bindings::FPDFDOC_AACTION_WP as i32,
+    /// After the document is printed.
+    DidPrint = bindings::FPDFDOC_AACTION_DP as i32,
+}
+
+/// The additional actions of a page, run with [`Library::form_do_page_additional_action`].
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PageAction {
+    /// After the page is opened.
+    Open = bindings::FPDFPAGE_AACTION_OPEN as i32,
+    /// Before the page is closed.
+    Close = bindings::FPDFPAGE_AACTION_CLOSE as i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+
+    #[test]
+    fn actions_without_effect() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+        let name = library.get_annotation(&page, 0).unwrap();
+
+        // The document has no actions, so nothing changes
+        library.form_do_document_javascript_action(&form);
+        library.form_do_document_open_action(&form);
+        library.form_do_document_additional_action(&form, DocumentAction::WillClose);
+        library.form_do_page_additional_action(&form_page, PageAction::Open);
+        library.form_do_page_additional_action(&form_page, PageAction::Close);
+
+        assert_eq!(
+            library.get_annotation_form_field_value(&form, &name),
+            "Alice"
+        );
+    }
+}
@@ -0,0 +1,632 @@
+use crate::{bindings, AnnotationHandle, FormPageHandle, Library, Point};
+use std::ptr::{self, NonNull};
+
+impl Library {
+    /// Forward a mouse move to the form fields of a page, for example to show the hover appearance.
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`]. `point` is in page space.
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// let handled = library.form_on_mouse_move(&form_page_handle, 0, &Point { x: 150.0, y: 710.0 });
+    /// ```
+    pub fn form_on_mouse_move(
+        &self,
+        form_page: &FormPageHandle,
+        modifiers: i32,
+        point: &Point,
+    ) -> bool {
+        unsafe {
+            bindings::FORM_OnMouseMove(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                modifiers,
+                point.x as f64,
+                point.y as f64,
+            ) != 0
+        }
+    }
+
+    /// Forward a press of the left mouse button to the form fields of a page.
+    ///
+    /// Pressing on a form field gives it the focus.
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`]. `point` is in page space.
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// let handled =
+    ///     library.form_on_left_button_down(&form_page_handle, 0, &Point { x: 150.0, y: 710.0 });
+    /// assert!(handled);
+    /// ```
+    pub fn form_on_left_button_down(
+        &self,
+        form_page: &FormPageHandle,
+        modifiers: i32,
+        point: &Point,
+    ) -> bool {
+        unsafe {
+            bindings::FORM_OnLButtonDown(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                modifiers,
+                point.x as f64,
+                point.y as f64,
+            ) != 0
+        }
+    }
+
+    /// Forward a release of the left mouse button to the form fields of a page.
+    ///
+    /// Releasing on a check box or radio button toggles it.
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`]. `point` is in page space.
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// let handled =
+    ///     library.form_on_left_button_up(&form_page_handle, 0, &Point { x: 150.0, y: 710.0 });
+    /// ```
+    pub fn form_on_left_button_up(
+        &self,
+        form_page: &FormPageHandle,
+        modifiers: i32,
+        point: &Point,
+    ) -> bool {
+        unsafe {
+            bindings::FORM_OnLButtonUp(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                modifiers,
+                point.x as f64,
+                point.y as f64,
+            ) != 0
+        }
+    }
+
+    /// Forward a double click of the left mouse button to the form fields of a page.
+    ///
+    /// Double clicking on a text field selects a word.
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`]. `point` is in page space.
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// let handled = library.form_on_left_button_double_click(
+    ///     &form_page_handle,
+    ///     0,
+    ///     &Point { x: 150.0, y: 710.0 },
+    /// );
+    /// ```
+    pub fn form_on_left_button_double_click(
+        &self,
+        form_page: &FormPageHandle,
+        modifiers: i32,
+        point: &Point,
+    ) -> bool {
+        unsafe {
+            bindings::FORM_OnLButtonDoubleClick(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                modifiers,
+                point.x as f64,
+                point.y as f64,
+            ) != 0
+        }
+    }
+
+    /// Give the focus to the form field at a point of a page, without a mouse click.
+    ///
+    /// Moves the focus away from the focused form field if there is no form field at `point`.
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`]. `point` is in page space.
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Point};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    ///
+    /// let handled = library.form_on_focus(&form_page_handle, 0, &Point { x: 150.0, y: 710.0 });
+    /// assert!(handled);
+    /// ```
+    pub fn form_on_focus(&self, form_page: &FormPageHandle, modifiers: i32, point: &Point) -> bool {
+        unsafe {
+            bindings::FORM_OnFocus(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                modifiers,
+                point.x as f64,
+                point.y as f64,
+            ) != 0
+        }
+    }
+
+    /// Forward a key press to the form field with the focus.
+    ///
+    /// `key_code` is a virtual key code, see [`key_codes`]. Keys that produce text are sent with
+    /// [`Library::form_on_char`] as well.
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`].
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{key_codes, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library
+    ///     .set_form_focused_annotation(&form_page_handle, &annotation_handle)
+    ///     .unwrap();
+    ///
+    /// let handled = library.form_on_key_down(&form_page_handle, key_codes::END, 0);
+    /// assert!(handled);
+    /// ```
+    pub fn form_on_key_down(
+        &self,
+        form_page: &FormPageHandle,
+        key_code: i32,
+        modifiers: i32,
+    ) -> bool {
+        unsafe {
+            bindings::FORM_OnKeyDown(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                key_code,
+                modifiers,
+            ) != 0
+        }
+    }
+
+    /// Forward a key release to the form field with the focus.
+    ///
+    /// `key_code` is a virtual key code, see [`key_codes`].
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`].
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{key_codes, Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library
+    ///     .set_form_focused_annotation(&form_page_handle, &annotation_handle)
+    ///     .unwrap();
+    ///
+    /// let handled = library.form_on_key_up(&form_page_handle, key_codes::END, 0);
+    /// ```
+    pub fn form_on_key_up(
+        &self,
+        form_page: &FormPageHandle,
+        key_code: i32,
+        modifiers: i32,
+    ) -> bool {
+        unsafe {
+            bindings::FORM_OnKeyUp(
+                form_page.form.as_ptr(),
+                form_page.page.as_ptr(),
+                key_code,
+                modifiers,
+            ) != 0
+        }
+    }
+
+    /// Type a character into the form field with the focus.
+    ///
+    /// The character is inserted at the caret of a text field, replacing the selected text.
+    /// The new value is committed when the form field loses the focus, for example with [`Library::form_kill_focus`].
+    ///
+    /// `modifiers` are the pressed keys and mouse buttons, see [`event_flags`].
+    ///
+    /// Returns `true` if a form field handled the event.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// library
+    ///     .set_form_focused_annotation(&form_page_handle, &annotation_handle)
+    ///     .unwrap();
+    ///
+    /// let handled = library.form_on_char(&form_page_handle, 'a', 0);
+    /// assert!(handled);
+    /// ```
+    pub fn form_on_char(
+        &self,
+        form_page: &FormPageHandle,
+        character: char,
+        modifiers: i32,
+    ) -> bool {
+        let mut buffer = [0; 2];
+
+        // PDFium expects UTF-16 code units, characters outside the BMP are sent as a surrogate pair
+        character
+            .encode_utf16(&mut buffer)
+            .iter()
+            .fold(true, |handled, &unit| {
+                let unit_handled = unsafe {
+                    bindings::FORM_OnChar(
+                        form_page.form.as_ptr(),
+                        form_page.page.as_ptr(),
+                        unit as i32,
+                        modifiers,
+                    ) != 0
+                };
+
+                handled && unit_handled
+            })
+    }
+
+    /// Get the form field annotation with the keyboard focus, if it is on the page of `form_page`.
+    ///
+    /// Returns `None` if no form field has the focus, or the focused form field is on another page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library};
+    /// # static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(FORMS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let form_handle = library.init_form_fill_environment(&document_handle).unwrap();
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_page_handle = library.form_on_after_load_page(&form_handle, &page_handle);
+    /// let annotation_handle = library.get_annotation(&page_handle, 0).unwrap();
+    ///
+    /// assert!(library.get_form_focused_annotation(&form_page_handle).is_none());
+    ///
+    /// library
+    ///     .set_form_focused_annotation(&form_page_handle, &annotation_handle)
+    ///     .unwrap();
+    ///
+    /// assert!(library.get_form_focused_annotation(&form_page_handle).is_some());
+    /// ```
+    pub fn get_form_focused_annotation<'a, 'library>(
+        &'library self,
+        form_page: &'a FormPageHandle,
+    ) -> Option<AnnotationHandle<'a, 'library>> {
+        let mut page_index = 0;
+        let mut annotation = ptr::null_mut();
+
+        let success = unsafe {
+            bindings::FORM_GetFocusedAnnot(
+                form_page.form.as_ptr(),
+                &mut page_index,
+                &mut annotation,
+            )
+        };
+
+        if success == 0 {
+            return None;
+        }
+
+        // The annotation is closed when the handle is dropped, also if it's on another page
+        let annotation = AnnotationHandle::new(NonNull::new(annotation)?);
+
+        let index = unsafe {
+            bindings::FPDFPage_GetAnnotIndex(form_page.page.as_ptr(), annotation.handle.as_ptr())
+        };
+
+        if index >= 0 {
+            Some(annotation)
+        } else {
+            None
+        }
+    }
+}
+
+pub mod event_flags {
+    //! Pressed keys and mouse buttons of form events, like [`form_on_left_button_down`](crate::Library::form_on_left_button_down).
+    //! They are combined with bit-wise OR.
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::event_flags::*;
+    //!
+    //! // Shift-click
+    //! let flags = SHIFT | LEFT_BUTTON_DOWN;
+    //! assert!(flags & CONTROL == 0);
+    //! ```
+
+    use crate::bindings;
+
+    /// No keys or buttons are pressed.
+    pub const NONE: i32 = 0;
+
+    /// The shift key is pressed.
+    pub const SHIFT: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_ShiftKey as i32;
+
+    /// The control key is pressed.
+    pub const CONTROL: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_ControlKey as i32;
+
+    /// The alt key is pressed.
+    pub const ALT: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_AltKey as i32;
+
+    /// The meta key, like the command or windows key, is pressed.
+    pub const META: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_MetaKey as i32;
+
+    /// The key is on the key pad.
+    pub const KEY_PAD: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_KeyPad as i32;
+
+    /// The key event is repeated because the key is held down.
+    pub const AUTO_REPEAT: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_AutoRepeat as i32;
+
+    /// The left mouse button is pressed.
+    pub const LEFT_BUTTON_DOWN: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_LeftButtonDown as i32;
+
+    /// The middle mouse button is pressed.
+    pub const MIDDLE_BUTTON_DOWN: i32 =
+        bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_MiddleButtonDown as i32;
+
+    /// The right mouse button is pressed.
+    pub const RIGHT_BUTTON_DOWN: i32 = bindings::FWL_EVENTFLAG_FWL_EVENTFLAG_RightButtonDown as i32;
+}
+
+pub mod key_codes {
+    //! Virtual key codes of key events, like [`form_on_key_down`](crate::Library::form_on_key_down).
+    //!
+    //! Letter and digit keys use the code of the upper case ASCII character, like `'A' as i32`.
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::key_codes::*;
+    //!
+    //! assert_ne!(LEFT, RIGHT);
+    //! ```
+
+    use crate::bindings;
+
+    /// The backspace key.
+    pub const BACKSPACE: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Back as i32;
+
+    /// The tab key.
+    pub const TAB: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Tab as i32;
+
+    /// The return or enter key.
+    pub const RETURN: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Return as i32;
+
+    /// The escape key.
+    pub const ESCAPE: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Escape as i32;
+
+    /// The space bar.
+    pub const SPACE: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Space as i32;
+
+    /// The page up key.
+    pub const PAGE_UP: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Prior as i32;
+
+    /// The page down key.
+    pub const PAGE_DOWN: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Next as i32;
+
+    /// The end key.
+    pub const END: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_End as i32;
+
+    /// The home key.
+    pub const HOME: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Home as i32;
+
+    /// The left arrow key.
+    pub const LEFT: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Left as i32;
+
+    /// The up arrow key.
+    pub const UP: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Up as i32;
+
+    /// The right arrow key.
+    pub const RIGHT: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Right as i32;
+
+    /// The down arrow key.
+    pub const DOWN: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Down as i32;
+
+    /// The insert key.
+    pub const INSERT: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Insert as i32;
+
+    /// The delete key.
+    pub const DELETE: i32 = bindings::FWL_VKEYCODE_FWL_VKEY_Delete as i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FormFillCallbacks, Rect, TEST_LOCK};
+    use std::cell::RefCell;
+
+    static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+
+    struct Invalidated<'a>(&'a RefCell<Vec<Rect>>);
+
+    impl FormFillCallbacks for Invalidated<'_> {
+        fn invalidate(&mut self, rect: Rect) {
+            self.0.borrow_mut().push(rect);
+        }
+    }
+
+    #[test]
+    fn mouse_events() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let invalidated = RefCell::new(Vec::new());
+        let form = library
+            .init_form_fill_environment_with_callbacks(&document, Invalidated(&invalidated))
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+
+        let name = Point { x: 150.0, y: 710.0 };
+        assert!(library.form_on_left_button_down(&form_page, event_flags::NONE, &name));
+        assert!(library.form_on_left_button_up(&form_page, event_flags::NONE, &name));
+        assert!(!invalidated.borrow().is_empty());
+
+        let nothing = Point { x: 10.0, y: 10.0 };
+        assert!(!library.form_on_left_button_down(&form_page, event_flags::NONE, &nothing));
+        assert!(!library.form_on_focus(&form_page, event_flags::NONE, &nothing));
+    }
+
+    struct Panicking;
+
+    impl FormFillCallbacks for Panicking {
+        fn invalidate(&mut self, _: Rect) {
+            panic!("invalidate")
+        }
+    }
+
+    #[test]
+    fn panicking_callbacks() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library
+            .init_form_fill_environment_with_callbacks(&document, Panicking)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+
+        // The panics don't unwind into PDFium
+        let name = Point { x: 150.0, y: 710.0 };
+        assert!(library.form_on_left_button_down(&form_page, event_flags::NONE, &name));
+        assert!(library.form_on_left_button_up(&form_page, event_flags::NONE, &name));
+    }
+
+    #[test]
+    fn keyboard_events() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        let form = library.init_form_fill_environment(&document).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form_page = library.form_on_after_load_page(&form, &page);
+
+        assert!(library.get_form_focused_annotation(&form_page).is_none());
+
+        let name = library.get_annotation(&page, 0).unwrap();
+        library
+            .set_form_focused_annotation(&form_page, &name)
+            .unwrap();
+
+        let focused = library.get_form_focused_annotation(&form_page).unwrap();
+        assert_eq!(library.get_annotation_index(&page, &focused), Some(0));
+
+        assert!(library.form_on_key_down(&form_page, key_codes::END, event_flags::NONE));
+        assert!(library.form_on_key_up(&form_page, key_codes::END, event_flags::NONE));
+        for character in "ce".chars() {
+            assert!(library.form_on_char(&form_page, character, event_flags::NONE));
+        }
+
+        library.form_kill_focus(&form_page).unwrap();
+        assert!(library.get_form_focused_annotation(&form_page).is_none());
+        assert_eq!(
+            library.get_annotation_form_field_value(&form, &name),
+            "Alicece"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{bindings, Library, PageObjectHandle};
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get number of page objects inside a form object.
+    ///
+    /// Form objects can contain other form objects, so [`Library::get_form_object`] has to be used
+    /// recursively to reach all of the content of a page.
+    ///
+    /// Returns `0` if `form_object` is not a form object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_object_handle = library.get_page_object(&page_handle, 2).unwrap();
+    /// assert_eq!(library.get_form_object_count(&form_object_handle), 1);
+    /// ```
+    pub fn get_form_object_count(&self, form_object: &PageObjectHandle) -> usize {
+        let count = unsafe { bindings::FPDFFormObj_CountObjects(form_object.handle.as_ptr()) };
+
+        // PDFium returns -1 if `form_object` is not a form object.
+        count.max(0) as usize
+    }
+
+    /// Get a page object inside a form object.
+    ///
+    /// `index` 0 for the first page object.
+    ///
+    /// Will return `None` if `index` is out of bounds or `form_object` is not a form object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PageObjectType};
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let form_object_handle = library.get_page_object(&page_handle, 2).unwrap();
+    /// let object_handle = library.get_form_object(&form_object_handle, 0).unwrap();
+    /// assert_eq!(library.get_page_object_type(&object_handle), PageObjectType::Path);
+    /// ```
+    pub fn get_form_object<'data, 'library>(
+        &'library self,
+        form_object: &'data PageObjectHandle,
+        index: usize,
+    ) -> Option<PageObjectHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFFormObj_GetObject(form_object.handle.as_ptr(), index as _)
+        });
+
+        handle.map(|handle| PageObjectHandle::new(handle, form_object.text_page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PageObjectType, TEST_LOCK};
+
+    static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    fn count_objects(library: &Library, object: &PageObjectHandle) -> usize {
+        match library.get_page_object_type(object) {
+            PageObjectType::Form => (0..library.get_form_object_count(object))
+                .map(|index| library.get_form_object(object, index).unwrap())
+                .map(|object| count_objects(library, &object))
+                .sum(),
+            _ => 1,
+        }
+    }
+
+    #[test]
+    fn form_objects() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(PATHS_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let form = library.get_page_object(&page, 2).unwrap();
+        assert_eq!(library.get_page_object_type(&form), PageObjectType::Form);
+        assert_eq!(library.get_form_object_count(&form), 1);
+        assert!(library.get_form_object(&form, 1).is_none());
+
+        let total: usize = (0..library.get_page_object_count(&page))
+            .map(|index| library.get_page_object(&page, index).unwrap())
+            .map(|object| count_objects(&library, &object))
+            .sum();
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn not_a_form() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let text = library.get_page_object(&page, 0).unwrap();
+
+        assert_eq!(library.get_form_object_count(&text), 0);
+        assert!(library.get_form_object(&text, 0).is_none());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::bindings;
+
+/// A rectangle in page space.
+///
+/// Page space is measured in points (about 1/72 inch) and its origin is the bottom-left corner of the page.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    /// The x-coordinate of the left side.
+    pub left: f32,
+    /// The y-coordinate of the bottom side.
+    pub bottom: f32,
+    /// The x-coordinate of the right side.
+    pub right: f32,
+    /// The y-coordinate of the top side.
+    pub top: f32,
+}
+
+impl Rect {
+    /// Width of the rectangle.
+    ///
+    /// ## Example
+    /// ```
+    /// use pdfium_core::Rect;
+    ///
+    /// let rect = Rect { left: 10.0, bottom: 10.0, right: 110.0, top: 60.0 };
+    /// assert_eq!(rect.width(), 100.0);
+    /// ```
+    pub fn width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    /// Height of the rectangle.
+    ///
+    /// ## Example
+    /// ```
+    /// use pdfium_core::Rect;
+    ///
+    /// let rect = Rect { left: 10.0, bottom: 10.0, right: 110.0, top: 60.0 };
+    /// assert_eq!(rect.height(), 50.0);
+    /// ```
+    pub fn height(&self) -> f32 {
+        self.top - self.bottom
+    }
+
+    pub(crate) fn from_fs_rectf(rect: &bindings::FS_RECTF) -> Rect {
+        Rect {
+            left: rect.left,
+            bottom: rect.bottom,
+            right: rect.right,
+            top: rect.top,
+        }
+    }
+
+    pub(crate) fn to_fs_rectf(self) -> bindings::FS_RECTF {
+        bindings::FS_RECTF {
+            left: self.left,
+            top: self.top,
+            right: self.right,
+            bottom: self.bottom,
+        }
+    }
+}
+
+/// A point in page space.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    /// The x-coordinate.
+    pub x: f32,
+    /// The y-coordinate.
+    pub y: f32,
+}
+
+impl Point {
+    pub(crate) fn from_fs_pointf(point: &bindings::FS_POINTF) -> Point {
+        Point {
+            x: point.x,
+            y: point.y,
+        }
+    }
+
+    pub(crate) fn to_fs_pointf(self) -> bindings::FS_POINTF {
+        bindings::FS_POINTF {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+/// A size in page space, like the size of a page.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Size {
+    /// The horizontal extent.
+    pub width: f32,
+    /// The vertical extent.
+    pub height: f32,
+}
+
+impl Size {
+    pub(crate) fn from_fs_sizef(size: &bindings::FS_SIZEF) -> Size {
+        Size {
+            width: size.width,
+            height: size.height,
+        }
+    }
+}
+
+/// A quadrilateral in page space, used for the areas of text markup annotations.
+///
+/// For text markup the points are, in order, the top-left, top-right, bottom-left and bottom-right corner of the text.
+#[allow(missing_docs)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadPoints {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+    pub x3: f32,
+    pub y3: f32,
+    pub x4: f32,
+    pub y4: f32,
+}
+
+impl QuadPoints {
+    /// The quadrilateral covering `rect`, with its points in text markup order.
+    ///
+    /// ## Example
+    /// ```
+    /// use pdfium_core::{QuadPoints, Rect};
+    ///
+    /// let rect = Rect { left: 10.0, bottom: 20.0, right: 110.0, top: 70.0 };
+    /// let quad_points = QuadPoints::from_rect(&rect);
+    /// assert_eq!((quad_points.x1, quad_points.y1), (10.0, 70.0));
+    /// assert_eq!((quad_points.x4, quad_points.y4), (110.0, 20.0));
+    /// ```
+    pub fn from_rect(rect: &Rect) -> QuadPoints {
+        QuadPoints {
+            x1: rect.left,
+            y1: rect.top,
+            x2: rect.right,
+            y2: rect.top,
+            x3: rect.left,
+            y3: rect.bottom,
+            x4: rect.right,
+            y4: rect.bottom,
+        }
+    }
+
+    pub(crate) fn from_fs_quadpointsf(quad_points: &bindings::FS_QUADPOINTSF) -> QuadPoints {
+        QuadPoints {
+            x1: quad_points.x1,
+            y1: quad_points.y1,
+            x2: quad_points.x2,
+            y2: quad_points.y2,
+            x3: quad_points.x3,
+            y3: quad_points.y3,
+            x4: quad_points.x4,
+            y4: quad_points.y4,
+        }
+    }
+
+    pub(crate) fn to_fs_quadpointsf(self) -> bindings::FS_QUADPOINTSF {
+        bindings::FS_QUADPOINTSF {
+            x1: self.x1,
+            y1: self.y1,
+            x2: self.x2,
+            y2: self.y2,
+            x3: self.x3,
+            y3: self.y3,
+            x4: self.x4,
+            y4: self.y4,
+        }
+    }
+}
+
+/// Get a list of points from a PDFium function.
+///
+/// Works like [`get_bytes`](crate::get_bytes), but the length is the number of points.
+pub(crate) fn get_points(
+    mut get: impl FnMut(*mut bindings::FS_POINTF, usize) -> usize,
+) -> Vec<Point> {
+    let length = get(std::ptr::null_mut(), 0);
+
+    if length == 0 {
+        return Vec::new();
+    }
+
+    let mut buffer = vec![Point::default().to_fs_pointf(); length];
+    get(buffer.as_mut_ptr(), buffer.len());
+
+    buffer.iter().map(Point::from_fs_pointf).collect()
+}
+
+/// A transformation matrix.
+///
+/// The matrix `[a b c d e f]` maps the point `(x, y)` to `(a * x + c * y + e, b * x + d * y + f)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix {
+    /// Horizontal scaling.
+    pub a: f32,
+    /// Vertical skewing.
+    pub b: f32,
+    /// Horizontal skewing.
+    pub c: f32,
+    /// Vertical scaling.
+    pub d: f32,
+    /// Horizontal translation.
+    pub e: f32,
+    /// Vertical translation.
+    pub f: f32,
+}
+
+impl Matrix {
+    /// The identity matrix.
+    pub const IDENTITY: Matrix = Matrix {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        e: 0.0,
+        f: 0.0,
+    };
+
+    /// A matrix that maps the unit square onto `rect`.
+    ///
+    /// Image objects are drawn into the unit square, so this is the matrix that places an image into `rect`.
+    ///
+    /// ## Example
+    /// ```
+    /// use pdfium_core::{Matrix, Rect};
+    ///
+    /// let rect = Rect { left: 10.0, bottom: 20.0, right: 110.0, top: 70.0 };
+    /// let matrix = Matrix::from_rect(&rect);
+    /// assert_eq!(matrix, Matrix { a: 100.0, b: 0.0, c: 0.0, d: 50.0, e: 10.0, f: 20.0 });
+    /// ```
+    pub fn from_rect(rect: &Rect) -> Matrix {
+        Matrix {
+            a: rect.width(),
+            b: 0.0,
+            c: 0.0,
+            d: rect.height(),
+            e: rect.left,
+            f: rect.bottom,
+        }
+    }
+
+    pub(crate) fn from_fs_matrix(matrix: &bindings::FS_MATRIX) -> Matrix {
+        Matrix {
+            a: matrix.a,
+            b: matrix.b,
+            c: matrix.c,
+            d: matrix.d,
+            e: matrix.e,
+            f: matrix.f,
+        }
+    }
+
+    pub(crate) fn to_fs_matrix(self) -> bindings::FS_MATRIX {
+        bindings::FS_MATRIX {
+            a: self.a,
+            b: self.b,
+            c: self.c,
+            d: self.d,
+            e: self.e,
+            f: self.f,
+        }
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Matrix::IDENTITY
+    }
+}
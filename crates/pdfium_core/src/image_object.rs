@@ -0,0 +1,543 @@
+use crate::{bindings, file_access, get_bytes, get_string, to_result};
+use crate::{BitmapHandle, DocumentHandle, Library};
+use crate::{OwnedPageObjectHandle, PageHandle, PageObjectHandle, PageObjectMut, PdfiumError};
+use std::ptr::NonNull;
+
+impl Library {
+    /// Create a new image object.
+    ///
+    /// The image object is empty and is drawn into the unit square. Use [`Library::set_image_object_bitmap`]
+    /// to set its content and [`Library::set_page_object_matrix`] to position it, then insert it into a page
+    /// using [`Library::insert_page_object`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PageObjectType};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// assert_eq!(library.get_page_object_type(&image_object_handle), PageObjectType::Image);
+    /// ```
+    pub fn create_image_object<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+    ) -> Result<OwnedPageObjectHandle<'data, 'library>, PdfiumError> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDFPageObj_NewImageObj(document.handle.as_ptr()) });
+
+        handle
+            .map(OwnedPageObjectHandle::new)
+            .ok_or(PdfiumError::Unknown)
+    }
+
+    /// Set the content of an image object from a bitmap.
+    ///
+    /// The bitmap's pixels are copied into the image object so `bitmap` can be dropped afterwards.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` is not an image object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{BitmapFormat, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut bitmap_handle = library.create_bitmap(100, 100, BitmapFormat::BGRA).unwrap();
+    /// library.bitmap_fill_rect(&mut bitmap_handle, 0, 0, 100, 100, 0xFFFF0000);
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// let result = library.set_image_object_bitmap(&mut image_object_handle, &bitmap_handle);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_image_object_bitmap(
+        &self,
+        image_object: &mut impl PageObjectMut,
+        bitmap: &BitmapHandle,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDFImageObj_SetBitmap(
+                std::ptr::null_mut(),
+                0,
+                image_object.page_object_handle().as_ptr(),
+                bitmap.handle.as_ptr(),
+            )
+        })
+    }
+
+    /// Set the content of an image object from a JPEG image.
+    ///
+    /// The JPEG data is embedded in the document as is, without being decoded and re-encoded.
+    ///
+    /// PDFium reads the image lazily when the image object is rendered or the document is saved, so `jpeg`
+    /// is copied into `document` and kept until it's closed. Use [`Library::load_image_object_jpeg_inline`]
+    /// to copy it into the image object immediately instead.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` is not an image object.
+    /// - [`Unknown`](PdfiumError::Unknown): `jpeg` is empty.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// let result = library.load_image_object_jpeg(&document_handle, &mut image_object_handle, DUMMY_JPEG);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn load_image_object_jpeg(
+        &self,
+        document: &DocumentHandle,
+        image_object: &mut impl PageObjectMut,
+        jpeg: &[u8],
+    ) -> Result<(), PdfiumError> {
+        let jpeg: Box<[u8]> = jpeg.into();
+        // The boxed bytes don't move when more images are added
+        let mut file_access = file_access::from_bytes(&jpeg);
+        document.jpegs.borrow_mut().push(jpeg);
+
+        to_result(unsafe {
+            bindings::FPDFImageObj_LoadJpegFile(
+                std::ptr::null_mut(),
+                0,
+                image_object.page_object_handle().as_ptr(),
+                &mut file_access,
+            )
+        })
+    }
+
+    /// Set the content of an image object from a JPEG image.
+    ///
+    /// Similar to [`Library::load_image_object_jpeg`], but the JPEG data is copied into the image object immediately
+    /// instead of being kept by the document handle until it's closed.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` is not an image object.
+    /// - [`Unknown`](PdfiumError::Unknown): `jpeg` is empty.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let jpeg = include_bytes!("../../../test_assets/dummy.jpg").to_vec();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// let result = library.load_image_object_jpeg_inline(&mut image_object_handle, &jpeg);
+    /// assert!(result.is_ok());
+    ///
+    /// drop(jpeg);
+    /// ```
+    pub fn load_image_object_jpeg_inline(
+        &self,
+        image_object: &mut impl PageObjectMut,
+        jpeg: &[u8],
+    ) -> Result<(), PdfiumError> {
+        let mut file_access = file_access::from_bytes(jpeg);
+
+        to_result(unsafe {
+            bindings::FPDFImageObj_LoadJpegFileInline(
+                std::ptr::null_mut(),
+                0,
+                image_object.page_object_handle().as_ptr(),
+                &mut file_access,
+            )
+        })
+    }
+    /// Get the decoded bitmap of an image object.
+    ///
+    /// The bitmap has the image's own pixel size and does not take the image object's matrix
+    /// or the image's mask into account. Use [`Library::get_image_object_rendered_bitmap`] for that.
+    ///
+    /// The bitmap is a copy, so changing it does not change the image object.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` is not an image object.
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` has no content or its content can not be decoded.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.load_image_object_jpeg_inline(&mut image_object_handle, DUMMY_JPEG).unwrap();
+    ///
+    /// let bitmap_handle = library.get_image_object_bitmap(&image_object_handle).unwrap();
+    /// assert_eq!(library.get_bitmap_width(&bitmap_handle), 16);
+    /// assert_eq!(library.get_bitmap_height(&bitmap_handle), 8);
+    /// ```
+    pub fn get_image_object_bitmap<'library>(
+        &'library self,
+        image_object: &PageObjectHandle,
+    ) -> Result<BitmapHandle<'static, 'library>, PdfiumError> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDFImageObj_GetBitmap(image_object.handle.as_ptr()) });
+
+        handle
+            .map(|handle| BitmapHandle {
+                handle,
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or(PdfiumError::Unknown)
+    }
+
+    /// Render an image object into a new bitmap.
+    ///
+    /// Unlike [`Library::get_image_object_bitmap`], the image's mask and the image object's matrix are applied.
+    /// If `page` is given, the transforms of the page that contains `image_object` are applied as well.
+    /// `document` must be the document that contains `image_object`.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` is not an image object.
+    /// - [`Unknown`](PdfiumError::Unknown): `image_object` has no content or its content can not be decoded.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.load_image_object_jpeg_inline(&mut image_object_handle, DUMMY_JPEG).unwrap();
+    ///
+    /// let bitmap_handle =
+    ///     library.get_image_object_rendered_bitmap(&document_handle, None, &image_object_handle);
+    /// assert!(bitmap_handle.is_ok());
+    /// ```
+    pub fn get_image_object_rendered_bitmap<'library>(
+        &'library self,
+        document: &DocumentHandle,
+        page: Option<&PageHandle>,
+        image_object: &PageObjectHandle,
+    ) -> Result<BitmapHandle<'static, 'library>, PdfiumError> {
+        let page = page.map_or(std::ptr::null_mut(), |page| page.handle.as_ptr());
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFImageObj_GetRenderedBitmap(
+                document.handle.as_ptr(),
+                page,
+                image_object.handle.as_ptr(),
+            )
+        });
+
+        handle
+            .map(|handle| BitmapHandle {
+                handle,
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or(PdfiumError::Unknown)
+    }
+    /// Get the image data of an image object as it is stored in the document.
+    ///
+    /// No filters are applied, so the data is still compressed with the filters
+    /// returned by [`Library::get_image_object_filter`]. For example, the data of an image with
+    /// the `DCTDecode` filter is a complete JPEG file.
+    ///
+    /// Returns an empty buffer if `image_object` is not an image object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.load_image_object_jpeg_inline(&mut image_object_handle, DUMMY_JPEG).unwrap();
+    ///
+    /// let data = library.get_image_object_data_raw(&image_object_handle);
+    /// assert_eq!(data, DUMMY_JPEG);
+    /// ```
+    pub fn get_image_object_data_raw(&self, image_object: &PageObjectHandle) -> Vec<u8> {
+        get_bytes(|buffer, length| unsafe {
+            bindings::FPDFImageObj_GetImageDataRaw(
+                image_object.handle.as_ptr(),
+                buffer as *mut _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the image data of an image object with all of its filters applied.
+    ///
+    /// Returns an empty buffer if `image_object` is not an image object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.load_image_object_jpeg_inline(&mut image_object_handle, DUMMY_JPEG).unwrap();
+    ///
+    /// let data = library.get_image_object_data_decoded(&image_object_handle);
+    /// assert!(!data.is_empty());
+    /// ```
+    pub fn get_image_object_data_decoded(&self, image_object: &PageObjectHandle) -> Vec<u8> {
+        get_bytes(|buffer, length| unsafe {
+            bindings::FPDFImageObj_GetImageDataDecoded(
+                image_object.handle.as_ptr(),
+                buffer as *mut _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the number of filters of an image object.
+    ///
+    /// Returns `0` if `image_object` is not an image object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.load_image_object_jpeg_inline(&mut image_object_handle, DUMMY_JPEG).unwrap();
+    ///
+    /// assert_eq!(library.get_image_object_filter_count(&image_object_handle), 1);
+    /// ```
+    pub fn get_image_object_filter_count(&self, image_object: &PageObjectHandle) -> usize {
+        unsafe { bindings::FPDFImageObj_GetImageFilterCount(image_object.handle.as_ptr()) as usize }
+    }
+
+    /// Get the name of the filter at `index` of an image object, for example `FlateDecode` or `DCTDecode`.
+    ///
+    /// The filters must be applied in order to decode the raw image data.
+    ///
+    /// Returns an empty string if `index` is out of range or `image_object` is not an image object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    /// # static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.load_image_object_jpeg_inline(&mut image_object_handle, DUMMY_JPEG).unwrap();
+    ///
+    /// assert_eq!(library.get_image_object_filter(&image_object_handle, 0), "DCTDecode");
+    /// ```
+    pub fn get_image_object_filter(&self, image_object: &PageObjectHandle, index: usize) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDFImageObj_GetImageFilter(
+                image_object.handle.as_ptr(),
+                index as i32,
+                buffer as *mut _,
+                length as _,
+            ) as _
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BitmapFormat, Matrix, PageObjectType, Rect, TEST_LOCK};
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    static DUMMY_JPEG: &[u8] = include_bytes!("../../../test_assets/dummy.jpg");
+
+    #[test]
+    fn insert_image() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+        let object_count = library.get_page_object_count(&page);
+
+        let mut bitmap = library.create_bitmap(10, 10, BitmapFormat::BGRA).unwrap();
+        library.bitmap_fill_rect(&mut bitmap, 0, 0, 10, 10, 0xFF00FF00);
+
+        let rect = Rect {
+            left: 100.0,
+            bottom: 100.0,
+            right: 200.0,
+            top: 200.0,
+        };
+
+        let mut image = library.create_image_object(&document).unwrap();
+        library
+            .set_image_object_bitmap(&mut image, &bitmap)
+            .unwrap();
+        library
+            .set_page_object_matrix(&mut image, &Matrix::from_rect(&rect))
+            .unwrap();
+        library.insert_page_object(&mut page, image);
+        library.generate_page_content(&mut page).unwrap();
+
+        assert_eq!(library.get_page_object_count(&page), object_count + 1);
+
+        let image = library.get_page_object(&page, object_count).unwrap();
+        assert_eq!(library.get_page_object_type(&image), PageObjectType::Image);
+        assert_eq!(library.get_page_object_bounds(&image), Some(rect));
+    }
+
+    #[test]
+    fn jpeg() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        // The image is read after the bytes are dropped
+        let jpeg = DUMMY_JPEG.to_vec();
+        let mut image = library.create_image_object(&document).unwrap();
+        library
+            .load_image_object_jpeg(&document, &mut image, &jpeg)
+            .unwrap();
+        drop(jpeg);
+        let bitmap = library.get_image_object_bitmap(&image).unwrap();
+        assert_eq!(library.get_bitmap_width(&bitmap), 16);
+
+        let mut inline_image = library.create_image_object(&document).unwrap();
+        library
+            .load_image_object_jpeg_inline(&mut inline_image, DUMMY_JPEG)
+            .unwrap();
+
+        let mut empty_image = library.create_image_object(&document).unwrap();
+        assert!(library
+            .load_image_object_jpeg_inline(&mut empty_image, &[])
+            .is_err());
+    }
+
+    #[test]
+    fn extract_bitmap() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+        let object_count = library.get_page_object_count(&page);
+
+        let mut image = library.create_image_object(&document).unwrap();
+        library
+            .load_image_object_jpeg_inline(&mut image, DUMMY_JPEG)
+            .unwrap();
+        library.insert_page_object(&mut page, image);
+
+        let image = library.get_page_object(&page, object_count).unwrap();
+
+        let bitmap = library.get_image_object_bitmap(&image).unwrap();
+        assert_eq!(library.get_bitmap_width(&bitmap), 16);
+        assert_eq!(library.get_bitmap_height(&bitmap), 8);
+
+        assert!(library
+            .get_image_object_rendered_bitmap(&document, Some(&page), &image)
+            .is_ok());
+
+        let text = library.get_page_object(&page, 0).unwrap();
+        assert!(library.get_image_object_bitmap(&text).is_err());
+    }
+
+    #[test]
+    fn image_data() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        let mut image = library.create_image_object(&document).unwrap();
+        library
+            .load_image_object_jpeg_inline(&mut image, DUMMY_JPEG)
+            .unwrap();
+
+        assert_eq!(library.get_image_object_data_raw(&image), DUMMY_JPEG);
+        assert_eq!(library.get_image_object_filter_count(&image), 1);
+        assert_eq!(library.get_image_object_filter(&image, 0), "DCTDecode");
+        assert_eq!(library.get_image_object_filter(&image, 1), "");
+
+        let page = library.load_page(&document, 0).unwrap();
+        let text = library.get_page_object(&page, 0).unwrap();
+        assert!(library.get_image_object_data_raw(&text).is_empty());
+        assert_eq!(library.get_image_object_filter_count(&text), 0);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{bindings, to_result, Capability, DocumentHandle, Library, PdfiumError};
+use std::ptr;
+
+impl Library {
+    /// Copy pages of `source` into `destination`, in front of the page at `index`.
+    ///
+    /// `pages` are the indices of the pages to copy, 0 for the first page, in the order they are inserted.
+    /// All pages are copied if `pages` is `None`.
+    /// The pages keep their content and annotations, other parts of `source` like its outline are not copied.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): A page index is out of bounds.
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to copy the pages.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let source_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut document_handle = library.create_document().unwrap();
+    ///
+    /// library
+    ///     .import_pages(&mut document_handle, &source_handle, None, 0)
+    ///     .unwrap();
+    /// assert_eq!(library.get_page_count(&document_handle), 1);
+    /// ```
+    pub fn import_pages(
+        &self,
+        destination: &mut DocumentHandle,
+        source: &DocumentHandle,
+        pages: Option<&[usize]>,
+        index: usize,
+    ) -> Result<(), PdfiumError> {
+        let page_count = self.get_page_count(source);
+        let pages = pages.map(|pages| pages.iter().map(|&page| page as _).collect::<Vec<_>>());
+
+        if let Some(pages) = &pages {
+            if pages.is_empty() || pages.iter().any(|&page| page as usize >= page_count) {
+                return Err(PdfiumError::Unknown);
+            }
+        }
+
+        let (indices, length) = match &pages {
+            Some(pages) => (pages.as_ptr(), pages.len()),
+            None => (ptr::null(), 0),
+        };
+        // The pages after `index` get new indices
+        destination.pages.clear();
+
+        to_result(unsafe {
+            bindings::FPDF_ImportPagesByIndex(
+                destination.handle.as_ptr(),
+                source.handle.as_ptr(),
+                indices,
+                length as _,
+                index as _,
+            )
+        })
+    }
+
+    /// Move pages of a document in front of the page at `index`, the index from before the pages are moved.
+    ///
+    /// `pages` are the indices of the pages to move, 0 for the first page, in the order they are inserted.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unsupported`](PdfiumError::Unsupported): PDFium doesn't have [`Capability::MovePages`].
+    /// - [`Unknown`](PdfiumError::Unknown): A page index is out of bounds or in `pages` more than once.
+    /// - [`Unknown`](PdfiumError::Unknown): `index` is after the last page once the pages are moved.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let source_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut document_handle = library.create_document().unwrap();
+    /// library
+    ///     .import_pages(&mut document_handle, &source_handle, Some(&[0, 0]), 0)
+    ///     .unwrap();
+    ///
+    /// // Move the second page to the front
+    /// library.move_pages(&mut document_handle, &[1], 0).unwrap();
+    /// ```
+    pub fn move_pages(
+        &self,
+        document: &mut DocumentHandle,
+        pages: &[usize],
+        index: usize,
+    ) -> Result<(), PdfiumError> {
+        self.require_capability(Capability::MovePages)?;
+
+        #[cfg(pdfium_function = "FPDF_MovePages")]
+        let result = {
+            let pages: Vec<_> = pages.iter().map(|&page| page as _).collect();
+            document.pages.clear();
+
+            to_result(unsafe {
+                bindings::FPDF_MovePages(
+                    document.handle.as_ptr(),
+                    pages.as_ptr(),
+                    pages.len() as _,
+                    index as _,
+                )
+            })
+        };
+
+        // Not reached, the capability is missing if the headers don't declare the function
+        #[cfg(not(pdfium_function = "FPDF_MovePages"))]
+        let result = {
+            let _ = (document, pages, index);
+            Err(PdfiumError::Unsupported)
+        };
+
+        result
+    }
+
+    /// Copy the viewer preferences of `source` to `destination`, like how the document is displayed when it's opened.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `source` doesn't have viewer preferences.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let source_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut document_handle = library.create_document().unwrap();
+    ///
+    /// let result = library.copy_viewer_preferences(&mut document_handle, &source_handle);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn copy_viewer_preferences(
+        &self,
+        destination: &mut DocumentHandle,
+        source: &DocumentHandle,
+    ) -> Result<(), PdfiumError> {
+        to_result(unsafe {
+            bindings::FPDF_CopyViewerPreferences(
+                destination.handle.as_ptr(),
+                source.handle.as_ptr(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn import_pages() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let source = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut document = library.create_document().unwrap();
+
+        library
+            .import_pages(&mut document, &source, None, 0)
+            .unwrap();
+        library
+            .import_pages(&mut document, &source, Some(&[0, 0]), 1)
+            .unwrap();
+        assert_eq!(library.get_page_count(&document), 3);
+
+        assert!(library
+            .import_pages(&mut document, &source, Some(&[1]), 0)
+            .is_err());
+        assert!(library
+            .import_pages(&mut document, &source, Some(&[]), 0)
+            .is_err());
+        assert_eq!(library.get_page_count(&document), 3);
+    }
+
+    #[test]
+    fn move_pages() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let source = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut document = library.create_document().unwrap();
+        library
+            .import_pages(&mut document, &source, Some(&[0, 0]), 0)
+            .unwrap();
+
+        let result = library.move_pages(&mut document, &[1], 0);
+        if library.has_capability(Capability::MovePages) {
+            assert!(result.is_ok());
+            assert!(library.move_pages(&mut document, &[2], 0).is_err());
+            assert!(library.move_pages(&mut document, &[0, 0], 0).is_err());
+        } else {
+            assert_eq!(result, Err(PdfiumError::Unsupported));
+        }
+        assert_eq!(library.get_page_count(&document), 2);
+    }
+}
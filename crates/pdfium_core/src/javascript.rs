@@ -0,0 +1,185 @@
+use crate::{bindings, get_wide_string, DocumentHandle, Library};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the number of document level JavaScript actions, the entries of the `JavaScript` name tree.
+    ///
+    /// Scripts of other actions, like the `OpenAction` of the document or the actions of form fields,
+    /// are not counted.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static JAVASCRIPT_PDF: &[u8] = include_bytes!("../../../test_assets/javascript.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(JAVASCRIPT_PDF, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(library.get_javascript_action_count(&document_handle), 2);
+    /// ```
+    pub fn get_javascript_action_count(&self, document: &DocumentHandle) -> usize {
+        let count = unsafe { bindings::FPDFDoc_GetJavaScriptActionCount(document.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a document level JavaScript action.
+    ///
+    /// `index` 0 for the first action.
+    ///
+    /// Returns `None` if `index` is out of bounds or the entry is not a JavaScript action.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static JAVASCRIPT_PDF: &[u8] = include_bytes!("../../../test_assets/javascript.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(JAVASCRIPT_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let javascript_handle = library.get_javascript_action(&document_handle, 0);
+    /// assert!(javascript_handle.is_some());
+    /// ```
+    pub fn get_javascript_action<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        index: usize,
+    ) -> Option<JavaScriptActionHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFDoc_GetJavaScriptAction(document.handle.as_ptr(), index as _)
+        });
+
+        handle.map(JavaScriptActionHandle::new)
+    }
+
+    /// Get the name of a JavaScript action, its key in the `JavaScript` name tree.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static JAVASCRIPT_PDF: &[u8] = include_bytes!("../../../test_assets/javascript.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(JAVASCRIPT_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let javascript_handle = library.get_javascript_action(&document_handle, 0).unwrap();
+    ///
+    /// let name = library.get_javascript_action_name(&javascript_handle);
+    /// assert_eq!(name, "alert");
+    /// ```
+    pub fn get_javascript_action_name(&self, javascript: &JavaScriptActionHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFJavaScriptAction_GetName(javascript.handle.as_ptr(), buffer, length as _)
+                as _
+        })
+    }
+
+    /// Get the script of a JavaScript action, from a string or a stream.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static JAVASCRIPT_PDF: &[u8] = include_bytes!("../../../test_assets/javascript.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(JAVASCRIPT_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let javascript_handle = library.get_javascript_action(&document_handle, 0).unwrap();
+    ///
+    /// let script = library.get_javascript_action_script(&javascript_handle);
+    /// assert_eq!(script, "app.alert('Hello');");
+    /// ```
+    pub fn get_javascript_action_script(&self, javascript: &JavaScriptActionHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFJavaScriptAction_GetScript(
+                javascript.handle.as_ptr(),
+                buffer,
+                length as _,
+            ) as _
+        })
+    }
+}
+
+/// Safe handle to PDFium JavaScript Action, a document level script.
+///
+/// Created using [`Library::get_javascript_action`].
+///
+/// JavaScript action is closed when handle is dropped. The action itself stays in its document.
+pub struct JavaScriptActionHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_javascript_action_t>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(JavaScriptActionHandle: Sync, Send);
+
+impl JavaScriptActionHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_javascript_action_t>) -> Self {
+        JavaScriptActionHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+impl Drop for JavaScriptActionHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FPDFDoc_CloseJavaScriptAction(self.handle.as_ptr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static JAVASCRIPT_PDF: &[u8] = include_bytes!("../../../test_assets/javascript.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn javascript_actions() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(JAVASCRIPT_PDF, None)
+            .unwrap();
+
+        assert_eq!(library.get_javascript_action_count(&document), 2);
+        assert!(library.get_javascript_action(&document, 2).is_none());
+
+        let alert = library.get_javascript_action(&document, 0).unwrap();
+        assert_eq!(library.get_javascript_action_name(&alert), "alert");
+        assert_eq!(
+            library.get_javascript_action_script(&alert),
+            "app.alert('Hello');"
+        );
+
+        // Scripts can also be streams
+        let helpers = library.get_javascript_action(&document, 1).unwrap();
+        assert_eq!(library.get_javascript_action_name(&helpers), "helpers");
+        assert_eq!(
+            library.get_javascript_action_script(&helpers),
+            "var total = 0;\nfunction add(x) { total += x; }\n"
+        );
+
+        let dummy = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_javascript_action_count(&dummy), 0);
+    }
+}
@@ -5,7 +5,11 @@
 //!
 //! Download the prebuilt PDFium binary from: https://github.com/bblanchon/pdfium-binaries.
 //!
-//! This crate doesn't use any the V8 or XFA features from PDFium so you only have to use the base library.
+//! By default this crate doesn't use any the V8 or XFA features from PDFium so you only have to use the base library.
+//! The `v8` and `xfa` features need the V8 and XFA builds of the library.
+//!
+//! With the `dynamic` feature PDFium isn't linked, it's loaded at runtime with [`load_library`](crate::load_library)
+//! so applications can start without it. Only the headers are needed to build.
 //!
 //! ## Example
 //! Here is an example of getting the number of pages in a PDF:
@@ -104,23 +108,106 @@
 
 #![warn(missing_docs)]
 
+mod action;
+mod annotation;
+mod attachment;
 mod bindings;
-
+mod bookmark;
+mod capability;
+mod clip_path;
+mod color;
+mod config;
+mod data_avail;
+mod destination;
+mod document;
+#[cfg(feature = "dynamic")]
+mod dynamic;
+mod file_access;
+mod font;
+mod form;
+mod form_actions;
+mod form_events;
+mod form_object;
+mod geometry;
+mod image_object;
+mod import;
+mod javascript;
+mod link;
+mod mark;
+mod page_cache;
+mod page_object;
+mod path;
+mod save;
+mod signature;
+mod struct_tree;
+mod system_font;
+mod text_object;
+mod text_page;
+#[cfg(feature = "xfa")]
+mod xfa;
+
+pub use action::{ActionHandle, ActionType};
+pub use annotation::{
+    annotation_flags, form_field_flags, AnnotationActionEvent, AnnotationBorder,
+    AnnotationColorType, AnnotationHandle, AnnotationSubtype, AppearanceMode, FormFieldType,
+};
+pub use attachment::AttachmentHandle;
+pub use bookmark::BookmarkHandle;
+pub use capability::Capability;
+pub use clip_path::{ClipPathHandle, OwnedClipPathHandle};
+pub use color::Color;
+#[cfg(feature = "v8")]
+pub use config::V8Config;
+pub use config::{LibraryConfig, SandboxPolicy};
+pub use data_avail::{DataAvailabilityHandle, DataSource};
+pub use destination::{DestinationHandle, DestinationLocation, DestinationViewMode};
+pub use document::{permission_flags, FileIdentifierType, PageMode};
+#[cfg(feature = "dynamic")]
+pub use dynamic::load_library;
+pub use font::{font_flags, FontHandle, GlyphPathHandle};
+pub use form::{FormFillCallbacks, FormHandle, FormPageHandle, FormType};
+pub use form_actions::{DocumentAction, PageAction};
+pub use form_events::{event_flags, key_codes};
+pub use geometry::{Matrix, Point, QuadPoints, Rect, Size};
+pub use javascript::JavaScriptActionHandle;
+pub use link::LinkHandle;
+pub use mark::PageObjectMarkHandle;
+pub use page_object::{OwnedPageObjectHandle, PageObjectHandle, PageObjectMut, PageObjectType};
+pub use path::{PathSegmentHandle, PathSegmentType};
+pub use save::{FlattenUsage, SaveMode};
+pub use signature::SignatureHandle;
+pub use struct_tree::{
+    StructAttributeHandle, StructAttributeValueHandle, StructElementHandle, StructTreeHandle,
+};
+pub use system_font::{charsets, CharsetFont};
+pub use text_object::TextRenderMode;
+pub use text_page::TextPageHandle;
+
+use file_access::FileRead;
+use form::CachedForm;
+use page_cache::{OpenPage, PageCache};
 use parking_lot::{const_mutex, Mutex};
 use static_assertions::assert_not_impl_any;
+use std::cell::{OnceCell, RefCell};
 use std::ffi::{c_void, CStr};
 use std::fmt;
+use std::io::{Read, Seek};
 use std::marker::PhantomData;
 use std::ptr::NonNull;
+use text_page::CachedTextPage;
 
 /// A properly initialized instance of the PDFium library.
 ///
-/// Created using [`Library::init_library`].
+/// Created using [`Library::init_library`] or [`Library::init_library_with_config`].
 ///
 /// The PDFium library is not thread safe so there can only be one instance per process.
 ///
 /// The PDFium library will be uninitialized when this value is dropped.
-pub struct Library(PhantomData<*mut ()>);
+pub struct Library {
+    _font_paths: Option<config::FontPaths>,
+    _font_info: Option<Box<system_font::SystemFontInfo>>,
+    not_send: PhantomData<*mut ()>,
+}
 
 assert_not_impl_any!(Library: Sync, Send);
 
@@ -157,24 +244,7 @@ impl Library {
     /// assert!(Library::init_library().is_some());
     /// ```
     pub fn init_library() -> Option<Library> {
-        let mut initialized = INITIALIZED.lock();
-
-        if *initialized {
-            None
-        } else {
-            let config = bindings::FPDF_LIBRARY_CONFIG_ {
-                version: 2,
-                m_pUserFontPaths: std::ptr::null::<*const i8>() as *mut _,
-                m_pIsolate: std::ptr::null::<std::ffi::c_void>() as *mut _,
-                m_v8EmbedderSlot: 0,
-                m_pPlatform: std::ptr::null::<std::ffi::c_void>() as *mut _,
-            };
-            unsafe {
-                bindings::FPDF_InitLibraryWithConfig(&config);
-            }
-            *initialized = true;
-            Some(Library(Default::default()))
-        }
+        Self::init_library_with_config(LibraryConfig::default())
     }
 
     /// Get last last error code when a function fails.
@@ -243,6 +313,10 @@ impl Library {
         handle
             .map(|handle| DocumentHandle {
                 handle,
+                _file_read: None,
+                pages: PageCache::default(),
+                form: OnceCell::new(),
+                jpegs: RefCell::default(),
                 data_life_time: Default::default(),
                 library_life_time: Default::default(),
             })
@@ -251,6 +325,8 @@ impl Library {
 
     /// Open and load a PDF document from a bytes buffer.
     ///
+    /// `buffer` can be larger than 2 GiB, its length is passed to PDFium as a `size_t`.
+    ///
     /// See the [`load_document`](Library::load_document) function for more details.
     /// ## Examples
     /// ```
@@ -272,9 +348,9 @@ impl Library {
         let password = password.map(|x| x.as_ptr()).unwrap_or_else(std::ptr::null);
 
         let handle = NonNull::new(unsafe {
-            bindings::FPDF_LoadMemDocument(
-                buffer.as_ptr() as *mut c_void,
-                buffer.len() as i32,
+            bindings::FPDF_LoadMemDocument64(
+                buffer.as_ptr() as *const c_void,
+                buffer.len(),
                 password,
             )
         });
@@ -282,12 +358,106 @@ impl Library {
         handle
             .map(|handle| DocumentHandle {
                 handle,
+                _file_read: None,
+                pages: PageCache::default(),
+                form: OnceCell::new(),
+                jpegs: RefCell::default(),
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or_else(|| self.last_error())
+    }
+
+    /// Open and load a PDF document from a reader, like a [`File`](std::fs::File),
+    /// without reading all of it into memory.
+    ///
+    /// PDFium reads the parts of the document when they are needed, so `reader` is kept until the document is closed.
+    /// See the [`load_document`](Library::load_document) function for more details.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`BadFile`](PdfiumError::BadFile): Unable to seek to the end of `reader` to find its length.
+    /// - [`BadFile`](PdfiumError::BadFile): Unable to read from `reader`, or reading from it panics.
+    /// - See [`load_document`](Library::load_document) for the errors of invalid documents.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// use std::io::Cursor;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let reader = Cursor::new(DUMMY_PDF);
+    /// let document_handle = library.load_document_from_reader(reader, None).unwrap();
+    /// assert_eq!(library.get_page_count(&document_handle), 1);
+    /// ```
+    pub fn load_document_from_reader<'data, 'library>(
+        &'library self,
+        reader: impl Read + Seek + 'data,
+        password: Option<&CStr>,
+    ) -> Result<DocumentHandle<'data, 'library>, PdfiumError> {
+        let password = password.map(|x| x.as_ptr()).unwrap_or_else(std::ptr::null);
+
+        let mut file_read = FileRead::new(reader);
+        let mut file_access = file_read.file_access().map_err(|_| PdfiumError::BadFile)?;
+
+        // PDFium copies the file access, only `file_read` has to outlive the document
+        let handle =
+            NonNull::new(unsafe { bindings::FPDF_LoadCustomDocument(&mut file_access, password) });
+
+        handle
+            .map(|handle| DocumentHandle {
+                handle,
+                _file_read: Some(file_read),
+                pages: PageCache::default(),
+                form: OnceCell::new(),
+                jpegs: RefCell::default(),
                 data_life_time: Default::default(),
                 library_life_time: Default::default(),
             })
             .ok_or_else(|| self.last_error())
     }
 
+    /// Create a new empty PDF document, without any pages.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to create the document.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library.create_document().unwrap();
+    /// assert_eq!(library.get_page_count(&document_handle), 0);
+    /// ```
+    pub fn create_document(&self) -> Result<DocumentHandle<'static, '_>, PdfiumError> {
+        let handle = NonNull::new(unsafe { bindings::FPDF_CreateNewDocument() });
+
+        handle
+            .map(|handle| DocumentHandle {
+                handle,
+                _file_read: None,
+                pages: PageCache::default(),
+                form: OnceCell::new(),
+                jpegs: RefCell::default(),
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or(PdfiumError::Unknown)
+    }
+
     /// Get total number of pages in the document.
     /// ## Examples
     /// ```
@@ -346,6 +516,8 @@ impl Library {
         handle
             .map(|handle| PageHandle {
                 handle,
+                text_page: CachedTextPage::default(),
+                cache: None,
                 data_life_time: Default::default(),
                 library_life_time: Default::default(),
             })
@@ -398,6 +570,41 @@ impl Library {
         unsafe { bindings::FPDF_GetPageHeightF(page.handle.as_ptr()) }
     }
 
+    /// Get the size of a page without loading it, see [`Library::get_page_width`] and [`Library::get_page_height`].
+    ///
+    /// `index` 0 for the first page. Returns `None` if the document has no page at `index`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Size};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let size = library.get_page_size_by_index(&document_handle, 0);
+    /// assert_eq!(size, Some(Size { width: 595.0, height: 842.0 }));
+    /// ```
+    pub fn get_page_size_by_index(&self, document: &DocumentHandle, index: usize) -> Option<Size> {
+        let mut size = bindings::FS_SIZEF {
+            width: 0.0,
+            height: 0.0,
+        };
+
+        let success = unsafe {
+            bindings::FPDF_GetPageSizeByIndexF(document.handle.as_ptr(), index as _, &mut size)
+        };
+
+        if success != 0 {
+            Some(Size::from_fs_sizef(&size))
+        } else {
+            None
+        }
+    }
+
     /// Render contents of a page to a device independent bitmap.
     ///
     /// `start_x` is the x-axis coordinate in the bitmap at which to place the top-left corner of the page.
@@ -531,6 +738,10 @@ impl Library {
     /// A scan line is the number of bytes separating pixels in the y-direction.
     /// This input allows for buffers that have scan lines larger than `width * number_of_bytes_per_pixel`.
     ///
+    /// PDFium writes to `buffer` while the handle exists, like when a page is rendered, even if it's memory
+    /// that is shared with other processes. See `Library::bitmap_from_external_buffer` of `pdfium_rs`
+    /// for how to share it safely.
+    ///
     /// ## Errors
     /// This function will return an error under a number of different circumstances.
     /// Some of these error conditions are listed here, together with their [`PdfiumError`].
@@ -539,6 +750,7 @@ impl Library {
     ///
     /// - [`BadFormat`](PdfiumError::BadFormat): `width` or `height` is 0.
     /// - [`BadFormat`](PdfiumError::BadFormat): `buffer` is an incorrect size.
+    /// - [`BadFormat`](PdfiumError::BadFormat): `height_stride` is smaller than a row of pixels.
     ///
     /// ### Examples
     /// ```
@@ -594,15 +806,21 @@ impl Library {
     ) -> Result<BitmapHandle<'data, 'library>, PdfiumError> {
         let buffer = buffer
             .map(|buffer| {
-                if buffer.len() < height * height_stride {
+                let fits = match height.checked_mul(height_stride) {
+                    Some(length) => buffer.len() >= length,
+                    None => false,
+                };
+
+                if height_stride < width * format.bytes_per_pixel() || !fits {
                     Err(PdfiumError::BadFormat)
                 } else {
-                    Ok(buffer.as_ptr())
+                    // The pointer has to come from the mutable borrow, PDFium writes through it
+                    Ok(buffer.as_mut_ptr())
                 }
             })
             .transpose()?;
 
-        let buffer = buffer.unwrap_or_else(std::ptr::null);
+        let buffer = buffer.unwrap_or_else(std::ptr::null_mut);
 
         let handle = NonNull::new(unsafe {
             bindings::FPDFBitmap_CreateEx(
@@ -798,6 +1016,14 @@ pub enum PdfiumError {
     UnsupportedSecurityScheme = bindings::FPDF_ERR_SECURITY as i32,
     /// Page not found or content error.
     BadPage = bindings::FPDF_ERR_PAGE as i32,
+    /// The document uses XFA forms, which are not supported.
+    ///
+    /// This error is never reported by PDFium itself.
+    UnsupportedForm,
+    /// The function is missing from the build of PDFium, see [`Capability`].
+    ///
+    /// This error is never reported by PDFium itself.
+    Unsupported,
 }
 
 impl PdfiumError {
@@ -870,6 +1096,50 @@ pub enum PageOrientation {
     CounterClockwise = 3,
 }
 
+/// The type of a PDF object, for example the value of a parameter.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ObjectType {
+    /// Unknown object type.
+    Unknown = bindings::FPDF_OBJECT_UNKNOWN as i32,
+    /// Boolean.
+    Boolean = bindings::FPDF_OBJECT_BOOLEAN as i32,
+    /// Integer or real number.
+    Number = bindings::FPDF_OBJECT_NUMBER as i32,
+    /// String.
+    String = bindings::FPDF_OBJECT_STRING as i32,
+    /// Name.
+    Name = bindings::FPDF_OBJECT_NAME as i32,
+    /// Array.
+    Array = bindings::FPDF_OBJECT_ARRAY as i32,
+    /// Dictionary.
+    Dictionary = bindings::FPDF_OBJECT_DICTIONARY as i32,
+    /// Stream.
+    Stream = bindings::FPDF_OBJECT_STREAM as i32,
+    /// The null object.
+    Null = bindings::FPDF_OBJECT_NULLOBJ as i32,
+    /// Indirect reference to another object.
+    Reference = bindings::FPDF_OBJECT_REFERENCE as i32,
+}
+
+impl ObjectType {
+    fn from_i32(number: i32) -> Option<ObjectType> {
+        match number {
+            x if x == ObjectType::Unknown as i32 => Some(ObjectType::Unknown),
+            x if x == ObjectType::Boolean as i32 => Some(ObjectType::Boolean),
+            x if x == ObjectType::Number as i32 => Some(ObjectType::Number),
+            x if x == ObjectType::String as i32 => Some(ObjectType::String),
+            x if x == ObjectType::Name as i32 => Some(ObjectType::Name),
+            x if x == ObjectType::Array as i32 => Some(ObjectType::Array),
+            x if x == ObjectType::Dictionary as i32 => Some(ObjectType::Dictionary),
+            x if x == ObjectType::Stream as i32 => Some(ObjectType::Stream),
+            x if x == ObjectType::Null as i32 => Some(ObjectType::Null),
+            x if x == ObjectType::Reference as i32 => Some(ObjectType::Reference),
+            _ => None,
+        }
+    }
+}
+
 pub mod rendering_flags {
     //! Page rendering flags used for [`render_page_to_bitmap`](crate::Library::render_page_to_bitmap). They can be combined with bit-wise OR.
     //!
@@ -925,11 +1195,20 @@ pub mod rendering_flags {
 
 /// Safe handle to PDFium Document.
 ///
-/// Created using [`Library::load_document`] or [`Library::load_document_from_bytes`].
+/// Created using [`Library::load_document`], [`Library::load_document_from_bytes`]
+/// or [`Library::load_document_from_reader`].
 ///
 /// Document is closed when handle is dropped.
 pub struct DocumentHandle<'a, 'b> {
     handle: NonNull<bindings::fpdf_document_t__>,
+    /// The source of a document loaded with [`Library::load_document_from_reader`].
+    _file_read: Option<Box<FileRead<'a>>>,
+    /// The pages that are kept open by [`Library::load_cached_page`].
+    pages: PageCache,
+    /// The form fill environment of [`Library::init_cached_form_fill_environment`].
+    form: OnceCell<CachedForm>,
+    /// The copies of the images of [`Library::load_image_object_jpeg`], PDFium reads them until the document is closed.
+    jpegs: RefCell<Vec<Box<[u8]>>>,
     data_life_time: PhantomData<&'a [u8]>,
     library_life_time: PhantomData<&'b Library>,
 }
@@ -938,6 +1217,10 @@ assert_not_impl_any!(DocumentHandle: Sync, Send);
 
 impl Drop for DocumentHandle<'_, '_> {
     fn drop(&mut self) {
+        // The pages and the form fill environment have to be closed before the document
+        self.pages.clear();
+        drop(self.form.take());
+
         unsafe {
             bindings::FPDF_CloseDocument(self.handle.as_ptr());
         }
@@ -952,21 +1235,38 @@ impl fmt::Debug for DocumentHandle<'_, '_> {
 
 /// Safe handle to PDFium Page.
 ///
-/// Created using [`Library::load_page`].
+/// Created using [`Library::load_page`] or [`Library::load_cached_page`].
 ///
-/// Page is closed when handle is dropped.
+/// Page is closed when handle is dropped, unless it's kept in the page cache of its document.
 pub struct PageHandle<'a, 'b> {
     handle: NonNull<bindings::fpdf_page_t__>,
+    /// The text page of [`Library::load_cached_text_page`], closed with the page or when the page is changed.
+    pub(crate) text_page: CachedTextPage,
+    /// The cache of the document and the index of the page, for a page loaded with [`Library::load_cached_page`].
+    cache: Option<(&'a PageCache, usize)>,
     data_life_time: PhantomData<&'a [u8]>,
     library_life_time: PhantomData<&'b Library>,
 }
 
 assert_not_impl_any!(PageHandle: Sync, Send);
 
+impl PageHandle<'_, '_> {
+    /// Close the text page of [`Library::load_cached_text_page`], called by the functions that change the page.
+    pub(crate) fn changed(&mut self) {
+        self.text_page.close();
+    }
+}
+
 impl Drop for PageHandle<'_, '_> {
     fn drop(&mut self) {
-        unsafe {
-            bindings::FPDF_ClosePage(self.handle.as_ptr());
+        let page = OpenPage {
+            page: self.handle,
+            text_page: self.text_page.take(),
+        };
+
+        match self.cache {
+            Some((cache, index)) => cache.put(index, page),
+            None => page.close(),
         }
     }
 }
@@ -1008,6 +1308,84 @@ fn cstr(path: &Path) -> Result<CString, PdfiumError> {
     CString::new(path.as_os_str().as_bytes()).map_err(|_| PdfiumError::BadFile)
 }
 
+/// Run the code of the application in a callback of PDFium, returns `failure` if it panics,
+/// a panic can't unwind into PDFium.
+fn catch_panic<T>(failure: T, callback: impl FnOnce() -> T) -> T {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).unwrap_or(failure)
+}
+
+/// Convert the `FPDF_BOOL` returned by PDFium into a result.
+fn to_result(success: bindings::FPDF_BOOL) -> Result<(), PdfiumError> {
+    if success != 0 {
+        Ok(())
+    } else {
+        Err(PdfiumError::Unknown)
+    }
+}
+
+/// Convert a string into a null terminated byte string.
+///
+/// Fails with [`PdfiumError::Unknown`] if `string` contains a null character.
+fn to_byte_string(string: &str) -> Result<CString, PdfiumError> {
+    CString::new(string).map_err(|_| PdfiumError::Unknown)
+}
+
+/// Convert a string into a null terminated UTF-16LE string.
+fn to_wide_string(string: &str) -> Vec<u16> {
+    string.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// Get a UTF-16LE string from a PDFium function.
+///
+/// PDFium functions that return strings are called twice. First with a null buffer to get the
+/// length of the string in bytes, including the null terminator, and then with a buffer of that length.
+fn get_wide_string(mut get: impl FnMut(*mut u16, usize) -> usize) -> String {
+    let length = get(std::ptr::null_mut(), 0);
+
+    if length == 0 {
+        return String::new();
+    }
+
+    let mut buffer = vec![0u16; length / 2];
+    get(buffer.as_mut_ptr(), buffer.len() * 2);
+
+    let end = buffer.iter().position(|x| *x == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..end])
+}
+
+/// Get a UTF-8 string from a PDFium function.
+///
+/// Works like [`get_wide_string`] but for functions that return null terminated byte strings.
+fn get_string(mut get: impl FnMut(*mut u8, usize) -> usize) -> String {
+    let length = get(std::ptr::null_mut(), 0);
+
+    if length == 0 {
+        return String::new();
+    }
+
+    let mut buffer = vec![0u8; length];
+    get(buffer.as_mut_ptr(), buffer.len());
+
+    let end = buffer.iter().position(|x| *x == 0).unwrap_or(buffer.len());
+    String::from_utf8_lossy(&buffer[..end]).into_owned()
+}
+
+/// Get a byte buffer from a PDFium function.
+///
+/// Works like [`get_string`] but keeps all bytes, for functions that return binary data.
+fn get_bytes(mut get: impl FnMut(*mut u8, usize) -> usize) -> Vec<u8> {
+    let length = get(std::ptr::null_mut(), 0);
+
+    if length == 0 {
+        return Vec::new();
+    }
+
+    let mut buffer = vec![0u8; length];
+    get(buffer.as_mut_ptr(), buffer.len());
+
+    buffer
+}
+
 #[cfg(test)]
 static TEST_LOCK: Mutex<()> = const_mutex(());
 
@@ -1033,6 +1411,47 @@ mod tests {
         assert!(third.is_some());
     }
 
+    #[test]
+    fn load_document_from_reader() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        let file = std::fs::File::open("../../test_assets/dummy.pdf").unwrap();
+        let document = library.load_document_from_reader(file, None).unwrap();
+        assert_eq!(library.get_page_count(&document), 1);
+        assert!(library.load_page(&document, 0).is_ok());
+
+        let password = CString::new("test").unwrap();
+        let reader = std::io::Cursor::new(DUMMY_PASSWORD_PDF);
+        assert!(library
+            .load_document_from_reader(reader, Some(&password))
+            .is_ok());
+
+        let reader = std::io::Cursor::new(DUMMY_PASSWORD_PDF);
+        assert_eq!(
+            library.load_document_from_reader(reader, None).unwrap_err(),
+            PdfiumError::BadPassword
+        );
+
+        // An empty reader is not a document
+        let reader = std::io::Cursor::new(&[][..]);
+        assert!(library.load_document_from_reader(reader, None).is_err());
+
+        // A panic while PDFium reads is a failed read
+        struct Panicking;
+        impl std::io::Read for Panicking {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                panic!("read")
+            }
+        }
+        impl std::io::Seek for Panicking {
+            fn seek(&mut self, _: std::io::SeekFrom) -> std::io::Result<u64> {
+                Ok(DUMMY_PDF.len() as u64)
+            }
+        }
+        assert!(library.load_document_from_reader(Panicking, None).is_err());
+    }
+
     #[test]
     fn page_count() {
         let _guard = TEST_LOCK.lock();
@@ -1053,6 +1472,22 @@ mod tests {
         assert_eq!(library.get_page_height(&page), 842.0);
     }
 
+    #[test]
+    fn page_size_by_index() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        assert_eq!(
+            library.get_page_size_by_index(&document, 0),
+            Some(Size {
+                width: 595.0,
+                height: 842.0
+            })
+        );
+        assert_eq!(library.get_page_size_by_index(&document, 1), None);
+    }
+
     #[test]
     fn render() {
         let _guard = TEST_LOCK.lock();
@@ -0,0 +1,367 @@
+use crate::{
+    bindings, to_result, ActionHandle, AnnotationHandle, DestinationHandle, DocumentHandle,
+    Library, PageHandle, QuadPoints, Rect,
+};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+impl Library {
+    /// Get the link of a link annotation.
+    ///
+    /// Returns `None` if the annotation is not a link annotation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 7).unwrap();
+    ///
+    /// let link_handle = library.get_annotation_link(&annotation_handle);
+    /// assert!(link_handle.is_some());
+    /// ```
+    pub fn get_annotation_link<'page, 'library>(
+        &'library self,
+        annotation: &AnnotationHandle<'page, '_>,
+    ) -> Option<LinkHandle<'page, 'library>> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDFAnnot_GetLink(annotation.handle.as_ptr()) });
+
+        handle.map(LinkHandle::new)
+    }
+
+    /// Get the annotation of a link, so it can be changed like any other annotation.
+    ///
+    /// `page` has to be the page the link is on.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{AnnotationSubtype, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let annotation_handle = library.get_annotation(&page_handle, 7).unwrap();
+    /// let link_handle = library.get_annotation_link(&annotation_handle).unwrap();
+    ///
+    /// let link_annotation_handle = library.get_link_annotation(&page_handle, &link_handle).unwrap();
+    /// assert_eq!(library.get_annotation_subtype(&link_annotation_handle), AnnotationSubtype::Link);
+    /// ```
+    pub fn get_link_annotation<'page, 'library>(
+        &'library self,
+        page: &'page PageHandle,
+        link: &LinkHandle,
+    ) -> Option<AnnotationHandle<'page, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFLink_GetAnnot(page.handle.as_ptr(), link.handle.as_ptr())
+        });
+
+        handle.map(AnnotationHandle::new)
+    }
+
+    /// Get all links of a page, in the order of the annotations of the page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let links = library.get_page_links(&page_handle);
+    /// assert_eq!(links.len(), 2);
+    /// ```
+    pub fn get_page_links<'page, 'library>(
+        &'library self,
+        page: &'page PageHandle,
+    ) -> Vec<LinkHandle<'page, 'library>> {
+        let mut links = Vec::new();
+        let mut position = 0;
+        let mut link = ptr::null_mut();
+
+        while unsafe {
+            bindings::FPDFLink_Enumerate(page.handle.as_ptr(), &mut position, &mut link)
+        } != 0
+        {
+            links.extend(NonNull::new(link).map(LinkHandle::new));
+        }
+
+        links
+    }
+
+    /// Get the rectangle of a link, the area of the page that can be clicked.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Rect};
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let links = library.get_page_links(&page_handle);
+    ///
+    /// let rect = library.get_link_rect(&links[0]);
+    /// assert_eq!(rect, Some(Rect { left: 72.0, bottom: 600.0, right: 200.0, top: 620.0 }));
+    /// ```
+    pub fn get_link_rect(&self, link: &LinkHandle) -> Option<Rect> {
+        let mut rect = Rect::default().to_fs_rectf();
+
+        to_result(unsafe { bindings::FPDFLink_GetAnnotRect(link.handle.as_ptr(), &mut rect) })
+            .ok()
+            .map(|_| Rect::from_fs_rectf(&rect))
+    }
+
+    /// Get the number of quadrilaterals of a link, which cover the clickable area more precisely than its rectangle.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let links = library.get_page_links(&page_handle);
+    ///
+    /// assert_eq!(library.get_link_quad_points_count(&links[0]), 1);
+    /// assert_eq!(library.get_link_quad_points_count(&links[1]), 0);
+    /// ```
+    pub fn get_link_quad_points_count(&self, link: &LinkHandle) -> usize {
+        let count = unsafe { bindings::FPDFLink_CountQuadPoints(link.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a quadrilateral of a link.
+    ///
+    /// `index` 0 for the first quadrilateral.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, QuadPoints, Rect};
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let links = library.get_page_links(&page_handle);
+    ///
+    /// let quad_points = library.get_link_quad_points(&links[0], 0);
+    /// let rect = Rect { left: 72.0, bottom: 600.0, right: 200.0, top: 620.0 };
+    /// assert_eq!(quad_points, Some(QuadPoints::from_rect(&rect)));
+    /// ```
+    pub fn get_link_quad_points(&self, link: &LinkHandle, index: usize) -> Option<QuadPoints> {
+        let mut quad_points = QuadPoints::default().to_fs_quadpointsf();
+
+        to_result(unsafe {
+            bindings::FPDFLink_GetQuadPoints(link.handle.as_ptr(), index as _, &mut quad_points)
+        })
+        .ok()
+        .map(|_| QuadPoints::from_fs_quadpointsf(&quad_points))
+    }
+
+    /// Get the destination of a link.
+    ///
+    /// Links with an [`ActionType::GoTo`](crate::ActionType::GoTo) action return the destination of the action.
+    ///
+    /// Returns `None` if the link has no destination.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let links = library.get_page_links(&page_handle);
+    ///
+    /// let destination_handle = library
+    ///     .get_link_destination(&document_handle, &links[0])
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     library.get_destination_page_index(&document_handle, &destination_handle),
+    ///     Some(2)
+    /// );
+    /// ```
+    pub fn get_link_destination<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        link: &LinkHandle,
+    ) -> Option<DestinationHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFLink_GetDest(document.handle.as_ptr(), link.handle.as_ptr())
+        });
+
+        handle.map(DestinationHandle::new)
+    }
+
+    /// Get the action of a link.
+    ///
+    /// Returns `None` if the link has no action.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{ActionType, Library};
+    /// # static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(OUTLINE_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let links = library.get_page_links(&page_handle);
+    ///
+    /// let action_handle = library.get_link_action(&links[1]).unwrap();
+    /// assert_eq!(library.get_action_type(&action_handle), ActionType::Uri);
+    /// ```
+    pub fn get_link_action<'page, 'library>(
+        &'library self,
+        link: &LinkHandle<'page, '_>,
+    ) -> Option<ActionHandle<'page, 'library>> {
+        let handle = NonNull::new(unsafe { bindings::FPDFLink_GetAction(link.handle.as_ptr()) });
+
+        handle.map(ActionHandle::new)
+    }
+}
+
+/// Safe handle to PDFium Link.
+///
+/// Created using [`Library::get_page_links`] and [`Library::get_annotation_link`].
+///
+/// The link is owned by its page so nothing is freed when the handle is dropped.
+pub struct LinkHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_link_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(LinkHandle: Sync, Send);
+
+impl LinkHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_link_t__>) -> Self {
+        LinkHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ActionType, AnnotationSubtype, TEST_LOCK};
+
+    static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    static OUTLINE_PDF: &[u8] = include_bytes!("../../../test_assets/outline.pdf");
+
+    #[test]
+    fn annotation_link() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let annotation = library.get_annotation(&page, 7).unwrap();
+        let link = library.get_annotation_link(&annotation).unwrap();
+
+        let link_annotation = library.get_link_annotation(&page, &link).unwrap();
+        assert_eq!(
+            library.get_annotation_subtype(&link_annotation),
+            AnnotationSubtype::Link
+        );
+        assert_eq!(
+            library.get_annotation_rect(&link_annotation),
+            library.get_annotation_rect(&annotation)
+        );
+
+        let square = library.get_annotation(&page, 3).unwrap();
+        assert!(library.get_annotation_link(&square).is_none());
+    }
+
+    #[test]
+    fn page_links() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(OUTLINE_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let links = library.get_page_links(&page);
+        assert_eq!(links.len(), 2);
+
+        let rect = Rect {
+            left: 72.0,
+            bottom: 600.0,
+            right: 200.0,
+            top: 620.0,
+        };
+        assert_eq!(library.get_link_rect(&links[0]), Some(rect));
+        assert_eq!(library.get_link_quad_points_count(&links[0]), 1);
+        assert_eq!(
+            library.get_link_quad_points(&links[0], 0),
+            Some(QuadPoints::from_rect(&rect))
+        );
+        assert!(library.get_link_quad_points(&links[0], 1).is_none());
+        let destination = library.get_link_destination(&document, &links[0]).unwrap();
+        assert_eq!(
+            library.get_destination_page_index(&document, &destination),
+            Some(2)
+        );
+        assert!(library.get_link_action(&links[0]).is_none());
+
+        assert_eq!(library.get_link_quad_points_count(&links[1]), 0);
+        assert!(library.get_link_destination(&document, &links[1]).is_none());
+        let action = library.get_link_action(&links[1]).unwrap();
+        assert_eq!(library.get_action_type(&action), ActionType::Uri);
+        assert_eq!(
+            library.get_action_uri_path(&document, &action),
+            "https://example.com/"
+        );
+
+        let page = library.load_page(&document, 1).unwrap();
+        assert!(library.get_page_links(&page).is_empty());
+    }
+}
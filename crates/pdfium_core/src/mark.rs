@@ -0,0 +1,963 @@
+use crate::{bindings, get_bytes, get_wide_string, to_byte_string, to_result};
+use crate::{DocumentHandle, Library, ObjectType, PageHandle};
+use crate::{PageObjectHandle, PageObjectMut, PdfiumError};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get number of content marks of a page object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// assert_eq!(library.get_page_object_mark_count(&object_handle), 0);
+    /// ```
+    pub fn get_page_object_mark_count(&self, page_object: &PageObjectHandle) -> usize {
+        let count = unsafe { bindings::FPDFPageObj_CountMarks(page_object.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a content mark of a page object.
+    ///
+    /// `index` 0 for the first content mark.
+    ///
+    /// Will return `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    ///
+    /// let mark_handle = library.get_page_object_mark(&object_handle, 0);
+    /// assert!(mark_handle.is_some());
+    /// ```
+    pub fn get_page_object_mark<'data, 'library>(
+        &'library self,
+        page_object: &'data PageObjectHandle,
+        index: usize,
+    ) -> Option<PageObjectMarkHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFPageObj_GetMark(page_object.handle.as_ptr(), index as _)
+        });
+
+        handle.map(|handle| PageObjectMarkHandle::new(handle, page_object.handle))
+    }
+
+    /// Add a new content mark to a page object.
+    ///
+    /// The new mark is the last mark of the page object. The page's content has to be regenerated using
+    /// [`Library::generate_page_content`] for the mark to be saved.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `name` contains a null character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// assert_eq!(library.get_page_object_mark_name(&mark_handle), "Generated");
+    /// ```
+    pub fn add_page_object_mark<'data, 'library>(
+        &'library self,
+        page_object: &'data mut impl PageObjectMut,
+        name: &str,
+    ) -> Result<PageObjectMarkHandle<'data, 'library>, PdfiumError> {
+        let name = to_byte_string(name)?;
+        let page_object = page_object.page_object_handle();
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFPageObj_AddMark(page_object.as_ptr(), name.as_ptr())
+        });
+
+        handle
+            .map(|handle| PageObjectMarkHandle::new(handle, page_object))
+            .ok_or(PdfiumError::Unknown)
+    }
+
+    /// Remove a content mark from an object of a page.
+    ///
+    /// `object` is the index of the page object, `index` 0 for its first content mark.
+    ///
+    /// The mark is freed, so it takes the page mutably to make sure no [`PageObjectMarkHandle`] of
+    /// the page's objects is alive.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `object` is out of bounds.
+    /// - [`Unknown`](PdfiumError::Unknown): `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    ///
+    /// assert!(library.remove_page_object_mark(&mut page_handle, 0, 0).is_ok());
+    ///
+    /// let object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// assert_eq!(library.get_page_object_mark_count(&object_handle), 0);
+    /// ```
+    pub fn remove_page_object_mark(
+        &self,
+        page: &mut PageHandle,
+        object: usize,
+        index: usize,
+    ) -> Result<(), PdfiumError> {
+        let page_object = NonNull::new(unsafe {
+            bindings::FPDFPage_GetObject(page.handle.as_ptr(), object as _)
+        })
+        .ok_or(PdfiumError::Unknown)?;
+        let mark = unsafe { bindings::FPDFPageObj_GetMark(page_object.as_ptr(), index as _) };
+
+        if mark.is_null() {
+            return Err(PdfiumError::Unknown);
+        }
+
+        to_result(unsafe { bindings::FPDFPageObj_RemoveMark(page_object.as_ptr(), mark) })
+    }
+
+    /// Get the name of a content mark.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// assert_eq!(library.get_page_object_mark_name(&mark_handle), "Generated");
+    /// ```
+    pub fn get_page_object_mark_name(&self, mark: &PageObjectMarkHandle) -> String {
+        get_wide_string(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDFPageObjMark_GetName(
+                    mark.handle.as_ptr(),
+                    buffer,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            if success != 0 {
+                out_length as _
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Get number of parameters of a content mark.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// assert_eq!(library.get_page_object_mark_param_count(&mark_handle), 0);
+    /// ```
+    pub fn get_page_object_mark_param_count(&self, mark: &PageObjectMarkHandle) -> usize {
+        let count = unsafe { bindings::FPDFPageObjMark_CountParams(mark.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get the key of a parameter of a content mark.
+    ///
+    /// `index` 0 for the first parameter.
+    ///
+    /// Will return `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_int_param(&document_handle, &mut mark_handle, "Version", 1)
+    ///     .unwrap();
+    ///
+    /// let key = library.get_page_object_mark_param_key(&mark_handle, 0);
+    /// assert_eq!(key.as_deref(), Some("Version"));
+    /// ```
+    pub fn get_page_object_mark_param_key(
+        &self,
+        mark: &PageObjectMarkHandle,
+        index: usize,
+    ) -> Option<String> {
+        let mut found = false;
+
+        let key = get_wide_string(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDFPageObjMark_GetParamKey(
+                    mark.handle.as_ptr(),
+                    index as _,
+                    buffer,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(key).filter(|_| found)
+    }
+
+    /// Get the type of the value of a parameter of a content mark.
+    ///
+    /// Returns [`ObjectType::Unknown`] if the parameter does not exist.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, ObjectType};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_int_param(&document_handle, &mut mark_handle, "Version", 1)
+    ///     .unwrap();
+    ///
+    /// let value_type = library.get_page_object_mark_param_type(&mark_handle, "Version");
+    /// assert_eq!(value_type, ObjectType::Number);
+    /// ```
+    pub fn get_page_object_mark_param_type(
+        &self,
+        mark: &PageObjectMarkHandle,
+        key: &str,
+    ) -> ObjectType {
+        let key = match to_byte_string(key) {
+            Ok(key) => key,
+            Err(_) => return ObjectType::Unknown,
+        };
+
+        let value_type = unsafe {
+            bindings::FPDFPageObjMark_GetParamValueType(mark.handle.as_ptr(), key.as_ptr())
+        };
+
+        ObjectType::from_i32(value_type).unwrap_or(ObjectType::Unknown)
+    }
+
+    /// Get the value of an integer parameter of a content mark.
+    ///
+    /// Will return `None` if the parameter does not exist or is not an integer.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_int_param(&document_handle, &mut mark_handle, "Version", 1)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_page_object_mark_int_param(&mark_handle, "Version");
+    /// assert_eq!(value, Some(1));
+    /// ```
+    pub fn get_page_object_mark_int_param(
+        &self,
+        mark: &PageObjectMarkHandle,
+        key: &str,
+    ) -> Option<i32> {
+        let key = to_byte_string(key).ok()?;
+        let mut value = 0;
+
+        let success = unsafe {
+            bindings::FPDFPageObjMark_GetParamIntValue(
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+                &mut value,
+            )
+        };
+
+        Some(value).filter(|_| success != 0)
+    }
+
+    /// Get the value of a number parameter of a content mark.
+    ///
+    /// Will return `None` if the parameter does not exist or is not a number.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_float_param(&document_handle, &mut mark_handle, "Scale", 0.5)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_page_object_mark_float_param(&mark_handle, "Scale");
+    /// assert_eq!(value, Some(0.5));
+    /// ```
+    pub fn get_page_object_mark_float_param(
+        &self,
+        mark: &PageObjectMarkHandle,
+        key: &str,
+    ) -> Option<f32> {
+        let key = to_byte_string(key).ok()?;
+        let mut value = 0.0;
+
+        let success = unsafe {
+            bindings::FPDFPageObjMark_GetParamFloatValue(
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+                &mut value,
+            )
+        };
+
+        Some(value).filter(|_| success != 0)
+    }
+
+    /// Get the value of a string parameter of a content mark.
+    ///
+    /// Will return `None` if the parameter does not exist or is not a string.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_string_param(&document_handle, &mut mark_handle, "Source", "stamp")
+    ///     .unwrap();
+    ///
+    /// let value = library.get_page_object_mark_string_param(&mark_handle, "Source");
+    /// assert_eq!(value.as_deref(), Some("stamp"));
+    /// ```
+    pub fn get_page_object_mark_string_param(
+        &self,
+        mark: &PageObjectMarkHandle,
+        key: &str,
+    ) -> Option<String> {
+        let key = to_byte_string(key).ok()?;
+        let mut found = false;
+
+        let value = get_wide_string(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDFPageObjMark_GetParamStringValue(
+                    mark.handle.as_ptr(),
+                    key.as_ptr(),
+                    buffer,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(value).filter(|_| found)
+    }
+
+    /// Get the value of a blob parameter of a content mark.
+    ///
+    /// Will return `None` if the parameter does not exist or is not a string.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_blob_param(&document_handle, &mut mark_handle, "Id", &[1, 2, 3])
+    ///     .unwrap();
+    ///
+    /// let value = library.get_page_object_mark_blob_param(&mark_handle, "Id");
+    /// assert_eq!(value, Some(vec![1, 2, 3]));
+    /// ```
+    pub fn get_page_object_mark_blob_param(
+        &self,
+        mark: &PageObjectMarkHandle,
+        key: &str,
+    ) -> Option<Vec<u8>> {
+        let key = to_byte_string(key).ok()?;
+        let mut found = false;
+
+        let value = get_bytes(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDFPageObjMark_GetParamBlobValue(
+                    mark.handle.as_ptr(),
+                    key.as_ptr(),
+                    buffer,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(value).filter(|_| found)
+    }
+
+    /// Set an integer parameter of a content mark.
+    ///
+    /// `document` must be the document that contains the mark's page object.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `key` contains a null character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// let result = library.set_page_object_mark_int_param(&document_handle, &mut mark_handle, "Version", 1);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_page_object_mark_int_param(
+        &self,
+        document: &DocumentHandle,
+        mark: &mut PageObjectMarkHandle,
+        key: &str,
+        value: i32,
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+
+        to_result(unsafe {
+            bindings::FPDFPageObjMark_SetIntParam(
+                document.handle.as_ptr(),
+                mark.page_object.as_ptr(),
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+                value,
+            )
+        })
+    }
+
+    /// Set a number parameter of a content mark.
+    ///
+    /// `document` must be the document that contains the mark's page object.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `key` contains a null character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// let result = library.set_page_object_mark_float_param(&document_handle, &mut mark_handle, "Scale", 0.5);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_page_object_mark_float_param(
+        &self,
+        document: &DocumentHandle,
+        mark: &mut PageObjectMarkHandle,
+        key: &str,
+        value: f32,
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+
+        to_result(unsafe {
+            bindings::FPDFPageObjMark_SetFloatParam(
+                document.handle.as_ptr(),
+                mark.page_object.as_ptr(),
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+                value,
+            )
+        })
+    }
+
+    /// Set a string parameter of a content mark.
+    ///
+    /// `document` must be the document that contains the mark's page object.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `key` or `value` contains a null character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// let result = library.set_page_object_mark_string_param(&document_handle, &mut mark_handle, "Source", "stamp");
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_page_object_mark_string_param(
+        &self,
+        document: &DocumentHandle,
+        mark: &mut PageObjectMarkHandle,
+        key: &str,
+        value: &str,
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+        let value = to_byte_string(value)?;
+
+        to_result(unsafe {
+            bindings::FPDFPageObjMark_SetStringParam(
+                document.handle.as_ptr(),
+                mark.page_object.as_ptr(),
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        })
+    }
+
+    /// Set a blob parameter of a content mark.
+    ///
+    /// Blobs are stored as strings, so they can be any binary data.
+    ///
+    /// `document` must be the document that contains the mark's page object.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `key` contains a null character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// let result = library.set_page_object_mark_blob_param(&document_handle, &mut mark_handle, "Id", &[1, 2, 3]);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_page_object_mark_blob_param(
+        &self,
+        document: &DocumentHandle,
+        mark: &mut PageObjectMarkHandle,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+
+        to_result(unsafe {
+            bindings::FPDFPageObjMark_SetBlobParam(
+                document.handle.as_ptr(),
+                mark.page_object.as_ptr(),
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+                value.as_ptr(),
+                value.len() as _,
+            )
+        })
+    }
+
+    /// Remove a parameter from a content mark.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The parameter does not exist.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let mut mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+    /// library
+    ///     .set_page_object_mark_int_param(&document_handle, &mut mark_handle, "Version", 1)
+    ///     .unwrap();
+    ///
+    /// assert!(library.remove_page_object_mark_param(&mut mark_handle, "Version").is_ok());
+    /// assert_eq!(library.get_page_object_mark_param_count(&mark_handle), 0);
+    /// ```
+    pub fn remove_page_object_mark_param(
+        &self,
+        mark: &mut PageObjectMarkHandle,
+        key: &str,
+    ) -> Result<(), PdfiumError> {
+        let key = to_byte_string(key)?;
+
+        to_result(unsafe {
+            bindings::FPDFPageObjMark_RemoveParam(
+                mark.page_object.as_ptr(),
+                mark.handle.as_ptr(),
+                key.as_ptr(),
+            )
+        })
+    }
+}
+
+/// Safe handle to PDFium Page Object Mark.
+///
+/// Created using [`Library::get_page_object_mark`] or [`Library::add_page_object_mark`].
+///
+/// The mark is owned by its page object so nothing is freed when the handle is dropped.
+/// The handle can't be used after the marks of the page's objects are removed:
+/// ```compile_fail
+/// use pdfium_core::Library;
+/// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+///
+/// let library = Library::init_library().unwrap();
+/// let document_handle = library
+///     .load_document_from_bytes(DUMMY_PDF, None)
+///     .unwrap();
+///
+/// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+/// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+/// let mark_handle = library.add_page_object_mark(&mut object_handle, "Generated").unwrap();
+///
+/// library.remove_page_object_mark(&mut page_handle, 0, 0).unwrap();
+/// library.get_page_object_mark_name(&mark_handle);
+/// ```
+pub struct PageObjectMarkHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_pageobjectmark_t__>,
+    page_object: NonNull<bindings::fpdf_pageobject_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(PageObjectMarkHandle: Sync, Send);
+
+impl PageObjectMarkHandle<'_, '_> {
+    fn new(
+        handle: NonNull<bindings::fpdf_pageobjectmark_t__>,
+        page_object: NonNull<bindings::fpdf_pageobject_t__>,
+    ) -> Self {
+        PageObjectMarkHandle {
+            handle,
+            page_object,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn marks() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+        let mut object = library.get_page_object(&page, 0).unwrap();
+
+        assert_eq!(library.get_page_object_mark_count(&object), 0);
+        assert!(library.get_page_object_mark(&object, 0).is_none());
+
+        library.add_page_object_mark(&mut object, "First").unwrap();
+        library.add_page_object_mark(&mut object, "Second").unwrap();
+        assert_eq!(library.get_page_object_mark_count(&object), 2);
+
+        let names: Vec<_> = (0..2)
+            .map(|index| library.get_page_object_mark(&object, index).unwrap())
+            .map(|mark| library.get_page_object_mark_name(&mark))
+            .collect();
+        assert_eq!(names, ["First", "Second"]);
+
+        library.remove_page_object_mark(&mut page, 0, 0).unwrap();
+        assert!(library.remove_page_object_mark(&mut page, 0, 1).is_err());
+        let count = library.get_page_object_count(&page);
+        assert!(library
+            .remove_page_object_mark(&mut page, count, 0)
+            .is_err());
+
+        let object = library.get_page_object(&page, 0).unwrap();
+        assert_eq!(library.get_page_object_mark_count(&object), 1);
+
+        let mark = library.get_page_object_mark(&object, 0).unwrap();
+        assert_eq!(library.get_page_object_mark_name(&mark), "Second");
+    }
+
+    #[test]
+    fn mark_params() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let mut object = library.get_page_object(&page, 0).unwrap();
+        let mut mark = library
+            .add_page_object_mark(&mut object, "Generated")
+            .unwrap();
+
+        library
+            .set_page_object_mark_int_param(&document, &mut mark, "Int", 42)
+            .unwrap();
+        library
+            .set_page_object_mark_float_param(&document, &mut mark, "Float", 1.5)
+            .unwrap();
+        library
+            .set_page_object_mark_string_param(&document, &mut mark, "String", "value")
+            .unwrap();
+        library
+            .set_page_object_mark_blob_param(&document, &mut mark, "Blob", &[0, 1, 2])
+            .unwrap();
+
+        assert_eq!(library.get_page_object_mark_param_count(&mark), 4);
+        let mut keys: Vec<_> = (0..4)
+            .map(|index| {
+                library
+                    .get_page_object_mark_param_key(&mark, index)
+                    .unwrap()
+            })
+            .collect();
+        keys.sort();
+        assert_eq!(keys, ["Blob", "Float", "Int", "String"]);
+        assert!(library.get_page_object_mark_param_key(&mark, 4).is_none());
+
+        assert_eq!(
+            library.get_page_object_mark_param_type(&mark, "Int"),
+            ObjectType::Number
+        );
+        assert_eq!(
+            library.get_page_object_mark_param_type(&mark, "String"),
+            ObjectType::String
+        );
+        assert_eq!(
+            library.get_page_object_mark_param_type(&mark, "Missing"),
+            ObjectType::Unknown
+        );
+
+        assert_eq!(
+            library.get_page_object_mark_int_param(&mark, "Int"),
+            Some(42)
+        );
+        assert_eq!(
+            library.get_page_object_mark_float_param(&mark, "Float"),
+            Some(1.5)
+        );
+        assert_eq!(
+            library
+                .get_page_object_mark_string_param(&mark, "String")
+                .as_deref(),
+            Some("value")
+        );
+        assert_eq!(
+            library.get_page_object_mark_blob_param(&mark, "Blob"),
+            Some(vec![0, 1, 2])
+        );
+        assert_eq!(
+            library.get_page_object_mark_int_param(&mark, "String"),
+            None
+        );
+
+        library
+            .remove_page_object_mark_param(&mut mark, "Int")
+            .unwrap();
+        assert_eq!(library.get_page_object_mark_int_param(&mark, "Int"), None);
+        assert!(library
+            .remove_page_object_mark_param(&mut mark, "Int")
+            .is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::text_page::CachedTextPage;
+use crate::{bindings, DocumentHandle, Library, PageHandle, PdfiumError};
+use std::cell::{Cell, RefCell};
+use std::ptr::NonNull;
+
+/// The pages of a document that are kept open after their handles are dropped, see [`Library::load_cached_page`].
+#[derive(Default)]
+pub(crate) struct PageCache {
+    capacity: Cell<usize>,
+    /// The indices and pages, from the least to the most recently used.
+    pages: RefCell<Vec<(usize, OpenPage)>>,
+}
+
+/// A page without a handle, with the text page of [`Library::load_cached_text_page`] if it was loaded.
+pub(crate) struct OpenPage {
+    pub(crate) page: NonNull<bindings::fpdf_page_t__>,
+    pub(crate) text_page: Option<NonNull<bindings::fpdf_textpage_t__>>,
+}
+
+impl OpenPage {
+    pub(crate) fn close(self) {
+        unsafe {
+            if let Some(text_page) = self.text_page {
+                bindings::FPDFText_ClosePage(text_page.as_ptr());
+            }
+            bindings::FPDF_ClosePage(self.page.as_ptr());
+        }
+    }
+}
+
+impl PageCache {
+    /// Take a page out of the cache, so only one handle uses it.
+    fn take(&self, index: usize) -> Option<OpenPage> {
+        let mut pages = self.pages.borrow_mut();
+        let position = pages.iter().position(|&(cached, _)| cached == index)?;
+
+        Some(pages.remove(position).1)
+    }
+
+    /// Keep a page whose handle is dropped, it's closed if the cache is disabled or already has the page.
+    pub(crate) fn put(&self, index: usize, page: OpenPage) {
+        let mut pages = self.pages.borrow_mut();
+
+        if self.capacity.get() == 0 || pages.iter().any(|&(cached, _)| cached == index) {
+            page.close();
+            return;
+        }
+
+        pages.push((index, page));
+        self.evict(&mut pages);
+    }
+
+    fn set_capacity(&self, capacity: usize) {
+        self.capacity.set(capacity);
+        self.evict(&mut self.pages.borrow_mut());
+    }
+
+    /// Close the least recently used pages until the cache is within its capacity.
+    fn evict(&self, pages: &mut Vec<(usize, OpenPage)>) {
+        let excess = pages.len().saturating_sub(self.capacity.get());
+
+        for (_, page) in pages.drain(..excess) {
+            page.close();
+        }
+    }
+
+    /// Close all pages, the indices of the pages are wrong after pages are added or moved.
+    pub(crate) fn clear(&self) {
+        for (_, page) in self.pages.borrow_mut().drain(..) {
+            page.close();
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.pages.borrow().len()
+    }
+}
+
+impl Library {
+    /// Load a page like [`Library::load_page`], but keep it open in the page cache of the document
+    /// when the handle is dropped, so loading it again doesn't parse it again.
+    ///
+    /// The cache is disabled until it gets a capacity with [`Library::set_page_cache_capacity`].
+    /// A page that is in the cache is taken out of it while it has a handle, loading it again
+    /// at the same time loads another copy of the page.
+    ///
+    /// The cache is cleared when pages are added or moved, like with [`Library::import_pages`].
+    ///
+    /// ## Errors
+    /// The same errors as [`Library::load_page`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &'static [u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    /// library.set_page_cache_capacity(&document_handle, 4);
+    ///
+    /// // The page is only loaded once
+    /// for _ in 0..3 {
+    ///     let page_handle = library.load_cached_page(&document_handle, 0);
+    ///     assert!(page_handle.is_ok());
+    /// }
+    /// ```
+    pub fn load_cached_page<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        index: usize,
+    ) -> Result<PageHandle<'data, 'library>, PdfiumError> {
+        let mut page = match document.pages.take(index) {
+            Some(page) => PageHandle {
+                handle: page.page,
+                text_page: CachedTextPage::new(page.text_page),
+                cache: None,
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            },
+            None => self.load_page(document, index)?,
+        };
+        page.cache = Some((&document.pages, index));
+
+        Ok(page)
+    }
+
+    /// Set how many pages of a document [`Library::load_cached_page`] keeps open, 0 disables the cache.
+    ///
+    /// The least recently used pages are closed when there are more pages than `capacity`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &'static [u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// library.set_page_cache_capacity(&document_handle, 16);
+    /// ```
+    pub fn set_page_cache_capacity(&self, document: &DocumentHandle, capacity: usize) {
+        document.pages.set_capacity(capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn load_cached_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let mut document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        // Disabled by default
+        drop(library.load_cached_page(&document, 0).unwrap());
+        assert_eq!(document.pages.len(), 0);
+
+        library.set_page_cache_capacity(&document, 1);
+        let page = library.load_cached_page(&document, 0).unwrap();
+        let pointer = page.handle;
+        drop(page);
+        assert_eq!(document.pages.len(), 1);
+
+        // The page is taken out of the cache while it's used
+        let page = library.load_cached_page(&document, 0).unwrap();
+        assert_eq!(page.handle, pointer);
+        assert_eq!(document.pages.len(), 0);
+        let copy = library.load_cached_page(&document, 0).unwrap();
+        assert_ne!(copy.handle, pointer);
+        drop(page);
+        drop(copy);
+        assert_eq!(document.pages.len(), 1);
+        assert!(library.load_cached_page(&document, 1).is_err());
+
+        let source = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        library
+            .import_pages(&mut document, &source, None, 0)
+            .unwrap();
+        assert_eq!(document.pages.len(), 0);
+
+        drop(library.load_cached_page(&document, 1).unwrap());
+        library.set_page_cache_capacity(&document, 0);
+        assert_eq!(document.pages.len(), 0);
+    }
+}
@@ -0,0 +1,733 @@
+use crate::text_page::CachedTextPage;
+use crate::{
+    bindings, to_result, Capability, Library, Matrix, PageHandle, PdfiumError, QuadPoints, Rect,
+};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get number of page objects inside the page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_count = library.get_page_object_count(&page_handle);
+    /// assert!(object_count > 0);
+    /// ```
+    pub fn get_page_object_count(&self, page: &PageHandle) -> usize {
+        unsafe { bindings::FPDFPage_CountObjects(page.handle.as_ptr()) as usize }
+    }
+
+    /// Get a page object inside the page.
+    ///
+    /// `index` 0 for the first page object.
+    ///
+    /// Will return `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 0);
+    /// assert!(object_handle.is_some());
+    /// ```
+    pub fn get_page_object<'page, 'library>(
+        &'library self,
+        page: &'page PageHandle,
+        index: usize,
+    ) -> Option<PageObjectHandle<'page, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFPage_GetObject(page.handle.as_ptr(), index as i32)
+        });
+
+        handle.map(|handle| PageObjectHandle::new(handle, Some(&page.text_page)))
+    }
+
+    /// Get the type of a page object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PageObjectType};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let object_type = library.get_page_object_type(&object_handle);
+    /// assert_eq!(object_type, PageObjectType::Text);
+    /// ```
+    pub fn get_page_object_type(&self, object: &PageObjectHandle) -> PageObjectType {
+        let object_type = unsafe { bindings::FPDFPageObj_GetType(object.handle.as_ptr()) };
+
+        PageObjectType::from_i32(object_type).unwrap_or(PageObjectType::Unknown)
+    }
+
+    /// Get the marked content ID of a page object, the `MCID` of the marked content sequence it's part of.
+    ///
+    /// The ID connects the object to the structure element that contains it, see
+    /// [`Library::get_struct_element_child_marked_content_id`].
+    ///
+    /// Returns `None` if the object is not part of a marked content sequence with an ID.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 1).unwrap();
+    ///
+    /// let id = library.get_page_object_marked_content_id(&object_handle);
+    /// assert_eq!(id, Some(1));
+    /// ```
+    pub fn get_page_object_marked_content_id(&self, object: &PageObjectHandle) -> Option<i32> {
+        let id = unsafe { bindings::FPDFPageObj_GetMarkedContentID(object.handle.as_ptr()) };
+
+        Some(id).filter(|id| *id >= 0)
+    }
+
+    /// Insert a page object into the page.
+    ///
+    /// The page takes ownership of the page object. The page object is drawn on top of the page's other page objects.
+    ///
+    /// Call [`Library::generate_page_content`] to save the change to the page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_count = library.get_page_object_count(&page_handle);
+    ///
+    /// let image_object_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.insert_page_object(&mut page_handle, image_object_handle);
+    ///
+    /// assert_eq!(library.get_page_object_count(&page_handle), object_count + 1);
+    /// ```
+    pub fn insert_page_object(&self, page: &mut PageHandle, object: OwnedPageObjectHandle) {
+        page.changed();
+
+        let object = ManuallyDrop::new(object);
+
+        unsafe {
+            bindings::FPDFPage_InsertObject(page.handle.as_ptr(), object.handle.as_ptr());
+        }
+    }
+
+    /// Remove a page object from the page.
+    ///
+    /// `index` 0 for the first page object.
+    ///
+    /// Ownership of the page object is given back to the caller, so it can be inserted again
+    /// using [`Library::insert_page_object`]. Otherwise it is destroyed when the returned handle is dropped.
+    ///
+    /// Call [`Library::generate_page_content`] to save the change to the page.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_count = library.get_page_object_count(&page_handle);
+    ///
+    /// let object_handle = library.remove_page_object(&mut page_handle, 0);
+    /// assert!(object_handle.is_ok());
+    /// assert_eq!(library.get_page_object_count(&page_handle), object_count - 1);
+    /// ```
+    pub fn remove_page_object<'data, 'library>(
+        &'library self,
+        page: &mut PageHandle<'data, '_>,
+        index: usize,
+    ) -> Result<OwnedPageObjectHandle<'data, 'library>, PdfiumError> {
+        page.changed();
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFPage_GetObject(page.handle.as_ptr(), index as i32)
+        })
+        .ok_or(PdfiumError::Unknown)?;
+
+        to_result(unsafe {
+            bindings::FPDFPage_RemoveObject(page.handle.as_ptr(), handle.as_ptr())
+        })?;
+
+        Ok(OwnedPageObjectHandle::new(handle))
+    }
+
+    /// Move a page object to a new position in the page's drawing order.
+    ///
+    /// Page objects are drawn in order, so a page object is drawn on top of all page objects with a lower index.
+    /// After the move the page object is at index `to`, for example `0` puts it below all other page objects.
+    ///
+    /// The page objects from `to` onwards are removed and inserted again in their new order,
+    /// then the page's content is regenerated using [`Library::generate_page_content`].
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `from` or `to` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// // Insert a watermark below the existing content.
+    /// let watermark_handle = library.create_image_object(&document_handle).unwrap();
+    /// library.insert_page_object(&mut page_handle, watermark_handle);
+    ///
+    /// let last = library.get_page_object_count(&page_handle) - 1;
+    /// assert!(library.move_page_object(&mut page_handle, last, 0).is_ok());
+    /// ```
+    pub fn move_page_object(
+        &self,
+        page: &mut PageHandle,
+        from: usize,
+        to: usize,
+    ) -> Result<(), PdfiumError> {
+        page.changed();
+
+        let count = self.get_page_object_count(page);
+
+        if from >= count || to >= count {
+            return Err(PdfiumError::Unknown);
+        }
+
+        if from != to {
+            let object = self.remove_page_object(page, from)?;
+
+            let mut above = Vec::with_capacity(count - to);
+            for _ in to..count - 1 {
+                above.push(self.remove_page_object(page, to)?);
+            }
+
+            self.insert_page_object(page, object);
+            for object in above {
+                self.insert_page_object(page, object);
+            }
+        }
+
+        self.generate_page_content(page)
+    }
+
+    /// Get the bounding box of a page object.
+    ///
+    /// Will return `None` if the bounds can't be calculated.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let bounds = library.get_page_object_bounds(&object_handle).unwrap();
+    /// assert!(bounds.width() > 0.0);
+    /// ```
+    pub fn get_page_object_bounds(&self, object: &PageObjectHandle) -> Option<Rect> {
+        let mut rect = Rect::default();
+
+        to_result(unsafe {
+            bindings::FPDFPageObj_GetBounds(
+                object.handle.as_ptr(),
+                &mut rect.left,
+                &mut rect.bottom,
+                &mut rect.right,
+                &mut rect.top,
+            )
+        })
+        .ok()
+        .map(|_| rect)
+    }
+
+    /// Get the bounds of a page object that is rotated, the corners of the rotated box around it.
+    ///
+    /// Unlike [`Library::get_page_object_bounds`] the box is not aligned to the page,
+    /// so it's tight around rotated text and images.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unsupported`](PdfiumError::Unsupported): PDFium doesn't have [`Capability::RotatedBounds`].
+    /// - [`Unknown`](PdfiumError::Unknown): The object is not a text or image object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Capability, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// if library.has_capability(Capability::RotatedBounds) {
+    ///     let quad_points = library
+    ///         .get_page_object_rotated_bounds(&object_handle)
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn get_page_object_rotated_bounds(
+        &self,
+        object: &PageObjectHandle,
+    ) -> Result<QuadPoints, PdfiumError> {
+        self.require_capability(Capability::RotatedBounds)?;
+
+        #[cfg(pdfium_function = "FPDFPageObj_GetRotatedBounds")]
+        let result = {
+            let mut quad_points = QuadPoints::default().to_fs_quadpointsf();
+
+            to_result(unsafe {
+                bindings::FPDFPageObj_GetRotatedBounds(object.handle.as_ptr(), &mut quad_points)
+            })
+            .map(|_| QuadPoints::from_fs_quadpointsf(&quad_points))
+        };
+
+        // Not reached, the capability is missing if the headers don't declare the function
+        #[cfg(not(pdfium_function = "FPDFPageObj_GetRotatedBounds"))]
+        let result = {
+            let _ = object;
+            Err(PdfiumError::Unsupported)
+        };
+
+        result
+    }
+
+    /// Get the transformation matrix of a page object.
+    ///
+    /// Will return `None` if the matrix can't be retrieved.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, Matrix};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let image_object_handle = library.create_image_object(&document_handle).unwrap();
+    ///
+    /// let matrix = library.get_page_object_matrix(&image_object_handle);
+    /// assert_eq!(matrix, Some(Matrix::IDENTITY));
+    /// ```
+    pub fn get_page_object_matrix(&self, object: &PageObjectHandle) -> Option<Matrix> {
+        let mut matrix = Matrix::IDENTITY.to_fs_matrix();
+
+        to_result(unsafe { bindings::FPDFPageObj_GetMatrix(object.handle.as_ptr(), &mut matrix) })
+            .ok()
+            .map(|_| Matrix::from_fs_matrix(&matrix))
+    }
+
+    /// Set the transformation matrix of a page object.
+    ///
+    /// Replaces the existing matrix of the page object, unlike transforming which multiplies it.
+    /// The text page of [`Library::load_cached_text_page`] is loaded again the next time it's used, the text moved.
+    ///
+    /// ## Examples
+    /// Place an image object into a rectangle:
+    /// ```
+    /// use pdfium_core::{Library, Matrix, Rect};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+    ///
+    /// let rect = Rect { left: 100.0, bottom: 100.0, right: 200.0, top: 150.0 };
+    /// let matrix = Matrix::from_rect(&rect);
+    /// library.set_page_object_matrix(&mut image_object_handle, &matrix).unwrap();
+    ///
+    /// assert_eq!(library.get_page_object_matrix(&image_object_handle), Some(matrix));
+    /// ```
+    pub fn set_page_object_matrix(
+        &self,
+        object: &mut impl PageObjectMut,
+        matrix: &Matrix,
+    ) -> Result<(), PdfiumError> {
+        let matrix = matrix.to_fs_matrix();
+        object.text_changed();
+
+        to_result(unsafe {
+            bindings::FPDFPageObj_SetMatrix(object.page_object_handle().as_ptr(), &matrix)
+        })
+    }
+
+    /// Generate the content stream of the page.
+    ///
+    /// Changes made to the page objects of a page are not saved or reflected in the
+    /// page's content stream until this function is called.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// assert!(library.generate_page_content(&mut page_handle).is_ok());
+    /// ```
+    pub fn generate_page_content(&self, page: &mut PageHandle) -> Result<(), PdfiumError> {
+        page.changed();
+
+        to_result(unsafe { bindings::FPDFPage_GenerateContent(page.handle.as_ptr()) })
+    }
+}
+
+/// The type of a page object.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PageObjectType {
+    /// Unknown object type.
+    Unknown = bindings::FPDF_PAGEOBJ_UNKNOWN as i32,
+    /// Text object.
+    Text = bindings::FPDF_PAGEOBJ_TEXT as i32,
+    /// Path object.
+    Path = bindings::FPDF_PAGEOBJ_PATH as i32,
+    /// Image object.
+    Image = bindings::FPDF_PAGEOBJ_IMAGE as i32,
+    /// Shading object.
+    Shading = bindings::FPDF_PAGEOBJ_SHADING as i32,
+    /// Form XObject.
+    Form = bindings::FPDF_PAGEOBJ_FORM as i32,
+}
+
+impl PageObjectType {
+    fn from_i32(number: i32) -> Option<PageObjectType> {
+        match number {
+            x if x == PageObjectType::Unknown as i32 => Some(PageObjectType::Unknown),
+            x if x == PageObjectType::Text as i32 => Some(PageObjectType::Text),
+            x if x == PageObjectType::Path as i32 => Some(PageObjectType::Path),
+            x if x == PageObjectType::Image as i32 => Some(PageObjectType::Image),
+            x if x == PageObjectType::Shading as i32 => Some(PageObjectType::Shading),
+            x if x == PageObjectType::Form as i32 => Some(PageObjectType::Form),
+            _ => None,
+        }
+    }
+}
+
+/// Safe handle to PDFium Page Object.
+///
+/// Created using [`Library::get_page_object`].
+///
+/// The page object is owned by its page so nothing is freed when the handle is dropped.
+/// See [`OwnedPageObjectHandle`] for page objects that don't belong to a page.
+pub struct PageObjectHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_pageobject_t__>,
+    /// The text page of the page that contains the object, outdated when the object changes.
+    pub(crate) text_page: Option<&'a CachedTextPage>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(PageObjectHandle: Sync, Send);
+
+impl<'a> PageObjectHandle<'a, '_> {
+    pub(crate) fn new(
+        handle: NonNull<bindings::fpdf_pageobject_t__>,
+        text_page: Option<&'a CachedTextPage>,
+    ) -> Self {
+        PageObjectHandle {
+            handle,
+            text_page,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// Safe handle to PDFium Page Object that doesn't belong to a page.
+///
+/// Created using [`Library::create_image_object`] or [`Library::remove_page_object`].
+///
+/// Derefs to [`PageObjectHandle`] so it can be used with any function that takes a page object.
+///
+/// Page object is destroyed when handle is dropped, unless it is inserted into a page using [`Library::insert_page_object`].
+/// It doesn't deref mutably, so it can't be swapped with the handle of an object that belongs to a page:
+/// ```compile_fail
+/// use pdfium_core::Library;
+/// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+///
+/// let library = Library::init_library().unwrap();
+/// let document_handle = library
+///     .load_document_from_bytes(DUMMY_PDF, None)
+///     .unwrap();
+///
+/// let page_handle = library.load_page(&document_handle, 0).unwrap();
+/// let mut object_handle = library.get_page_object(&page_handle, 0).unwrap();
+/// let mut image_object_handle = library.create_image_object(&document_handle).unwrap();
+/// std::mem::swap(&mut *image_object_handle, &mut object_handle);
+/// ```
+pub struct OwnedPageObjectHandle<'a, 'b>(PageObjectHandle<'a, 'b>);
+
+assert_not_impl_any!(OwnedPageObjectHandle: Sync, Send);
+
+impl OwnedPageObjectHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_pageobject_t__>) -> Self {
+        OwnedPageObjectHandle(PageObjectHandle::new(handle, None))
+    }
+}
+
+impl Drop for OwnedPageObjectHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FPDFPageObj_Destroy(self.0.handle.as_ptr());
+        }
+    }
+}
+
+impl<'a, 'b> Deref for OwnedPageObjectHandle<'a, 'b> {
+    type Target = PageObjectHandle<'a, 'b>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A page object that can be changed, either a [`PageObjectHandle`] or an [`OwnedPageObjectHandle`].
+///
+/// The functions that change a page object take `&mut impl PageObjectMut`, because [`OwnedPageObjectHandle`] only
+/// derefs to `&PageObjectHandle`. With a `&mut PageObjectHandle` it could be swapped with the handle of an object
+/// that belongs to a page, which would then be destroyed when the owned handle is dropped.
+pub trait PageObjectMut: sealed::Sealed {}
+
+impl PageObjectMut for PageObjectHandle<'_, '_> {}
+
+impl PageObjectMut for OwnedPageObjectHandle<'_, '_> {}
+
+pub(crate) mod sealed {
+    use crate::bindings;
+    use std::ptr::NonNull;
+
+    pub trait Sealed {
+        fn page_object_handle(&self) -> NonNull<bindings::fpdf_pageobject_t__>;
+
+        /// Outdate the cached text page of the page that contains the object.
+        fn text_changed(&self);
+    }
+
+    impl Sealed for super::PageObjectHandle<'_, '_> {
+        fn page_object_handle(&self) -> NonNull<bindings::fpdf_pageobject_t__> {
+            self.handle
+        }
+
+        fn text_changed(&self) {
+            if let Some(text_page) = self.text_page {
+                text_page.outdate();
+            }
+        }
+    }
+
+    impl Sealed for super::OwnedPageObjectHandle<'_, '_> {
+        fn page_object_handle(&self) -> NonNull<bindings::fpdf_pageobject_t__> {
+            self.0.handle
+        }
+
+        fn text_changed(&self) {
+            self.0.text_changed();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+
+    #[test]
+    fn page_objects() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let count = library.get_page_object_count(&page);
+        assert!(count > 0);
+
+        for index in 0..count {
+            let object = library.get_page_object(&page, index).unwrap();
+            assert_eq!(library.get_page_object_type(&object), PageObjectType::Text);
+        }
+
+        assert!(library.get_page_object(&page, count).is_none());
+    }
+
+    #[test]
+    fn page_object_rotated_bounds() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let object = library.get_page_object(&page, 0).unwrap();
+
+        let result = library.get_page_object_rotated_bounds(&object);
+        if library.has_capability(Capability::RotatedBounds) {
+            // The text isn't rotated, so the box is the same as the bounds
+            let bounds = library.get_page_object_bounds(&object).unwrap();
+            let quad_points = result.unwrap();
+            assert!((quad_points.x1 - bounds.left).abs() < 0.01);
+            assert!((quad_points.y1 - bounds.bottom).abs() < 0.01);
+        } else {
+            assert_eq!(result, Err(PdfiumError::Unsupported));
+        }
+    }
+
+    #[test]
+    fn marked_content_ids() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(TAGGED_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let ids: Vec<_> = (0..library.get_page_object_count(&page))
+            .filter_map(|index| library.get_page_object(&page, index))
+            .map(|object| library.get_page_object_marked_content_id(&object))
+            .collect();
+        assert_eq!(ids, [Some(0), Some(1), Some(2), Some(3)]);
+
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let object = library.get_page_object(&page, 0).unwrap();
+        assert_eq!(library.get_page_object_marked_content_id(&object), None);
+    }
+
+    #[test]
+    fn move_page_object() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+
+        let count = library.get_page_object_count(&page);
+        let bounds = |page: &PageHandle| -> Vec<_> {
+            (0..count)
+                .map(|index| library.get_page_object(page, index).unwrap())
+                .map(|object| library.get_page_object_bounds(&object).unwrap())
+                .collect()
+        };
+        let before = bounds(&page);
+
+        // Move the top object to the bottom.
+        library.move_page_object(&mut page, count - 1, 0).unwrap();
+        let after = bounds(&page);
+        assert_eq!(after[0], before[count - 1]);
+        assert_eq!(&after[1..], &before[..count - 1]);
+
+        // And back again.
+        library.move_page_object(&mut page, 0, count - 1).unwrap();
+        assert_eq!(bounds(&page), before);
+
+        assert!(library.move_page_object(&mut page, 0, count).is_err());
+        assert_eq!(library.get_page_object_count(&page), count);
+    }
+
+    #[test]
+    fn remove_page_object() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+        let count = library.get_page_object_count(&page);
+
+        let object = library.remove_page_object(&mut page, 0).unwrap();
+        assert_eq!(library.get_page_object_type(&object), PageObjectType::Text);
+        assert_eq!(library.get_page_object_count(&page), count - 1);
+
+        library.insert_page_object(&mut page, object);
+        assert_eq!(library.get_page_object_count(&page), count);
+
+        assert!(library.remove_page_object(&mut page, count).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{bindings, Library, PageObjectHandle};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get number of segments inside a path object.
+    ///
+    /// Returns `0` if `path` is not a path object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let path_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let segment_count = library.get_path_segment_count(&path_handle);
+    /// assert!(segment_count > 0);
+    /// ```
+    pub fn get_path_segment_count(&self, path: &PageObjectHandle) -> usize {
+        let count = unsafe { bindings::FPDFPath_CountSegments(path.handle.as_ptr()) };
+
+        // PDFium returns -1 if `path` is not a path object.
+        count.max(0) as usize
+    }
+
+    /// Get a segment of a path object.
+    ///
+    /// `index` 0 for the first segment.
+    ///
+    /// Will return `None` if `index` is out of bounds or `path` is not a path object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let path_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let segment_handle = library.get_path_segment(&path_handle, 0);
+    /// assert!(segment_handle.is_some());
+    /// ```
+    pub fn get_path_segment<'data, 'library>(
+        &'library self,
+        path: &'data PageObjectHandle,
+        index: usize,
+    ) -> Option<PathSegmentHandle<'data, 'library>> {
+        let handle =
+            unsafe { bindings::FPDFPath_GetPathSegment(path.handle.as_ptr(), index as i32) };
+
+        PathSegmentHandle::new(handle)
+    }
+
+    /// Get the end point of a path segment.
+    ///
+    /// The point is in the coordinate space of the path object, before its matrix is applied.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let path_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let segment_handle = library.get_path_segment(&path_handle, 0).unwrap();
+    /// assert_eq!(library.get_path_segment_point(&segment_handle), Some((100.0, 100.0)));
+    /// ```
+    pub fn get_path_segment_point(&self, segment: &PathSegmentHandle) -> Option<(f32, f32)> {
+        let mut x = 0.0;
+        let mut y = 0.0;
+
+        let success =
+            unsafe { bindings::FPDFPathSegment_GetPoint(segment.handle.as_ptr(), &mut x, &mut y) };
+
+        if success != 0 {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Get the type of a path segment.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, PathSegmentType};
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let path_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let segment_handle = library.get_path_segment(&path_handle, 0).unwrap();
+    /// assert_eq!(library.get_path_segment_type(&segment_handle), PathSegmentType::MoveTo);
+    /// ```
+    pub fn get_path_segment_type(&self, segment: &PathSegmentHandle) -> PathSegmentType {
+        let segment_type = unsafe { bindings::FPDFPathSegment_GetType(segment.handle.as_ptr()) };
+
+        PathSegmentType::from_i32(segment_type).unwrap_or(PathSegmentType::Unknown)
+    }
+
+    /// Check if a path segment closes the current subpath.
+    ///
+    /// A closing segment is followed by a straight line back to the start of its subpath.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(PATHS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let path_handle = library.get_page_object(&page_handle, 0).unwrap();
+    /// let segment_handle = library.get_path_segment(&path_handle, 0).unwrap();
+    /// assert!(!library.get_path_segment_close(&segment_handle));
+    /// ```
+    pub fn get_path_segment_close(&self, segment: &PathSegmentHandle) -> bool {
+        unsafe { bindings::FPDFPathSegment_GetClose(segment.handle.as_ptr()) != 0 }
+    }
+}
+
+/// The type of a path segment.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PathSegmentType {
+    /// Unknown segment type.
+    Unknown = -1,
+    /// Straight line from the previous point.
+    LineTo = bindings::FPDF_SEGMENT_LINETO as i32,
+    /// Point of a cubic Bézier curve from the previous point.
+    ///
+    /// Bézier curves take three segments, two control points followed by the end point.
+    BezierTo = bindings::FPDF_SEGMENT_BEZIERTO as i32,
+    /// Start of a new subpath.
+    MoveTo = bindings::FPDF_SEGMENT_MOVETO as i32,
+}
+
+impl PathSegmentType {
+    fn from_i32(number: i32) -> Option<PathSegmentType> {
+        match number {
+            x if x == PathSegmentType::Unknown as i32 => Some(PathSegmentType::Unknown),
+            x if x == PathSegmentType::LineTo as i32 => Some(PathSegmentType::LineTo),
+            x if x == PathSegmentType::BezierTo as i32 => Some(PathSegmentType::BezierTo),
+            x if x == PathSegmentType::MoveTo as i32 => Some(PathSegmentType::MoveTo),
+            _ => None,
+        }
+    }
+}
+
+/// Safe handle to PDFium Path Segment.
+///
+/// Created using [`Library::get_path_segment`].
+///
+/// The segment is owned by its path so nothing is freed when the handle is dropped.
+pub struct PathSegmentHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_pathsegment_t>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(PathSegmentHandle: Sync, Send);
+
+impl PathSegmentHandle<'_, '_> {
+    pub(crate) fn new(handle: bindings::FPDF_PATHSEGMENT) -> Option<Self> {
+        NonNull::new(handle as *mut _).map(|handle| PathSegmentHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static PATHS_PDF: &[u8] = include_bytes!("../../../test_assets/paths.pdf");
+
+    #[test]
+    fn segments() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(PATHS_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let path = library.get_page_object(&page, 0).unwrap();
+
+        let count = library.get_path_segment_count(&path);
+        assert!(count >= 3);
+
+        let segments: Vec<_> = (0..count)
+            .map(|index| library.get_path_segment(&path, index).unwrap())
+            .collect();
+
+        let points: Vec<_> = segments
+            .iter()
+            .map(|segment| library.get_path_segment_point(segment).unwrap())
+            .collect();
+        assert_eq!(
+            &points[..3],
+            &[(100.0, 100.0), (200.0, 100.0), (200.0, 200.0)]
+        );
+
+        let types: Vec<_> = segments
+            .iter()
+            .map(|segment| library.get_path_segment_type(segment))
+            .collect();
+        assert_eq!(types[0], PathSegmentType::MoveTo);
+        assert!(types[1..].iter().all(|x| *x == PathSegmentType::LineTo));
+
+        assert!(library.get_path_segment_close(segments.last().unwrap()));
+        assert!(library.get_path_segment(&path, count).is_none());
+    }
+
+    #[test]
+    fn bezier_segments() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(PATHS_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let path = library.get_page_object(&page, 1).unwrap();
+
+        let types: Vec<_> = (0..library.get_path_segment_count(&path))
+            .map(|index| library.get_path_segment(&path, index).unwrap())
+            .map(|segment| library.get_path_segment_type(&segment))
+            .collect();
+
+        assert_eq!(
+            &types[..4],
+            &[
+                PathSegmentType::MoveTo,
+                PathSegmentType::BezierTo,
+                PathSegmentType::BezierTo,
+                PathSegmentType::BezierTo
+            ]
+        );
+    }
+
+    #[test]
+    fn not_a_path() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(PATHS_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let form = library.get_page_object(&page, 2).unwrap();
+
+        assert_eq!(library.get_path_segment_count(&form), 0);
+        assert!(library.get_path_segment(&form, 0).is_none());
+    }
+}
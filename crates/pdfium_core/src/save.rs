@@ -0,0 +1,182 @@
+use crate::file_access::FileWrite;
+use crate::{bindings, DocumentHandle, Library, PageHandle, PdfiumError};
+use std::io::Write;
+
+impl Library {
+    /// Save a document, including all changes, to `writer`.
+    ///
+    /// `mode` decides how the document is written. See [`SaveMode`] for more information.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to save the document or `writer` failed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, SaveMode};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut bytes = Vec::new();
+    /// library
+    ///     .save_document(&document_handle, &mut bytes, SaveMode::NoIncremental)
+    ///     .unwrap();
+    /// assert!(bytes.starts_with(b"%PDF"));
+    /// ```
+    pub fn save_document(
+        &self,
+        document: &DocumentHandle,
+        writer: &mut dyn Write,
+        mode: SaveMode,
+    ) -> Result<(), PdfiumError> {
+        let mut file_write = FileWrite::new(writer);
+
+        let success = unsafe {
+            bindings::FPDF_SaveAsCopy(document.handle.as_ptr(), file_write.as_mut_ptr(), mode as _)
+        };
+
+        if success != 0 {
+            Ok(())
+        } else {
+            Err(PdfiumError::Unknown)
+        }
+    }
+
+    /// Flatten the annotations and form fields of a page into its content, so they can't be changed anymore.
+    ///
+    /// Only annotations with an appearance stream are flattened, they are removed from the page.
+    /// `usage` decides which annotations are flattened, based on their flags.
+    ///
+    /// Returns `false` if there was nothing to flatten.
+    ///
+    /// The page has to be loaded again to see the flattened content.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium failed to flatten the page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{FlattenUsage, Library};
+    /// # static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(ANNOTATIONS_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let result = library.flatten_page(&mut page_handle, FlattenUsage::Print);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn flatten_page(
+        &self,
+        page: &mut PageHandle,
+        usage: FlattenUsage,
+    ) -> Result<bool, PdfiumError> {
+        page.changed();
+
+        match unsafe { bindings::FPDFPage_Flatten(page.handle.as_ptr(), usage as _) } as u32 {
+            bindings::FLATTEN_SUCCESS => Ok(true),
+            bindings::FLATTEN_NOTHINGTODO => Ok(false),
+            _ => Err(PdfiumError::Unknown),
+        }
+    }
+}
+
+/// How a document is saved.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SaveMode {
+    /// Append the changes to the original file.
+    Incremental = bindings::FPDF_INCREMENTAL as i32,
+    /// Write the whole document again.
+    NoIncremental = bindings::FPDF_NO_INCREMENTAL as i32,
+    /// Write the whole document again, without encryption.
+    RemoveSecurity = bindings::FPDF_REMOVE_SECURITY as i32,
+}
+
+/// Which annotations [`Library::flatten_page`] flattens.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FlattenUsage {
+    /// Flatten the annotations that are displayed on screen.
+    Display = bindings::FLAT_NORMALDISPLAY as i32,
+    /// Flatten the annotations that are printed.
+    Print = bindings::FLAT_PRINT as i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AppearanceMode, TEST_LOCK};
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    static ANNOTATIONS_PDF: &[u8] = include_bytes!("../../../test_assets/annotations.pdf");
+
+    #[test]
+    fn save_document() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+
+        let mut bytes = Vec::new();
+        library
+            .save_document(&document, &mut bytes, SaveMode::NoIncremental)
+            .unwrap();
+
+        let saved = library.load_document_from_bytes(&bytes, None).unwrap();
+        assert_eq!(
+            library.get_page_count(&saved),
+            library.get_page_count(&document)
+        );
+    }
+
+    #[test]
+    fn flatten_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library
+            .load_document_from_bytes(ANNOTATIONS_PDF, None)
+            .unwrap();
+
+        let mut page = library.load_page(&document, 0).unwrap();
+        let annotation_count = library.get_annotation_count(&page);
+        let object_count = library.get_page_object_count(&page);
+
+        // Only the square gets an appearance stream, so it's the only annotation that is flattened
+        let mut square = library.get_annotation(&page, 3).unwrap();
+        library
+            .set_annotation_appearance(
+                &mut square,
+                AppearanceMode::Normal,
+                Some("0 0 1 rg 0 0 100 100 re f"),
+            )
+            .unwrap();
+        drop(square);
+
+        assert!(library
+            .flatten_page(&mut page, FlattenUsage::Print)
+            .unwrap());
+        drop(page);
+
+        let page = library.load_page(&document, 0).unwrap();
+        assert_eq!(library.get_annotation_count(&page), annotation_count - 1);
+        assert_eq!(library.get_page_object_count(&page), object_count + 1);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{bindings, get_bytes, get_string, DocumentHandle, Library};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+impl Library {
+    /// Get the number of digital signatures in a document, the signature fields that have a value.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static SIGNED_PDF: &[u8] = include_bytes!("../../../test_assets/signed.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(SIGNED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(library.get_signature_count(&document_handle), 1);
+    /// ```
+    pub fn get_signature_count(&self, document: &DocumentHandle) -> usize {
+        let count = unsafe { bindings::FPDF_GetSignatureCount(document.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a digital signature of a document.
+    ///
+    /// `index` 0 for the first signature.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static SIGNED_PDF: &[u8] = include_bytes!("../../../test_assets/signed.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(SIGNED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let signature_handle = library.get_signature(&document_handle, 0);
+    /// assert!(signature_handle.is_some());
+    /// ```
+    pub fn get_signature<'data, 'library>(
+        &'library self,
+        document: &'data DocumentHandle,
+        index: usize,
+    ) -> Option<SignatureHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_GetSignatureObject(document.handle.as_ptr(), index as _)
+        } as *mut _);
+
+        handle.map(SignatureHandle::new)
+    }
+
+    /// Get the `Contents` of a signature, the encoded signature value.
+    ///
+    /// For the `adbe.pkcs7.detached` and `ETSI.CAdES.detached` sub filters, this is a DER encoded
+    /// CMS `SignedData`, usually followed by zero padding.
+    ///
+    /// Returns an empty vector if the signature doesn't have contents.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static SIGNED_PDF: &[u8] = include_bytes!("../../../test_assets/signed.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(SIGNED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let signature_handle = library.get_signature(&document_handle, 0).unwrap();
+    ///
+    /// let contents = library.get_signature_contents(&signature_handle);
+    /// assert!(contents.starts_with(&[0x30]));
+    /// ```
+    pub fn get_signature_contents(&self, signature: &SignatureHandle) -> Vec<u8> {
+        get_bytes(|buffer, length| unsafe {
+            bindings::FPDFSignatureObj_GetContents(
+                signature.handle.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the `ByteRange` of a signature, the parts of the document file that are signed.
+    ///
+    /// Each pair is an offset in bytes from the start of the file and a length in bytes.
+    /// The values are returned as stored in the document, so they can be negative or out of bounds.
+    ///
+    /// Returns an empty vector if the signature doesn't have a byte range.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static SIGNED_PDF: &[u8] = include_bytes!("../../../test_assets/signed.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(SIGNED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let signature_handle = library.get_signature(&document_handle, 0).unwrap();
+    ///
+    /// let byte_range = library.get_signature_byte_range(&signature_handle);
+    /// assert_eq!(byte_range.len(), 2);
+    /// assert_eq!(byte_range[0].0, 0);
+    /// ```
+    pub fn get_signature_byte_range(&self, signature: &SignatureHandle) -> Vec<(i32, i32)> {
+        let length = unsafe {
+            bindings::FPDFSignatureObj_GetByteRange(signature.handle.as_ptr(), ptr::null_mut(), 0)
+        };
+
+        if length == 0 {
+            return Vec::new();
+        }
+
+        let mut buffer = vec![0; length as usize];
+
+        unsafe {
+            bindings::FPDFSignatureObj_GetByteRange(
+                signature.handle.as_ptr(),
+                buffer.as_mut_ptr(),
+                length,
+            )
+        };
+
+        buffer
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+
+    /// Get the `SubFilter` of a signature, the encoding of its contents, like `adbe.pkcs7.detached`.
+    ///
+    /// Returns an empty string if the signature doesn't have a sub filter.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static SIGNED_PDF: &[u8] = include_bytes!("../../../test_assets/signed.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(SIGNED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let signature_handle = library.get_signature(&document_handle, 0).unwrap();
+    ///
+    /// let sub_filter = library.get_signature_sub_filter(&signature_handle);
+    /// assert_eq!(sub_filter, "adbe.pkcs7.detached");
+    /// ```
+    pub fn get_signature_sub_filter(&self, signature: &SignatureHandle) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDFSignatureObj_GetSubFilter(
+                signature.handle.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+}
+
+/// Safe handle to PDFium Signature, the value of a signature field.
+///
+/// Created using [`Library::get_signature`].
+///
+/// The signature is owned by its document so nothing is freed when the handle is dropped.
+pub struct SignatureHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_signature_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(SignatureHandle: Sync, Send);
+
+impl SignatureHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_signature_t__>) -> Self {
+        SignatureHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static SIGNED_PDF: &[u8] = include_bytes!("../../../test_assets/signed.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn signatures() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(SIGNED_PDF, None).unwrap();
+
+        assert_eq!(library.get_signature_count(&document), 1);
+        assert!(library.get_signature(&document, 1).is_none());
+
+        let signature = library.get_signature(&document, 0).unwrap();
+        let contents = library.get_signature_contents(&signature);
+        assert_eq!(contents.len(), 64);
+        assert!(contents.starts_with(&[0x30, 0x03, 0x02, 0x01, 0x01]));
+        assert_eq!(
+            library.get_signature_byte_range(&signature),
+            vec![(0, 640), (770, 346)]
+        );
+        assert_eq!(
+            library.get_signature_sub_filter(&signature),
+            "adbe.pkcs7.detached"
+        );
+
+        let dummy = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        assert_eq!(library.get_signature_count(&dummy), 0);
+    }
+}
@@ -0,0 +1,1392 @@
+use crate::{
+    bindings, get_bytes, get_string, get_wide_string, to_byte_string, Library, ObjectType,
+    PageHandle,
+};
+use static_assertions::assert_not_impl_any;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the structure tree of a page, the logical structure of its content in a tagged document.
+    ///
+    /// Only the elements with content on the page, and their ancestors, are part of the tree.
+    ///
+    /// Returns `None` if the page doesn't have a structure tree.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle);
+    /// assert!(struct_tree_handle.is_some());
+    /// ```
+    pub fn get_struct_tree<'data, 'library>(
+        &'library self,
+        page: &'data PageHandle,
+    ) -> Option<StructTreeHandle<'data, 'library>> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDF_StructTree_GetForPage(page.handle.as_ptr()) });
+
+        handle.map(StructTreeHandle::new)
+    }
+
+    /// Get the number of top level elements of a structure tree.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    ///
+    /// let count = library.get_struct_tree_child_count(&struct_tree_handle);
+    /// assert_eq!(count, 1);
+    /// ```
+    pub fn get_struct_tree_child_count(&self, struct_tree: &StructTreeHandle) -> usize {
+        let count = unsafe { bindings::FPDF_StructTree_CountChildren(struct_tree.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a top level element of a structure tree.
+    ///
+    /// `index` 0 for the first element.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    ///
+    /// let element_handle = library.get_struct_tree_child(&struct_tree_handle, 0);
+    /// assert!(element_handle.is_some());
+    /// ```
+    pub fn get_struct_tree_child<'data, 'library>(
+        &'library self,
+        struct_tree: &'data StructTreeHandle,
+        index: usize,
+    ) -> Option<StructElementHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_StructTree_GetChildAtIndex(struct_tree.handle.as_ptr(), index as _)
+        });
+
+        handle.map(StructElementHandle::new)
+    }
+
+    /// Get the number of children of a structure element.
+    ///
+    /// Marked content and object references are counted, but are not elements, see
+    /// [`Library::get_struct_element_child`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    ///
+    /// let count = library.get_struct_element_child_count(&document_element);
+    /// assert_eq!(count, 4);
+    /// ```
+    pub fn get_struct_element_child_count(&self, element: &StructElementHandle) -> usize {
+        let count = unsafe { bindings::FPDF_StructElement_CountChildren(element.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get a child element of a structure element.
+    ///
+    /// `index` 0 for the first child.
+    ///
+    /// Returns `None` if `index` is out of bounds or the child is not an element.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    ///
+    /// let element_handle = library.get_struct_element_child(&document_element, 0);
+    /// assert!(element_handle.is_some());
+    /// ```
+    pub fn get_struct_element_child<'data, 'library>(
+        &'library self,
+        element: &StructElementHandle<'data, 'library>,
+        index: usize,
+    ) -> Option<StructElementHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_StructElement_GetChildAtIndex(element.handle.as_ptr(), index as _)
+        });
+
+        handle.map(StructElementHandle::new)
+    }
+
+    /// Get the type of a structure element, the `S` entry, like `H1`, `P` or `Figure`.
+    ///
+    ///     /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 0)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_type(&element_handle);
+    /// assert_eq!(value, "H1");
+    /// ```
+    pub fn get_struct_element_type(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetType(element.handle.as_ptr(), buffer as _, length as _)
+                as _
+        })
+    }
+
+    /// Get the object type of a structure element, the `Type` entry, usually `StructElem`.
+    ///
+    /// Returns an empty string if the element doesn't have an object type.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 0)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_object_type(&element_handle);
+    /// assert_eq!(value, "StructElem");
+    /// ```
+    pub fn get_struct_element_object_type(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetObjType(
+                element.handle.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the alternate description of a structure element, the `Alt` entry, a text replacement for images and other non text content.
+    ///
+    /// Returns an empty string if the element doesn't have an alternate description.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_alt_text(&element_handle);
+    /// assert_eq!(value, "A gray rectangle");
+    /// ```
+    pub fn get_struct_element_alt_text(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetAltText(
+                element.handle.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the actual text of a structure element, the `ActualText` entry, the exact text replacement of its content.
+    ///
+    /// Returns an empty string if the element doesn't have an actual text.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 1)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_actual_text(&element_handle);
+    /// assert_eq!(value, "Hello, world.");
+    /// ```
+    pub fn get_struct_element_actual_text(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetActualText(
+                element.handle.as_ptr(),
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the title of a structure element, the `T` entry.
+    ///
+    /// Returns an empty string if the element doesn't have a title.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 0)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_title(&element_handle);
+    /// assert_eq!(value, "Title");
+    /// ```
+    pub fn get_struct_element_title(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetTitle(element.handle.as_ptr(), buffer as _, length as _)
+                as _
+        })
+    }
+
+    /// Get the language of a structure element, the `Lang` entry, a language tag like `en-US`.
+    ///
+    /// Returns an empty string if the element doesn't have a language.
+    /// The language of the element is inherited from its ancestors and the document if empty.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 0)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_lang(&element_handle);
+    /// assert_eq!(value, "en-GB");
+    /// ```
+    pub fn get_struct_element_lang(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetLang(element.handle.as_ptr(), buffer as _, length as _)
+                as _
+        })
+    }
+
+    /// Get the identifier of a structure element, the `ID` entry.
+    ///
+    /// Returns an empty string if the element doesn't have an identifier.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 0)
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_element_id(&element_handle);
+    /// assert_eq!(value, "heading");
+    /// ```
+    pub fn get_struct_element_id(&self, element: &StructElementHandle) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDF_StructElement_GetID(element.handle.as_ptr(), buffer as _, length as _)
+                as _
+        })
+    }
+
+    /// Get the first marked content ID of a structure element, the `MCID` of the content it contains.
+    ///
+    /// Use [`Library::get_struct_element_marked_content_id_at_index`] for elements with multiple
+    /// marked content sequences.
+    ///
+    /// Returns `None` if the element doesn't contain marked content directly.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 1)
+    ///     .unwrap();
+    ///
+    /// let id = library.get_struct_element_marked_content_id(&element_handle);
+    /// assert_eq!(id, Some(1));
+    /// ```
+    pub fn get_struct_element_marked_content_id(
+        &self,
+        element: &StructElementHandle,
+    ) -> Option<i32> {
+        let id =
+            unsafe { bindings::FPDF_StructElement_GetMarkedContentID(element.handle.as_ptr()) };
+
+        Some(id).filter(|id| *id >= 0)
+    }
+
+    /// Get the number of marked content IDs of a structure element, on all pages.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 1)
+    ///     .unwrap();
+    ///
+    /// let count = library.get_struct_element_marked_content_id_count(&element_handle);
+    /// assert_eq!(count, 1);
+    /// ```
+    pub fn get_struct_element_marked_content_id_count(
+        &self,
+        element: &StructElementHandle,
+    ) -> usize {
+        let count = unsafe {
+            bindings::FPDF_StructElement_GetMarkedContentIdCount(element.handle.as_ptr())
+        };
+
+        count.max(0) as usize
+    }
+
+    /// Get a marked content ID of a structure element.
+    ///
+    /// `index` 0 for the first marked content ID.
+    ///
+    /// The marked content can be on another page than the page of the structure tree,
+    /// use [`Library::get_struct_element_child_marked_content_id`] to only get the marked content of the page.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 1)
+    ///     .unwrap();
+    ///
+    /// let id = library.get_struct_element_marked_content_id_at_index(&element_handle, 0);
+    /// assert_eq!(id, Some(1));
+    /// ```
+    pub fn get_struct_element_marked_content_id_at_index(
+        &self,
+        element: &StructElementHandle,
+        index: usize,
+    ) -> Option<i32> {
+        let id = unsafe {
+            bindings::FPDF_StructElement_GetMarkedContentIdAtIndex(
+                element.handle.as_ptr(),
+                index as _,
+            )
+        };
+
+        Some(id).filter(|id| *id >= 0)
+    }
+
+    /// Get the marked content ID of a child of a structure element, for children that are marked
+    /// content on the page of the structure tree.
+    ///
+    /// The ID matches the marked content ID of the page objects that are part of the content,
+    /// see [`Library::get_page_object_marked_content_id`].
+    ///
+    /// `index` 0 for the first child, see [`Library::get_struct_element_child_count`].
+    ///
+    /// Returns `None` if `index` is out of bounds or the child is not marked content.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 1)
+    ///     .unwrap();
+    ///
+    /// let id = library.get_struct_element_child_marked_content_id(&element_handle, 0);
+    /// assert_eq!(id, Some(1));
+    /// ```
+    pub fn get_struct_element_child_marked_content_id(
+        &self,
+        element: &StructElementHandle,
+        index: usize,
+    ) -> Option<i32> {
+        let id = unsafe {
+            bindings::FPDF_StructElement_GetChildMarkedContentID(
+                element.handle.as_ptr(),
+                index as _,
+            )
+        };
+
+        Some(id).filter(|id| *id >= 0)
+    }
+
+    /// Get the number of attribute objects of a structure element, the entries of its `A` entry.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    ///
+    /// let count = library.get_struct_element_attribute_count(&element_handle);
+    /// assert_eq!(count, 1);
+    /// ```
+    pub fn get_struct_element_attribute_count(&self, element: &StructElementHandle) -> usize {
+        let count =
+            unsafe { bindings::FPDF_StructElement_GetAttributeCount(element.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get an attribute object of a structure element.
+    ///
+    /// `index` 0 for the first attribute object.
+    ///
+    /// Returns `None` if `index` is out of bounds or the attribute object is not a dictionary.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    ///
+    /// let attribute_handle = library.get_struct_element_attribute(&element_handle, 0);
+    /// assert!(attribute_handle.is_some());
+    /// ```
+    pub fn get_struct_element_attribute<'data, 'library>(
+        &'library self,
+        element: &StructElementHandle<'data, 'library>,
+        index: usize,
+    ) -> Option<StructAttributeHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_StructElement_GetAttributeAtIndex(element.handle.as_ptr(), index as _)
+        } as *mut _);
+
+        handle.map(StructAttributeHandle::new)
+    }
+
+    /// Get the number of attributes in an attribute object, including its owner `O`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    ///
+    /// let count = library.get_struct_attribute_count(&attribute_handle);
+    /// assert_eq!(count, 3);
+    /// ```
+    pub fn get_struct_attribute_count(&self, attribute: &StructAttributeHandle) -> usize {
+        let count =
+            unsafe { bindings::FPDF_StructElement_Attr_GetCount(attribute.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get the name of an attribute in an attribute object, like `O`, `Placement` or `Width`.
+    ///
+    /// `index` 0 for the first attribute.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    ///
+    /// let name = library.get_struct_attribute_name(&attribute_handle, 0);
+    /// assert!(name.is_some());
+    /// ```
+    pub fn get_struct_attribute_name(
+        &self,
+        attribute: &StructAttributeHandle,
+        index: usize,
+    ) -> Option<String> {
+        let mut found = false;
+
+        let name = get_string(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDF_StructElement_Attr_GetName(
+                    attribute.handle.as_ptr(),
+                    index as _,
+                    buffer as _,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(name).filter(|_| found)
+    }
+
+    /// Get the value of an attribute in an attribute object.
+    ///
+    /// Returns `None` if the attribute object doesn't have the attribute or `name` contains a nul character.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    ///
+    /// let value_handle = library.get_struct_attribute_value(&attribute_handle, "Width");
+    /// assert!(value_handle.is_some());
+    /// ```
+    pub fn get_struct_attribute_value<'data, 'library>(
+        &'library self,
+        attribute: &StructAttributeHandle<'data, 'library>,
+        name: &str,
+    ) -> Option<StructAttributeValueHandle<'data, 'library>> {
+        let name = to_byte_string(name).ok()?;
+
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_StructElement_Attr_GetValue(attribute.handle.as_ptr(), name.as_ptr())
+        } as *mut _);
+
+        handle.map(StructAttributeValueHandle::new)
+    }
+
+    /// Get the type of the value of an attribute.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, ObjectType};
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Width")
+    ///     .unwrap();
+    ///
+    /// let value_type = library.get_struct_attribute_value_type(&value_handle);
+    /// assert_eq!(value_type, ObjectType::Number);
+    /// ```
+    pub fn get_struct_attribute_value_type(
+        &self,
+        value: &StructAttributeValueHandle,
+    ) -> ObjectType {
+        let value_type =
+            unsafe { bindings::FPDF_StructElement_Attr_GetType(value.handle.as_ptr()) };
+
+        ObjectType::from_i32(value_type).unwrap_or(ObjectType::Unknown)
+    }
+
+    /// Get the value of a boolean attribute.
+    ///
+    /// Returns `None` if the value is not a boolean.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Width")
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_attribute_boolean_value(&value_handle);
+    /// assert_eq!(value, None);
+    /// ```
+    pub fn get_struct_attribute_boolean_value(
+        &self,
+        value: &StructAttributeValueHandle,
+    ) -> Option<bool> {
+        let mut result = 0;
+
+        let success = unsafe {
+            bindings::FPDF_StructElement_Attr_GetBooleanValue(value.handle.as_ptr(), &mut result)
+        };
+
+        Some(result != 0).filter(|_| success != 0)
+    }
+
+    /// Get the value of a number attribute.
+    ///
+    /// Returns `None` if the value is not a number.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Width")
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_attribute_number_value(&value_handle);
+    /// assert_eq!(value, Some(100.0));
+    /// ```
+    pub fn get_struct_attribute_number_value(
+        &self,
+        value: &StructAttributeValueHandle,
+    ) -> Option<f32> {
+        let mut result = 0.0;
+
+        let success = unsafe {
+            bindings::FPDF_StructElement_Attr_GetNumberValue(value.handle.as_ptr(), &mut result)
+        };
+
+        Some(result).filter(|_| success != 0)
+    }
+
+    /// Get the value of a string or name attribute.
+    ///
+    /// Returns `None` if the value is not a string or a name.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Placement")
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_attribute_string_value(&value_handle);
+    /// assert_eq!(value.as_deref(), Some("Block"));
+    /// ```
+    pub fn get_struct_attribute_string_value(
+        &self,
+        value: &StructAttributeValueHandle,
+    ) -> Option<String> {
+        let mut found = false;
+
+        let result = get_wide_string(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDF_StructElement_Attr_GetStringValue(
+                    value.handle.as_ptr(),
+                    buffer as _,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(result).filter(|_| found)
+    }
+
+    /// Get the raw bytes of a string attribute, for strings that are not text.
+    ///
+    /// Returns `None` if the value is not a string.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Width")
+    ///     .unwrap();
+    ///
+    /// let value = library.get_struct_attribute_blob_value(&value_handle);
+    /// assert_eq!(value, None);
+    /// ```
+    pub fn get_struct_attribute_blob_value(
+        &self,
+        value: &StructAttributeValueHandle,
+    ) -> Option<Vec<u8>> {
+        let mut found = false;
+
+        let result = get_bytes(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDF_StructElement_Attr_GetBlobValue(
+                    value.handle.as_ptr(),
+                    buffer as _,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(result).filter(|_| found)
+    }
+
+    /// Get the number of elements of an array attribute.
+    ///
+    /// Returns 0 if the value is not an array.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Width")
+    ///     .unwrap();
+    ///
+    /// let count = library.get_struct_attribute_value_child_count(&value_handle);
+    /// assert_eq!(count, 0);
+    /// ```
+    pub fn get_struct_attribute_value_child_count(
+        &self,
+        value: &StructAttributeValueHandle,
+    ) -> usize {
+        let count =
+            unsafe { bindings::FPDF_StructElement_Attr_CountChildren(value.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get an element of an array attribute.
+    ///
+    /// `index` 0 for the first element.
+    ///
+    /// Returns `None` if the value is not an array or `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(TAGGED_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let struct_tree_handle = library.get_struct_tree(&page_handle).unwrap();
+    /// let document_element = library
+    ///     .get_struct_tree_child(&struct_tree_handle, 0)
+    ///     .unwrap();
+    /// let element_handle = library
+    ///     .get_struct_element_child(&document_element, 2)
+    ///     .unwrap();
+    /// let attribute_handle = library
+    ///     .get_struct_element_attribute(&element_handle, 0)
+    ///     .unwrap();
+    /// let value_handle = library
+    ///     .get_struct_attribute_value(&attribute_handle, "Width")
+    ///     .unwrap();
+    ///
+    /// let child_handle = library.get_struct_attribute_value_child(&value_handle, 0);
+    /// assert!(child_handle.is_none());
+    /// ```
+    pub fn get_struct_attribute_value_child<'data, 'library>(
+        &'library self,
+        value: &StructAttributeValueHandle<'data, 'library>,
+        index: usize,
+    ) -> Option<StructAttributeValueHandle<'data, 'library>> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDF_StructElement_Attr_GetChildAtIndex(value.handle.as_ptr(), index as _)
+        } as *mut _);
+
+        handle.map(StructAttributeValueHandle::new)
+    }
+}
+
+/// Safe handle to PDFium Structure Tree, the logical structure of a page.
+///
+/// Created using [`Library::get_struct_tree`].
+///
+/// Structure tree is closed when handle is dropped.
+pub struct StructTreeHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_structtree_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(StructTreeHandle: Sync, Send);
+
+impl StructTreeHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_structtree_t__>) -> Self {
+        StructTreeHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+impl Drop for StructTreeHandle<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::FPDF_StructTree_Close(self.handle.as_ptr());
+        }
+    }
+}
+
+/// Safe handle to PDFium Structure Element, a node of a structure tree.
+///
+/// Created using [`Library::get_struct_tree_child`] and [`Library::get_struct_element_child`].
+///
+/// The element is owned by its structure tree so nothing is freed when the handle is dropped.
+pub struct StructElementHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_structelement_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(StructElementHandle: Sync, Send);
+
+impl StructElementHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_structelement_t__>) -> Self {
+        StructElementHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// Safe handle to PDFium Structure Element Attribute, an attribute object of a structure element.
+///
+/// Created using [`Library::get_struct_element_attribute`].
+///
+/// The attribute object is owned by its structure tree so nothing is freed when the handle is dropped.
+pub struct StructAttributeHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_structelement_attr_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(StructAttributeHandle: Sync, Send);
+
+impl StructAttributeHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_structelement_attr_t__>) -> Self {
+        StructAttributeHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+/// Safe handle to PDFium Structure Element Attribute Value.
+///
+/// Created using [`Library::get_struct_attribute_value`] and [`Library::get_struct_attribute_value_child`].
+///
+/// The value is owned by its structure tree so nothing is freed when the handle is dropped.
+pub struct StructAttributeValueHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_structelement_attr_value_t__>,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(StructAttributeValueHandle: Sync, Send);
+
+impl StructAttributeValueHandle<'_, '_> {
+    pub(crate) fn new(handle: NonNull<bindings::fpdf_structelement_attr_value_t__>) -> Self {
+        StructAttributeValueHandle {
+            handle,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static TAGGED_PDF: &[u8] = include_bytes!("../../../test_assets/tagged.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn struct_tree() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(TAGGED_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let tree = library.get_struct_tree(&page).unwrap();
+
+        assert_eq!(library.get_struct_tree_child_count(&tree), 1);
+        assert!(library.get_struct_tree_child(&tree, 1).is_none());
+
+        let root = library.get_struct_tree_child(&tree, 0).unwrap();
+        assert_eq!(library.get_struct_element_type(&root), "Document");
+        assert_eq!(library.get_struct_element_object_type(&root), "StructElem");
+        assert_eq!(library.get_struct_element_child_count(&root), 4);
+
+        let heading = library.get_struct_element_child(&root, 0).unwrap();
+        assert_eq!(library.get_struct_element_type(&heading), "H1");
+        assert_eq!(library.get_struct_element_title(&heading), "Title");
+        assert_eq!(library.get_struct_element_id(&heading), "heading");
+        assert_eq!(library.get_struct_element_lang(&heading), "en-GB");
+        assert_eq!(library.get_struct_element_alt_text(&heading), "");
+        assert_eq!(library.get_struct_element_attribute_count(&heading), 0);
+        // The marked content of the heading is not an element
+        assert_eq!(library.get_struct_element_child_count(&heading), 1);
+        assert!(library.get_struct_element_child(&heading, 0).is_none());
+        assert_eq!(
+            library.get_struct_element_child_marked_content_id(&heading, 0),
+            Some(0)
+        );
+        assert_eq!(
+            library.get_struct_element_child_marked_content_id(&heading, 1),
+            None
+        );
+        assert_eq!(
+            library.get_struct_element_marked_content_id(&heading),
+            Some(0)
+        );
+        assert_eq!(
+            library.get_struct_element_marked_content_id_count(&heading),
+            1
+        );
+        assert_eq!(
+            library.get_struct_element_marked_content_id_at_index(&heading, 0),
+            Some(0)
+        );
+        assert_eq!(
+            library.get_struct_element_marked_content_id_at_index(&heading, 1),
+            None
+        );
+        // The document element only contains other elements
+        assert_eq!(library.get_struct_element_marked_content_id(&root), None);
+        assert_eq!(library.get_struct_element_marked_content_id_count(&root), 0);
+
+        let paragraph = library.get_struct_element_child(&root, 1).unwrap();
+        assert_eq!(
+            library.get_struct_element_actual_text(&paragraph),
+            "Hello, world."
+        );
+
+        let figure = library.get_struct_element_child(&root, 2).unwrap();
+        assert_eq!(library.get_struct_element_type(&figure), "Figure");
+        assert_eq!(
+            library.get_struct_element_alt_text(&figure),
+            "A gray rectangle"
+        );
+
+        let figure_without_alt = library.get_struct_element_child(&root, 3).unwrap();
+        assert_eq!(library.get_struct_element_alt_text(&figure_without_alt), "");
+    }
+
+    #[test]
+    fn struct_attributes() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(TAGGED_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let tree = library.get_struct_tree(&page).unwrap();
+        let root = library.get_struct_tree_child(&tree, 0).unwrap();
+        let figure = library.get_struct_element_child(&root, 2).unwrap();
+
+        assert_eq!(library.get_struct_element_attribute_count(&figure), 1);
+        assert!(library.get_struct_element_attribute(&figure, 1).is_none());
+        let attribute = library.get_struct_element_attribute(&figure, 0).unwrap();
+
+        assert_eq!(library.get_struct_attribute_count(&attribute), 3);
+        let mut names: Vec<_> = (0..3)
+            .filter_map(|index| library.get_struct_attribute_name(&attribute, index))
+            .collect();
+        names.sort();
+        assert_eq!(names, ["O", "Placement", "Width"]);
+        assert_eq!(library.get_struct_attribute_name(&attribute, 3), None);
+
+        let owner = library.get_struct_attribute_value(&attribute, "O").unwrap();
+        assert_eq!(
+            library.get_struct_attribute_value_type(&owner),
+            ObjectType::Name
+        );
+        assert_eq!(
+            library.get_struct_attribute_string_value(&owner).as_deref(),
+            Some("Layout")
+        );
+
+        let width = library
+            .get_struct_attribute_value(&attribute, "Width")
+            .unwrap();
+        assert_eq!(
+            library.get_struct_attribute_value_type(&width),
+            ObjectType::Number
+        );
+        assert_eq!(
+            library.get_struct_attribute_number_value(&width),
+            Some(100.0)
+        );
+        assert_eq!(library.get_struct_attribute_string_value(&width), None);
+
+        assert!(library
+            .get_struct_attribute_value(&attribute, "Height")
+            .is_none());
+    }
+
+    #[test]
+    fn untagged_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let tree = library.get_struct_tree(&page);
+        if let Some(tree) = &tree {
+            assert_eq!(library.get_struct_tree_child_count(tree), 0);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{bindings, Library};
+use std::ffi::{c_void, CStr, CString};
+use std::os::raw::{c_char, c_int, c_uchar, c_uint, c_ulong};
+use std::ptr::NonNull;
+
+/// A font for a character set, an entry of [`Library::get_default_ttf_map`]
+/// or a font of [`LibraryConfig::installed_fonts`](crate::LibraryConfig::installed_fonts).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharsetFont {
+    /// The character set, see [`charsets`].
+    pub charset: i32,
+    /// The face name of the font, like `Noto Sans CJK SC`.
+    pub face: String,
+}
+
+impl Library {
+    /// Get the fonts PDFium uses for the character sets when a document doesn't embed its fonts.
+    ///
+    /// The fonts are found by their face name with the fonts of the system, or the font paths
+    /// of the [`LibraryConfig`](crate::LibraryConfig).
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{charsets, Library};
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let map = library.get_default_ttf_map();
+    /// assert!(map.iter().any(|font| font.charset == charsets::SHIFTJIS));
+    /// ```
+    pub fn get_default_ttf_map(&self) -> Vec<CharsetFont> {
+        let mut fonts = Vec::new();
+        let mut entry = unsafe { bindings::FPDF_GetDefaultTTFMap() };
+
+        // The map ends with a `{ -1, NULL }` entry
+        while let Some(font) = unsafe { entry.as_ref() } {
+            if font.charset == -1 || font.fontname.is_null() {
+                break;
+            }
+
+            fonts.push(CharsetFont {
+                charset: font.charset,
+                face: unsafe { CStr::from_ptr(font.fontname) }
+                    .to_string_lossy()
+                    .into_owned(),
+            });
+            entry = unsafe { entry.add(1) };
+        }
+
+        fonts
+    }
+}
+
+/// The font info of the system, with the installed fonts of a config added to the fonts it finds.
+///
+/// It's installed with `FPDF_SetSystemFontInfo` and kept until the library is destroyed.
+#[repr(C)]
+pub(crate) struct SystemFontInfo {
+    /// The first field, so the pointer PDFium passes to the callbacks points to the whole struct.
+    interface: bindings::FPDF_SYSFONTINFO,
+    default: NonNull<bindings::FPDF_SYSFONTINFO>,
+    fonts: Vec<(CString, c_int)>,
+}
+
+impl SystemFontInfo {
+    /// Install the font info, fonts with a nul byte in their face name are skipped.
+    ///
+    /// Returns `None` if there are no fonts or the platform doesn't have a default font info.
+    pub(crate) fn install(fonts: &[CharsetFont]) -> Option<Box<SystemFontInfo>> {
+        let fonts: Vec<_> = fonts
+            .iter()
+            .filter_map(|font| Some((CString::new(font.face.as_str()).ok()?, font.charset)))
+            .collect();
+
+        if fonts.is_empty() {
+            return None;
+        }
+
+        let default = NonNull::new(unsafe { bindings::FPDF_GetDefaultSystemFontInfo() })?;
+
+        let mut font_info = Box::new(SystemFontInfo {
+            interface: bindings::FPDF_SYSFONTINFO {
+                // Version 1 calls `EnumFonts`, where the fonts are added
+                version: 1,
+                Release: Some(release),
+                EnumFonts: Some(enum_fonts),
+                MapFont: Some(map_font),
+                GetFont: Some(get_font),
+                GetFontData: Some(get_font_data),
+                GetFaceName: Some(get_face_name),
+                GetFontCharset: Some(get_font_charset),
+                DeleteFont: Some(delete_font),
+            },
+            default,
+            fonts,
+        });
+
+        unsafe {
+            bindings::FPDF_SetSystemFontInfo(&mut font_info.interface);
+        }
+
+        Some(font_info)
+    }
+}
+
+/// The default font info of the font info that is passed to a callback.
+unsafe fn default(this: *mut bindings::FPDF_SYSFONTINFO) -> *mut bindings::FPDF_SYSFONTINFO {
+    (*(this as *mut SystemFontInfo)).default.as_ptr()
+}
+
+unsafe extern "C" fn release(this: *mut bindings::FPDF_SYSFONTINFO) {
+    bindings::FPDF_FreeDefaultSystemFontInfo(default(this));
+}
+
+unsafe extern "C" fn enum_fonts(this: *mut bindings::FPDF_SYSFONTINFO, mapper: *mut c_void) {
+    let default = default(this);
+
+    if let Some(enum_fonts) = (*default).EnumFonts {
+        enum_fonts(default, mapper);
+    }
+
+    for (face, charset) in &(*(this as *mut SystemFontInfo)).fonts {
+        bindings::FPDF_AddInstalledFont(mapper, face.as_ptr(), *charset);
+    }
+}
+
+unsafe extern "C" fn map_font(
+    this: *mut bindings::FPDF_SYSFONTINFO,
+    weight: c_int,
+    italic: bindings::FPDF_BOOL,
+    charset: c_int,
+    pitch_family: c_int,
+    face: *const c_char,
+    exact: *mut bindings::FPDF_BOOL,
+) -> *mut c_void {
+    let default = default(this);
+
+    match (*default).MapFont {
+        Some(map_font) => map_font(default, weight, italic, charset, pitch_family, face, exact),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn get_font(
+    this: *mut bindings::FPDF_SYSFONTINFO,
+    face: *const c_char,
+) -> *mut c_void {
+    let default = default(this);
+
+    match (*default).GetFont {
+        Some(get_font) => get_font(default, face),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn get_font_data(
+    this: *mut bindings::FPDF_SYSFONTINFO,
+    font: *mut c_void,
+    table: c_uint,
+    buffer: *mut c_uchar,
+    buffer_size: c_ulong,
+) -> c_ulong {
+    let default = default(this);
+
+    match (*default).GetFontData {
+        Some(get_font_data) => get_font_data(default, font, table, buffer, buffer_size),
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn get_face_name(
+    this: *mut bindings::FPDF_SYSFONTINFO,
+    font: *mut c_void,
+    buffer: *mut c_char,
+    buffer_size: c_ulong,
+) -> c_ulong {
+    let default = default(this);
+
+    match (*default).GetFaceName {
+        Some(get_face_name) => get_face_name(default, font, buffer, buffer_size),
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn get_font_charset(
+    this: *mut bindings::FPDF_SYSFONTINFO,
+    font: *mut c_void,
+) -> c_int {
+    let default = default(this);
+
+    match (*default).GetFontCharset {
+        Some(get_font_charset) => get_font_charset(default, font),
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn delete_font(this: *mut bindings::FPDF_SYSFONTINFO, font: *mut c_void) {
+    let default = default(this);
+
+    if let Some(delete_font) = (*default).DeleteFont {
+        delete_font(default, font);
+    }
+}
+
+pub mod charsets {
+    //! Character sets of fonts, used by [`CharsetFont`](crate::CharsetFont).
+    //!
+    //! ## Examples
+    //! ```
+    //! use pdfium_core::charsets;
+    //! use pdfium_core::CharsetFont;
+    //!
+    //! let font = CharsetFont {
+    //!     charset: charsets::GB2312,
+    //!     face: "Noto Sans CJK SC".to_string(),
+    //! };
+    //! ```
+
+    /// Latin characters, Windows-1252.
+    pub const ANSI: i32 = 0;
+
+    /// The default character set of the system.
+    pub const DEFAULT: i32 = 1;
+
+    /// Symbols.
+    pub const SYMBOL: i32 = 2;
+
+    /// Japanese, Shift JIS.
+    pub const SHIFTJIS: i32 = 128;
+
+    /// Korean.
+    pub const HANGEUL: i32 = 129;
+
+    /// Simplified Chinese, GB 2312.
+    pub const GB2312: i32 = 134;
+
+    /// Traditional Chinese, Big5.
+    pub const CHINESEBIG5: i32 = 136;
+
+    /// Greek.
+    pub const GREEK: i32 = 161;
+
+    /// Vietnamese.
+    pub const VIETNAMESE: i32 = 163;
+
+    /// Hebrew.
+    pub const HEBREW: i32 = 177;
+
+    /// Arabic.
+    pub const ARABIC: i32 = 178;
+
+    /// Cyrillic.
+    pub const CYRILLIC: i32 = 204;
+
+    /// Thai.
+    pub const THAI: i32 = 222;
+
+    /// Central European, Windows-1250.
+    pub const EASTERNEUROPEAN: i32 = 238;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LibraryConfig, TEST_LOCK};
+
+    #[test]
+    fn default_ttf_map() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+
+        let map = library.get_default_ttf_map();
+        assert!(map.iter().any(|font| font.charset == charsets::ANSI));
+        assert!(map.iter().all(|font| !font.face.is_empty()));
+    }
+
+    #[test]
+    fn installed_fonts() {
+        let _guard = TEST_LOCK.lock();
+        let config = LibraryConfig {
+            installed_fonts: vec![CharsetFont {
+                charset: charsets::GB2312,
+                face: "Noto Sans CJK SC".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let library = Library::init_library_with_config(config).unwrap();
+        assert!(!library.get_default_ttf_map().is_empty());
+    }
+}
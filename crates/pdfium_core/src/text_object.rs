@@ -0,0 +1,392 @@
+use crate::{bindings, get_wide_string, to_result, to_wide_string};
+use crate::{BitmapHandle, DocumentHandle, FontHandle, Library, PageHandle};
+use crate::{PageObjectHandle, PageObjectMut, PdfiumError, TextPageHandle};
+use std::ptr::NonNull;
+
+impl Library {
+    /// Get the text of a text object.
+    ///
+    /// `text_page` must be loaded from the page that contains `text_object`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_page_handle = library.load_text_page(&page_handle).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let text = library.get_text_object_text(&text_object_handle, &text_page_handle);
+    /// assert_eq!(text, "Dumm");
+    /// ```
+    pub fn get_text_object_text(
+        &self,
+        text_object: &PageObjectHandle,
+        text_page: &TextPageHandle,
+    ) -> String {
+        get_wide_string(|buffer, length| unsafe {
+            bindings::FPDFTextObj_GetText(
+                text_object.handle.as_ptr(),
+                text_page.handle.as_ptr(),
+                buffer,
+                length as _,
+            ) as usize
+        })
+    }
+
+    /// Set the text of a text object.
+    ///
+    /// The font of the text object must be able to encode `text`.
+    /// Call [`Library::generate_page_content`] to save the change to the page.
+    /// The text page of [`Library::load_cached_text_page`] is loaded again, with the new text, the next time it's used.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `text_object` is not a text object.
+    ///
+    /// ## Examples
+    /// Replace a placeholder in the page:
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let mut page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_page_handle = library.load_text_page(&page_handle).unwrap();
+    ///
+    /// for index in 0..library.get_page_object_count(&page_handle) {
+    ///     let mut object_handle = library.get_page_object(&page_handle, index).unwrap();
+    ///
+    ///     if library.get_text_object_text(&object_handle, &text_page_handle) == "PDF" {
+    ///         library.set_text_object_text(&mut object_handle, "file").unwrap();
+    ///     }
+    /// }
+    ///
+    /// // drop the text page so that the page can be modified
+    /// drop(text_page_handle);
+    ///
+    /// library.generate_page_content(&mut page_handle).unwrap();
+    /// ```
+    pub fn set_text_object_text(
+        &self,
+        text_object: &mut impl PageObjectMut,
+        text: &str,
+    ) -> Result<(), PdfiumError> {
+        let text = to_wide_string(text);
+        text_object.text_changed();
+
+        to_result(unsafe {
+            bindings::FPDFText_SetText(text_object.page_object_handle().as_ptr(), text.as_ptr())
+        })
+    }
+
+    /// Get the font size of a text object.
+    ///
+    /// The font size is measured in points (about 1/72 inch) before the text object's matrix is applied.
+    ///
+    /// Will return `None` if `text_object` is not a text object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let font_size = library.get_text_object_font_size(&text_object_handle);
+    /// assert_eq!(font_size, Some(16.1));
+    /// ```
+    pub fn get_text_object_font_size(&self, text_object: &PageObjectHandle) -> Option<f32> {
+        let mut size = 0.0;
+
+        to_result(unsafe {
+            bindings::FPDFTextObj_GetFontSize(text_object.handle.as_ptr(), &mut size)
+        })
+        .ok()
+        .map(|_| size)
+    }
+
+    /// Get the text rendering mode of a text object.
+    ///
+    /// Will return `None` if `text_object` is not a text object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::{Library, TextRenderMode};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let render_mode = library.get_text_object_render_mode(&text_object_handle);
+    /// assert_eq!(render_mode, Some(TextRenderMode::Fill));
+    /// ```
+    pub fn get_text_object_render_mode(
+        &self,
+        text_object: &PageObjectHandle,
+    ) -> Option<TextRenderMode> {
+        let mode = unsafe { bindings::FPDFTextObj_GetTextRenderMode(text_object.handle.as_ptr()) };
+
+        TextRenderMode::from_i32(mode as i32)
+    }
+
+    /// Get the font of a text object.
+    ///
+    /// Will return `None` if `text_object` is not a text object.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let font_handle = library.get_text_object_font(&text_object_handle);
+    /// assert!(font_handle.is_some());
+    /// ```
+    pub fn get_text_object_font<'data, 'library>(
+        &'library self,
+        text_object: &'data PageObjectHandle,
+    ) -> Option<FontHandle<'data, 'library>> {
+        let handle =
+            NonNull::new(unsafe { bindings::FPDFTextObj_GetFont(text_object.handle.as_ptr()) });
+
+        handle.map(FontHandle::new)
+    }
+
+    /// Render a text object into a new bitmap.
+    ///
+    /// `page` must be the page that contains `text_object`, and `document` the document that contains `page`.
+    ///
+    /// `scale` is the scale factor to render the text object with. `1.0` renders one pixel per point.
+    ///
+    /// The bitmap's format is [`BitmapFormat::BGRA`](crate::BitmapFormat::BGRA) and its size is the size of the text object's bounds.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): `text_object` is not a text object.
+    /// - [`Unknown`](PdfiumError::Unknown): `scale` is not greater than 0.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_object_handle = library.get_page_object(&page_handle, 0).unwrap();
+    ///
+    /// let bitmap_handle = library.get_text_object_rendered_bitmap(
+    ///     &document_handle,
+    ///     &page_handle,
+    ///     &text_object_handle,
+    ///     2.0,
+    /// );
+    /// assert!(bitmap_handle.is_ok());
+    /// ```
+    pub fn get_text_object_rendered_bitmap<'library>(
+        &'library self,
+        document: &DocumentHandle,
+        page: &PageHandle,
+        text_object: &PageObjectHandle,
+        scale: f32,
+    ) -> Result<BitmapHandle<'static, 'library>, PdfiumError> {
+        let handle = NonNull::new(unsafe {
+            bindings::FPDFTextObj_GetRenderedBitmap(
+                document.handle.as_ptr(),
+                page.handle.as_ptr(),
+                text_object.handle.as_ptr(),
+                scale,
+            )
+        });
+
+        handle
+            .map(|handle| BitmapHandle {
+                handle,
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or(PdfiumError::Unknown)
+    }
+}
+
+/// Text rendering modes.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TextRenderMode {
+    /// Fill text.
+    Fill = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL,
+    /// Stroke text.
+    Stroke = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_STROKE,
+    /// Fill, then stroke text.
+    FillStroke = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL_STROKE,
+    /// Neither fill nor stroke text (invisible).
+    Invisible = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_INVISIBLE,
+    /// Fill text and add to path for clipping.
+    FillClip = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL_CLIP,
+    /// Stroke text and add to path for clipping.
+    StrokeClip = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_STROKE_CLIP,
+    /// Fill, then stroke text and add to path for clipping.
+    FillStrokeClip = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL_STROKE_CLIP,
+    /// Add text to path for clipping.
+    Clip = bindings::FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_CLIP,
+}
+
+impl TextRenderMode {
+    fn from_i32(number: i32) -> Option<TextRenderMode> {
+        match number {
+            x if x == TextRenderMode::Fill as i32 => Some(TextRenderMode::Fill),
+            x if x == TextRenderMode::Stroke as i32 => Some(TextRenderMode::Stroke),
+            x if x == TextRenderMode::FillStroke as i32 => Some(TextRenderMode::FillStroke),
+            x if x == TextRenderMode::Invisible as i32 => Some(TextRenderMode::Invisible),
+            x if x == TextRenderMode::FillClip as i32 => Some(TextRenderMode::FillClip),
+            x if x == TextRenderMode::StrokeClip as i32 => Some(TextRenderMode::StrokeClip),
+            x if x == TextRenderMode::FillStrokeClip as i32 => Some(TextRenderMode::FillStrokeClip),
+            x if x == TextRenderMode::Clip as i32 => Some(TextRenderMode::Clip),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BitmapFormat, TEST_LOCK};
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let text_page = library.load_text_page(&page).unwrap();
+
+        let object_count = library.get_page_object_count(&page);
+        let text: String = (0..object_count)
+            .map(|index| library.get_page_object(&page, index).unwrap())
+            .map(|object| library.get_text_object_text(&object, &text_page))
+            .collect();
+
+        assert_eq!(text, "Dummy PDF file");
+    }
+
+    #[test]
+    fn set_text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+
+        {
+            let mut object = library.get_page_object(&page, 0).unwrap();
+            library.set_text_object_text(&mut object, "File").unwrap();
+        }
+
+        library.generate_page_content(&mut page).unwrap();
+
+        let text_page = library.load_text_page(&page).unwrap();
+        let object = library.get_page_object(&page, 0).unwrap();
+        assert_eq!(library.get_text_object_text(&object, &text_page), "File");
+    }
+
+    #[test]
+    fn set_text_cached_text_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+
+        let text_page = library.load_cached_text_page(&page).unwrap();
+        let count = library.get_text_char_count(&text_page);
+        assert!(library.get_text(&text_page, 0, count).starts_with("Dumm"));
+
+        let mut object = library.get_page_object(&page, 0).unwrap();
+        library.set_text_object_text(&mut object, "File").unwrap();
+
+        // The borrowed text page still works, the next one has the new text
+        assert_eq!(library.get_text_char_count(&text_page), count);
+        let text_page = library.load_cached_text_page(&page).unwrap();
+        let count = library.get_text_char_count(&text_page);
+        assert!(library.get_text(&text_page, 0, count).starts_with("File"));
+    }
+
+    #[test]
+    fn font_metrics() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let object = library.get_page_object(&page, 0).unwrap();
+
+        assert_eq!(library.get_text_object_font_size(&object), Some(16.1));
+        assert_eq!(
+            library.get_text_object_render_mode(&object),
+            Some(TextRenderMode::Fill)
+        );
+        assert!(library.get_text_object_font(&object).is_some());
+    }
+
+    #[test]
+    fn rendered_bitmap() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let object = library.get_page_object(&page, 0).unwrap();
+
+        let bitmap = library
+            .get_text_object_rendered_bitmap(&document, &page, &object, 1.0)
+            .unwrap();
+
+        assert_eq!(library.get_bitmap_format(&bitmap), BitmapFormat::BGRA);
+        // the text is black so at least one pixel is visible
+        assert!(library.get_bitmap_buffer(&bitmap).iter().any(|x| *x != 0));
+    }
+}
@@ -0,0 +1,387 @@
+use crate::{bindings, Library, PageHandle, PdfiumError, Rect};
+use static_assertions::assert_not_impl_any;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+impl Library {
+    /// Prepare information about all characters in a page.
+    ///
+    /// The text page is a snapshot of the page's text. It will not reflect changes made to the page after it is loaded.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`BadPage`](PdfiumError::BadPage): Unable to parse the page's text.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &'static [u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_page_handle = library.load_text_page(&page_handle);
+    /// assert!(text_page_handle.is_ok());
+    /// ```
+    pub fn load_text_page<'data, 'library>(
+        &'library self,
+        page: &'data PageHandle,
+    ) -> Result<TextPageHandle<'data, 'library>, PdfiumError> {
+        let handle = NonNull::new(unsafe { bindings::FPDFText_LoadPage(page.handle.as_ptr()) });
+
+        handle
+            .map(|handle| TextPageHandle {
+                handle,
+                owned: true,
+                data_life_time: Default::default(),
+                library_life_time: Default::default(),
+            })
+            .ok_or(PdfiumError::BadPage)
+    }
+
+    /// Prepare information about all characters in a page like [`Library::load_text_page`],
+    /// but keep the text page with the page so loading it again is free.
+    ///
+    /// The text page is kept until the page is closed or its content is changed by a function that takes
+    /// a `&mut PageHandle`, like [`Library::generate_page_content`] or [`Library::flatten_page`].
+    /// Changes to page objects are seen after the content is generated, adding or removing annotations
+    /// doesn't change the text.
+    /// A page that is kept in the page cache of its document, see [`Library::load_cached_page`], keeps its text page too.
+    ///
+    /// ## Errors
+    /// The same errors as [`Library::load_text_page`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    ///
+    /// // The text page is only loaded once
+    /// for _ in 0..3 {
+    ///     let text_page_handle = library.load_cached_text_page(&page_handle).unwrap();
+    ///     assert!(library.get_text_char_count(&text_page_handle) > 0);
+    /// }
+    /// ```
+    pub fn load_cached_text_page<'data, 'library>(
+        &'library self,
+        page: &'data PageHandle,
+    ) -> Result<TextPageHandle<'data, 'library>, PdfiumError> {
+        let handle = match page.text_page.current.get() {
+            Some(handle) => handle,
+            None => {
+                let handle =
+                    NonNull::new(unsafe { bindings::FPDFText_LoadPage(page.handle.as_ptr()) })
+                        .ok_or(PdfiumError::BadPage)?;
+                page.text_page.current.set(Some(handle));
+
+                handle
+            }
+        };
+
+        Ok(TextPageHandle {
+            handle,
+            owned: false,
+            data_life_time: Default::default(),
+            library_life_time: Default::default(),
+        })
+    }
+
+    /// Get number of characters in a text page.
+    ///
+    /// Generated characters, like spaces and line breaks between text objects, are included.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_page_handle = library.load_text_page(&page_handle).unwrap();
+    ///
+    /// assert!(library.get_text_char_count(&text_page_handle) >= "Dummy PDF file".len());
+    /// ```
+    pub fn get_text_char_count(&self, text_page: &TextPageHandle) -> usize {
+        let count = unsafe { bindings::FPDFText_CountChars(text_page.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get the text of a range of characters in a text page.
+    ///
+    /// The range is clamped to the characters in the text page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_page_handle = library.load_text_page(&page_handle).unwrap();
+    ///
+    /// let text = library.get_text(&text_page_handle, 0, 5);
+    /// assert_eq!(text, "Dummy");
+    /// ```
+    pub fn get_text(&self, text_page: &TextPageHandle, start: usize, count: usize) -> String {
+        let count = count.min(self.get_text_char_count(text_page).saturating_sub(start));
+
+        if count == 0 {
+            return String::new();
+        }
+
+        // PDFium writes a null character after the text
+        let mut buffer = vec![0u16; count + 1];
+        let written = unsafe {
+            bindings::FPDFText_GetText(
+                text_page.handle.as_ptr(),
+                start as _,
+                count as _,
+                buffer.as_mut_ptr(),
+            )
+        };
+
+        let end = buffer[..(written.max(0) as usize).min(buffer.len())]
+            .iter()
+            .position(|x| *x == 0)
+            .unwrap_or(count);
+        String::from_utf16_lossy(&buffer[..end])
+    }
+
+    /// Get the rects covering a range of characters in a text page.
+    ///
+    /// Characters on the same line that are next to each other share a rect.
+    /// The range is clamped to the characters in the text page.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(DUMMY_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let page_handle = library.load_page(&document_handle, 0).unwrap();
+    /// let text_page_handle = library.load_text_page(&page_handle).unwrap();
+    ///
+    /// let rects = library.get_text_rects(&text_page_handle, 0, 5);
+    /// assert!(!rects.is_empty());
+    /// ```
+    pub fn get_text_rects(
+        &self,
+        text_page: &TextPageHandle,
+        start: usize,
+        count: usize,
+    ) -> Vec<Rect> {
+        let rect_count = unsafe {
+            bindings::FPDFText_CountRects(text_page.handle.as_ptr(), start as i32, count as i32)
+        };
+
+        (0..rect_count.max(0))
+            .filter_map(|index| {
+                let (mut left, mut top, mut right, mut bottom) = (0.0, 0.0, 0.0, 0.0);
+
+                let found = unsafe {
+                    bindings::FPDFText_GetRect(
+                        text_page.handle.as_ptr(),
+                        index,
+                        &mut left,
+                        &mut top,
+                        &mut right,
+                        &mut bottom,
+                    )
+                } != 0;
+
+                Some(Rect {
+                    left: left as f32,
+                    bottom: bottom as f32,
+                    right: right as f32,
+                    top: top as f32,
+                })
+                .filter(|_| found)
+            })
+            .collect()
+    }
+}
+
+/// Safe handle to PDFium Text Page.
+///
+/// Created using [`Library::load_text_page`] or [`Library::load_cached_text_page`].
+///
+/// Text page is closed when handle is dropped, unless it's kept with its page.
+pub struct TextPageHandle<'a, 'b> {
+    pub(crate) handle: NonNull<bindings::fpdf_textpage_t__>,
+    /// `false` for a text page that is kept with its page by [`Library::load_cached_text_page`].
+    owned: bool,
+    data_life_time: PhantomData<&'a ()>,
+    library_life_time: PhantomData<&'b Library>,
+}
+
+assert_not_impl_any!(TextPageHandle: Sync, Send);
+
+impl Drop for TextPageHandle<'_, '_> {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe {
+                bindings::FPDFText_ClosePage(self.handle.as_ptr());
+            }
+        }
+    }
+}
+
+/// The text page of [`Library::load_cached_text_page`].
+///
+/// When a text object of the page changes, the text page is loaded again the next time it's used.
+/// The outdated text pages might still be borrowed, they are closed when the page is changed mutably or closed.
+#[derive(Default)]
+pub(crate) struct CachedTextPage {
+    current: Cell<Option<NonNull<bindings::fpdf_textpage_t__>>>,
+    outdated: RefCell<Vec<NonNull<bindings::fpdf_textpage_t__>>>,
+}
+
+impl CachedTextPage {
+    pub(crate) fn new(current: Option<NonNull<bindings::fpdf_textpage_t__>>) -> Self {
+        CachedTextPage {
+            current: Cell::new(current),
+            outdated: RefCell::default(),
+        }
+    }
+
+    /// Load the text page again the next time it's used, called by the functions that change a page object.
+    pub(crate) fn outdate(&self) {
+        if let Some(text_page) = self.current.take() {
+            self.outdated.borrow_mut().push(text_page);
+        }
+    }
+
+    /// Take the text page to keep it with its page, the outdated text pages are closed.
+    pub(crate) fn take(&mut self) -> Option<NonNull<bindings::fpdf_textpage_t__>> {
+        for text_page in self.outdated.get_mut().drain(..) {
+            unsafe {
+                bindings::FPDFText_ClosePage(text_page.as_ptr());
+            }
+        }
+
+        self.current.take()
+    }
+
+    /// Close all text pages.
+    pub(crate) fn close(&mut self) {
+        if let Some(text_page) = self.take() {
+            unsafe {
+                bindings::FPDFText_ClosePage(text_page.as_ptr());
+            }
+        }
+    }
+}
+
+impl Drop for CachedTextPage {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../../../test_assets/dummy.pdf");
+
+    #[test]
+    fn text_rects() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let text_page = library.load_text_page(&page).unwrap();
+
+        let count = library.get_text_char_count(&text_page);
+        assert!(count >= "Dummy PDF file".len());
+
+        let rects = library.get_text_rects(&text_page, 0, count);
+        assert!(!rects.is_empty());
+        assert!(rects
+            .iter()
+            .all(|rect| rect.width() > 0.0 && rect.height() > 0.0));
+
+        assert!(library.get_text_rects(&text_page, count, 1).is_empty());
+    }
+
+    #[test]
+    fn text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let page = library.load_page(&document, 0).unwrap();
+        let text_page = library.load_text_page(&page).unwrap();
+
+        let count = library.get_text_char_count(&text_page);
+        assert!(library
+            .get_text(&text_page, 0, count)
+            .starts_with("Dummy PDF file"));
+        assert_eq!(library.get_text(&text_page, 6, 3), "PDF");
+        assert_eq!(library.get_text(&text_page, count, 1), "");
+    }
+
+    #[test]
+    fn load_cached_text_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(DUMMY_PDF, None).unwrap();
+        let mut page = library.load_page(&document, 0).unwrap();
+
+        let first = library.load_cached_text_page(&page).unwrap().handle;
+        let second = library.load_cached_text_page(&page).unwrap();
+        assert_eq!(second.handle, first);
+        assert!(library.get_text_char_count(&second) > 0);
+        drop(second);
+
+        // Changing the page closes its text page
+        library.generate_page_content(&mut page).unwrap();
+        assert!(page.text_page.current.get().is_none());
+        let text_page = library.load_cached_text_page(&page).unwrap();
+        assert!(library
+            .get_text(&text_page, 0, 14)
+            .starts_with("Dummy PDF file"));
+        drop(text_page);
+
+        // The text page is kept with a page in the page cache
+        library.set_page_cache_capacity(&document, 1);
+        let cached = library.load_cached_page(&document, 0).unwrap();
+        let text_page = library.load_cached_text_page(&cached).unwrap().handle;
+        drop(cached);
+        let cached = library.load_cached_page(&document, 0).unwrap();
+        assert_eq!(cached.text_page.current.get(), Some(text_page));
+    }
+}
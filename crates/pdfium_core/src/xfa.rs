@@ -0,0 +1,183 @@
+use crate::{bindings, get_bytes, get_string, to_result, DocumentHandle, Library, PdfiumError};
+
+impl Library {
+    /// Get the number of packets of the XFA form of a document, the entries of the `XFA` entry of its `AcroForm`.
+    ///
+    /// Returns 0 if the document doesn't have an XFA form.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static XFA_PDF: &[u8] = include_bytes!("../../../test_assets/xfa.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(XFA_PDF, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(library.get_xfa_packet_count(&document_handle), 2);
+    /// ```
+    pub fn get_xfa_packet_count(&self, document: &DocumentHandle) -> usize {
+        let count = unsafe { bindings::FPDF_GetXFAPacketCount(document.handle.as_ptr()) };
+
+        count.max(0) as usize
+    }
+
+    /// Get the name of a packet of the XFA form of a document, like `template` or `datasets`.
+    ///
+    /// `index` 0 for the first packet.
+    ///
+    /// Returns an empty string if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static XFA_PDF: &[u8] = include_bytes!("../../../test_assets/xfa.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(XFA_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let name = library.get_xfa_packet_name(&document_handle, 1);
+    /// assert_eq!(name, "datasets");
+    /// ```
+    pub fn get_xfa_packet_name(&self, document: &DocumentHandle, index: usize) -> String {
+        get_string(|buffer, length| unsafe {
+            bindings::FPDF_GetXFAPacketName(
+                document.handle.as_ptr(),
+                index as _,
+                buffer as _,
+                length as _,
+            ) as _
+        })
+    }
+
+    /// Get the content of a packet of the XFA form of a document, usually an XML document.
+    ///
+    /// `index` 0 for the first packet.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_core::Library;
+    /// # static XFA_PDF: &[u8] = include_bytes!("../../../test_assets/xfa.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let document_handle = library
+    ///     .load_document_from_bytes(XFA_PDF, None)
+    ///     .unwrap();
+    ///
+    /// let content = library.get_xfa_packet_content(&document_handle, 1).unwrap();
+    /// assert!(content.starts_with(b"<xfa:datasets"));
+    /// ```
+    pub fn get_xfa_packet_content(
+        &self,
+        document: &DocumentHandle,
+        index: usize,
+    ) -> Option<Vec<u8>> {
+        let mut found = false;
+
+        let content = get_bytes(|buffer, length| {
+            let mut out_length = 0;
+
+            let success = unsafe {
+                bindings::FPDF_GetXFAPacketContent(
+                    document.handle.as_ptr(),
+                    index as _,
+                    buffer as _,
+                    length as _,
+                    &mut out_length,
+                )
+            };
+
+            found = success != 0;
+            if found {
+                out_length as _
+            } else {
+                0
+            }
+        });
+
+        Some(content).filter(|_| found)
+    }
+
+    /// Load the XFA form of a document, so its fields are rendered and can be filled in.
+    ///
+    /// Call it before the pages are loaded, the pages of an XFA form are created from the form.
+    ///
+    /// ## Errors
+    /// This function will return an error under a number of different circumstances.
+    /// Some of these error conditions are listed here, together with their [`PdfiumError`].
+    /// The mapping to [`PdfiumError`]s is not part of the compatibility contract of the function,
+    /// especially the [`Unknown`](PdfiumError::Unknown) kind might change to more specific kinds in the future.
+    ///
+    /// - [`Unknown`](PdfiumError::Unknown): The document doesn't have an XFA form.
+    /// - [`Unknown`](PdfiumError::Unknown): PDFium was built without XFA support.
+    ///
+    /// ## Examples
+    /// ```no_run
+    /// use pdfium_core::Library;
+    /// # static XFA_PDF: &[u8] = include_bytes!("../../../test_assets/xfa.pdf");
+    ///
+    /// let library = Library::init_library().unwrap();
+    ///
+    /// let mut document_handle = library
+    ///     .load_document_from_bytes(XFA_PDF, None)
+    ///     .unwrap();
+    ///
+    /// library.load_xfa(&mut document_handle).unwrap();
+    /// ```
+    pub fn load_xfa(&self, document: &mut DocumentHandle) -> Result<(), PdfiumError> {
+        // The pages of the XFA form replace the pages of the document
+        document.pages.clear();
+        drop(document.form.take());
+
+        to_result(unsafe { bindings::FPDF_LoadXFA(document.handle.as_ptr()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static XFA_PDF: &[u8] = include_bytes!("../../../test_assets/xfa.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../../../test_assets/forms.pdf");
+
+    #[test]
+    fn xfa_packets() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let document = library.load_document_from_bytes(XFA_PDF, None).unwrap();
+
+        assert_eq!(library.get_xfa_packet_count(&document), 2);
+        assert_eq!(library.get_xfa_packet_name(&document, 0), "template");
+        assert_eq!(library.get_xfa_packet_name(&document, 1), "datasets");
+        assert_eq!(library.get_xfa_packet_name(&document, 2), "");
+
+        let template = library.get_xfa_packet_content(&document, 0).unwrap();
+        assert!(template.starts_with(b"<template"));
+        assert_eq!(template.len(), 125);
+        let datasets = library.get_xfa_packet_content(&document, 1).unwrap();
+        assert_eq!(datasets.len(), 134);
+        assert!(library.get_xfa_packet_content(&document, 2).is_none());
+
+        // An AcroForm without XFA
+        let forms = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+        assert_eq!(library.get_xfa_packet_count(&forms), 0);
+    }
+
+    #[test]
+    fn load_xfa() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init_library().unwrap();
+        let mut forms = library.load_document_from_bytes(FORMS_PDF, None).unwrap();
+
+        assert!(library.load_xfa(&mut forms).is_err());
+    }
+}
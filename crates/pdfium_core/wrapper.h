@@ -1 +1,35 @@
-#include <fpdfview.h>
\ No newline at end of file
+// All the public C headers of PDFium, so new parts of the API don't need new bindings.
+
+// Libraries, documents, pages and rendering
+#include <fpdfview.h>
+#include <fpdf_dataavail.h>
+#include <fpdf_progressive.h>
+#include <fpdf_sysfontinfo.h>
+#include <fpdf_ext.h>
+
+// Document structure
+#include <fpdf_attachment.h>
+#include <fpdf_catalog.h>
+#include <fpdf_doc.h>
+#include <fpdf_signature.h>
+#include <fpdf_structtree.h>
+
+// Page content
+#include <fpdf_annot.h>
+#include <fpdf_edit.h>
+#include <fpdf_flatten.h>
+#include <fpdf_thumbnail.h>
+#include <fpdf_transformpage.h>
+
+// Text
+#include <fpdf_searchex.h>
+#include <fpdf_text.h>
+
+// Forms and JavaScript
+#include <fpdf_formfill.h>
+#include <fpdf_fwlevent.h>
+#include <fpdf_javascript.h>
+
+// Saving and importing pages
+#include <fpdf_ppo.h>
+#include <fpdf_save.h>
@@ -0,0 +1,152 @@
+use crate::{Document, PdfiumError, StructElement};
+use std::collections::BTreeMap;
+
+impl Document<'_, '_> {
+    /// The content of a tagged document in reading order, the order of its structure tree,
+    /// for screen readers and conversion to HTML.
+    ///
+    /// Every structure element becomes a block, in depth first order. Elements that span
+    /// multiple pages have a block for every page.
+    ///
+    /// Pages without a structure tree have no blocks, see [`Document::is_tagged`].
+    pub fn accessible_export(&self) -> Result<Vec<AccessibleBlock>, PdfiumError> {
+        let language = self.language();
+        let mut blocks = Vec::new();
+
+        for page_index in 0..self.page_count() {
+            let page = self.page(page_index)?;
+            let texts = page.marked_content_texts()?;
+
+            for element in page.struct_tree() {
+                let mut export = Export {
+                    blocks: &mut blocks,
+                    page_index,
+                    texts: &texts,
+                };
+                export.push(&element, 0, language.as_deref());
+            }
+        }
+
+        Ok(blocks)
+    }
+}
+
+/// A structure element and its content, an entry of [`Document::accessible_export`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessibleBlock {
+    /// The page of the content, 0 for the first page.
+    pub page_index: usize,
+    /// The nesting level of the element, 0 for the top level elements of a page.
+    pub depth: usize,
+    /// The structure type of the element, like `H1`, `P`, `Table` or `Figure`.
+    pub role: String,
+    /// The level of a heading from 1 to 6, `None` for other elements and headings without a level.
+    pub heading_level: Option<u8>,
+    /// The text of the content of the element itself, without the text of its children.
+    ///
+    /// If the element has an actual text, it's used instead of the content
+    /// and its children are left out because the actual text replaces them too.
+    pub text: String,
+    /// The alternate description of an image or other non text content.
+    pub alt_text: Option<String>,
+    /// The language of the content, inherited from the ancestors of the element and the document.
+    pub lang: Option<String>,
+}
+
+struct Export<'a> {
+    blocks: &'a mut Vec<AccessibleBlock>,
+    page_index: usize,
+    texts: &'a BTreeMap<i32, String>,
+}
+
+impl Export<'_> {
+    fn push(&mut self, element: &StructElement, depth: usize, lang: Option<&str>) {
+        let lang = element.lang.as_deref().or(lang);
+        let text = match &element.actual_text {
+            Some(actual_text) => actual_text.clone(),
+            None => element
+                .marked_content_ids
+                .iter()
+                .filter_map(|id| self.texts.get(id))
+                .map(String::as_str)
+                .collect(),
+        };
+
+        self.blocks.push(AccessibleBlock {
+            page_index: self.page_index,
+            depth,
+            role: element.element_type.clone(),
+            heading_level: heading_level(&element.element_type),
+            text,
+            alt_text: element.alt_text.clone(),
+            lang: lang.map(String::from),
+        });
+
+        if element.actual_text.is_none() {
+            for child in &element.children {
+                self.push(child, depth + 1, lang);
+            }
+        }
+    }
+}
+
+fn heading_level(role: &str) -> Option<u8> {
+    match role.as_bytes() {
+        [b'H', level @ b'1'..=b'6'] => Some(level - b'0'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::heading_level;
+    use crate::*;
+
+    static TAGGED_PDF: &[u8] = include_bytes!("../test_assets/tagged.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn accessible_export() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(TAGGED_PDF).unwrap();
+
+        let block = |depth, role: &str, heading_level, text: &str, alt_text: Option<&str>, lang| {
+            AccessibleBlock {
+                page_index: 0,
+                depth,
+                role: role.to_string(),
+                heading_level,
+                text: text.to_string(),
+                alt_text: alt_text.map(String::from),
+                lang: Some(String::from(lang)),
+            }
+        };
+
+        assert_eq!(
+            document.accessible_export().unwrap(),
+            vec![
+                block(0, "Document", None, "", None, "en-US"),
+                block(1, "H1", Some(1), "Report", None, "en-GB"),
+                // The actual text replaces the content
+                block(1, "P", None, "Hello, world.", None, "en-US"),
+                block(1, "Figure", None, "", Some("A gray rectangle"), "en-US"),
+                block(1, "Figure", None, "", None, "en-US"),
+            ]
+        );
+
+        let dummy = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(dummy.accessible_export().unwrap().is_empty());
+    }
+
+    #[test]
+    fn heading_levels() {
+        assert_eq!(heading_level("H1"), Some(1));
+        assert_eq!(heading_level("H6"), Some(6));
+        assert_eq!(heading_level("H"), None);
+        assert_eq!(heading_level("H7"), None);
+        assert_eq!(heading_level("H12"), None);
+        assert_eq!(heading_level("P"), None);
+    }
+}
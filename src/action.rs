@@ -0,0 +1,50 @@
+use crate::destination::destination;
+use crate::{ActionType, Destination};
+use pdfium_core::{ActionHandle, DocumentHandle, Library};
+
+pub(crate) fn action(core: &Library, document: &DocumentHandle, handle: &ActionHandle) -> Action {
+    let file_path = || core.get_action_file_path(handle);
+
+    match core.get_action_type(handle) {
+        ActionType::Unsupported => Action::Unsupported,
+        ActionType::GoTo => Action::GoTo {
+            destination: core
+                .get_action_destination(document, handle)
+                .and_then(|handle| destination(core, document, &handle)),
+        },
+        ActionType::RemoteGoTo => Action::RemoteGoTo {
+            file_path: file_path(),
+        },
+        ActionType::Uri => Action::Uri {
+            uri: core.get_action_uri_path(document, handle),
+        },
+        ActionType::Launch => Action::Launch {
+            file_path: file_path(),
+        },
+        ActionType::EmbeddedGoTo => Action::EmbeddedGoTo,
+    }
+}
+
+/// What happens when an outline item or link is clicked.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// An action PDFium doesn't support, like JavaScript.
+    Unsupported,
+    /// Go to a destination in the same document.
+    ///
+    /// `destination` is `None` if it doesn't point to a page of the document.
+    GoTo { destination: Option<Destination> },
+    /// Go to a destination in the document at `file_path`.
+    RemoteGoTo { file_path: String },
+    /// Open a URI, like a web page.
+    ///
+    /// The URI is not validated, it's exactly what the document contains.
+    Uri { uri: String },
+    /// Launch an application or open the file at `file_path`.
+    ///
+    /// Viewers should ask before following these, they can run arbitrary programs.
+    Launch { file_path: String },
+    /// Go to a destination in a document embedded in this document.
+    EmbeddedGoTo,
+}
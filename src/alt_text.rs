@@ -0,0 +1,206 @@
+use crate::scan::contains_image;
+use crate::{Document, Page, PdfiumError, Rect, StructElement};
+use std::collections::BTreeSet;
+
+impl Document<'_, '_> {
+    /// The images and figures of the document that don't have an alternate description,
+    /// for accessibility audits like WCAG.
+    ///
+    /// See [`Page::alt_text_audit`].
+    pub fn alt_text_audit(&self) -> Result<Vec<MissingAltText>, PdfiumError> {
+        let mut missing = Vec::new();
+
+        for index in 0..self.page_count() {
+            missing.extend(self.page(index)?.alt_text_audit(index));
+        }
+
+        Ok(missing)
+    }
+}
+
+impl Page<'_, '_> {
+    /// The images and figures of the page that don't have an alternate description.
+    ///
+    /// `Figure` structure elements need an alt text or actual text. Image objects, and form objects
+    /// with images, need to be part of a structure element that has one, unless they are marked
+    /// as artifacts, decorations that are not part of the content.
+    /// Images of untagged pages never have an alternate description.
+    ///
+    /// `page_index` is used for [`MissingAltText::page_index`], it should be the index of the page.
+    pub fn alt_text_audit(&self, page_index: usize) -> Vec<MissingAltText> {
+        let tree = self.struct_tree();
+
+        let mut described = BTreeSet::new();
+        let mut figures = Vec::new();
+        for element in &tree {
+            visit(element, false, &mut described, &mut figures);
+        }
+
+        let images: Vec<_> = (0..self.core.get_page_object_count(&self.handle))
+            .filter_map(|index| self.core.get_page_object(&self.handle, index))
+            .filter(|object| contains_image(self.core, object) && !self.is_artifact(object))
+            .map(|object| {
+                (
+                    self.core.get_page_object_marked_content_id(&object),
+                    self.core.get_page_object_bounds(&object),
+                )
+            })
+            .collect();
+
+        let figures = figures.into_iter().map(|ids| MissingAltText {
+            page_index,
+            kind: MissingAltTextKind::Figure,
+            bounds: self.marked_content_bounds(&ids),
+        });
+        let images = images
+            .iter()
+            .filter(|(id, _)| !matches!(id, Some(id) if described.contains(id)))
+            .map(|(_, bounds)| MissingAltText {
+                page_index,
+                kind: MissingAltTextKind::Image,
+                bounds: *bounds,
+            });
+
+        figures.chain(images).collect()
+    }
+
+    fn is_artifact(&self, object: &pdfium_core::PageObjectHandle) -> bool {
+        (0..self.core.get_page_object_mark_count(object))
+            .filter_map(|index| self.core.get_page_object_mark(object, index))
+            .any(|mark| self.core.get_page_object_mark_name(&mark) == "Artifact")
+    }
+
+    /// The union of the bounds of the objects of the page that are part of the marked content with `ids`.
+    fn marked_content_bounds(&self, ids: &BTreeSet<i32>) -> Option<Rect> {
+        (0..self.core.get_page_object_count(&self.handle))
+            .filter_map(|index| self.core.get_page_object(&self.handle, index))
+            .filter(|object| {
+                matches!(
+                    self.core.get_page_object_marked_content_id(object),
+                    Some(id) if ids.contains(&id)
+                )
+            })
+            .filter_map(|object| self.core.get_page_object_bounds(&object))
+            .reduce(|a, b| Rect {
+                left: a.left.min(b.left),
+                bottom: a.bottom.min(b.bottom),
+                right: a.right.max(b.right),
+                top: a.top.max(b.top),
+            })
+    }
+}
+
+/// Collect the marked content that is described by an element or its ancestors into `described`
+/// and the marked content of every figure without a description into `figures`.
+fn visit(
+    element: &StructElement,
+    described_by_ancestor: bool,
+    described: &mut BTreeSet<i32>,
+    figures: &mut Vec<BTreeSet<i32>>,
+) {
+    let has_description = element.alt_text.is_some() || element.actual_text.is_some();
+    let is_described = described_by_ancestor || has_description;
+
+    if is_described {
+        described.extend(&element.marked_content_ids);
+    }
+
+    if element.element_type == "Figure" && !has_description {
+        let mut ids = BTreeSet::new();
+        collect_marked_content_ids(element, &mut ids);
+        figures.push(ids);
+    }
+
+    for child in &element.children {
+        visit(child, is_described, described, figures);
+    }
+}
+
+fn collect_marked_content_ids(element: &StructElement, ids: &mut BTreeSet<i32>) {
+    ids.extend(&element.marked_content_ids);
+
+    for child in &element.children {
+        collect_marked_content_ids(child, ids);
+    }
+}
+
+/// An image or figure without an alternate description.
+///
+/// Created using [`Document::alt_text_audit`] and [`Page::alt_text_audit`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MissingAltText {
+    /// The page of the image or figure, 0 for the first page.
+    pub page_index: usize,
+    pub kind: MissingAltTextKind,
+    /// The area of the page covered by the image or figure.
+    ///
+    /// `None` if it couldn't be calculated, like for figures without content on the page.
+    pub bounds: Option<Rect>,
+}
+
+/// What is missing an alternate description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MissingAltTextKind {
+    /// An image object that is not part of a structure element with an alternate description.
+    Image,
+    /// A `Figure` structure element.
+    Figure,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TAGGED_PDF: &[u8] = include_bytes!("../test_assets/tagged.pdf");
+    static SCANNED_PDF: &[u8] = include_bytes!("../test_assets/scanned.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn alt_text_audit() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(TAGGED_PDF).unwrap();
+
+        // Only the second figure doesn't have alt text
+        let bounds = Some(Rect {
+            left: 300.0,
+            bottom: 600.0,
+            right: 400.0,
+            top: 650.0,
+        });
+        assert_eq!(
+            document.alt_text_audit().unwrap(),
+            vec![
+                MissingAltText {
+                    page_index: 0,
+                    kind: MissingAltTextKind::Figure,
+                    bounds,
+                },
+                MissingAltText {
+                    page_index: 0,
+                    kind: MissingAltTextKind::Image,
+                    bounds,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn untagged_alt_text_audit() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let document = library.document_from_bytes(SCANNED_PDF).unwrap();
+        let missing = document.alt_text_audit().unwrap();
+        assert_eq!(missing.len(), 2);
+        assert!(missing
+            .iter()
+            .all(|missing| missing.kind == MissingAltTextKind::Image));
+        assert_eq!(missing[1].page_index, 1);
+
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(document.alt_text_audit().unwrap().is_empty());
+    }
+}
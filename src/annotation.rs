@@ -0,0 +1,412 @@
+use crate::{AnnotationBorder, AnnotationColorType, AnnotationSubtype, Color, Document, Page};
+use crate::{PdfiumError, Point, QuadPoints, Rect};
+use std::ops::Range;
+
+impl Page<'_, '_> {
+    /// The annotations on the page, in drawing order.
+    pub fn annotations(&self) -> Vec<Annotation<'_, '_>> {
+        (0..self.core.get_annotation_count(&self.handle))
+            .filter_map(|index| self.core.get_annotation(&self.handle, index))
+            .map(|handle| Annotation {
+                handle,
+                page: &self.handle,
+                core: self.core,
+            })
+            .collect()
+    }
+
+    /// Remove the annotation at `index` in [`Page::annotations`].
+    ///
+    /// The annotation is only removed from the document when it's saved.
+    pub fn remove_annotation(&mut self, index: usize) -> Result<(), PdfiumError> {
+        self.core.remove_annotation(&mut self.handle, index)
+    }
+
+    /// Add a highlight annotation for each range of character indices, like the results of a text search.
+    ///
+    /// Ranges without any visible characters are skipped.
+    pub fn highlight(&mut self, ranges: &[Range<usize>], color: &Color) -> Result<(), PdfiumError> {
+        let text_page = self.core.load_cached_text_page(&self.handle)?;
+        let highlights: Vec<Vec<Rect>> = ranges
+            .iter()
+            .map(|range| {
+                self.core
+                    .get_text_rects(&text_page, range.start, range.len())
+            })
+            .filter(|rects| !rects.is_empty())
+            .collect();
+        drop(text_page);
+
+        for rects in highlights {
+            let mut annotation = self
+                .core
+                .create_annotation(&mut self.handle, AnnotationSubtype::Highlight)?;
+
+            self.core
+                .set_annotation_color(&mut annotation, AnnotationColorType::Stroke, color)?;
+            for rect in &rects {
+                self.core.append_annotation_attachment_points(
+                    &mut annotation,
+                    &QuadPoints::from_rect(rect),
+                )?;
+            }
+
+            let bounds = rects.iter().fold(rects[0], |bounds, rect| Rect {
+                left: bounds.left.min(rect.left),
+                bottom: bounds.bottom.min(rect.bottom),
+                right: bounds.right.max(rect.right),
+                top: bounds.top.max(rect.top),
+            });
+            self.core.set_annotation_rect(&mut annotation, &bounds)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Document<'_, '_> {
+    /// The annotations of all pages, in page order.
+    ///
+    /// Together with [`Document::import_annotations`] this copies annotations between documents,
+    /// with the `serde` feature they can also be stored separately from the document, for example as JSON.
+    pub fn export_annotations(&self) -> Result<Vec<AnnotationData>, PdfiumError> {
+        let mut annotations = Vec::new();
+
+        for page_index in 0..self.page_count() {
+            let page = self.page(page_index)?;
+            annotations.extend(
+                page.annotations()
+                    .iter()
+                    .map(|annotation| annotation.data(page_index)),
+            );
+        }
+
+        Ok(annotations)
+    }
+
+    /// Add annotations to the document's pages, usually ones exported from another copy of the document.
+    ///
+    /// Annotations with a subtype PDFium can't create, like line and polygon annotations, are skipped.
+    /// Reply links ([`AnnotationData::in_reply_to`]) are not restored because PDFium can't set them.
+    ///
+    /// Returns the number of annotations that were added.
+    pub fn import_annotations(&self, annotations: &[AnnotationData]) -> Result<usize, PdfiumError> {
+        let mut count = 0;
+
+        for data in annotations {
+            let mut page = self.page(data.page)?;
+            let core = page.core;
+
+            let mut annotation = match core.create_annotation(&mut page.handle, data.subtype) {
+                Ok(annotation) => annotation,
+                Err(_) => continue,
+            };
+
+            if let Some(rect) = &data.rect {
+                core.set_annotation_rect(&mut annotation, rect)?;
+            }
+            core.set_annotation_flags(&mut annotation, data.flags)?;
+
+            let strings = [
+                ("Contents", &data.contents),
+                ("T", &data.author),
+                ("NM", &data.name),
+            ];
+            for (key, value) in &strings {
+                if let Some(value) = value {
+                    core.set_annotation_string_value(&mut annotation, key, value)?;
+                }
+            }
+
+            let colors = [
+                (AnnotationColorType::Stroke, &data.stroke_color),
+                (AnnotationColorType::Interior, &data.interior_color),
+            ];
+            for (color_type, color) in &colors {
+                if let Some(color) = color {
+                    core.set_annotation_color(&mut annotation, *color_type, color)?;
+                }
+            }
+
+            if let Some(border) = &data.border {
+                core.set_annotation_border(&mut annotation, border)?;
+            }
+            for stroke in &data.ink_strokes {
+                core.add_annotation_ink_stroke(&mut annotation, stroke)?;
+            }
+            for quad_points in &data.attachment_points {
+                core.append_annotation_attachment_points(&mut annotation, quad_points)?;
+            }
+
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+/// The properties of an annotation, independent of the document it is in.
+///
+/// Created using [`Document::export_annotations`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnnotationData {
+    /// Index of the annotation's page.
+    pub page: usize,
+    pub subtype: AnnotationSubtype,
+    pub rect: Option<Rect>,
+    /// See [`annotation_flags`](crate::annotation_flags).
+    pub flags: i32,
+    pub contents: Option<String>,
+    pub author: Option<String>,
+    /// The unique name of the annotation in its page.
+    pub name: Option<String>,
+    pub stroke_color: Option<Color>,
+    pub interior_color: Option<Color>,
+    pub border: Option<AnnotationBorder>,
+    pub ink_strokes: Vec<Vec<Point>>,
+    /// The areas of a text markup annotation.
+    pub attachment_points: Vec<QuadPoints>,
+    /// Index of the annotation this annotation is a reply to, in the same page.
+    pub in_reply_to: Option<usize>,
+}
+
+/// An annotation on a page, like a comment, a highlight or a link.
+pub struct Annotation<'page, 'library> {
+    handle: pdfium_core::AnnotationHandle<'page, 'library>,
+    page: &'page pdfium_core::PageHandle<'page, 'library>,
+    core: &'library pdfium_core::Library,
+}
+
+impl<'page, 'library> Annotation<'page, 'library> {
+    pub fn subtype(&self) -> AnnotationSubtype {
+        self.core.get_annotation_subtype(&self.handle)
+    }
+
+    /// The position of the annotation in [`Page::annotations`].
+    pub fn index(&self) -> Option<usize> {
+        self.core.get_annotation_index(self.page, &self.handle)
+    }
+
+    pub fn rect(&self) -> Option<Rect> {
+        self.core.get_annotation_rect(&self.handle)
+    }
+
+    pub fn set_rect(&mut self, rect: &Rect) -> Result<(), PdfiumError> {
+        self.core.set_annotation_rect(&mut self.handle, rect)
+    }
+
+    pub fn color(&self, color_type: AnnotationColorType) -> Option<Color> {
+        self.core.get_annotation_color(&self.handle, color_type)
+    }
+
+    pub fn set_color(
+        &mut self,
+        color_type: AnnotationColorType,
+        color: &Color,
+    ) -> Result<(), PdfiumError> {
+        self.core
+            .set_annotation_color(&mut self.handle, color_type, color)
+    }
+
+    /// See [`annotation_flags`](crate::annotation_flags).
+    pub fn flags(&self) -> i32 {
+        self.core.get_annotation_flags(&self.handle)
+    }
+
+    /// See [`annotation_flags`](crate::annotation_flags).
+    pub fn set_flags(&mut self, flags: i32) -> Result<(), PdfiumError> {
+        self.core.set_annotation_flags(&mut self.handle, flags)
+    }
+
+    pub fn border(&self) -> Option<AnnotationBorder> {
+        self.core.get_annotation_border(&self.handle)
+    }
+
+    pub fn set_border(&mut self, border: &AnnotationBorder) -> Result<(), PdfiumError> {
+        self.core.set_annotation_border(&mut self.handle, border)
+    }
+
+    pub fn contents(&self) -> Option<String> {
+        self.core
+            .get_annotation_string_value(&self.handle, "Contents")
+    }
+
+    pub fn author(&self) -> Option<String> {
+        self.core.get_annotation_string_value(&self.handle, "T")
+    }
+
+    /// The strokes of an ink annotation.
+    pub fn ink_strokes(&self) -> Vec<Vec<Point>> {
+        (0..self.core.get_annotation_ink_list_count(&self.handle))
+            .map(|index| self.core.get_annotation_ink_list_path(&self.handle, index))
+            .collect()
+    }
+
+    /// The vertices of a polygon or polyline annotation.
+    pub fn vertices(&self) -> Vec<Point> {
+        self.core.get_annotation_vertices(&self.handle)
+    }
+
+    /// The start and end points of a line annotation.
+    pub fn line(&self) -> Option<(Point, Point)> {
+        self.core.get_annotation_line(&self.handle)
+    }
+
+    /// The annotation this annotation is a reply to.
+    pub fn in_reply_to(&self) -> Option<Annotation<'page, 'library>> {
+        let handle = self.core.get_annotation_in_reply_to(&self.handle)?;
+
+        Some(self.linked(handle))
+    }
+
+    /// The popup annotation that displays the text of this annotation.
+    pub fn popup(&self) -> Option<Annotation<'page, 'library>> {
+        let handle = self.core.get_annotation_popup(&self.handle)?;
+
+        Some(self.linked(handle))
+    }
+
+    fn data(&self, page: usize) -> AnnotationData {
+        let core = self.core;
+
+        AnnotationData {
+            page,
+            subtype: self.subtype(),
+            rect: self.rect(),
+            flags: self.flags(),
+            contents: self.contents(),
+            author: self.author(),
+            name: core.get_annotation_string_value(&self.handle, "NM"),
+            stroke_color: self.color(AnnotationColorType::Stroke),
+            interior_color: self.color(AnnotationColorType::Interior),
+            border: self.border(),
+            ink_strokes: self.ink_strokes(),
+            attachment_points: (0..core.get_annotation_attachment_points_count(&self.handle))
+                .filter_map(|index| core.get_annotation_attachment_points(&self.handle, index))
+                .collect(),
+            in_reply_to: self.in_reply_to().and_then(|annotation| annotation.index()),
+        }
+    }
+
+    fn linked(
+        &self,
+        handle: pdfium_core::AnnotationHandle<'page, 'library>,
+    ) -> Annotation<'page, 'library> {
+        Annotation {
+            handle,
+            page: self.page,
+            core: self.core,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static ANNOTATIONS_PDF: &[u8] = include_bytes!("../test_assets/annotations.pdf");
+
+    #[test]
+    fn annotations() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(ANNOTATIONS_PDF).unwrap();
+        let page = document.page(0).unwrap();
+        let annotations = page.annotations();
+
+        assert_eq!(annotations.len(), 10);
+        assert_eq!(annotations[3].subtype(), AnnotationSubtype::Square);
+        assert_eq!(annotations[3].border().unwrap().width, 2.0);
+        assert_eq!(annotations[4].ink_strokes().len(), 2);
+        assert_eq!(annotations[6].vertices().len(), 3);
+
+        let comment = annotations[2].in_reply_to().unwrap();
+        assert_eq!(comment.index(), Some(0));
+        assert_eq!(comment.popup().unwrap().index(), Some(1));
+    }
+
+    #[test]
+    fn edit_annotation() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(ANNOTATIONS_PDF).unwrap();
+        let page = document.page(0).unwrap();
+        let mut annotations = page.annotations();
+
+        let rect = Rect {
+            left: 10.0,
+            bottom: 10.0,
+            right: 20.0,
+            top: 20.0,
+        };
+        annotations[3].set_rect(&rect).unwrap();
+        annotations[3]
+            .set_flags(annotation_flags::PRINT | annotation_flags::LOCKED)
+            .unwrap();
+
+        let square = &page.annotations()[3];
+        assert_eq!(square.rect(), Some(rect));
+        assert_eq!(
+            square.flags(),
+            annotation_flags::PRINT | annotation_flags::LOCKED
+        );
+    }
+
+    #[test]
+    fn highlight() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let mut page = document.page(0).unwrap();
+
+        let yellow = Color::rgb(255, 255, 0);
+        page.highlight(&[0..5, 6..9, 1000..1005], &yellow).unwrap();
+
+        let annotations = page.annotations();
+        assert_eq!(annotations.len(), 2);
+        for annotation in &annotations {
+            assert_eq!(annotation.subtype(), AnnotationSubtype::Highlight);
+            assert_eq!(annotation.color(AnnotationColorType::Stroke), Some(yellow));
+        }
+    }
+
+    #[test]
+    fn export_import_annotations() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(ANNOTATIONS_PDF).unwrap();
+
+        let exported = document.export_annotations().unwrap();
+        assert_eq!(exported.len(), 10);
+        assert_eq!(exported[0].contents.as_deref(), Some("Comment"));
+        assert_eq!(exported[0].author.as_deref(), Some("Alice"));
+        assert_eq!(exported[2].in_reply_to, Some(0));
+        assert_eq!(exported[4].ink_strokes.len(), 2);
+        assert_eq!(exported[9].attachment_points.len(), 1);
+
+        let copy = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let imported = copy.import_annotations(&exported).unwrap();
+        assert!(imported > 0);
+
+        let reimported = copy.export_annotations().unwrap();
+        assert_eq!(reimported.len(), imported);
+        assert_eq!(reimported[0].contents, exported[0].contents);
+        assert_eq!(reimported[0].author, exported[0].author);
+        assert_eq!(reimported[0].rect, exported[0].rect);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn annotations_json() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(ANNOTATIONS_PDF).unwrap();
+
+        let exported = document.export_annotations().unwrap();
+        let json = serde_json::to_string(&exported).unwrap();
+        let parsed: Vec<AnnotationData> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, exported);
+    }
+}
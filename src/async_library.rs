@@ -0,0 +1,159 @@
+use crate::worker::{JobFuture, RenderedPage, Worker};
+use crate::{AccessibleBlock, Document, Library, Metadata, PdfiumError};
+use std::sync::Arc;
+
+/// A library that runs on its own thread, with calls that return futures.
+///
+/// PDFium is not thread safe, so every call is sent to a dedicated thread that is the only one using the library.
+/// The futures don't depend on an async runtime, they can be awaited with tokio or any other executor
+/// without blocking its threads.
+///
+/// The thread stops when the library and all of its documents are dropped.
+#[derive(Clone)]
+pub struct AsyncLibrary {
+    worker: Arc<Worker>,
+}
+
+impl AsyncLibrary {
+    /// Start the thread of the library.
+    ///
+    /// Returns `None` if the library can't be initialized, like when a [`Library`] already exists.
+    pub fn new() -> Option<AsyncLibrary> {
+        Some(AsyncLibrary {
+            worker: Arc::new(Worker::start(None)?),
+        })
+    }
+
+    /// Open a document from its bytes, see [`Library::document_from_bytes`].
+    ///
+    /// The document is open until the returned [`AsyncDocument`] is dropped.
+    pub fn open(&self, buffer: impl Into<Arc<[u8]>>) -> JobFuture<AsyncDocument> {
+        let buffer = buffer.into();
+        let worker = self.worker.clone();
+
+        self.worker.run(move |documents| {
+            let document = documents.library.document_from_arc(buffer)?;
+
+            Ok(AsyncDocument {
+                id: documents.insert(document),
+                worker,
+            })
+        })
+    }
+
+    /// Run `job` with the library on its thread.
+    pub fn run<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&Library) -> Result<T, PdfiumError> + Send + 'static,
+    ) -> JobFuture<T> {
+        self.worker.run(move |documents| job(documents.library))
+    }
+}
+
+/// A document that is open on the thread of an [`AsyncLibrary`].
+///
+/// The document is closed when it's dropped.
+pub struct AsyncDocument {
+    id: u64,
+    worker: Arc<Worker>,
+}
+
+impl AsyncDocument {
+    /// The number of pages in the document.
+    pub fn page_count(&self) -> JobFuture<usize> {
+        self.run(|document| Ok(document.page_count()))
+    }
+
+    /// Render a page on a white background into a new buffer of `width` by `height` pixels.
+    pub fn render_page(
+        &self,
+        index: usize,
+        width: usize,
+        height: usize,
+    ) -> JobFuture<RenderedPage> {
+        self.run(move |document| document.render_page(index, width, height))
+    }
+
+    /// The metadata of the document, see [`Document::metadata`].
+    pub fn metadata(&self) -> JobFuture<Metadata> {
+        self.run(|document| Ok(document.metadata()))
+    }
+
+    /// Extract the content of a tagged document in reading order, see [`Document::accessible_export`].
+    pub fn accessible_export(&self) -> JobFuture<Vec<AccessibleBlock>> {
+        self.run(|document| document.accessible_export())
+    }
+
+    /// Run `job` with the document on the thread of the library.
+    pub fn run<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&Document) -> Result<T, PdfiumError> + Send + 'static,
+    ) -> JobFuture<T> {
+        let id = self.id;
+
+        self.worker.run(move |documents| job(documents.get(id)?))
+    }
+}
+
+impl Drop for AsyncDocument {
+    fn drop(&mut self) {
+        let id = self.id;
+
+        self.worker
+            .send(Box::new(move |documents| documents.remove(id)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn async_library() {
+        let _guard = TEST_LOCK.lock();
+        let library = AsyncLibrary::new().unwrap();
+
+        let document = block_on(library.open(DUMMY_PDF)).unwrap();
+        assert_eq!(block_on(document.page_count()), Ok(1));
+
+        let page = block_on(document.render_page(0, 20, 30)).unwrap();
+        assert_eq!((page.width, page.height), (20, 30));
+        assert_eq!(page.pixels.len(), 20 * 30 * 4);
+
+        assert!(block_on(document.render_page(1, 20, 30)).is_err());
+        assert!(block_on(library.open(&b"not a pdf"[..])).is_err());
+
+        // The library is used on its own thread only
+        assert!(Library::init().is_none());
+        drop(document);
+        drop(library);
+        assert!(Library::init().is_some());
+    }
+}
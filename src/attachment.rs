@@ -0,0 +1,188 @@
+use crate::{Document, PdfDate, PdfiumError};
+
+impl Document<'_, '_> {
+    /// The files embedded in the document, like the XML of a Factur-X invoice.
+    pub fn attachments(&self) -> Vec<Attachment<'_, '_>> {
+        (0..self.core.get_attachment_count(&self.handle))
+            .filter_map(|index| self.core.get_attachment(&self.handle, index))
+            .map(|handle| Attachment {
+                handle,
+                core: self.core,
+            })
+            .collect()
+    }
+
+    /// Embed a file in the document, it's saved with the document.
+    ///
+    /// Fails if `name` is empty or the document already has an attachment with that name.
+    pub fn add_attachment(
+        &mut self,
+        name: &str,
+        data: &[u8],
+    ) -> Result<Attachment<'_, '_>, PdfiumError> {
+        let mut handle = self.core.add_attachment(&mut self.handle, name)?;
+        self.core.set_attachment_file(&mut handle, data)?;
+
+        Ok(Attachment {
+            handle,
+            core: self.core,
+        })
+    }
+
+    /// Remove the attachment at `index` in [`Document::attachments`].
+    ///
+    /// The data of the file is only removed from the document when it's saved.
+    pub fn delete_attachment(&mut self, index: usize) -> Result<(), PdfiumError> {
+        self.core.delete_attachment(&mut self.handle, index)
+    }
+}
+
+/// A file embedded in a document.
+pub struct Attachment<'data, 'library> {
+    handle: pdfium_core::AttachmentHandle<'data, 'library>,
+    core: &'library pdfium_core::Library,
+}
+
+impl Attachment<'_, '_> {
+    /// The name of the file, which is not a path.
+    pub fn name(&self) -> String {
+        self.core.get_attachment_name(&self.handle)
+    }
+
+    /// The MIME type of the file, like `text/xml`.
+    pub fn mime_type(&self) -> Option<String> {
+        Some(self.core.get_attachment_mime_type(&self.handle)).filter(|x| !x.is_empty())
+    }
+
+    /// The contents of the file.
+    ///
+    /// Returns `None` if the document doesn't contain a readable file.
+    pub fn data(&self) -> Option<Vec<u8>> {
+        self.core.get_attachment_file(&self.handle)
+    }
+
+    /// The size of the file in bytes.
+    pub fn size(&self) -> Option<usize> {
+        self.data().map(|data| data.len())
+    }
+
+    /// The MD5 hash of the file stored in the document, as lowercase hexadecimal digits.
+    ///
+    /// The hash is not checked against the data.
+    pub fn checksum(&self) -> Option<String> {
+        self.string_value("CheckSum")
+            .map(|checksum| checksum.to_lowercase())
+    }
+
+    pub fn creation_date(&self) -> Option<PdfDate> {
+        self.string_value("CreationDate")
+            .and_then(|date| PdfDate::parse(&date))
+    }
+
+    pub fn modification_date(&self) -> Option<PdfDate> {
+        self.string_value("ModDate")
+            .and_then(|date| PdfDate::parse(&date))
+    }
+
+    /// Replace the contents of the file.
+    ///
+    /// The creation date and checksum are updated, the modification date is removed.
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), PdfiumError> {
+        self.core.set_attachment_file(&mut self.handle, data)
+    }
+
+    /// Set a string parameter of the file, like `ModDate` in the PDF date format.
+    pub fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        self.core
+            .set_attachment_string_value(&mut self.handle, key, value)
+    }
+
+    fn string_value(&self, key: &str) -> Option<String> {
+        Some(self.core.get_attachment_string_value(&self.handle, key)).filter(|x| !x.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static ATTACHMENTS_PDF: &[u8] = include_bytes!("../test_assets/attachments.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn attachments() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(ATTACHMENTS_PDF).unwrap();
+
+        let attachments = document.attachments();
+        assert_eq!(attachments.len(), 2);
+
+        let invoice = &attachments[0];
+        assert_eq!(invoice.name(), "factur-x.xml");
+        assert_eq!(invoice.mime_type().as_deref(), Some("text/xml"));
+        assert_eq!(invoice.size(), Some(73));
+        assert_eq!(
+            invoice.checksum().as_deref(),
+            Some("723ee463091d40193468e909c02ad5a9")
+        );
+        assert_eq!(
+            invoice.creation_date(),
+            Some(PdfDate {
+                year: 2024,
+                month: 1,
+                day: 5,
+                hour: 12,
+                minute: 0,
+                second: 0,
+                utc_offset: Some(60)
+            })
+        );
+        assert_eq!(invoice.modification_date().map(|date| date.day), Some(6));
+        assert!(invoice.data().unwrap().ends_with(b"</Invoice>\n"));
+
+        let notes = &attachments[1];
+        assert_eq!(notes.name(), "notes.txt");
+        assert_eq!(notes.mime_type(), None);
+        assert_eq!(notes.data().as_deref(), Some(&b"Notes\n"[..]));
+        assert_eq!(notes.checksum(), None);
+        assert_eq!(notes.creation_date(), None);
+    }
+
+    #[test]
+    fn add_attachment() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let mut document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        let mut attachment = document
+            .add_attachment("factur-x.xml", b"<Invoice/>")
+            .unwrap();
+        attachment
+            .set_string_value("ModDate", "D:20240201000000Z")
+            .unwrap();
+        assert!(attachment.checksum().is_some());
+        assert!(attachment.creation_date().is_some());
+        assert_eq!(
+            attachment.modification_date().map(|date| date.month),
+            Some(2)
+        );
+
+        assert!(document.add_attachment("factur-x.xml", b"").is_err());
+
+        let mut bytes = Vec::new();
+        document.save_to(&mut bytes).unwrap();
+        let mut document = library.document_from_bytes(&bytes).unwrap();
+        let mut attachments = document.attachments();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].name(), "factur-x.xml");
+        assert_eq!(attachments[0].data().as_deref(), Some(&b"<Invoice/>"[..]));
+
+        attachments[0].set_data(b"<Invoice></Invoice>").unwrap();
+        assert_eq!(attachments[0].size(), Some(19));
+        assert_eq!(attachments[0].modification_date(), None);
+
+        document.delete_attachment(0).unwrap();
+        assert!(document.attachments().is_empty());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{Document, Library, PdfiumError};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Keeps recently opened documents, so a document that is opened again isn't parsed again.
+///
+/// Documents are found by their content, not by a name, so the same bytes from different sources share an entry.
+/// When the total size of the documents is over the budget, the least recently used documents are closed.
+///
+/// The budget measures the size of the files of the documents, not the memory PDFium uses for their parsed objects
+/// and open pages, which can be several times larger and grows as pages are loaded.
+///
+/// With [`DocumentCache::set_page_cache_capacity`] the documents also keep their recently used pages open,
+/// with their text pages, see [`Document::set_page_cache_capacity`].
+///
+/// ## Examples
+/// ```
+/// use pdfium_rs::{DocumentCache, Library};
+/// # static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+///
+/// let library = Library::init().unwrap();
+/// let mut cache = DocumentCache::new(&library, 64 * 1024 * 1024);
+///
+/// for _ in 0..3 {
+///     let document = cache.get(DUMMY_PDF).unwrap();
+///     assert_eq!(document.page_count(), 1);
+/// }
+/// assert_eq!(cache.len(), 1);
+/// ```
+pub struct DocumentCache<'library> {
+    library: &'library Library,
+    budget: usize,
+    page_cache_capacity: usize,
+    /// From the least to the most recently used.
+    entries: Vec<Entry<'library>>,
+}
+
+struct Entry<'library> {
+    hash: u64,
+    data: Arc<[u8]>,
+    document: Document<'static, 'library>,
+}
+
+impl<'library> DocumentCache<'library> {
+    /// Create a cache that keeps documents with a total size of up to `budget` bytes.
+    ///
+    /// The pages of the documents are not kept, see [`DocumentCache::set_page_cache_capacity`].
+    pub fn new(library: &'library Library, budget: usize) -> Self {
+        DocumentCache {
+            library,
+            budget,
+            page_cache_capacity: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Get the document with the content of `buffer`, it's opened if it isn't in the cache.
+    ///
+    /// The document is kept even if its size is over the budget, until another document is opened.
+    /// Documents that fail to open are not cached.
+    pub fn get(&mut self, buffer: &[u8]) -> Result<&Document<'static, 'library>, PdfiumError> {
+        let hash = content_hash(buffer);
+
+        let index = match self
+            .entries
+            .iter()
+            .position(|entry| entry.hash == hash && &*entry.data == buffer)
+        {
+            Some(index) => {
+                let entry = self.entries.remove(index);
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+            None => {
+                let data: Arc<[u8]> = buffer.into();
+                let document = self.library.document_from_arc(data.clone())?;
+                document.set_page_cache_capacity(self.page_cache_capacity);
+
+                self.entries.push(Entry {
+                    hash,
+                    data,
+                    document,
+                });
+                self.evict();
+                self.entries.len() - 1
+            }
+        };
+
+        Ok(&self.entries[index].document)
+    }
+
+    /// Check if the document with the content of `buffer` is in the cache.
+    pub fn contains(&self, buffer: &[u8]) -> bool {
+        let hash = content_hash(buffer);
+
+        self.entries
+            .iter()
+            .any(|entry| entry.hash == hash && &*entry.data == buffer)
+    }
+
+    /// Close the document with the content of `buffer`, returns `false` if it wasn't in the cache.
+    pub fn remove(&mut self, buffer: &[u8]) -> bool {
+        let hash = content_hash(buffer);
+        let length = self.entries.len();
+
+        self.entries
+            .retain(|entry| entry.hash != hash || &*entry.data != buffer);
+        self.entries.len() != length
+    }
+
+    /// Close all documents.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// The number of documents in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The total size of the files of the documents in the cache in bytes.
+    pub fn size(&self) -> usize {
+        self.entries.iter().map(|entry| entry.data.len()).sum()
+    }
+
+    /// The maximum total size of the files of the documents in bytes.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Change the budget, documents are closed if the cache is over the new budget.
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict();
+    }
+
+    /// Set how many pages every document keeps open between calls to [`DocumentCache::get`],
+    /// see [`Document::set_page_cache_capacity`]. 0, the default, closes pages when they are dropped.
+    ///
+    /// The pages keep their text pages once their text is extracted, so both are only parsed once.
+    /// Open pages are not counted in the budget.
+    pub fn set_page_cache_capacity(&mut self, capacity: usize) {
+        self.page_cache_capacity = capacity;
+
+        for entry in &self.entries {
+            entry.document.set_page_cache_capacity(capacity);
+        }
+    }
+
+    /// The number of pages every document keeps open, see [`DocumentCache::set_page_cache_capacity`].
+    pub fn page_cache_capacity(&self) -> usize {
+        self.page_cache_capacity
+    }
+
+    /// Close the least recently used documents until the cache fits the budget, except the most recent one.
+    fn evict(&mut self) {
+        let mut size = self.size();
+
+        while size > self.budget && self.entries.len() > 1 {
+            let entry = self.entries.remove(0);
+            size -= entry.data.len();
+        }
+    }
+}
+
+fn content_hash(buffer: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    buffer.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../test_assets/forms.pdf");
+
+    #[test]
+    fn document_cache() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let budget = DUMMY_PDF.len() + FORMS_PDF.len();
+        let mut cache = DocumentCache::new(&library, budget);
+
+        cache.get(DUMMY_PDF).unwrap();
+        cache.get(FORMS_PDF).unwrap();
+        // The same content from another buffer
+        let copy = DUMMY_PDF.to_vec();
+        cache.get(&copy).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.size(), budget);
+
+        assert!(cache.get(b"not a pdf").is_err());
+        assert_eq!(cache.len(), 2);
+
+        // FORMS_PDF is the least recently used
+        cache.set_budget(DUMMY_PDF.len());
+        assert!(cache.contains(DUMMY_PDF));
+        assert!(!cache.contains(FORMS_PDF));
+
+        // The most recent document is kept over the budget
+        cache.set_budget(0);
+        assert_eq!(cache.len(), 1);
+
+        assert!(cache.remove(DUMMY_PDF));
+        assert!(!cache.remove(DUMMY_PDF));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn page_cache_capacity() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let mut cache = DocumentCache::new(&library, usize::MAX);
+        assert_eq!(cache.page_cache_capacity(), 0);
+
+        let text = cache
+            .get(DUMMY_PDF)
+            .unwrap()
+            .page(0)
+            .unwrap()
+            .text()
+            .unwrap();
+        cache.set_page_cache_capacity(4);
+        assert_eq!(cache.page_cache_capacity(), 4);
+
+        // The page and its text page are kept between calls
+        for _ in 0..3 {
+            let page = cache.get(DUMMY_PDF).unwrap().page(0).unwrap();
+            assert_eq!(page.text().unwrap(), text);
+        }
+        let page = cache.get(FORMS_PDF).unwrap().page(0);
+        assert!(page.is_ok());
+    }
+}
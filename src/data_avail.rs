@@ -0,0 +1,130 @@
+use crate::{DataSource, Document, Library, OpenOptions, PdfiumError};
+use std::ffi::CStr;
+
+impl Library {
+    /// Start opening a document that is still being downloaded, to show its first pages before the rest arrives.
+    ///
+    /// `length` is the size of the whole document in bytes.
+    pub fn partial_document<'data>(
+        &self,
+        source: impl DataSource + 'data,
+        length: usize,
+    ) -> Result<PartialDocument<'data, '_>, PdfiumError> {
+        let handle = self.core.create_data_availability(source, length)?;
+
+        Ok(PartialDocument {
+            handle,
+            core: &self.core,
+        })
+    }
+}
+
+/// A document that is still being downloaded.
+///
+/// Created using [`Library::partial_document`].
+///
+/// Call [`PartialDocument::is_available`] whenever new data arrives, it requests the missing parts
+/// from the [`DataSource`]. Once it returns `true` the document can be opened, and pages can be loaded
+/// once [`PartialDocument::is_page_available`] returns `true` for them.
+pub struct PartialDocument<'data, 'library> {
+    handle: pdfium_core::DataAvailabilityHandle<'data, 'library>,
+    core: &'library pdfium_core::Library,
+}
+
+impl<'library> PartialDocument<'_, 'library> {
+    /// Check if the document is linearized, so its first page is available before the rest.
+    ///
+    /// Returns `None` if less than 1 KiB of the document is available.
+    pub fn is_linearized(&self) -> Option<bool> {
+        self.core.is_linearized(&self.handle)
+    }
+
+    /// Check if enough of the document is available to open it with [`PartialDocument::document`].
+    pub fn is_available(&self) -> Result<bool, PdfiumError> {
+        self.core.is_document_available(&self.handle)
+    }
+
+    /// Open the document, once [`PartialDocument::is_available`] returns `true`.
+    ///
+    /// Use [`OpenOptions::open_partial`] to open it with a password.
+    pub fn document(&self) -> Result<Document<'_, 'library>, PdfiumError> {
+        OpenOptions::new().open_partial(self)
+    }
+
+    pub(crate) fn available_document(
+        &self,
+        password: Option<&CStr>,
+    ) -> Result<Document<'_, 'library>, PdfiumError> {
+        let handle = self.core.get_available_document(&self.handle, password)?;
+
+        Ok(Document {
+            handle,
+            core: self.core,
+        })
+    }
+
+    /// Check if enough of a page is available to load it, the document has to be opened first.
+    pub fn is_page_available(&self, index: usize) -> Result<bool, PdfiumError> {
+        self.core.is_page_available(&self.handle, index)
+    }
+}
+
+impl Document<'_, '_> {
+    /// The page that becomes available first while the document is downloaded, 0 for most documents.
+    pub fn first_available_page(&self) -> usize {
+        self.core.get_first_available_page(&self.handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    /// A download of `DUMMY_PDF` that has received `downloaded` bytes.
+    struct Download {
+        downloaded: Rc<Cell<usize>>,
+    }
+
+    impl DataSource for Download {
+        fn is_data_available(&mut self, offset: usize, size: usize) -> bool {
+            offset + size <= self.downloaded.get()
+        }
+
+        fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+            if !self.is_data_available(offset, buffer.len()) {
+                return false;
+            }
+
+            buffer.copy_from_slice(&DUMMY_PDF[offset..offset + buffer.len()]);
+            true
+        }
+
+        fn add_segment(&mut self, _offset: usize, _size: usize) {}
+    }
+
+    #[test]
+    fn partial_document() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let downloaded = Rc::new(Cell::new(0));
+        let source = Download {
+            downloaded: downloaded.clone(),
+        };
+        let partial = library.partial_document(source, DUMMY_PDF.len()).unwrap();
+        assert!(!partial.is_available().unwrap());
+
+        downloaded.set(DUMMY_PDF.len());
+        assert!(partial.is_available().unwrap());
+        assert_eq!(partial.is_linearized(), Some(false));
+
+        let document = partial.document().unwrap();
+        assert_eq!(document.first_available_page(), 0);
+        assert!(partial.is_page_available(0).unwrap());
+        assert!(document.page(0).is_ok());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{DestinationViewMode, Rect};
+use pdfium_core::{DestinationHandle, DocumentHandle, Library};
+
+pub(crate) fn destination(
+    core: &Library,
+    document: &DocumentHandle,
+    handle: &DestinationHandle,
+) -> Option<Destination> {
+    let page_index = core.get_destination_page_index(document, handle)?;
+
+    let (mode, parameters) = core.get_destination_view(handle);
+    let parameter = |index: usize| parameters.get(index).copied().unwrap_or_default();
+
+    let view = match mode {
+        DestinationViewMode::Unknown => DestinationView::Unknown,
+        DestinationViewMode::Xyz => {
+            let location = core.get_destination_location(handle).unwrap_or_default();
+
+            DestinationView::Xyz {
+                left: location.x,
+                top: location.y,
+                // A zoom of 0 keeps the current zoom, just like null
+                zoom: location.zoom.filter(|zoom| *zoom != 0.0),
+            }
+        }
+        DestinationViewMode::Fit => DestinationView::Fit,
+        DestinationViewMode::FitH => DestinationView::FitH { top: parameter(0) },
+        DestinationViewMode::FitV => DestinationView::FitV { left: parameter(0) },
+        DestinationViewMode::FitR => DestinationView::FitR(Rect {
+            left: parameter(0),
+            bottom: parameter(1),
+            right: parameter(2),
+            top: parameter(3),
+        }),
+        DestinationViewMode::FitB => DestinationView::FitB,
+        DestinationViewMode::FitBH => DestinationView::FitBH { top: parameter(0) },
+        DestinationViewMode::FitBV => DestinationView::FitBV { left: parameter(0) },
+    };
+
+    Some(Destination { page_index, view })
+}
+
+/// A place in a document that outline items and links jump to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Destination {
+    pub page_index: usize,
+    /// Which part of the page is shown and how it's zoomed.
+    pub view: DestinationView,
+}
+
+/// How a destination displays its page, in page coordinates.
+///
+/// `None` values keep the current value of the viewer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DestinationView {
+    /// The destination doesn't specify a view.
+    Unknown,
+    /// Position the top left corner of the window at a point, with a zoom factor.
+    Xyz {
+        left: Option<f32>,
+        top: Option<f32>,
+        zoom: Option<f32>,
+    },
+    /// Fit the whole page in the window.
+    Fit,
+    /// Fit the width of the page, with the top of the window at `top`.
+    FitH { top: f32 },
+    /// Fit the height of the page, with the left side of the window at `left`.
+    FitV { left: f32 },
+    /// Fit a rectangle of the page in the window.
+    FitR(Rect),
+    /// Fit the bounding box of the page content in the window.
+    FitB,
+    /// Fit the width of the bounding box of the page content, with the top of the window at `top`.
+    FitBH { top: f32 },
+    /// Fit the height of the bounding box of the page content, with the left side of the window at `left`.
+    FitBV { left: f32 },
+}
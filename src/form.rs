@@ -0,0 +1,821 @@
+use crate::{form_field_flags, AnnotationSubtype, Color, Document, FormFieldType, Page};
+use crate::{PdfiumError, Point, Rect};
+use std::collections::BTreeMap;
+
+impl Page<'_, '_> {
+    /// The form fields of the widget annotations on the page, in drawing order.
+    ///
+    /// A field with several widgets, like a group of radio buttons, is listed once for every widget.
+    pub fn form_fields(&self) -> Result<Vec<FormField>, PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        let mut fields = Vec::new();
+
+        for index in 0..self.core.get_annotation_count(&self.handle) {
+            let annotation = match self.core.get_annotation(&self.handle, index) {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            if self.core.get_annotation_subtype(&annotation) != AnnotationSubtype::Widget {
+                continue;
+            }
+
+            fields.push(self.form_field(&form, &annotation, index));
+        }
+
+        Ok(fields)
+    }
+
+    /// The form field at `point` in page space, the one on top if widgets overlap.
+    ///
+    /// Returns `None` if there is no form field at `point`.
+    pub fn form_field_at(&self, point: &Point) -> Result<Option<FormField>, PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+
+        Ok(self
+            .core
+            .get_form_field_annotation_at_point(&form, &self.handle, point)
+            .and_then(|annotation| {
+                let index = self.core.get_annotation_index(&self.handle, &annotation)?;
+                Some(self.form_field(&form, &annotation, index))
+            }))
+    }
+
+    fn form_field(
+        &self,
+        form: &pdfium_core::FormHandle,
+        annotation: &pdfium_core::AnnotationHandle,
+        index: usize,
+    ) -> FormField {
+        FormField {
+            annotation_index: index,
+            name: self.core.get_annotation_form_field_name(form, annotation),
+            alternate_name: self
+                .core
+                .get_annotation_form_field_alternate_name(form, annotation),
+            field_type: self.core.get_annotation_form_field_type(form, annotation),
+            value: self.core.get_annotation_form_field_value(form, annotation),
+            export_value: self
+                .core
+                .get_annotation_form_field_export_value(form, annotation),
+            flags: self.core.get_annotation_form_field_flags(form, annotation),
+            rect: self.core.get_annotation_rect(annotation),
+            font_size: self.core.get_annotation_font_size(form, annotation),
+            font_color: self.core.get_annotation_font_color(form, annotation),
+        }
+    }
+
+    /// Set the value of the text field called `name`, like a user typing it, and regenerate its appearance.
+    ///
+    /// The value runs through the field's format and validate JavaScript.
+    /// The form fill environment is initialized on the first call and kept with the document.
+    ///
+    /// Returns `false` if the page has no text field called `name`.
+    pub fn set_text_field_value(&mut self, name: &str, value: &str) -> Result<bool, PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        let form_page = self.core.form_on_after_load_page(&form, &self.handle);
+
+        self.fill_text_field(&form, &form_page, name, value)
+    }
+
+    fn fill_text_field(
+        &self,
+        form: &pdfium_core::FormHandle,
+        form_page: &pdfium_core::FormPageHandle,
+        name: &str,
+        value: &str,
+    ) -> Result<bool, PdfiumError> {
+        for index in 0..self.core.get_annotation_count(&self.handle) {
+            let annotation = match self.core.get_annotation(&self.handle, index) {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            if self.core.get_annotation_form_field_type(form, &annotation)
+                != FormFieldType::TextField
+                || self.core.get_annotation_form_field_name(form, &annotation) != name
+            {
+                continue;
+            }
+
+            // All widgets of a field share its value, so setting it through one of them is enough.
+            self.core
+                .set_form_focused_annotation(form_page, &annotation)?;
+            // There is nothing to select in an empty field.
+            let _ = self.core.form_select_all_text(form_page);
+            self.core.form_replace_selection(form_page, value);
+            self.core.form_kill_focus(form_page)?;
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Select the option `value` of the combo box or list box called `name`, like a user clicking on it.
+    ///
+    /// Returns `false` if the page has no combo box or list box called `name` or it has no option `value`.
+    pub fn set_choice_field_value(&mut self, name: &str, value: &str) -> Result<bool, PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        let form_page = self.core.form_on_after_load_page(&form, &self.handle);
+
+        for index in 0..self.core.get_annotation_count(&self.handle) {
+            let annotation = match self.core.get_annotation(&self.handle, index) {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            let field_type = self.core.get_annotation_form_field_type(&form, &annotation);
+            if (field_type != FormFieldType::ComboBox && field_type != FormFieldType::ListBox)
+                || self.core.get_annotation_form_field_name(&form, &annotation) != name
+            {
+                continue;
+            }
+
+            let option = (0..self
+                .core
+                .get_annotation_option_count(&form, &annotation)
+                .unwrap_or(0))
+                .find(|&option| {
+                    self.core
+                        .get_annotation_option_label(&form, &annotation, option)
+                        .as_deref()
+                        == Some(value)
+                });
+            let option = match option {
+                Some(option) => option,
+                None => return Ok(false),
+            };
+
+            self.core
+                .set_form_focused_annotation(&form_page, &annotation)?;
+            self.core
+                .form_set_index_selected(&form_page, option, true)?;
+            self.core.form_kill_focus(&form_page)?;
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Check the check box or radio button of the field called `name` whose export value is `value`,
+    /// like a user clicking on it. A `value` of `Off` unchecks a check box.
+    ///
+    /// Returns `false` if the page has no check box or radio button called `name`, or it can't get `value`.
+    pub fn set_button_field_value(&mut self, name: &str, value: &str) -> Result<bool, PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        let form_page = self.core.form_on_after_load_page(&form, &self.handle);
+
+        for index in 0..self.core.get_annotation_count(&self.handle) {
+            let annotation = match self.core.get_annotation(&self.handle, index) {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            let field_type = self.core.get_annotation_form_field_type(&form, &annotation);
+            if (field_type != FormFieldType::CheckBox && field_type != FormFieldType::RadioButton)
+                || self.core.get_annotation_form_field_name(&form, &annotation) != name
+            {
+                continue;
+            }
+
+            if self
+                .core
+                .get_annotation_form_field_value(&form, &annotation)
+                == value
+            {
+                return Ok(true);
+            }
+
+            // Clicking toggles a checked check box off, clicking an unchecked widget checks it.
+            let click = if value == "Off" {
+                self.core.is_annotation_checked(&form, &annotation)
+            } else {
+                self.core
+                    .get_annotation_form_field_export_value(&form, &annotation)
+                    == value
+            };
+            if !click {
+                continue;
+            }
+            let rect = match self.core.get_annotation_rect(&annotation) {
+                Some(rect) => rect,
+                None => continue,
+            };
+            let center = Point {
+                x: (rect.left + rect.right) / 2.0,
+                y: (rect.top + rect.bottom) / 2.0,
+            };
+            self.core
+                .form_on_left_button_down(&form_page, pdfium_core::event_flags::NONE, &center);
+            self.core
+                .form_on_left_button_up(&form_page, pdfium_core::event_flags::NONE, &center);
+            // The button keeps the focus after the click.
+            let _ = self.core.form_kill_focus(&form_page);
+
+            return Ok(self
+                .core
+                .get_annotation_form_field_value(&form, &annotation)
+                == value);
+        }
+
+        Ok(false)
+    }
+
+    /// Change the font size and text color of every widget of the field called `name`, and regenerate their appearances.
+    ///
+    /// `None` keeps the current font size or color. A font size of `0` auto-sizes the text to fit the widget.
+    ///
+    /// Widgets without their own default appearance are skipped, their font is inherited from the field or the form.
+    /// If a widget can't be changed, the widgets that were already changed get their default appearance back.
+    /// Returns `false` if the page has no field called `name`.
+    pub fn set_form_field_font(
+        &mut self,
+        name: &str,
+        font_size: Option<f32>,
+        color: Option<&Color>,
+    ) -> Result<bool, PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        let mut found = false;
+        let mut changed = Vec::new();
+
+        for index in 0..self.core.get_annotation_count(&self.handle) {
+            let mut annotation = match self.core.get_annotation(&self.handle, index) {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            if self.core.get_annotation_subtype(&annotation) != AnnotationSubtype::Widget
+                || self.core.get_annotation_form_field_name(&form, &annotation) != name
+            {
+                continue;
+            }
+            found = true;
+
+            let default_appearance = match self.core.get_annotation_string_value(&annotation, "DA")
+            {
+                Some(default_appearance) => default_appearance,
+                None => continue,
+            };
+
+            let result = font_size
+                .map_or(Ok(()), |font_size| {
+                    self.core
+                        .set_annotation_font_size(&mut annotation, font_size)
+                })
+                .and_then(|_| {
+                    color.map_or(Ok(()), |color| {
+                        self.core
+                            .set_annotation_font_color(&form, &mut annotation, color)
+                    })
+                });
+            changed.push((annotation, default_appearance));
+
+            if let Err(error) = result {
+                for (mut annotation, default_appearance) in changed {
+                    let _ = self.core.set_annotation_string_value(
+                        &mut annotation,
+                        "DA",
+                        &default_appearance,
+                    );
+                }
+                return Err(error);
+            }
+        }
+
+        for (mut annotation, _) in changed {
+            self.core.set_annotation_appearance(
+                &mut annotation,
+                pdfium_core::AppearanceMode::Normal,
+                None,
+            )?;
+        }
+
+        if found {
+            // PDFium generates missing appearances of widgets when their page is loaded into a form fill environment.
+            drop(self.core.form_on_after_load_page(&form, &self.handle));
+        }
+
+        Ok(found)
+    }
+}
+
+impl Page<'_, '_> {
+    /// Draw the annotations and form fields that are printed into the page content and remove them.
+    ///
+    /// Form fields without an appearance get one first, so they aren't lost.
+    pub fn flatten(&mut self) -> Result<(), PdfiumError> {
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        // PDFium generates missing appearances of widgets when their page is loaded into a form fill environment.
+        drop(self.core.form_on_after_load_page(&form, &self.handle));
+
+        self.core
+            .flatten_page(&mut self.handle, pdfium_core::FlattenUsage::Print)?;
+
+        Ok(())
+    }
+}
+
+impl Document<'_, '_> {
+    /// Set the values of text fields on all pages, see [`Page::set_text_field_value`].
+    ///
+    /// The form fill environment is initialized once for all pages and fields.
+    ///
+    /// `values` are pairs of field names and values. Returns the names of the fields that were not found.
+    pub fn set_text_field_values<'a>(
+        &self,
+        values: &[(&'a str, &str)],
+    ) -> Result<Vec<&'a str>, PdfiumError> {
+        let mut missing: Vec<&str> = values.iter().map(|(name, _)| *name).collect();
+        let form = self.core.init_cached_form_fill_environment(&self.handle)?;
+
+        for index in 0..self.page_count() {
+            let page = self.page(index)?;
+            // Every page is loaded into the form fill environment once for all fields
+            let form_page = self.core.form_on_after_load_page(&form, &page.handle);
+
+            for (name, value) in values {
+                if missing.contains(name) && page.fill_text_field(&form, &form_page, name, value)? {
+                    missing.retain(|x| x != name);
+                }
+            }
+        }
+
+        Ok(missing)
+    }
+
+    /// Fill text fields, flatten all pages and write the result to `writer`.
+    ///
+    /// `values` are pairs of field names and values, see [`Document::set_text_field_values`].
+    /// Returns the names of the fields that were not found, the others are part of the page content afterwards.
+    ///
+    /// The document itself is changed too, so it can't be filled again.
+    pub fn fill_and_flatten<'a>(
+        &self,
+        values: &[(&'a str, &str)],
+        writer: &mut impl std::io::Write,
+    ) -> Result<Vec<&'a str>, PdfiumError> {
+        let missing = self.set_text_field_values(values)?;
+
+        for index in 0..self.page_count() {
+            self.page(index)?.flatten()?;
+        }
+
+        self.save_to(writer)?;
+
+        Ok(missing)
+    }
+
+    /// The values of the form fields on all pages, by their fully qualified name.
+    ///
+    /// Push buttons are left out, because they have no value.
+    pub fn form_values(&self) -> Result<BTreeMap<String, FormValue>, PdfiumError> {
+        let mut values = BTreeMap::new();
+
+        for index in 0..self.page_count() {
+            for field in self.page(index)?.form_fields()? {
+                if field.field_type != FormFieldType::PushButton {
+                    values.entry(field.name).or_insert(FormValue {
+                        field_type: field.field_type,
+                        value: field.value,
+                    });
+                }
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Set the values of form fields on all pages, for example the ones of [`Document::form_values`].
+    ///
+    /// Text fields are set with [`Page::set_text_field_value`], check boxes and radio buttons with
+    /// [`Page::set_button_field_value`] and combo boxes and list boxes with [`Page::set_choice_field_value`].
+    ///
+    /// Returns the fields that could not be set and why, the other fields are set.
+    pub fn fill_form(
+        &self,
+        values: &BTreeMap<String, FormValue>,
+    ) -> Result<Vec<FieldError>, PdfiumError> {
+        let mut fields = Vec::new();
+        for index in 0..self.page_count() {
+            for field in self.page(index)?.form_fields()? {
+                fields.push((index, field));
+            }
+        }
+
+        let mut errors = Vec::new();
+
+        for (name, value) in values {
+            let error = |kind| FieldError {
+                name: name.clone(),
+                kind,
+            };
+
+            let (page_index, field) = match fields.iter().find(|(_, field)| &field.name == name) {
+                Some(found) => found,
+                None => {
+                    errors.push(error(FieldErrorKind::UnknownField));
+                    continue;
+                }
+            };
+            if field.flags & form_field_flags::READ_ONLY != 0 {
+                errors.push(error(FieldErrorKind::ReadOnly));
+                continue;
+            }
+            if field.field_type != value.field_type {
+                errors.push(error(FieldErrorKind::TypeMismatch(field.field_type)));
+                continue;
+            }
+
+            let mut page = self.page(*page_index)?;
+            let set = match field.field_type {
+                FormFieldType::TextField => page.set_text_field_value(name, &value.value)?,
+                FormFieldType::CheckBox | FormFieldType::RadioButton => {
+                    page.set_button_field_value(name, &value.value)?
+                }
+                FormFieldType::ComboBox | FormFieldType::ListBox => {
+                    page.set_choice_field_value(name, &value.value)?
+                }
+                _ => {
+                    errors.push(error(FieldErrorKind::Unsupported));
+                    continue;
+                }
+            };
+            if !set {
+                errors.push(error(FieldErrorKind::InvalidValue));
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Run the document level JavaScript and open action of the document and the open actions of its pages,
+    /// for example to compute the totals of form fields.
+    ///
+    /// Actions, including JavaScript, never run unless this is called.
+    pub fn run_open_actions(&self) -> Result<(), PdfiumError> {
+        let form = self.core.init_form_fill_environment(&self.handle)?;
+        self.core.form_do_document_javascript_action(&form);
+        self.core.form_do_document_open_action(&form);
+
+        for index in 0..self.page_count() {
+            let page = self.page(index)?;
+            let form_page = self.core.form_on_after_load_page(&form, &page.handle);
+            self.core
+                .form_do_page_additional_action(&form_page, pdfium_core::PageAction::Open);
+        }
+
+        Ok(())
+    }
+}
+
+/// A form field, as seen through one of its widget annotations.
+///
+/// Created using [`Page::form_fields`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormField {
+    /// Index of the widget annotation on its page.
+    pub annotation_index: usize,
+    /// The fully qualified name, with the names of the parent fields joined by periods.
+    pub name: String,
+    /// The name meant to be shown to the user, empty if the field has none.
+    pub alternate_name: String,
+    pub field_type: FormFieldType,
+    pub value: String,
+    /// The value of the field when this check box or radio button is checked, empty for other fields.
+    pub export_value: String,
+    /// See [`form_field_flags`](crate::form_field_flags).
+    pub flags: i32,
+    /// Where the widget is drawn on the page.
+    pub rect: Option<Rect>,
+    /// `0` means the text is auto-sized to fit the widget.
+    pub font_size: Option<f32>,
+    pub font_color: Option<Color>,
+}
+
+/// The value of a form field, independent of its widgets.
+///
+/// Created using [`Document::form_values`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormValue {
+    pub field_type: FormFieldType,
+    /// The export value of the selected option for check boxes and radio buttons, `Off` if none is selected.
+    pub value: String,
+}
+
+/// A form field that [`Document::fill_form`] could not set.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldError {
+    /// The fully qualified name of the field.
+    pub name: String,
+    pub kind: FieldErrorKind,
+}
+
+/// Why [`Document::fill_form`] could not set a form field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldErrorKind {
+    /// The document has no field with the name.
+    UnknownField,
+    /// The field has the [`READ_ONLY`](crate::form_field_flags::READ_ONLY) flag.
+    ReadOnly,
+    /// The value is for another type of field, the type of the field is included.
+    TypeMismatch(FormFieldType),
+    /// The field can't have the value, like an option a combo box doesn't have.
+    InvalidValue,
+    /// Fields of this type, like push buttons and signatures, can't be set.
+    Unsupported,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::BTreeMap;
+
+    static FORMS_PDF: &[u8] = include_bytes!("../test_assets/forms.pdf");
+
+    #[test]
+    fn form_fields() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        let fields = page.form_fields().unwrap();
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| (field.name.as_str(), field.field_type, field.value.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("name", FormFieldType::TextField, "Alice"),
+                ("agree", FormFieldType::CheckBox, "Off"),
+                ("color", FormFieldType::RadioButton, "Blue"),
+                ("color", FormFieldType::RadioButton, "Blue"),
+                ("country", FormFieldType::ComboBox, "Germany"),
+                ("id", FormFieldType::TextField, "42"),
+            ]
+        );
+
+        assert_eq!(fields[0].alternate_name, "Your name");
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.export_value.as_str())
+                .collect::<Vec<_>>(),
+            ["", "Yes", "Red", "Blue", "", ""]
+        );
+        assert_eq!(
+            fields[0].rect,
+            Some(Rect {
+                left: 100.0,
+                bottom: 700.0,
+                right: 300.0,
+                top: 720.0
+            })
+        );
+        assert_eq!(
+            fields[5].flags,
+            form_field_flags::READ_ONLY | form_field_flags::REQUIRED
+        );
+    }
+
+    #[test]
+    fn set_text_field_value() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        let mut page = document.page(0).unwrap();
+
+        assert!(page.set_text_field_value("name", "Bob").unwrap());
+        assert!(!page.set_text_field_value("agree", "Yes").unwrap());
+        assert!(!page.set_text_field_value("missing", "").unwrap());
+
+        let fields = page.form_fields().unwrap();
+        assert_eq!(fields[0].value, "Bob");
+        assert_eq!(fields[1].value, "Off");
+    }
+
+    #[test]
+    fn set_text_field_values() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        let missing = document
+            .set_text_field_values(&[("name", "Carol"), ("missing", "")])
+            .unwrap();
+        assert_eq!(missing, ["missing"]);
+
+        let fields = document.page(0).unwrap().form_fields().unwrap();
+        assert_eq!(fields[0].value, "Carol");
+    }
+
+    #[test]
+    fn set_form_field_font() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        let mut page = document.page(0).unwrap();
+
+        let fields = page.form_fields().unwrap();
+        assert_eq!(fields[0].font_size, Some(12.0));
+        assert_eq!(fields[0].font_color, Some(Color::rgb(0, 0, 255)));
+
+        assert!(page
+            .set_form_field_font("name", Some(8.0), Some(&Color::rgb(255, 0, 0)))
+            .unwrap());
+        assert!(page.set_form_field_font("id", None, None).unwrap());
+        // The widgets of "color" inherit the default appearance of the form
+        assert!(page.set_form_field_font("color", Some(8.0), None).unwrap());
+        assert!(!page
+            .set_form_field_font("missing", Some(8.0), None)
+            .unwrap());
+
+        let fields = page.form_fields().unwrap();
+        assert_eq!(fields[0].font_size, Some(8.0));
+        assert_eq!(fields[0].font_color, Some(Color::rgb(255, 0, 0)));
+        assert_eq!(fields[5].font_size, Some(10.0));
+    }
+
+    #[test]
+    fn fill_and_flatten() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        let mut bytes = Vec::new();
+        let missing = document
+            .fill_and_flatten(&[("name", "Dave"), ("missing", "")], &mut bytes)
+            .unwrap();
+        assert_eq!(missing, ["missing"]);
+
+        let document = library.document_from_bytes(&bytes).unwrap();
+        let page = document.page(0).unwrap();
+        assert!(page.form_fields().unwrap().is_empty());
+        assert!(!page.objects().is_empty());
+    }
+
+    #[test]
+    fn form_field_at() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        let field = page
+            .form_field_at(&Point { x: 150.0, y: 510.0 })
+            .unwrap()
+            .unwrap();
+        assert_eq!(field.name, "id");
+        assert_eq!(field.annotation_index, 5);
+
+        assert!(page
+            .form_field_at(&Point { x: 10.0, y: 10.0 })
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn run_open_actions() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        document.run_open_actions().unwrap();
+
+        let fields = document.page(0).unwrap().form_fields().unwrap();
+        assert_eq!(fields[0].value, "Alice");
+    }
+
+    #[test]
+    fn form_values() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        let values = document.form_values().unwrap();
+        assert_eq!(
+            values.keys().collect::<Vec<_>>(),
+            ["agree", "color", "country", "id", "name"]
+        );
+        assert_eq!(
+            values["name"],
+            FormValue {
+                field_type: FormFieldType::TextField,
+                value: "Alice".to_string()
+            }
+        );
+        assert_eq!(values["agree"].value, "Off");
+        assert_eq!(values["color"].field_type, FormFieldType::RadioButton);
+        assert_eq!(values["color"].value, "Blue");
+        assert_eq!(values["country"].value, "Germany");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn form_values_json() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        let values = document.form_values().unwrap();
+        let json = serde_json::to_string(&values).unwrap();
+        let parsed: BTreeMap<String, FormValue> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, values);
+    }
+
+    #[test]
+    fn fill_form() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        let mut values = document.form_values().unwrap();
+        values.get_mut("name").unwrap().value = "Bob".to_string();
+        values.get_mut("agree").unwrap().value = "Yes".to_string();
+        values.get_mut("color").unwrap().value = "Red".to_string();
+        values.get_mut("country").unwrap().value = "Japan".to_string();
+        values.get_mut("id").unwrap().value = "7".to_string();
+        values.insert(
+            "missing".to_string(),
+            FormValue {
+                field_type: FormFieldType::TextField,
+                value: String::new(),
+            },
+        );
+
+        let errors = document.fill_form(&values).unwrap();
+        assert_eq!(
+            errors,
+            [
+                FieldError {
+                    name: "id".to_string(),
+                    kind: FieldErrorKind::ReadOnly
+                },
+                FieldError {
+                    name: "missing".to_string(),
+                    kind: FieldErrorKind::UnknownField
+                }
+            ]
+        );
+
+        let filled = document.form_values().unwrap();
+        assert_eq!(filled["name"].value, "Bob");
+        assert_eq!(filled["agree"].value, "Yes");
+        assert_eq!(filled["color"].value, "Red");
+        assert_eq!(filled["country"].value, "Japan");
+        assert_eq!(filled["id"].value, "42");
+    }
+
+    #[test]
+    fn fill_form_invalid() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        let mut values = BTreeMap::new();
+        values.insert(
+            "name".to_string(),
+            FormValue {
+                field_type: FormFieldType::CheckBox,
+                value: "Yes".to_string(),
+            },
+        );
+        values.insert(
+            "country".to_string(),
+            FormValue {
+                field_type: FormFieldType::ComboBox,
+                value: "Atlantis".to_string(),
+            },
+        );
+        values.insert(
+            "color".to_string(),
+            FormValue {
+                field_type: FormFieldType::RadioButton,
+                value: "Green".to_string(),
+            },
+        );
+
+        let errors = document.fill_form(&values).unwrap();
+        assert_eq!(
+            errors,
+            [
+                FieldError {
+                    name: "color".to_string(),
+                    kind: FieldErrorKind::InvalidValue
+                },
+                FieldError {
+                    name: "country".to_string(),
+                    kind: FieldErrorKind::InvalidValue
+                },
+                FieldError {
+                    name: "name".to_string(),
+                    kind: FieldErrorKind::TypeMismatch(FormFieldType::TextField)
+                }
+            ]
+        );
+        let values = document.form_values().unwrap();
+        assert_eq!(values["color"].value, "Blue");
+        assert_eq!(values["country"].value, "Germany");
+    }
+}
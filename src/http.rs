@@ -0,0 +1,384 @@
+use crate::{DataSource, Document, Library, PartialDocument, PdfiumError};
+use std::collections::HashMap;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Range;
+
+/// A client that downloads parts of a remote document with HTTP range requests,
+/// like `Range: bytes=0-65535`.
+///
+/// Implement it with the HTTP library of the application, see [`HttpSource`].
+pub trait RangeClient {
+    /// The size of the document in bytes, like the `Content-Length` of a `HEAD` request.
+    fn content_length(&mut self) -> io::Result<usize>;
+
+    /// Download the bytes of `range`, the body of a `206 Partial Content` response.
+    fn fetch(&mut self, range: Range<usize>) -> io::Result<Vec<u8>>;
+}
+
+/// A remote document that is downloaded in chunks.
+///
+/// It can be used in two ways:
+/// - As a [`DataSource`] of a [`PartialDocument`], see [`Library::partial_document_from_http`].
+///   The chunks PDFium asks for with download hints are downloaded while the document and its pages are checked
+///   for availability, and PDFium only reads downloaded chunks. So a page can be rendered as soon as it's available.
+/// - As a [`Read`] and [`Seek`] source of [`Library::document_from_reader`], see [`Library::document_from_http`].
+///   Every read of a chunk that isn't downloaded yet blocks on a request.
+///
+/// Only the parts of the document that are used are downloaded, like the pages that are loaded.
+/// Downloaded chunks are kept until the source is dropped.
+pub struct HttpSource<C> {
+    client: C,
+    length: usize,
+    chunk_size: usize,
+    chunks: HashMap<usize, Vec<u8>>,
+    position: u64,
+}
+
+impl<C: RangeClient> HttpSource<C> {
+    /// The default size of the chunks, 64 KiB.
+    pub const CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Create a source that downloads chunks of [`HttpSource::CHUNK_SIZE`] bytes.
+    ///
+    /// Fails if the length of the document can't be found.
+    pub fn new(mut client: C) -> io::Result<Self> {
+        let length = client.content_length()?;
+
+        Ok(HttpSource {
+            client,
+            length,
+            chunk_size: Self::CHUNK_SIZE,
+            chunks: HashMap::new(),
+            position: 0,
+        })
+    }
+
+    /// Set the size of the chunks in bytes, bigger chunks mean fewer but slower requests.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "the chunk size must not be 0");
+
+        self.chunk_size = chunk_size;
+        self.chunks.clear();
+        self
+    }
+
+    /// The size of the document in bytes.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Check if the document is empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The number of bytes that have been downloaded.
+    pub fn downloaded(&self) -> usize {
+        self.chunks.values().map(Vec::len).sum()
+    }
+
+    fn chunk(&mut self, index: usize) -> io::Result<&[u8]> {
+        self.download(index..index + 1)?;
+
+        Ok(&self.chunks[&index])
+    }
+
+    /// The indices of the chunks with the bytes from `offset` to `offset + size`.
+    fn chunks_of(&self, offset: usize, size: usize) -> Range<usize> {
+        let end = offset.saturating_add(size).min(self.length);
+
+        offset / self.chunk_size..end.div_ceil(self.chunk_size)
+    }
+
+    /// Download the missing chunks of `chunks`, with one request for every run of missing chunks.
+    fn download(&mut self, chunks: Range<usize>) -> io::Result<()> {
+        let mut index = chunks.start;
+
+        while index < chunks.end {
+            if self.chunks.contains_key(&index) {
+                index += 1;
+                continue;
+            }
+
+            let end = (index..chunks.end)
+                .find(|index| self.chunks.contains_key(index))
+                .unwrap_or(chunks.end);
+            let range = index * self.chunk_size..(end * self.chunk_size).min(self.length);
+            let data = self.client.fetch(range.clone())?;
+
+            // Servers without range requests send the whole document
+            if data.len() != range.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "the response doesn't match the requested range",
+                ));
+            }
+
+            for (offset, chunk) in data.chunks(self.chunk_size).enumerate() {
+                self.chunks.insert(index + offset, chunk.to_vec());
+            }
+            index = end;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: RangeClient> DataSource for HttpSource<C> {
+    fn is_data_available(&mut self, offset: usize, size: usize) -> bool {
+        self.chunks_of(offset, size)
+            .all(|index| self.chunks.contains_key(&index))
+    }
+
+    fn read_block(&mut self, offset: usize, buffer: &mut [u8]) -> bool {
+        if offset.saturating_add(buffer.len()) > self.length
+            || !self.is_data_available(offset, buffer.len())
+        {
+            return false;
+        }
+
+        let mut written = 0;
+        while written < buffer.len() {
+            let position = offset + written;
+            let chunk = &self.chunks[&(position / self.chunk_size)];
+            let start = position % self.chunk_size;
+            let length = (buffer.len() - written).min(chunk.len() - start);
+
+            buffer[written..written + length].copy_from_slice(&chunk[start..start + length]);
+            written += length;
+        }
+
+        true
+    }
+
+    fn add_segment(&mut self, offset: usize, size: usize) {
+        // A failed request is repeated when PDFium asks for the data again, the client sees the error
+        let _ = self.download(self.chunks_of(offset, size));
+    }
+}
+
+impl<C: RangeClient> Read for HttpSource<C> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        if buffer.is_empty() || self.position >= self.length as u64 {
+            return Ok(0);
+        }
+
+        let position = self.position as usize;
+        let offset = position % self.chunk_size;
+        let chunk = self.chunk(position / self.chunk_size)?;
+
+        let length = buffer.len().min(chunk.len() - offset);
+        buffer[..length].copy_from_slice(&chunk[offset..offset + length]);
+
+        self.position += length as u64;
+        Ok(length)
+    }
+}
+
+impl<C: RangeClient> Seek for HttpSource<C> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let position = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => offset_position(self.length as u64, offset),
+            SeekFrom::Current(offset) => offset_position(self.position, offset),
+        };
+
+        match position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+fn offset_position(base: u64, offset: i64) -> Option<u64> {
+    if offset < 0 {
+        base.checked_sub(offset.unsigned_abs())
+    } else {
+        base.checked_add(offset as u64)
+    }
+}
+
+impl Library {
+    /// Open a remote document with range requests, without downloading all of it.
+    ///
+    /// PDFium reads the document through [`HttpSource`] as a [`Read`] source, so reading a part
+    /// that isn't downloaded yet blocks on a request. Use [`Library::partial_document_from_http`]
+    /// to download the parts PDFium needs up front, and [`HttpSource`] directly to change the chunk size.
+    ///
+    /// Fails with [`PdfiumError::BadFile`] if the length of the document can't be found.
+    pub fn document_from_http<'data>(
+        &self,
+        client: impl RangeClient + 'data,
+    ) -> Result<Document<'data, '_>, PdfiumError> {
+        let source = HttpSource::new(client).map_err(|_| PdfiumError::BadFile)?;
+
+        self.document_from_reader(source)
+    }
+
+    /// Start opening a remote document with range requests, see [`Library::partial_document`].
+    ///
+    /// [`HttpSource`] downloads the parts PDFium asks for when [`PartialDocument::is_available`] and
+    /// [`PartialDocument::is_page_available`] are called, so the first pages can be rendered before the rest
+    /// is downloaded. A call that returns `false` has requested the missing parts, the next one sees them.
+    ///
+    /// Fails with [`PdfiumError::BadFile`] if the length of the document can't be found.
+    pub fn partial_document_from_http<'data>(
+        &self,
+        client: impl RangeClient + 'data,
+    ) -> Result<PartialDocument<'data, '_>, PdfiumError> {
+        let source = HttpSource::new(client).map_err(|_| PdfiumError::BadFile)?;
+        let length = source.len();
+
+        self.partial_document(source, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::cell::RefCell;
+    use std::io::{self, Read, Seek, SeekFrom};
+    use std::ops::Range;
+    use std::rc::Rc;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    /// A server with `DUMMY_PDF`, that logs the requested ranges.
+    struct Server {
+        requests: Rc<RefCell<Vec<Range<usize>>>>,
+        ranges: bool,
+    }
+
+    impl RangeClient for Server {
+        fn content_length(&mut self) -> io::Result<usize> {
+            Ok(DUMMY_PDF.len())
+        }
+
+        fn fetch(&mut self, range: Range<usize>) -> io::Result<Vec<u8>> {
+            self.requests.borrow_mut().push(range.clone());
+
+            if self.ranges {
+                Ok(DUMMY_PDF[range].to_vec())
+            } else {
+                Ok(DUMMY_PDF.to_vec())
+            }
+        }
+    }
+
+    #[test]
+    fn http_source() {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let server = Server {
+            requests: requests.clone(),
+            ranges: true,
+        };
+        let mut source = HttpSource::new(server).unwrap().with_chunk_size(1024);
+        assert_eq!(source.len(), DUMMY_PDF.len());
+
+        source.seek(SeekFrom::Start(1000)).unwrap();
+        let mut buffer = [0; 100];
+        source.read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer[..], &DUMMY_PDF[1000..1100]);
+        assert_eq!(*requests.borrow(), [0..1024, 1024..2048]);
+
+        // Downloaded chunks are not requested again
+        source.seek(SeekFrom::Current(-100)).unwrap();
+        source.read_exact(&mut buffer).unwrap();
+        assert_eq!(requests.borrow().len(), 2);
+        assert_eq!(source.downloaded(), 2048);
+
+        let end = source.seek(SeekFrom::End(-10)).unwrap();
+        let mut rest = Vec::new();
+        source.read_to_end(&mut rest).unwrap();
+        assert_eq!(end as usize, DUMMY_PDF.len() - 10);
+        assert_eq!(rest, &DUMMY_PDF[DUMMY_PDF.len() - 10..]);
+
+        assert!(source.seek(SeekFrom::Current(-100_000)).is_err());
+
+        let server = Server {
+            requests,
+            ranges: false,
+        };
+        let mut source = HttpSource::new(server).unwrap().with_chunk_size(1024);
+        assert!(source.read(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn data_source() {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let server = Server {
+            requests: requests.clone(),
+            ranges: true,
+        };
+        let mut source = HttpSource::new(server).unwrap().with_chunk_size(1024);
+        let mut buffer = [0; 100];
+
+        assert!(!source.is_data_available(1000, 100));
+        assert!(!source.read_block(1000, &mut buffer));
+        assert!(requests.borrow().is_empty());
+
+        // Missing chunks next to each other are downloaded with one request
+        source.add_segment(1000, 100);
+        source.add_segment(0, 3000);
+        assert_eq!(*requests.borrow(), [0..2048, 2048..3072]);
+        assert!(source.is_data_available(0, 3000));
+        assert!(source.read_block(1000, &mut buffer));
+        assert_eq!(&buffer[..], &DUMMY_PDF[1000..1100]);
+
+        let mut buffer = vec![0; 2500];
+        assert!(source.read_block(100, &mut buffer));
+        assert_eq!(buffer, &DUMMY_PDF[100..2600]);
+
+        let end = DUMMY_PDF.len();
+        source.add_segment(end - 10, 10);
+        assert!(source.read_block(end - 10, &mut buffer[..10]));
+        assert!(!source.read_block(end - 10, &mut buffer[..11]));
+    }
+
+    #[test]
+    fn partial_document_from_http() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let server = Server {
+            requests: requests.clone(),
+            ranges: true,
+        };
+
+        let partial = library.partial_document_from_http(server).unwrap();
+        // Every call downloads what the previous one was missing
+        let mut calls = 0;
+        while !partial.is_available().unwrap() {
+            calls += 1;
+            assert!(calls < 10);
+        }
+        assert!(!requests.borrow().is_empty());
+
+        let document = partial.document().unwrap();
+        while !partial.is_page_available(0).unwrap() {}
+        assert!(document.page(0).is_ok());
+    }
+
+    #[test]
+    fn document_from_http() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let server = Server {
+            requests: Rc::new(RefCell::new(Vec::new())),
+            ranges: true,
+        };
+
+        let document = library.document_from_http(server).unwrap();
+        assert_eq!(document.page_count(), 1);
+        assert!(document.page(0).is_ok());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Document;
+
+impl Document<'_, '_> {
+    /// The document level JavaScript of the document, which runs when the document is opened.
+    ///
+    /// Scripts of other actions, like the open action of the document or the actions of
+    /// links, annotations and form fields, are not included.
+    pub fn javascript_actions(&self) -> Vec<JavaScriptAction> {
+        (0..self.core.get_javascript_action_count(&self.handle))
+            .filter_map(|index| self.core.get_javascript_action(&self.handle, index))
+            .map(|handle| JavaScriptAction {
+                name: self.core.get_javascript_action_name(&handle),
+                script: self.core.get_javascript_action_script(&handle),
+            })
+            .collect()
+    }
+}
+
+/// A named document level script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JavaScriptAction {
+    /// The name of the script in the document, which is unique.
+    pub name: String,
+    /// The JavaScript source code.
+    pub script: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static JAVASCRIPT_PDF: &[u8] = include_bytes!("../test_assets/javascript.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn javascript_actions() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(JAVASCRIPT_PDF).unwrap();
+
+        assert_eq!(
+            document.javascript_actions(),
+            vec![
+                JavaScriptAction {
+                    name: "alert".to_string(),
+                    script: "app.alert('Hello');".to_string(),
+                },
+                JavaScriptAction {
+                    name: "helpers".to_string(),
+                    script: "var total = 0;\nfunction add(x) { total += x; }\n".to_string(),
+                },
+            ]
+        );
+
+        let dummy = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(dummy.javascript_actions().is_empty());
+    }
+}
@@ -13,6 +13,9 @@
 //! });
 //! ```
 //!
+//! With the `sync` feature `SyncLibrary` and `SyncDocument` can be shared between threads,
+//! all of their calls to PDFium run one after another while holding a global lock.
+//!
 //! ## Install PDFium
 //! This crate loads PDFium as a binary library and also uses the headers from the system so it most be installed in order to use this crate.
 //!
@@ -20,9 +23,89 @@
 //!
 //! This crate doesn't use any the V8 or XFA features from PDFium so you only have to use the base library.
 
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "sync"), forbid(unsafe_code))]
+#![cfg_attr(feature = "sync", deny(unsafe_code))]
+
+mod accessible;
+mod action;
+mod alt_text;
+mod annotation;
+#[cfg(feature = "async")]
+mod async_library;
+mod attachment;
+mod cache;
+mod data_avail;
+mod destination;
+mod form;
+#[cfg(feature = "http")]
+mod http;
+mod javascript;
+mod link;
+mod metadata;
+mod open_options;
+mod outline;
+mod page_object;
+mod portfolio;
+mod sanitize;
+mod scan;
+mod signature;
+mod struct_tree;
+#[cfg(feature = "sync")]
+mod sync;
+#[cfg(feature = "threaded")]
+mod threaded;
+#[cfg(any(feature = "async", feature = "threaded"))]
+mod worker;
+#[cfg(feature = "xfa")]
+mod xfa;
 
-pub use pdfium_core::{BitmapFormat, PageOrientation, PdfiumError};
+pub use accessible::AccessibleBlock;
+pub use action::Action;
+pub use alt_text::{MissingAltText, MissingAltTextKind};
+pub use annotation::{Annotation, AnnotationData};
+#[cfg(feature = "async")]
+pub use async_library::{AsyncDocument, AsyncLibrary};
+pub use attachment::Attachment;
+pub use cache::DocumentCache;
+pub use data_avail::PartialDocument;
+pub use destination::{Destination, DestinationView};
+pub use form::{FieldError, FieldErrorKind, FormField, FormValue};
+#[cfg(feature = "http")]
+pub use http::{HttpSource, RangeClient};
+pub use javascript::JavaScriptAction;
+pub use link::Link;
+pub use metadata::{Metadata, PdfDate, PdfVersion, Permissions};
+pub use open_options::OpenOptions;
+pub use outline::OutlineItem;
+pub use page_object::{ImageObject, OtherObject, PageObject, PathObject, PathSegment, TextObject};
+#[cfg(feature = "dynamic")]
+pub use pdfium_core::load_library;
+pub use pdfium_core::{
+    annotation_flags, charsets, form_field_flags, permission_flags, ActionType, AnnotationBorder,
+    AnnotationColorType, AnnotationSubtype, BitmapFormat, Capability, CharsetFont, Color,
+    DataSource, DestinationViewMode, FileIdentifierType, FormFieldType, FormType, LibraryConfig,
+    Matrix, PageMode, PageObjectType, PageOrientation, PathSegmentType, PdfiumError, Point,
+    QuadPoints, Rect, SandboxPolicy, Size,
+};
+pub use portfolio::PortfolioMember;
+pub use sanitize::{
+    DroppedParts, RemovedAnnotation, RemovedLink, SanitizeReport, Sanitizer, StripJavaScript,
+};
+pub use scan::ScanProfile;
+pub use signature::Signature;
+pub use struct_tree::{StructAttributeValue, StructAttributes, StructElement};
+#[cfg(feature = "sync")]
+pub use sync::{SyncDocument, SyncLibrary};
+#[cfg(feature = "threaded")]
+pub use threaded::{PdfiumWorker, WorkerDocument};
+#[cfg(feature = "async")]
+pub use worker::JobFuture;
+#[cfg(any(feature = "async", feature = "threaded"))]
+pub use worker::RenderedPage;
+#[cfg(feature = "xfa")]
+pub use xfa::XfaPacket;
+
+use std::sync::Arc;
 
 pub struct Library {
     core: pdfium_core::Library,
@@ -33,26 +116,203 @@ impl Library {
         pdfium_core::Library::init_library().map(|library| Library { core: library })
     }
 
+    /// Initialize the library with a configuration, like the directories of the fonts.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::{Library, LibraryConfig};
+    ///
+    /// let library = Library::init_with_config(LibraryConfig {
+    ///     font_paths: vec!["/opt/fonts".into()],
+    ///     ..Default::default()
+    /// });
+    /// assert!(library.is_some());
+    /// ```
+    pub fn init_with_config(config: LibraryConfig) -> Option<Library> {
+        pdfium_core::Library::init_library_with_config(config)
+            .map(|library| Library { core: library })
+    }
+
+    /// Enable or disable a policy of the sandbox PDFium runs in, all policies are enabled by default.
+    ///
+    /// Disabling [`SandboxPolicy::MachineTimeAccess`] stops documents from finding the time of the machine
+    /// with JavaScript, which can reveal where it is.
+    pub fn set_sandbox_policy(&self, policy: SandboxPolicy, enabled: bool) {
+        self.core.set_sandbox_policy(policy, enabled)
+    }
+
+    /// Check if PDFium has a part of the API that is missing from older builds,
+    /// the functions that need it fail with [`PdfiumError::Unsupported`] otherwise.
+    pub fn has_capability(&self, capability: Capability) -> bool {
+        self.core.has_capability(capability)
+    }
+
+    /// The version of PDFium the crate was built with, like `5135`, `None` if it's unknown.
+    pub fn pdfium_version(&self) -> Option<&'static str> {
+        self.core.get_pdfium_version()
+    }
+
+    /// The fonts used for the character sets when a document doesn't embed its fonts.
+    ///
+    /// Add fonts with [`LibraryConfig::installed_fonts`].
+    pub fn default_ttf_map(&self) -> Vec<CharsetFont> {
+        self.core.get_default_ttf_map()
+    }
+
     pub fn document_from_bytes<'a>(
         &'a self,
         buffer: &'a [u8],
     ) -> Result<Document<'a, 'a>, PdfiumError> {
-        let handle = self.core.load_document_from_bytes(buffer, None);
+        OpenOptions::new().open_bytes(self, buffer)
+    }
 
-        handle.map(|handle| Document {
-            handle,
-            core: &self.core,
-        })
+    /// Open a document from a reader, like a [`File`](std::fs::File), without reading all of it into memory.
+    ///
+    /// The document keeps `reader` and reads the parts it needs, like a page when it's loaded.
+    pub fn document_from_reader<'data>(
+        &self,
+        reader: impl std::io::Read + std::io::Seek + 'data,
+    ) -> Result<Document<'data, '_>, PdfiumError> {
+        OpenOptions::new().open_reader(self, reader)
+    }
+
+    /// Open a document that owns its data, so it doesn't borrow the buffer like [`Library::document_from_bytes`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::{Document, Library, PdfiumError};
+    ///
+    /// fn open<'library>(
+    ///     library: &'library Library,
+    ///     path: &str,
+    /// ) -> Result<Document<'static, 'library>, PdfiumError> {
+    ///     let bytes = std::fs::read(path).map_err(|_| PdfiumError::BadFile)?;
+    ///     library.document_from_vec(bytes)
+    /// }
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = open(&library, "test_assets/dummy.pdf").unwrap();
+    /// assert_eq!(document.page_count(), 1);
+    /// ```
+    pub fn document_from_vec(&self, buffer: Vec<u8>) -> Result<Document<'static, '_>, PdfiumError> {
+        self.document_from_reader(std::io::Cursor::new(buffer))
+    }
+
+    /// Open a document that shares its data with other owners, like other documents opened from the same buffer.
+    ///
+    /// See [`Library::document_from_vec`].
+    pub fn document_from_arc(
+        &self,
+        buffer: Arc<[u8]>,
+    ) -> Result<Document<'static, '_>, PdfiumError> {
+        self.document_from_reader(std::io::Cursor::new(buffer))
     }
 
-    pub fn bitmap_from_external_buffer<'a>(
+    /// Open a document that might require a password, asking `password` for one until it opens.
+    ///
+    /// The document is first opened without a password, `password` is only called when one is required
+    /// or the last one was wrong. It gets the number of the attempt, 1 for the first, and returns `None`
+    /// to give up, which fails with [`PdfiumError::BadPassword`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::Library;
+    /// # static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../test_assets/password.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    ///
+    /// let passwords = ["wrong", "test"];
+    /// let document = library
+    ///     .document_from_bytes_with_password(DUMMY_PASSWORD_PDF, |attempt| {
+    ///         passwords.get(attempt - 1).map(|x| x.to_string())
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(document.page_count(), 1);
+    /// ```
+    pub fn document_from_bytes_with_password<'a>(
         &'a self,
+        buffer: &'a [u8],
+        mut password: impl FnMut(usize) -> Option<String>,
+    ) -> Result<Document<'a, 'a>, PdfiumError> {
+        let mut document = self.document_from_bytes(buffer);
+
+        let mut attempt = 0;
+        while let Err(PdfiumError::BadPassword) = document {
+            attempt += 1;
+            let next = password(attempt).ok_or(PdfiumError::BadPassword)?;
+
+            document = OpenOptions::new().password(&next).open_bytes(self, buffer);
+        }
+
+        document
+    }
+
+    /// Check if a document can only be opened with a password.
+    ///
+    /// The document is opened without a password and closed again, PDFium has no way to check the encryption
+    /// of a document without opening it. It costs as much as [`Library::document_from_bytes`], so when the document
+    /// is used afterwards open it with [`Library::document_from_bytes_with_password`] instead.
+    ///
+    /// Documents that are encrypted with an empty user password, to restrict their permissions, don't require one.
+    pub fn requires_password(&self, buffer: &[u8]) -> Result<bool, PdfiumError> {
+        match self.core.load_document_from_bytes(buffer, None) {
+            Ok(_) => Ok(false),
+            Err(PdfiumError::BadPassword) => Ok(true),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create a bitmap that is drawn into `buffer`, like memory that is shared with another process.
+    ///
+    /// `buffer` has at least `height` rows of `height_stride` bytes, which fit at least `width` pixels
+    /// of [`BitmapFormat::bytes_per_pixel`]. The pixels are rendered directly into `buffer`,
+    /// they can be read from it after the bitmap is dropped.
+    ///
+    /// ## Shared memory
+    /// Any mutable slice works, like a `memmap2::MmapMut` of a shared file or an anonymous map that is shared
+    /// with a child process, or a part of one so a map can hold several frames.
+    ///
+    /// The bitmap borrows `buffer` mutably, so nothing else in this process can use it until the bitmap
+    /// is dropped, but the other processes aren't stopped by the borrow:
+    /// - Other processes must not read or write the frame while the bitmap exists, PDFium writes the pixels
+    ///   in any order and a reader sees a partial frame. Tell them when the bitmap is dropped,
+    ///   with a pipe or a semaphore.
+    /// - Don't create a bitmap for a frame that another process is still reading, wait until it's done.
+    /// - Other processes must not write to the map while this process has a `&mut [u8]` of it at all,
+    ///   that breaks the guarantee of the slice. Split the map into frames and only borrow the frame that is rendered.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::{BitmapFormat, Library};
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+    /// let page = document.page(0).unwrap();
+    ///
+    /// // Two frames of 100 by 140 pixels, like a map that is shared with another process
+    /// let stride = 100 * BitmapFormat::BGRA.bytes_per_pixel();
+    /// let frame = stride * 140;
+    /// let mut map = vec![0xFF; 2 * frame];
+    ///
+    /// // Render into the second frame
+    /// let mut bitmap = library
+    ///     .bitmap_from_external_buffer(100, 140, stride, BitmapFormat::BGRA, &mut map[frame..])
+    ///     .unwrap();
+    /// page.render_to(&mut bitmap);
+    /// drop(bitmap);
+    ///
+    /// assert!(map[..frame].iter().all(|&byte| byte == 0xFF));
+    /// assert!(map[frame..].iter().any(|&byte| byte != 0xFF));
+    /// ```
+    pub fn bitmap_from_external_buffer<'data>(
+        &self,
         width: usize,
         height: usize,
         height_stride: usize,
         format: BitmapFormat,
-        buffer: &'a mut [u8],
-    ) -> Result<Bitmap<'a, 'a>, PdfiumError> {
+        buffer: &'data mut [u8],
+    ) -> Result<Bitmap<'data, '_>, PdfiumError> {
         let handle =
             self.core
                 .create_bitmap_from_buffer(width, height, format, buffer, height_stride);
@@ -75,17 +335,179 @@ impl Document<'_, '_> {
     }
 
     pub fn page(&self, index: usize) -> Result<Page, PdfiumError> {
-        let handle = self.core.load_page(&self.handle, index);
+        let handle = self.core.load_cached_page(&self.handle, index);
 
         handle.map(|handle| Page {
             handle,
+            document: &self.handle,
             core: self.core,
         })
     }
+
+    /// Keep up to `capacity` pages open after they are dropped, so [`Document::page`] doesn't load
+    /// a page again when it's used again, like for rendering it and then reading its text and links.
+    ///
+    /// The cache is disabled by default, and with a `capacity` of 0. The least recently used pages are closed
+    /// when there are more pages than `capacity`. The pages keep their text too, which is loaded again
+    /// after the page is edited.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::Library;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+    /// document.set_page_cache_capacity(8);
+    ///
+    /// // The page is only loaded the first time
+    /// let text = document.page(0).unwrap().text().unwrap();
+    /// let links = document.page(0).unwrap().links().count();
+    /// ```
+    pub fn set_page_cache_capacity(&self, capacity: usize) {
+        self.core.set_page_cache_capacity(&self.handle, capacity)
+    }
+
+    /// The sizes of all pages in points, without loading them.
+    ///
+    /// Fails with [`PdfiumError::BadPage`] if the size of a page can't be read, like [`Document::page`] for a broken page.
+    pub fn page_sizes(&self) -> Result<Vec<Size>, PdfiumError> {
+        (0..self.page_count())
+            .map(|index| {
+                self.core
+                    .get_page_size_by_index(&self.handle, index)
+                    .ok_or(PdfiumError::BadPage)
+            })
+            .collect()
+    }
+
+    /// Write the whole document, including all changes, to `writer`.
+    pub fn save_to(&self, writer: &mut impl std::io::Write) -> Result<(), PdfiumError> {
+        self.core
+            .save_document(&self.handle, writer, pdfium_core::SaveMode::NoIncremental)
+    }
+
+    /// The type of the interactive form of the document.
+    pub fn form_type(&self) -> FormType {
+        self.core.get_form_type(&self.handle)
+    }
+
+    /// Every font used by text on the document's pages, including text inside form XObjects.
+    ///
+    /// Fonts are identified by their base name, so each font is only listed once.
+    pub fn fonts(&self) -> Result<Vec<FontInfo>, PdfiumError> {
+        let mut fonts: Vec<FontInfo> = Vec::new();
+
+        for index in 0..self.page_count() {
+            let page = self.core.load_page(&self.handle, index)?;
+
+            for index in 0..self.core.get_page_object_count(&page) {
+                if let Some(object) = self.core.get_page_object(&page, index) {
+                    collect_fonts(self.core, &object, &mut fonts);
+                }
+            }
+        }
+
+        Ok(fonts)
+    }
+}
+
+fn collect_fonts(
+    core: &pdfium_core::Library,
+    object: &pdfium_core::PageObjectHandle,
+    fonts: &mut Vec<FontInfo>,
+) {
+    match core.get_page_object_type(object) {
+        pdfium_core::PageObjectType::Text => {
+            if let Some(font) = core.get_text_object_font(object) {
+                let base_name = core.get_font_base_name(&font);
+
+                if fonts.iter().all(|x| x.base_name != base_name) {
+                    fonts.push(FontInfo::new(core, &font, base_name));
+                }
+            }
+        }
+        pdfium_core::PageObjectType::Form => {
+            for index in 0..core.get_form_object_count(object) {
+                if let Some(child) = core.get_form_object(object, index) {
+                    collect_fonts(core, &child, fonts);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A font used in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontInfo {
+    /// The PostScript name of the font, including the subset prefix, e.g. `"BAAAAA+Arial-BoldMT"`.
+    pub base_name: String,
+    /// The family name of the font, e.g. `"Arial"`.
+    pub family_name: String,
+    /// Whether the font program is embedded in the document.
+    pub is_embedded: bool,
+    /// Whether only the used glyphs of the font are embedded.
+    pub is_subset: bool,
+    /// The format of the embedded font program, `None` if the font is not embedded.
+    pub format: Option<FontFormat>,
+}
+
+impl FontInfo {
+    fn new(core: &pdfium_core::Library, font: &pdfium_core::FontHandle, base_name: String) -> Self {
+        let is_embedded = core.get_font_is_embedded(font);
+
+        // Subset fonts are prefixed with six uppercase letters and a plus sign.
+        let is_subset = base_name.len() > 7
+            && base_name.as_bytes()[6] == b'+'
+            && base_name.as_bytes()[..6].iter().all(u8::is_ascii_uppercase);
+
+        let format = if is_embedded {
+            Some(FontFormat::from_data(&core.get_font_data(font)))
+        } else {
+            None
+        };
+
+        FontInfo {
+            family_name: core.get_font_family_name(font),
+            base_name,
+            is_embedded,
+            is_subset,
+            format,
+        }
+    }
+}
+
+/// The format of an embedded font program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontFormat {
+    /// TrueType font, also used by TrueType based CID fonts.
+    TrueType,
+    /// OpenType font with PostScript outlines.
+    OpenType,
+    /// PostScript Type 1 font.
+    Type1,
+    /// Compact Font Format, e.g. Type 1C and CIDFontType0C fonts.
+    Cff,
+    /// The format could not be detected.
+    Unknown,
+}
+
+impl FontFormat {
+    fn from_data(data: &[u8]) -> Self {
+        match data {
+            [0x00, 0x01, 0x00, 0x00, ..] | [b't', b'r', b'u', b'e', ..] => FontFormat::TrueType,
+            [b'O', b'T', b'T', b'O', ..] => FontFormat::OpenType,
+            [b'%', b'!', ..] | [0x80, 0x01, ..] => FontFormat::Type1,
+            [0x01, 0x00, ..] => FontFormat::Cff,
+            _ => FontFormat::Unknown,
+        }
+    }
 }
 
 pub struct Page<'data, 'library> {
     handle: pdfium_core::PageHandle<'data, 'library>,
+    document: &'data pdfium_core::DocumentHandle<'data, 'library>,
     core: &'library pdfium_core::Library,
 }
 
@@ -98,6 +520,14 @@ impl Page<'_, '_> {
         self.core.get_page_height(&self.handle)
     }
 
+    /// The text of the page, in the order of its content.
+    pub fn text(&self) -> Result<String, PdfiumError> {
+        let text_page = self.core.load_cached_text_page(&self.handle)?;
+        let count = self.core.get_text_char_count(&text_page);
+
+        Ok(self.core.get_text(&text_page, 0, count))
+    }
+
     pub fn render_to(&self, bitmap: &mut Bitmap) {
         let width = bitmap.width() as i32;
         let height = bitmap.height() as i32;
@@ -112,6 +542,107 @@ impl Page<'_, '_> {
             0,
         );
     }
+
+    /// Like [`Page::render_to`] but also draws form fields with their current values.
+    ///
+    /// The form fill environment is initialized on the first call and kept with the document,
+    /// so rendering more pages of the same document doesn't initialize it again.
+    ///
+    /// Fails with [`PdfiumError::UnsupportedForm`] for documents with XFA forms, which would be drawn empty.
+    pub fn render_with_forms_to(&self, bitmap: &mut Bitmap) -> Result<(), PdfiumError> {
+        if self.core.get_form_type(self.document).is_xfa() {
+            return Err(PdfiumError::UnsupportedForm);
+        }
+
+        let width = bitmap.width() as i32;
+        let height = bitmap.height() as i32;
+        let form = self.core.init_cached_form_fill_environment(self.document)?;
+        let form_page = self.core.form_on_after_load_page(&form, &self.handle);
+
+        self.core.render_page_to_bitmap(
+            &mut bitmap.handle,
+            &self.handle,
+            0,
+            0,
+            width,
+            height,
+            PageOrientation::Normal,
+            pdfium_core::rendering_flags::ANNOTATIONS,
+        );
+        self.core.render_form_to_bitmap(
+            &mut bitmap.handle,
+            &form_page,
+            0,
+            0,
+            width,
+            height,
+            PageOrientation::Normal,
+            pdfium_core::rendering_flags::ANNOTATIONS,
+        );
+
+        Ok(())
+    }
+
+    /// Draw `image` on top of the page, stretched to fill `rect`.
+    #[cfg(feature = "image")]
+    pub fn insert_image(
+        &mut self,
+        image: &image::DynamicImage,
+        rect: Rect,
+    ) -> Result<(), PdfiumError> {
+        let object = self.image_object(image, &rect)?;
+        self.core.insert_page_object(&mut self.handle, object);
+
+        self.core.generate_page_content(&mut self.handle)
+    }
+
+    /// Add a stamp annotation showing `image`, stretched to fill `rect`.
+    ///
+    /// Unlike [`Page::insert_image`] the page's content is not changed,
+    /// so the stamp can be moved or removed by PDF viewers.
+    #[cfg(feature = "image")]
+    pub fn insert_image_stamp(
+        &mut self,
+        image: &image::DynamicImage,
+        rect: Rect,
+    ) -> Result<(), PdfiumError> {
+        let object = self.image_object(image, &rect)?;
+
+        let mut annotation = self
+            .core
+            .create_annotation(&mut self.handle, pdfium_core::AnnotationSubtype::Stamp)?;
+        self.core.set_annotation_rect(&mut annotation, &rect)?;
+        self.core.append_annotation_object(&mut annotation, object)
+    }
+}
+
+#[cfg(feature = "image")]
+impl<'data, 'library> Page<'data, 'library> {
+    fn image_object(
+        &self,
+        image: &image::DynamicImage,
+        rect: &Rect,
+    ) -> Result<pdfium_core::OwnedPageObjectHandle<'data, 'library>, PdfiumError> {
+        let mut image = image.to_bgra8();
+        let (width, height) = image.dimensions();
+        let format = BitmapFormat::BGRA;
+        let height_stride = width as usize * format.bytes_per_pixel();
+
+        let bitmap = self.core.create_bitmap_from_buffer(
+            width as usize,
+            height as usize,
+            format,
+            &mut image,
+            height_stride,
+        )?;
+
+        let mut object = self.core.create_image_object(self.document)?;
+        self.core.set_image_object_bitmap(&mut object, &bitmap)?;
+        self.core
+            .set_page_object_matrix(&mut object, &Matrix::from_rect(rect))?;
+
+        Ok(object)
+    }
 }
 
 pub struct Bitmap<'data, 'library> {
@@ -146,6 +677,8 @@ mod tests {
     use image::{Bgra, ImageBuffer};
 
     static DUMMY_PDF: &'static [u8] = include_bytes!("../test_assets/dummy.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../test_assets/forms.pdf");
+    static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../test_assets/password.pdf");
 
     #[test]
     fn only_one_library_at_a_time() {
@@ -169,6 +702,60 @@ mod tests {
         assert_eq!(document.page_count(), 1);
     }
 
+    #[test]
+    fn document_from_bytes_with_password() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let mut attempts = Vec::new();
+        let document = library.document_from_bytes_with_password(DUMMY_PASSWORD_PDF, |attempt| {
+            attempts.push(attempt);
+            ["wrong", "nul\0", "test"]
+                .get(attempt - 1)
+                .map(|x| x.to_string())
+        });
+        assert_eq!(document.unwrap().page_count(), 1);
+        assert_eq!(attempts, [1, 2, 3]);
+
+        let result = library.document_from_bytes_with_password(DUMMY_PASSWORD_PDF, |attempt| {
+            Some(attempt.to_string()).filter(|_| attempt < 3)
+        });
+        assert_eq!(result.err(), Some(PdfiumError::BadPassword));
+
+        // Documents without a password don't ask for one
+        let document = library
+            .document_from_bytes_with_password(DUMMY_PDF, |_| panic!("asked for a password"));
+        assert!(document.is_ok());
+    }
+
+    #[test]
+    fn owned_documents() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let document = library.document_from_vec(DUMMY_PDF.to_vec()).unwrap();
+        assert_eq!(document.page_count(), 1);
+
+        let buffer: Arc<[u8]> = Arc::from(DUMMY_PDF);
+        let first = library.document_from_arc(buffer.clone()).unwrap();
+        let second = library.document_from_arc(buffer).unwrap();
+        drop(first);
+        assert!(second.page(0).is_ok());
+
+        assert!(library.document_from_vec(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn document_from_reader() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let file = std::fs::File::open("test_assets/dummy.pdf").unwrap();
+        let document = library.document_from_reader(file).unwrap();
+        assert_eq!(document.page_count(), 1);
+        assert!(document.page(0).is_ok());
+    }
+
     #[test]
     fn page_dimensions() {
         let _guard = TEST_LOCK.lock();
@@ -215,4 +802,230 @@ mod tests {
         // There is at least one none white pixel
         assert!(image.pixels().any(|x| *x != Bgra::<u8>([0xFF; 4])));
     }
+
+    #[test]
+    fn render_with_forms() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        // Create white image
+        let mut image = ImageBuffer::from_pixel(
+            page.width().round() as u32,
+            page.height().round() as u32,
+            Bgra::<u8>([0xFF; 4]),
+        );
+        let layout = image.sample_layout();
+        let (width, height) = image.dimensions();
+        let mut buffer = image.as_flat_samples_mut();
+        let buffer = buffer.image_mut_slice().unwrap();
+
+        let mut bitmap = library
+            .bitmap_from_external_buffer(
+                width as usize,
+                height as usize,
+                layout.height_stride,
+                BitmapFormat::BGRA,
+                buffer,
+            )
+            .unwrap();
+
+        page.render_with_forms_to(&mut bitmap).unwrap();
+
+        drop(bitmap);
+
+        // The value of the "name" text field is drawn
+        let top = height - 720;
+        assert!(image
+            .enumerate_pixels()
+            .filter(|(x, y, _)| (100..300).contains(x) && (top..top + 20).contains(y))
+            .any(|(_, _, pixel)| *pixel != Bgra::<u8>([0xFF; 4])));
+    }
+
+    #[test]
+    fn form_type() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        assert_eq!(document.form_type(), FormType::AcroForm);
+
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert_eq!(document.form_type(), FormType::None);
+    }
+
+    #[test]
+    fn page_sizes() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        assert_eq!(
+            document.page_sizes().unwrap(),
+            [Size {
+                width: 595.0,
+                height: 842.0
+            }]
+        );
+    }
+
+    #[test]
+    fn page_text() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        let text = document.page(0).unwrap().text().unwrap();
+        assert!(text.starts_with("Dummy PDF file"));
+    }
+
+    #[test]
+    fn bitmap_from_external_buffer() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let mut buffer = vec![0; 2 * 10 * 4 * 10];
+
+        // A row is shorter than the pixels of a row
+        let result =
+            library.bitmap_from_external_buffer(10, 10, 39, BitmapFormat::BGRA, &mut buffer);
+        assert_eq!(result.err(), Some(PdfiumError::BadFormat));
+        let result =
+            library.bitmap_from_external_buffer(10, 10, 80, BitmapFormat::BGRA, &mut buffer[1..]);
+        assert_eq!(result.err(), Some(PdfiumError::BadFormat));
+        let result = library.bitmap_from_external_buffer(
+            10,
+            usize::MAX,
+            80,
+            BitmapFormat::BGRA,
+            &mut buffer,
+        );
+        assert_eq!(result.err(), Some(PdfiumError::BadFormat));
+
+        // A frame in the second half of the buffer
+        let bitmap = library
+            .bitmap_from_external_buffer(10, 10, 40, BitmapFormat::BGRA, &mut buffer[400..])
+            .unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (10, 10));
+    }
+
+    #[test]
+    fn page_cache() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        document.set_page_cache_capacity(2);
+
+        let first = document.page(0).unwrap();
+        let second = document.page(0).unwrap();
+        assert_eq!(first.text(), second.text());
+        drop((first, second));
+
+        for _ in 0..3 {
+            assert!(document
+                .page(0)
+                .unwrap()
+                .text()
+                .unwrap()
+                .starts_with("Dummy PDF file"));
+        }
+        assert!(document.page(1).is_err());
+        document.set_page_cache_capacity(0);
+    }
+
+    #[test]
+    fn fonts() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        let fonts = document.fonts().unwrap();
+        assert_eq!(fonts.len(), 1);
+
+        let font = &fonts[0];
+        assert_eq!(font.base_name, "BAAAAA+Arial-BoldMT");
+        assert!(font.is_embedded);
+        assert!(font.is_subset);
+        assert_eq!(font.format, Some(FontFormat::TrueType));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn insert_image() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let mut page = document.page(0).unwrap();
+
+        let image = image::DynamicImage::ImageBgra8(ImageBuffer::from_pixel(
+            10,
+            10,
+            Bgra::<u8>([0x00, 0x00, 0xFF, 0xFF]),
+        ));
+        let rect = Rect {
+            left: 0.0,
+            bottom: 0.0,
+            right: page.width(),
+            top: page.height(),
+        };
+        page.insert_image(&image, rect).unwrap();
+
+        let mut image = ImageBuffer::from_pixel(
+            page.width().round() as u32,
+            page.height().round() as u32,
+            Bgra::<u8>([0xFF; 4]),
+        );
+        let layout = image.sample_layout();
+        let (width, height) = image.dimensions();
+        let mut buffer = image.as_flat_samples_mut();
+        let buffer = buffer.image_mut_slice().unwrap();
+
+        let mut bitmap = library
+            .bitmap_from_external_buffer(
+                width as usize,
+                height as usize,
+                layout.height_stride,
+                BitmapFormat::BGRA,
+                buffer,
+            )
+            .unwrap();
+
+        page.render_to(&mut bitmap);
+
+        drop(bitmap);
+
+        // The image covers the whole page
+        assert!(image
+            .pixels()
+            .all(|x| *x == Bgra::<u8>([0x00, 0x00, 0xFF, 0xFF])));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn insert_image_stamp() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let mut page = document.page(0).unwrap();
+        let object_count = page.objects().len();
+
+        let image = image::DynamicImage::ImageBgra8(ImageBuffer::from_pixel(
+            10,
+            10,
+            Bgra::<u8>([0x00, 0x00, 0xFF, 0xFF]),
+        ));
+        let rect = Rect {
+            left: 100.0,
+            bottom: 100.0,
+            right: 200.0,
+            top: 200.0,
+        };
+        page.insert_image_stamp(&image, rect).unwrap();
+
+        assert_eq!(page.objects().len(), object_count);
+        assert_eq!(page.core.get_annotation_count(&page.handle), 1);
+
+        let annotation = page.core.get_annotation(&page.handle, 0).unwrap();
+        assert_eq!(page.core.get_annotation_rect(&annotation), Some(rect));
+    }
 }
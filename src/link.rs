@@ -0,0 +1,112 @@
+use crate::action::action;
+use crate::destination::destination;
+use crate::{Action, Destination, Page, QuadPoints, Rect};
+
+impl Page<'_, '_> {
+    /// The links of the page, the areas that jump somewhere when they are clicked.
+    pub fn links(&self) -> impl Iterator<Item = Link> + '_ {
+        self.core
+            .get_page_links(&self.handle)
+            .into_iter()
+            .map(move |link| {
+                let action = self
+                    .core
+                    .get_link_action(&link)
+                    .map(|handle| action(self.core, self.document, &handle));
+                let destination = match &action {
+                    Some(Action::GoTo { destination }) => *destination,
+                    _ => self
+                        .core
+                        .get_link_destination(self.document, &link)
+                        .and_then(|handle| destination(self.core, self.document, &handle)),
+                };
+
+                Link {
+                    rect: self.core.get_link_rect(&link).unwrap_or_default(),
+                    quad_points: (0..self.core.get_link_quad_points_count(&link))
+                        .filter_map(|index| self.core.get_link_quad_points(&link, index))
+                        .collect(),
+                    destination,
+                    action,
+                }
+            })
+    }
+}
+
+/// A link on a page.
+///
+/// Created using [`Page::links`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Link {
+    /// The area of the page that can be clicked.
+    pub rect: Rect,
+    /// The clickable area more precisely, like the lines of a link spanning multiple lines.
+    ///
+    /// Empty if the link only has a rectangle.
+    pub quad_points: Vec<QuadPoints>,
+    /// `None` if the link doesn't jump to a page of the document.
+    ///
+    /// This is also set for links with an [`Action::GoTo`] action.
+    pub destination: Option<Destination>,
+    /// `None` if the link just has a destination.
+    pub action: Option<Action>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static OUTLINE_PDF: &[u8] = include_bytes!("../test_assets/outline.pdf");
+
+    #[test]
+    fn links() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(OUTLINE_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        let rect = Rect {
+            left: 72.0,
+            bottom: 600.0,
+            right: 200.0,
+            top: 620.0,
+        };
+
+        assert_eq!(
+            page.links().collect::<Vec<_>>(),
+            [
+                Link {
+                    rect,
+                    quad_points: vec![QuadPoints::from_rect(&rect)],
+                    destination: Some(Destination {
+                        page_index: 2,
+                        view: DestinationView::FitR(Rect {
+                            left: 0.0,
+                            bottom: 0.0,
+                            right: 300.0,
+                            top: 400.0
+                        })
+                    }),
+                    action: None,
+                },
+                Link {
+                    rect: Rect {
+                        left: 72.0,
+                        bottom: 560.0,
+                        right: 200.0,
+                        top: 580.0,
+                    },
+                    quad_points: vec![],
+                    destination: None,
+                    action: Some(Action::Uri {
+                        uri: "https://example.com/".to_string()
+                    }),
+                },
+            ]
+        );
+
+        let page = document.page(1).unwrap();
+        assert_eq!(page.links().count(), 0);
+    }
+}
@@ -0,0 +1,469 @@
+use crate::{permission_flags, Document, FileIdentifierType, PageMode, PdfiumError};
+use std::fmt;
+
+impl Document<'_, '_> {
+    /// The entries of the document information dictionary.
+    pub fn metadata(&self) -> Metadata {
+        let text = |tag| Some(self.core.get_meta_text(&self.handle, tag)).filter(|x| !x.is_empty());
+
+        Metadata {
+            title: text("Title"),
+            author: text("Author"),
+            subject: text("Subject"),
+            keywords: text("Keywords"),
+            creator: text("Creator"),
+            producer: text("Producer"),
+            creation_date: text("CreationDate").and_then(|date| PdfDate::parse(&date)),
+            modification_date: text("ModDate").and_then(|date| PdfDate::parse(&date)),
+        }
+    }
+
+    /// What the document allows users to do, see [`Permissions`].
+    pub fn permissions(&self) -> Permissions {
+        Permissions::from_bits(self.core.get_document_permissions(&self.handle))
+    }
+
+    /// Check if the document is encrypted, which it can be without requiring a password to open it.
+    pub fn is_encrypted(&self) -> bool {
+        self.security_handler_revision().is_some()
+    }
+
+    /// The revision of the standard security handler, like `4` for AES-128 encryption.
+    ///
+    /// Returns `None` if the document is not encrypted.
+    pub fn security_handler_revision(&self) -> Option<i32> {
+        self.core.get_security_handler_revision(&self.handle)
+    }
+
+    /// How the document wants to be displayed when it's opened, like with the outline next to the pages.
+    pub fn page_mode(&self) -> PageMode {
+        self.core.get_page_mode(&self.handle)
+    }
+
+    /// A name entry of the viewer preferences, like `Direction` or `ViewArea`.
+    ///
+    /// Returns `None` if the viewer preferences don't have `key` or its value is not a name.
+    pub fn viewer_preference(&self, key: &str) -> Option<String> {
+        self.core.get_viewer_preference_name(&self.handle, key)
+    }
+
+    /// A file identifier of the document, usually a 16 byte hash.
+    ///
+    /// The [`Permanent`](FileIdentifierType::Permanent) identifier stays the same for all versions of a document,
+    /// the [`Changing`](FileIdentifierType::Changing) identifier changes whenever the document is changed.
+    ///
+    /// Returns `None` if the document doesn't have file identifiers.
+    pub fn file_identifier(&self, identifier_type: FileIdentifierType) -> Option<Vec<u8>> {
+        self.core.get_file_identifier(&self.handle, identifier_type)
+    }
+
+    /// Check if the document is a tagged PDF, with a structure tree that assistive technology can use.
+    pub fn is_tagged(&self) -> bool {
+        self.core.is_document_tagged(&self.handle)
+    }
+
+    /// The natural language of the document, like `en-US`.
+    pub fn language(&self) -> Option<String> {
+        Some(self.core.get_document_language(&self.handle)).filter(|x| !x.is_empty())
+    }
+
+    /// Set the natural language of the document, which screen readers use to pronounce its text.
+    pub fn set_language(&mut self, language: &str) -> Result<(), PdfiumError> {
+        self.core.set_document_language(&mut self.handle, language)
+    }
+
+    /// The PDF version in the header of the document.
+    pub fn version(&self) -> Option<PdfVersion> {
+        self.core
+            .get_file_version(&self.handle)
+            .map(|version| PdfVersion {
+                major: (version / 10) as u8,
+                minor: (version % 10) as u8,
+            })
+    }
+}
+
+/// The document information dictionary of a document.
+///
+/// Created using [`Document::metadata`]. Missing and empty entries are `None`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Option<String>,
+    /// The application that created the original document.
+    pub creator: Option<String>,
+    /// The application that converted the document to PDF.
+    pub producer: Option<String>,
+    /// `None` if the date is not in the PDF date format.
+    pub creation_date: Option<PdfDate>,
+    /// `None` if the date is not in the PDF date format.
+    pub modification_date: Option<PdfDate>,
+}
+
+/// The version of the PDF specification a document follows, like `1.7` or `2.0`.
+///
+/// Versions are ordered, so `version >= PdfVersion::PDF_2_0` checks for PDF 2.0 and later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PdfVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl PdfVersion {
+    pub const PDF_1_7: PdfVersion = PdfVersion { major: 1, minor: 7 };
+    pub const PDF_2_0: PdfVersion = PdfVersion { major: 2, minor: 0 };
+}
+
+impl fmt::Display for PdfVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// What an encrypted document allows users to do. Documents that are not encrypted allow everything.
+///
+/// PDFium doesn't enforce permissions, viewers are expected to respect them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Permissions(u32);
+
+impl Permissions {
+    /// Create permissions from [`permission_flags`](pdfium_core::permission_flags).
+    pub const fn from_bits(bits: u32) -> Permissions {
+        Permissions(bits)
+    }
+
+    /// The [`permission_flags`](pdfium_core::permission_flags) of the permissions.
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    fn contains(&self, flags: u32) -> bool {
+        self.0 & flags != 0
+    }
+
+    pub fn can_print(&self) -> bool {
+        self.contains(permission_flags::PRINT)
+    }
+
+    /// `false` means the document may only be printed in a degraded quality.
+    pub fn can_print_high_quality(&self) -> bool {
+        self.can_print() && self.contains(permission_flags::PRINT_HIGH_QUALITY)
+    }
+
+    /// Change the document in ways not covered by the other permissions.
+    pub fn can_modify(&self) -> bool {
+        self.contains(permission_flags::MODIFY)
+    }
+
+    /// Copy or extract text and graphics.
+    pub fn can_copy(&self) -> bool {
+        self.contains(permission_flags::COPY)
+    }
+
+    /// Add or change annotations.
+    pub fn can_annotate(&self) -> bool {
+        self.contains(permission_flags::ANNOTATE)
+    }
+
+    /// Fill in existing form fields.
+    pub fn can_fill_forms(&self) -> bool {
+        self.contains(permission_flags::ANNOTATE | permission_flags::FILL_FORMS)
+    }
+
+    /// Extract text and graphics for accessibility, like screen readers do.
+    pub fn can_extract_for_accessibility(&self) -> bool {
+        self.contains(permission_flags::EXTRACT_FOR_ACCESSIBILITY)
+    }
+
+    /// Insert, rotate or delete pages and create bookmarks and thumbnails.
+    pub fn can_assemble(&self) -> bool {
+        self.contains(permission_flags::MODIFY | permission_flags::ASSEMBLE)
+    }
+}
+
+/// A date in the PDF date format, like `D:20070223175637+02'00'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PdfDate {
+    pub year: u16,
+    /// From `1` to `12`.
+    pub month: u8,
+    /// From `1` to `31`.
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// The offset from UTC in minutes, `None` if the time zone is unknown.
+    pub utc_offset: Option<i16>,
+}
+
+impl PdfDate {
+    /// Parse a date in the PDF date format.
+    ///
+    /// Everything after the year is optional, missing parts are the start of their range.
+    /// The `D:` prefix is optional too, because some producers leave it out.
+    ///
+    /// Returns `None` if `date` is not in the PDF date format.
+    ///
+    /// ```
+    /// use pdfium_rs::PdfDate;
+    ///
+    /// let date = PdfDate::parse("D:20070223175637+02'00'").unwrap();
+    /// assert_eq!((date.year, date.month, date.day), (2007, 2, 23));
+    /// assert_eq!(date.utc_offset, Some(120));
+    /// ```
+    pub fn parse(date: &str) -> Option<PdfDate> {
+        let date = date.trim();
+        let date = date.strip_prefix("D:").unwrap_or(date);
+
+        let digits = date.bytes().take_while(u8::is_ascii_digit).count();
+        if digits < 4 || digits % 2 != 0 || digits > 14 {
+            return None;
+        }
+        let (numbers, zone) = date.split_at(digits);
+        let number = |range: std::ops::Range<usize>, default| {
+            numbers
+                .get(range)
+                .map_or(Some(default), |x| x.parse::<u16>().ok())
+        };
+
+        let result = PdfDate {
+            year: number(0..4, 0)?,
+            month: number(4..6, 1)? as u8,
+            day: number(6..8, 1)? as u8,
+            hour: number(8..10, 0)? as u8,
+            minute: number(10..12, 0)? as u8,
+            second: number(12..14, 0)? as u8,
+            utc_offset: parse_utc_offset(zone)?,
+        };
+
+        let valid = (1..=12).contains(&result.month)
+            && (1..=31).contains(&result.day)
+            && result.hour < 24
+            && result.minute < 60
+            && result.second < 60;
+
+        if valid {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse the time zone of a PDF date, like `+02'00'`, `Z` or nothing.
+///
+/// The outer `None` means `zone` is malformed.
+fn parse_utc_offset(zone: &str) -> Option<Option<i16>> {
+    let sign = match zone.chars().next() {
+        None => return Some(None),
+        Some('Z') => 0,
+        Some('+') => 1,
+        Some('-') => -1,
+        Some(_) => return None,
+    };
+
+    let mut parts = zone[1..]
+        .split('\'')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<i16>().ok().filter(|_| part.len() == 2));
+    let hours = parts.next().unwrap_or(Some(0))?;
+    let minutes = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() || hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some(Some(sign * (hours * 60 + minutes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../test_assets/password.pdf");
+    static BAD_PDF: &[u8] = include_bytes!("../test_assets/bad.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../test_assets/forms.pdf");
+    static TAGGED_PDF: &[u8] = include_bytes!("../test_assets/tagged.pdf");
+
+    #[test]
+    fn metadata() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        let metadata = document.metadata();
+        assert_eq!(metadata.title.as_deref(), Some("Dummy PDF file"));
+        assert_eq!(metadata.author.as_deref(), Some("Evangelos Vlachogiannis"));
+        assert_eq!(metadata.subject, None);
+        assert_eq!(metadata.creator.as_deref(), Some("Writer"));
+        assert_eq!(metadata.producer.as_deref(), Some("OpenOffice.org 2.1"));
+        assert_eq!(
+            metadata.creation_date,
+            Some(PdfDate {
+                year: 2007,
+                month: 2,
+                day: 23,
+                hour: 17,
+                minute: 56,
+                second: 37,
+                utc_offset: Some(120),
+            })
+        );
+        assert_eq!(metadata.modification_date, None);
+    }
+
+    #[test]
+    fn version() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        let version = document.version().unwrap();
+        assert_eq!(version, PdfVersion { major: 1, minor: 4 });
+        assert_eq!(version.to_string(), "1.4");
+        assert!(version < PdfVersion::PDF_1_7);
+        assert!(PdfVersion::PDF_1_7 < PdfVersion::PDF_2_0);
+    }
+
+    #[test]
+    fn permissions() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        let permissions = document.permissions();
+        assert_eq!(permissions.bits(), u32::MAX);
+        assert!(permissions.can_print_high_quality());
+        assert!(permissions.can_modify());
+    }
+
+    #[test]
+    fn permission_checks() {
+        let permissions = Permissions::from_bits(
+            permission_flags::PRINT | permission_flags::FILL_FORMS | permission_flags::ASSEMBLE,
+        );
+        assert!(permissions.can_print());
+        assert!(!permissions.can_print_high_quality());
+        assert!(!permissions.can_modify());
+        assert!(!permissions.can_copy());
+        assert!(!permissions.can_annotate());
+        assert!(permissions.can_fill_forms());
+        assert!(!permissions.can_extract_for_accessibility());
+        assert!(permissions.can_assemble());
+
+        let permissions = Permissions::from_bits(permission_flags::ANNOTATE);
+        assert!(permissions.can_annotate());
+        assert!(permissions.can_fill_forms());
+
+        let permissions = Permissions::from_bits(permission_flags::PRINT_HIGH_QUALITY);
+        assert!(!permissions.can_print_high_quality());
+    }
+
+    #[test]
+    fn encryption() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        assert!(!document.is_encrypted());
+        assert_eq!(document.security_handler_revision(), None);
+
+        assert!(!library.requires_password(DUMMY_PDF).unwrap());
+        assert!(library.requires_password(DUMMY_PASSWORD_PDF).unwrap());
+        assert!(library.requires_password(BAD_PDF).is_err());
+    }
+
+    #[test]
+    fn page_mode() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+
+        assert_eq!(document.page_mode(), PageMode::UseNone);
+    }
+
+    #[test]
+    fn viewer_preference() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+
+        assert_eq!(
+            document.viewer_preference("Direction").as_deref(),
+            Some("R2L")
+        );
+        assert_eq!(document.viewer_preference("NonFullScreenPageMode"), None);
+    }
+
+    #[test]
+    fn file_identifier() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let permanent = document
+            .file_identifier(FileIdentifierType::Permanent)
+            .unwrap();
+        assert_eq!(permanent.len(), 16);
+        assert_eq!(
+            document.file_identifier(FileIdentifierType::Changing),
+            Some(permanent)
+        );
+
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        assert_eq!(
+            document.file_identifier(FileIdentifierType::Permanent),
+            None
+        );
+    }
+
+    #[test]
+    fn tagged() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let mut document = library.document_from_bytes(TAGGED_PDF).unwrap();
+        assert!(document.is_tagged());
+        assert_eq!(document.language().as_deref(), Some("en-US"));
+
+        document.set_language("nl-BE").unwrap();
+        assert_eq!(document.language().as_deref(), Some("nl-BE"));
+
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(!document.is_tagged());
+        assert_eq!(document.language(), None);
+    }
+
+    #[test]
+    fn parse_date() {
+        let date = PdfDate::parse("D:1999").unwrap();
+        assert_eq!((date.year, date.month, date.day), (1999, 1, 1));
+        assert_eq!(date.utc_offset, None);
+
+        let date = PdfDate::parse("20201231235959Z").unwrap();
+        assert_eq!((date.hour, date.minute, date.second), (23, 59, 59));
+        assert_eq!(date.utc_offset, Some(0));
+
+        assert_eq!(
+            PdfDate::parse("D:202001011200-05'30").unwrap().utc_offset,
+            Some(-330)
+        );
+        assert_eq!(
+            PdfDate::parse("D:2020010112+01").unwrap().utc_offset,
+            Some(60)
+        );
+
+        assert_eq!(PdfDate::parse(""), None);
+        assert_eq!(PdfDate::parse("D:20"), None);
+        assert_eq!(PdfDate::parse("D:20201"), None);
+        assert_eq!(PdfDate::parse("D:20201301"), None);
+        assert_eq!(PdfDate::parse("D:2020010112+1"), None);
+        assert_eq!(PdfDate::parse("yesterday"), None);
+    }
+}
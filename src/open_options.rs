@@ -0,0 +1,313 @@
+use crate::{Document, Library, PartialDocument, PdfiumError};
+use std::ffi::CString;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+static HEADER: &[u8] = b"%PDF-";
+
+/// Options for opening documents, like their password.
+///
+/// All the `document_from_*` functions of [`Library`] open documents with the default options.
+///
+/// ## Examples
+/// ```
+/// use pdfium_rs::{Library, OpenOptions};
+/// # static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../test_assets/password.pdf");
+///
+/// let library = Library::init().unwrap();
+///
+/// let document = OpenOptions::new()
+///     .password("test")
+///     .open_bytes(&library, DUMMY_PASSWORD_PDF)
+///     .unwrap();
+/// assert_eq!(document.page_count(), 1);
+/// ```
+#[derive(Clone, Default)]
+pub struct OpenOptions {
+    password: Option<String>,
+    tolerant: bool,
+}
+
+impl OpenOptions {
+    /// The default options, without a password.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the password of the document, it's ignored if the document is not encrypted.
+    ///
+    /// Depending on the document, PDFium expects the password in UTF-8 or Latin-1,
+    /// it's converted to the other encoding when needed.
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Open documents that start after other data, like a document that was saved in an email.
+    ///
+    /// PDFium looks for the start of a document, the `%PDF-` header, in its first 1024 bytes only.
+    /// In tolerant mode the data is searched for the header before it's opened.
+    /// Damaged documents are repaired by PDFium in either mode, like documents with a broken cross-reference table.
+    pub fn tolerant(mut self, tolerant: bool) -> Self {
+        self.tolerant = tolerant;
+        self
+    }
+
+    /// Open a document from a bytes buffer, see [`Library::document_from_bytes`].
+    pub fn open_bytes<'a>(
+        &self,
+        library: &'a Library,
+        buffer: &'a [u8],
+    ) -> Result<Document<'a, 'a>, PdfiumError> {
+        let password = self.c_password()?;
+
+        let buffer = match self.tolerant {
+            true => &buffer[find_header_in_bytes(buffer)..],
+            false => buffer,
+        };
+
+        let handle = library
+            .core
+            .load_document_from_bytes(buffer, password.as_deref())?;
+
+        Ok(Document {
+            handle,
+            core: &library.core,
+        })
+    }
+
+    /// Open a document from a reader, see [`Library::document_from_reader`].
+    pub fn open_reader<'data, 'library>(
+        &self,
+        library: &'library Library,
+        mut reader: impl Read + Seek + 'data,
+    ) -> Result<Document<'data, 'library>, PdfiumError> {
+        let password = self.c_password()?;
+
+        let handle = match self.tolerant {
+            true => {
+                let offset = find_header(&mut reader).map_err(|_| PdfiumError::BadFile)?;
+                let reader = Skip { reader, offset };
+
+                library
+                    .core
+                    .load_document_from_reader(reader, password.as_deref())?
+            }
+            false => library
+                .core
+                .load_document_from_reader(reader, password.as_deref())?,
+        };
+
+        Ok(Document {
+            handle,
+            core: &library.core,
+        })
+    }
+
+    /// Open a document from a file.
+    ///
+    /// Fails with [`PdfiumError::BadFile`] if the file can't be opened.
+    pub fn open_file<'library>(
+        &self,
+        library: &'library Library,
+        path: impl AsRef<Path>,
+    ) -> Result<Document<'static, 'library>, PdfiumError> {
+        let path = path.as_ref();
+
+        if self.tolerant {
+            let file = File::open(path).map_err(|_| PdfiumError::BadFile)?;
+
+            return self.open_reader(library, file);
+        }
+
+        let password = self.c_password()?;
+        let handle = library.core.load_document(path, password.as_deref())?;
+
+        Ok(Document {
+            handle,
+            core: &library.core,
+        })
+    }
+
+    /// Open a document that is still being downloaded, see [`PartialDocument::document`].
+    ///
+    /// Tolerant mode doesn't apply, the document is read by PDFium as it's downloaded.
+    pub fn open_partial<'a, 'library>(
+        &self,
+        partial: &'a PartialDocument<'_, 'library>,
+    ) -> Result<Document<'a, 'library>, PdfiumError> {
+        let password = self.c_password()?;
+
+        partial.available_document(password.as_deref())
+    }
+
+    /// The password for PDFium, a password with a nul byte can't be passed to PDFium so it can't be right.
+    fn c_password(&self) -> Result<Option<CString>, PdfiumError> {
+        self.password
+            .as_deref()
+            .map(|password| CString::new(password).map_err(|_| PdfiumError::BadPassword))
+            .transpose()
+    }
+}
+
+impl fmt::Debug for OpenOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't leak the password into logs
+        f.debug_struct("OpenOptions")
+            .field("password", &self.password.as_ref().map(|_| "..."))
+            .field("tolerant", &self.tolerant)
+            .finish()
+    }
+}
+
+/// The offset of the header, 0 if it's not found so PDFium reports the error.
+fn find_header_in_bytes(buffer: &[u8]) -> usize {
+    buffer
+        .windows(HEADER.len())
+        .position(|window| window == HEADER)
+        .unwrap_or(0)
+}
+
+/// The offset of the header in `reader`, it's read in chunks from the start until the header is found.
+fn find_header(reader: &mut (impl Read + Seek)) -> io::Result<u64> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut buffer = vec![0; 64 * 1024];
+    let mut chunk_offset = 0;
+    // The end of the last chunk, so headers across chunks are found
+    let mut kept = 0;
+
+    loop {
+        let length = match reader.read(&mut buffer[kept..]) {
+            Ok(0) => return Ok(0),
+            Ok(length) => kept + length,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+
+        let chunk = &buffer[..length];
+        if let Some(position) = chunk
+            .windows(HEADER.len())
+            .position(|window| window == HEADER)
+        {
+            return Ok(chunk_offset + position as u64);
+        }
+
+        kept = length.min(HEADER.len() - 1);
+        buffer.copy_within(length - kept..length, 0);
+        chunk_offset += (length - kept) as u64;
+    }
+}
+
+/// A reader without its first `offset` bytes.
+struct Skip<R> {
+    reader: R,
+    offset: u64,
+}
+
+impl<R: Read> Read for Skip<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buffer)
+    }
+}
+
+impl<R: Seek> Seek for Skip<R> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let position = match position {
+            SeekFrom::Start(offset) => SeekFrom::Start(self.offset + offset),
+            position => position,
+        };
+        let position = self.reader.seek(position)?;
+
+        position.checked_sub(self.offset).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to before the start of the document",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_LOCK;
+    use std::io::Cursor;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static DUMMY_PASSWORD_PDF: &[u8] = include_bytes!("../test_assets/password.pdf");
+
+    #[test]
+    fn find_header() {
+        let mut data = vec![b'x'; 100_000];
+        data.extend_from_slice(DUMMY_PDF);
+        assert_eq!(find_header_in_bytes(&data), 100_000);
+        assert_eq!(
+            super::find_header(&mut Cursor::new(&data)).unwrap(),
+            100_000
+        );
+
+        // Across the chunks of the reader
+        let data = [&[b'x'; 64 * 1024 - 2][..], HEADER].concat();
+        assert_eq!(
+            super::find_header(&mut Cursor::new(&data)).unwrap(),
+            64 * 1024 - 2
+        );
+
+        assert_eq!(find_header_in_bytes(b"no header"), 0);
+        assert_eq!(
+            super::find_header(&mut Cursor::new(b"no header")).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn skip() {
+        let mut reader = Skip {
+            reader: Cursor::new(b"junk%PDF-"),
+            offset: 4,
+        };
+
+        assert_eq!(reader.seek(SeekFrom::End(0)).unwrap(), 5);
+        assert_eq!(reader.seek(SeekFrom::Start(1)).unwrap(), 1);
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"PDF-");
+        assert!(reader.seek(SeekFrom::Current(-10)).is_err());
+    }
+
+    #[test]
+    fn open_options() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let options = OpenOptions::new().password("test");
+        assert!(options.open_bytes(&library, DUMMY_PASSWORD_PDF).is_ok());
+        assert!(!format!("{:?}", options).contains("test"));
+
+        let result = OpenOptions::new()
+            .password("te\0st")
+            .open_bytes(&library, DUMMY_PASSWORD_PDF);
+        assert_eq!(result.err(), Some(PdfiumError::BadPassword));
+
+        let mut data = vec![b'x'; 2000];
+        data.extend_from_slice(DUMMY_PDF);
+        assert!(OpenOptions::new().open_bytes(&library, &data).is_err());
+
+        let options = OpenOptions::new().tolerant(true);
+        let document = options.open_bytes(&library, &data).unwrap();
+        assert_eq!(document.page_count(), 1);
+        let document = options.open_reader(&library, Cursor::new(&data)).unwrap();
+        assert_eq!(document.page_count(), 1);
+
+        let document = OpenOptions::new()
+            .open_file(&library, "test_assets/dummy.pdf")
+            .unwrap();
+        assert_eq!(document.page_count(), 1);
+        assert!(OpenOptions::new()
+            .open_file(&library, "test_assets/missing.pdf")
+            .is_err());
+    }
+}
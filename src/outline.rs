@@ -0,0 +1,176 @@
+use crate::action::action;
+use crate::destination::destination;
+use crate::{Action, Destination, Document};
+use pdfium_core::BookmarkHandle;
+use std::collections::HashSet;
+
+impl Document<'_, '_> {
+    /// The outline of the document, the tree of bookmarks shown as table of contents by viewers.
+    ///
+    /// Bookmarks that appear more than once in a broken outline are only included the first time.
+    pub fn outline(&self) -> Vec<OutlineItem> {
+        self.outline_children(None, &mut HashSet::new())
+    }
+
+    fn outline_children<'data>(
+        &'data self,
+        parent: Option<&BookmarkHandle>,
+        visited: &mut HashSet<BookmarkHandle<'data, 'data>>,
+    ) -> Vec<OutlineItem> {
+        let mut children = Vec::new();
+        let mut child = self.core.get_first_bookmark_child(&self.handle, parent);
+
+        while let Some(bookmark) = child {
+            if !visited.insert(bookmark.clone()) {
+                break;
+            }
+
+            let action = self
+                .core
+                .get_bookmark_action(&bookmark)
+                .map(|handle| action(self.core, &self.handle, &handle));
+            let destination = match &action {
+                Some(Action::GoTo { destination }) => *destination,
+                _ => self
+                    .core
+                    .get_bookmark_destination(&self.handle, &bookmark)
+                    .and_then(|handle| destination(self.core, &self.handle, &handle)),
+            };
+
+            children.push(OutlineItem {
+                title: self.core.get_bookmark_title(&bookmark),
+                destination,
+                action,
+                children: self.outline_children(Some(&bookmark), visited),
+            });
+
+            child = self.core.get_next_bookmark_sibling(&self.handle, &bookmark);
+        }
+
+        children
+    }
+}
+
+/// An item in the outline of a document.
+///
+/// Created using [`Document::outline`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlineItem {
+    pub title: String,
+    /// `None` if the item doesn't jump to a page of the document.
+    ///
+    /// This is also set for items with an [`Action::GoTo`] action.
+    pub destination: Option<Destination>,
+    /// `None` if the item just has a destination.
+    pub action: Option<Action>,
+    pub children: Vec<OutlineItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static OUTLINE_PDF: &[u8] = include_bytes!("../test_assets/outline.pdf");
+
+    fn item(
+        title: &str,
+        destination: Option<Destination>,
+        action: Option<Action>,
+        children: Vec<OutlineItem>,
+    ) -> OutlineItem {
+        OutlineItem {
+            title: title.to_string(),
+            destination,
+            action,
+            children,
+        }
+    }
+
+    fn destination(page_index: usize, view: DestinationView) -> Option<Destination> {
+        Some(Destination { page_index, view })
+    }
+
+    #[test]
+    fn outline() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(OUTLINE_PDF).unwrap();
+
+        let section = destination(
+            1,
+            DestinationView::Xyz {
+                left: Some(72.0),
+                top: Some(400.0),
+                zoom: Some(2.0),
+            },
+        );
+
+        assert_eq!(
+            document.outline(),
+            [
+                item(
+                    "Introduction",
+                    destination(
+                        0,
+                        DestinationView::Xyz {
+                            left: Some(72.0),
+                            top: Some(720.0),
+                            zoom: None
+                        }
+                    ),
+                    None,
+                    vec![]
+                ),
+                item(
+                    "Chapter 1",
+                    destination(1, DestinationView::FitH { top: 500.0 }),
+                    None,
+                    vec![
+                        item(
+                            "Section 1.1",
+                            section,
+                            Some(Action::GoTo {
+                                destination: section
+                            }),
+                            vec![]
+                        ),
+                        item(
+                            "Section 1.2",
+                            None,
+                            Some(Action::Uri {
+                                uri: "https://example.com/section".to_string()
+                            }),
+                            vec![]
+                        )
+                    ]
+                ),
+                item(
+                    "Chapter 2",
+                    destination(2, DestinationView::Fit),
+                    None,
+                    vec![item(
+                        "Appendix",
+                        None,
+                        Some(Action::RemoteGoTo {
+                            file_path: "other.pdf".to_string()
+                        }),
+                        vec![]
+                    )]
+                ),
+                item(
+                    "Notes",
+                    None,
+                    Some(Action::Launch {
+                        file_path: "notes.txt".to_string()
+                    }),
+                    vec![]
+                ),
+            ]
+        );
+
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(document.outline().is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{Bitmap, Matrix, Page, PageObjectType, PathSegmentType, PdfiumError, Rect};
+
+impl Page<'_, '_> {
+    /// The objects on the page, in drawing order.
+    ///
+    /// Changes made to the returned objects are visible when rendering the page,
+    /// use [`Page::edit_objects`] to also save them to the page's content.
+    pub fn objects(&self) -> Vec<PageObject<'_, '_>> {
+        (0..self.core.get_page_object_count(&self.handle))
+            .filter_map(|index| self.core.get_page_object(&self.handle, index))
+            .map(|handle| {
+                PageObject::new(Object {
+                    handle,
+                    page: &self.handle,
+                    core: self.core,
+                })
+            })
+            .collect()
+    }
+
+    /// Edit the objects on the page and save the changes to the page's content.
+    pub fn edit_objects<R>(
+        &mut self,
+        edit: impl FnOnce(&mut [PageObject]) -> R,
+    ) -> Result<R, PdfiumError> {
+        let result = edit(&mut self.objects());
+
+        self.core.generate_page_content(&mut self.handle)?;
+
+        Ok(result)
+    }
+}
+
+struct Object<'page, 'library> {
+    handle: pdfium_core::PageObjectHandle<'page, 'library>,
+    page: &'page pdfium_core::PageHandle<'page, 'library>,
+    core: &'library pdfium_core::Library,
+}
+
+/// An object on a page.
+pub enum PageObject<'page, 'library> {
+    Text(TextObject<'page, 'library>),
+    Image(ImageObject<'page, 'library>),
+    Path(PathObject<'page, 'library>),
+    /// Shading and form objects.
+    Other(OtherObject<'page, 'library>),
+}
+
+impl<'page, 'library> PageObject<'page, 'library> {
+    fn new(object: Object<'page, 'library>) -> Self {
+        match object.core.get_page_object_type(&object.handle) {
+            PageObjectType::Text => PageObject::Text(TextObject(object)),
+            PageObjectType::Image => PageObject::Image(ImageObject(object)),
+            PageObjectType::Path => PageObject::Path(PathObject(object)),
+            _ => PageObject::Other(OtherObject(object)),
+        }
+    }
+
+    fn object(&self) -> &Object<'page, 'library> {
+        match self {
+            PageObject::Text(TextObject(object))
+            | PageObject::Image(ImageObject(object))
+            | PageObject::Path(PathObject(object))
+            | PageObject::Other(OtherObject(object)) => object,
+        }
+    }
+
+    fn object_mut(&mut self) -> &mut Object<'page, 'library> {
+        match self {
+            PageObject::Text(TextObject(object))
+            | PageObject::Image(ImageObject(object))
+            | PageObject::Path(PathObject(object))
+            | PageObject::Other(OtherObject(object)) => object,
+        }
+    }
+
+    pub fn object_type(&self) -> PageObjectType {
+        let object = self.object();
+        object.core.get_page_object_type(&object.handle)
+    }
+
+    pub fn bounds(&self) -> Option<Rect> {
+        let object = self.object();
+        object.core.get_page_object_bounds(&object.handle)
+    }
+
+    pub fn matrix(&self) -> Option<Matrix> {
+        let object = self.object();
+        object.core.get_page_object_matrix(&object.handle)
+    }
+
+    /// The ID of the marked content the object is part of, which connects it to a [`StructElement`](crate::StructElement).
+    pub fn marked_content_id(&self) -> Option<i32> {
+        let object = self.object();
+        object
+            .core
+            .get_page_object_marked_content_id(&object.handle)
+    }
+
+    pub fn set_matrix(&mut self, matrix: &Matrix) -> Result<(), PdfiumError> {
+        let object = self.object_mut();
+        object
+            .core
+            .set_page_object_matrix(&mut object.handle, matrix)
+    }
+}
+
+pub struct TextObject<'page, 'library>(Object<'page, 'library>);
+
+impl TextObject<'_, '_> {
+    pub fn text(&self) -> Result<String, PdfiumError> {
+        let Object { handle, page, core } = &self.0;
+        let text_page = core.load_text_page(page)?;
+
+        Ok(core.get_text_object_text(handle, &text_page))
+    }
+
+    pub fn set_text(&mut self, text: &str) -> Result<(), PdfiumError> {
+        let Object { handle, core, .. } = &mut self.0;
+        core.set_text_object_text(handle, text)
+    }
+
+    pub fn font_size(&self) -> Option<f32> {
+        let Object { handle, core, .. } = &self.0;
+        core.get_text_object_font_size(handle)
+    }
+
+    pub fn font_name(&self) -> Option<String> {
+        let Object { handle, core, .. } = &self.0;
+        let font = core.get_text_object_font(handle)?;
+
+        Some(core.get_font_base_name(&font))
+    }
+}
+
+pub struct ImageObject<'page, 'library>(Object<'page, 'library>);
+
+impl<'library> ImageObject<'_, 'library> {
+    /// The decoded pixels of the image, without the object's matrix applied.
+    pub fn bitmap(&self) -> Result<Bitmap<'static, 'library>, PdfiumError> {
+        let Object { handle, core, .. } = &self.0;
+        let bitmap = core.get_image_object_bitmap(handle)?;
+
+        Ok(Bitmap {
+            handle: bitmap,
+            core,
+        })
+    }
+
+    /// The image data as it is stored in the document, see [`ImageObject::filters`].
+    pub fn raw_data(&self) -> Vec<u8> {
+        let Object { handle, core, .. } = &self.0;
+        core.get_image_object_data_raw(handle)
+    }
+
+    /// The filters that have to be applied, in order, to decode [`ImageObject::raw_data`].
+    pub fn filters(&self) -> Vec<String> {
+        let Object { handle, core, .. } = &self.0;
+
+        (0..core.get_image_object_filter_count(handle))
+            .map(|index| core.get_image_object_filter(handle, index))
+            .collect()
+    }
+}
+
+pub struct PathObject<'page, 'library>(Object<'page, 'library>);
+
+impl PathObject<'_, '_> {
+    pub fn segments(&self) -> Vec<PathSegment> {
+        let Object { handle, core, .. } = &self.0;
+
+        (0..core.get_path_segment_count(handle))
+            .filter_map(|index| core.get_path_segment(handle, index))
+            .map(|segment| {
+                let (x, y) = core.get_path_segment_point(&segment).unwrap_or_default();
+
+                PathSegment {
+                    segment_type: core.get_path_segment_type(&segment),
+                    x,
+                    y,
+                    close: core.get_path_segment_close(&segment),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A segment of a [`PathObject`], see [`PathSegmentType`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathSegment {
+    pub segment_type: PathSegmentType,
+    pub x: f32,
+    pub y: f32,
+    /// Whether the segment closes its subpath.
+    pub close: bool,
+}
+
+pub struct OtherObject<'page, 'library>(Object<'page, 'library>);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static PATHS_PDF: &[u8] = include_bytes!("../test_assets/paths.pdf");
+
+    #[test]
+    fn text_objects() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        let text: String = page
+            .objects()
+            .iter()
+            .map(|object| match object {
+                PageObject::Text(text) => text.text().unwrap(),
+                _ => panic!("dummy.pdf only contains text"),
+            })
+            .collect();
+        assert_eq!(text, "Dummy PDF file");
+    }
+
+    #[test]
+    fn edit_objects() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let mut page = document.page(0).unwrap();
+
+        page.edit_objects(|objects| {
+            if let PageObject::Text(text) = &mut objects[0] {
+                text.set_text("Smart").unwrap();
+            }
+        })
+        .unwrap();
+
+        match &page.objects()[0] {
+            PageObject::Text(text) => assert_eq!(text.text().unwrap(), "Smart"),
+            _ => panic!("expected a text object"),
+        }
+    }
+
+    #[test]
+    fn path_objects() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(PATHS_PDF).unwrap();
+        let page = document.page(0).unwrap();
+        let objects = page.objects();
+
+        match &objects[0] {
+            PageObject::Path(path) => {
+                let segments = path.segments();
+                assert_eq!(segments[0].segment_type, PathSegmentType::MoveTo);
+                assert_eq!((segments[0].x, segments[0].y), (100.0, 100.0));
+                assert!(segments.last().unwrap().close);
+            }
+            _ => panic!("expected a path object"),
+        }
+
+        assert_eq!(objects[2].object_type(), PageObjectType::Form);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Document, Library, PageMode, PdfiumError};
+
+impl Document<'_, '_> {
+    /// Check if the document is a portfolio, a collection of embedded documents that viewers show
+    /// instead of its pages, which usually are just a cover sheet.
+    ///
+    /// PDFium doesn't expose the `Collection` entry of the catalog, so portfolios are detected by
+    /// their page mode [`PageMode::UseAttachments`] and having at least one embedded PDF.
+    pub fn is_portfolio(&self) -> bool {
+        self.page_mode() == PageMode::UseAttachments && !self.portfolio_members().is_empty()
+    }
+
+    /// The embedded PDF documents of the document, the members of a portfolio.
+    ///
+    /// Other embedded files are left out, see [`Document::attachments`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::Library;
+    /// # static PORTFOLIO_PDF: &[u8] = include_bytes!("../test_assets/portfolio.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = library.document_from_bytes(PORTFOLIO_PDF).unwrap();
+    ///
+    /// for member in document.portfolio_members() {
+    ///     let member_document = member.open(&library).unwrap();
+    ///     assert!(member_document.page_count() > 0);
+    /// }
+    /// ```
+    pub fn portfolio_members(&self) -> Vec<PortfolioMember> {
+        self.attachments()
+            .iter()
+            .filter_map(|attachment| {
+                let data = attachment.data()?;
+
+                Some(PortfolioMember {
+                    name: attachment.name(),
+                    data,
+                })
+                .filter(|member| is_pdf(&member.data))
+            })
+            .collect()
+    }
+}
+
+/// A PDF document embedded in another document.
+///
+/// Created using [`Document::portfolio_members`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortfolioMember {
+    /// The name of the embedded file, usually the file name.
+    pub name: String,
+    /// The contents of the embedded file.
+    pub data: Vec<u8>,
+}
+
+impl PortfolioMember {
+    /// Open the embedded document.
+    ///
+    /// Fails like [`Library::document_from_bytes`], for example if the document is encrypted.
+    pub fn open<'a>(&'a self, library: &'a Library) -> Result<Document<'a, 'a>, PdfiumError> {
+        library.document_from_bytes(&self.data)
+    }
+}
+
+/// Check for the header of a PDF, which is allowed to come after some other bytes.
+fn is_pdf(data: &[u8]) -> bool {
+    data[..data.len().min(1024)]
+        .windows(5)
+        .any(|window| window == b"%PDF-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_pdf;
+    use crate::*;
+
+    static PORTFOLIO_PDF: &[u8] = include_bytes!("../test_assets/portfolio.pdf");
+    static PATHS_PDF: &[u8] = include_bytes!("../test_assets/paths.pdf");
+    static ATTACHMENTS_PDF: &[u8] = include_bytes!("../test_assets/attachments.pdf");
+
+    #[test]
+    fn portfolio() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(PORTFOLIO_PDF).unwrap();
+
+        assert!(document.is_portfolio());
+        assert_eq!(document.attachments().len(), 2);
+
+        let members = document.portfolio_members();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].name, "paths.pdf");
+        assert_eq!(members[0].data, PATHS_PDF);
+
+        let member = members[0].open(&library).unwrap();
+        assert_eq!(member.page_count(), 1);
+    }
+
+    #[test]
+    fn not_portfolio() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        // Attachments, but no embedded PDF
+        let document = library.document_from_bytes(ATTACHMENTS_PDF).unwrap();
+        assert!(!document.is_portfolio());
+        assert!(document.portfolio_members().is_empty());
+    }
+
+    #[test]
+    fn pdf_header() {
+        assert!(is_pdf(b"%PDF-1.7\n"));
+        assert!(is_pdf(b"\xef\xbb\xbf%PDF-1.4\n"));
+        assert!(!is_pdf(b"Notes\n"));
+        assert!(!is_pdf(b""));
+    }
+}
@@ -0,0 +1,688 @@
+use crate::action::action;
+use crate::{annotation_flags, Action, AnnotationSubtype, Document, Page, PdfiumError, Rect};
+use crate::{FormType, Metadata, PageMode};
+use pdfium_core::{AnnotationActionEvent, AnnotationHandle, FormHandle};
+use std::io::Write;
+
+impl<'data, 'library> Document<'data, 'library> {
+    /// Write a copy of the document without document level JavaScript to `writer`,
+    /// to neutralize scripts that run when the document is opened.
+    ///
+    /// PDFium can't remove entries of the catalog, so a document with JavaScript actions,
+    /// see [`Document::javascript_actions`], is rebuilt from its pages.
+    /// The copy keeps the pages with their annotations, the viewer preferences, the language and
+    /// the attachments, but loses the interactive form, the outline, the metadata, the page mode
+    /// and the structure tree. Without the interactive form the widgets of form fields still show
+    /// their values, but they can't be filled in anymore.
+    /// The `OpenAction` is always removed from a rebuilt copy.
+    ///
+    /// Returns the parts the document had that the rebuilt copy lost, or `None` if it was saved unchanged.
+    /// Documents without JavaScript actions are saved unchanged, unless [`StripJavaScript::open_action`] is set.
+    /// Scripts of annotations and form fields are not removed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::{Library, StripJavaScript};
+    /// # static JAVASCRIPT_PDF: &[u8] = include_bytes!("../test_assets/javascript.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = library.document_from_bytes(JAVASCRIPT_PDF).unwrap();
+    ///
+    /// let mut bytes = Vec::new();
+    /// let dropped = document
+    ///     .save_without_javascript(&mut bytes, StripJavaScript::default())
+    ///     .unwrap();
+    /// assert_eq!(dropped.map(|dropped| dropped.form), Some(false));
+    ///
+    /// let copy = library.document_from_bytes(&bytes).unwrap();
+    /// assert!(copy.javascript_actions().is_empty());
+    /// ```
+    pub fn save_without_javascript(
+        &self,
+        writer: &mut impl Write,
+        options: StripJavaScript,
+    ) -> Result<Option<DroppedParts>, PdfiumError> {
+        if !options.open_action && self.core.get_javascript_action_count(&self.handle) == 0 {
+            self.save_to(writer)?;
+            return Ok(None);
+        }
+
+        let (document, dropped) = self.rebuild()?;
+        document.save_to(writer)?;
+
+        Ok(Some(dropped))
+    }
+
+    /// Copy the pages and the parts of the catalog PDFium can copy into a new document,
+    /// and list the parts it can't copy.
+    pub(crate) fn rebuild(
+        &self,
+    ) -> Result<(Document<'static, 'library>, DroppedParts), PdfiumError> {
+        let mut handle = self.core.create_document()?;
+        self.core.import_pages(&mut handle, &self.handle, None, 0)?;
+
+        // Fails if there are no viewer preferences to copy
+        let _ = self.core.copy_viewer_preferences(&mut handle, &self.handle);
+
+        let mut document = Document {
+            handle,
+            core: self.core,
+        };
+
+        if let Some(language) = self.language() {
+            document.set_language(&language)?;
+        }
+
+        for attachment in self.attachments() {
+            if let Some(data) = attachment.data() {
+                document.add_attachment(&attachment.name(), &data)?;
+            }
+        }
+
+        let dropped = DroppedParts {
+            form: self.form_type() != FormType::None,
+            outline: self
+                .core
+                .get_first_bookmark_child(&self.handle, None)
+                .is_some(),
+            metadata: self.metadata() != Metadata::default(),
+            page_mode: self.page_mode() != PageMode::UseNone,
+            struct_tree: self.is_tagged(),
+        };
+
+        Ok((document, dropped))
+    }
+}
+
+/// The parts of a document that are lost when it's rebuilt from its pages, see [`Document::save_without_javascript`].
+///
+/// A field is `true` if the document had the part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DroppedParts {
+    /// The interactive form, see [`Document::form_type`]. The widgets of its fields are kept as
+    /// annotations that show the values, but can't be filled in.
+    pub form: bool,
+    /// The outline, see [`Document::outline`].
+    pub outline: bool,
+    /// The document information dictionary, see [`Document::metadata`].
+    pub metadata: bool,
+    /// A page mode other than [`PageMode::UseNone`], see [`Document::page_mode`].
+    pub page_mode: bool,
+    /// The structure tree of a tagged PDF, see [`Document::is_tagged`].
+    pub struct_tree: bool,
+}
+
+impl DroppedParts {
+    /// Check if the document had none of the parts, so the rebuilt copy didn't lose anything.
+    pub fn is_empty(&self) -> bool {
+        *self == DroppedParts::default()
+    }
+}
+
+/// What [`Document::save_without_javascript`] removes besides the document level JavaScript actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StripJavaScript {
+    /// Remove the `OpenAction` of the document, the action that runs when it's opened,
+    /// even if the document doesn't have JavaScript actions.
+    ///
+    /// PDFium can't read the `OpenAction`, so it's removed whether it's a script or not.
+    pub open_action: bool,
+}
+
+/// Removes active content from documents in one pass, like a mail gateway does before delivering them.
+///
+/// Everything but the `OpenAction` is removed by default, set fields to `false` to keep parts of the document.
+/// Removing the `OpenAction` rebuilds every document, see [`Sanitizer::open_action`], so it has to be set to `true`.
+///
+/// ## Examples
+/// ```
+/// use pdfium_rs::{Library, Sanitizer};
+/// # static ACTIVE_CONTENT_PDF: &[u8] = include_bytes!("../test_assets/active_content.pdf");
+///
+/// let library = Library::init().unwrap();
+/// let mut document = library.document_from_bytes(ACTIVE_CONTENT_PDF).unwrap();
+///
+/// let sanitizer = Sanitizer {
+///     hidden_annotations: false,
+///     ..Sanitizer::default()
+/// };
+///
+/// let mut bytes = Vec::new();
+/// let report = sanitizer.sanitize(&mut document, &mut bytes).unwrap();
+/// assert_eq!(report.embedded_files, ["payload.exe"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sanitizer {
+    /// Remove the document level JavaScript actions, see [`Document::save_without_javascript`].
+    pub javascript: bool,
+    /// Remove the `OpenAction` of the document, see [`StripJavaScript::open_action`].
+    ///
+    /// This rebuilds every document, so it also loses the interactive form, the outline and the other parts
+    /// listed in [`DroppedParts`]. It's `false` by default, documents are only rebuilt when they have
+    /// JavaScript actions or form fields with scripts.
+    pub open_action: bool,
+    /// Remove the files embedded in the document, see [`Document::attachments`],
+    /// and the file attachment annotations that embed files on pages.
+    pub embedded_files: bool,
+    /// Remove the links that leave the document, with an [`Action::Uri`], [`Action::Launch`] or
+    /// [`Action::RemoteGoTo`] action.
+    pub external_links: bool,
+    /// Remove the annotations that run scripts: links with an action PDFium can't read, like a JavaScript action,
+    /// other annotations with an action, annotations with additional actions and form fields with
+    /// JavaScript for their events.
+    ///
+    /// The interactive form keeps the removed form fields, so a document with them is rebuilt without it,
+    /// see [`DroppedParts::form`].
+    pub scripts: bool,
+    /// Remove the annotations with the [`HIDDEN`](annotation_flags::HIDDEN) flag, which viewers don't show.
+    pub hidden_annotations: bool,
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Sanitizer {
+            javascript: true,
+            open_action: false,
+            embedded_files: true,
+            external_links: true,
+            scripts: true,
+            hidden_annotations: true,
+        }
+    }
+}
+
+impl Sanitizer {
+    /// Remove the active content from `document` and write the result to `writer`.
+    ///
+    /// The attachments and annotations are removed from `document` itself,
+    /// the JavaScript actions and the `OpenAction` only from the written copy.
+    pub fn sanitize(
+        &self,
+        document: &mut Document,
+        writer: &mut impl Write,
+    ) -> Result<SanitizeReport, PdfiumError> {
+        let mut report = SanitizeReport::default();
+
+        if self.embedded_files {
+            report.embedded_files = document
+                .attachments()
+                .iter()
+                .map(|attachment| attachment.name())
+                .collect();
+
+            for index in (0..document.core.get_attachment_count(&document.handle)).rev() {
+                document.delete_attachment(index)?;
+            }
+        }
+
+        // Only needed to read the JavaScript of form fields
+        let form = if self.scripts && document.form_type() != FormType::None {
+            Some(document.core.init_form_fill_environment(&document.handle)?)
+        } else {
+            None
+        };
+        let mut removed_form_fields = false;
+
+        for page_index in 0..document.page_count() {
+            let mut page = document.page(page_index)?;
+            let mut external_links = Vec::new();
+            let mut scripts = Vec::new();
+            let mut file_attachments = Vec::new();
+            let mut hidden_annotations = Vec::new();
+
+            for index in (0..page.core.get_annotation_count(&page.handle)).rev() {
+                let (removed, action, script, hidden) =
+                    match page.core.get_annotation(&page.handle, index) {
+                        Some(annotation) => {
+                            let action = link_action(&page, &annotation);
+
+                            (
+                                RemovedAnnotation {
+                                    page_index,
+                                    subtype: page.core.get_annotation_subtype(&annotation),
+                                    rect: page.core.get_annotation_rect(&annotation),
+                                    contents: page
+                                        .core
+                                        .get_annotation_string_value(&annotation, "Contents")
+                                        .filter(|x| !x.is_empty()),
+                                },
+                                action.clone(),
+                                runs_script(&page, form.as_ref(), &annotation, &action),
+                                page.core.get_annotation_flags(&annotation)
+                                    & annotation_flags::HIDDEN
+                                    != 0,
+                            )
+                        }
+                        None => continue,
+                    };
+
+                match action {
+                    Some(action @ Action::Uri { .. })
+                    | Some(action @ Action::Launch { .. })
+                    | Some(action @ Action::RemoteGoTo { .. })
+                        if self.external_links =>
+                    {
+                        page.remove_annotation(index)?;
+                        external_links.push(RemovedLink {
+                            annotation: removed,
+                            action,
+                        });
+                    }
+                    _ if script && self.scripts => {
+                        page.remove_annotation(index)?;
+                        removed_form_fields |= removed.subtype == AnnotationSubtype::Widget;
+                        scripts.push(removed);
+                    }
+                    _ if removed.subtype == AnnotationSubtype::FileAttachment
+                        && self.embedded_files =>
+                    {
+                        page.remove_annotation(index)?;
+                        file_attachments.push(removed);
+                    }
+                    _ if hidden && self.hidden_annotations => {
+                        page.remove_annotation(index)?;
+                        hidden_annotations.push(removed);
+                    }
+                    _ => {}
+                }
+            }
+
+            report
+                .external_links
+                .extend(external_links.into_iter().rev());
+            report.scripts.extend(scripts.into_iter().rev());
+            report
+                .file_attachments
+                .extend(file_attachments.into_iter().rev());
+            report
+                .hidden_annotations
+                .extend(hidden_annotations.into_iter().rev());
+        }
+        drop(form);
+
+        let javascript: Vec<String> = document
+            .javascript_actions()
+            .into_iter()
+            .map(|action| action.name)
+            .collect();
+
+        report.rebuilt =
+            self.open_action || (self.javascript && !javascript.is_empty()) || removed_form_fields;
+
+        if report.rebuilt {
+            report.javascript = javascript;
+            let (rebuilt, dropped) = document.rebuild()?;
+            report.dropped = dropped;
+            rebuilt.save_to(writer)?;
+        } else {
+            document.save_to(writer)?;
+        }
+
+        Ok(report)
+    }
+}
+
+/// The action of a link annotation.
+fn link_action(page: &Page, annotation: &AnnotationHandle) -> Option<Action> {
+    let link = page.core.get_annotation_link(annotation)?;
+    let handle = page.core.get_link_action(&link)?;
+
+    Some(action(page.core, page.document, &handle))
+}
+
+/// Check if an annotation runs a script, or an action that could be one.
+///
+/// PDFium only reads the actions of links, the action of other annotations, like a push button, could be anything.
+fn runs_script(
+    page: &Page,
+    form: Option<&FormHandle>,
+    annotation: &AnnotationHandle,
+    action: &Option<Action>,
+) -> bool {
+    let subtype = page.core.get_annotation_subtype(annotation);
+    let events = [
+        AnnotationActionEvent::KeyStroke,
+        AnnotationActionEvent::Format,
+        AnnotationActionEvent::Validate,
+        AnnotationActionEvent::Calculate,
+    ];
+
+    *action == Some(Action::Unsupported)
+        || page.core.has_annotation_key(annotation, "AA")
+        || (subtype != AnnotationSubtype::Link && page.core.has_annotation_key(annotation, "A"))
+        || (subtype == AnnotationSubtype::Widget
+            && form.is_some_and(|form| {
+                events.iter().any(|&event| {
+                    !page
+                        .core
+                        .get_annotation_action_javascript(form, annotation, event)
+                        .is_empty()
+                })
+            }))
+}
+
+/// What [`Sanitizer::sanitize`] removed from a document.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SanitizeReport {
+    /// The names of the removed document level JavaScript actions.
+    pub javascript: Vec<String>,
+    /// Whether the document was rebuilt from its pages to remove the JavaScript actions or the `OpenAction`,
+    /// see [`Document::save_without_javascript`].
+    pub rebuilt: bool,
+    /// The parts of the document the rebuilt copy lost, all `false` if it wasn't rebuilt.
+    pub dropped: DroppedParts,
+    /// The names of the removed attachments.
+    pub embedded_files: Vec<String>,
+    /// The removed links to URIs, applications and other files.
+    pub external_links: Vec<RemovedLink>,
+    /// The removed annotations and form fields that run scripts.
+    pub scripts: Vec<RemovedAnnotation>,
+    /// The removed file attachment annotations.
+    pub file_attachments: Vec<RemovedAnnotation>,
+    /// The removed hidden annotations.
+    pub hidden_annotations: Vec<RemovedAnnotation>,
+}
+
+impl SanitizeReport {
+    /// Check if nothing was removed.
+    ///
+    /// A rebuilt document can still have lost an `OpenAction`, PDFium can't tell if it had one.
+    pub fn is_empty(&self) -> bool {
+        self.javascript.is_empty()
+            && self.embedded_files.is_empty()
+            && self.external_links.is_empty()
+            && self.scripts.is_empty()
+            && self.file_attachments.is_empty()
+            && self.hidden_annotations.is_empty()
+    }
+}
+
+/// An annotation removed by [`Sanitizer::sanitize`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemovedAnnotation {
+    /// The page of the annotation, 0 for the first page.
+    pub page_index: usize,
+    pub subtype: AnnotationSubtype,
+    pub rect: Option<Rect>,
+    /// The text of the annotation.
+    pub contents: Option<String>,
+}
+
+/// A link removed by [`Sanitizer::sanitize`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemovedLink {
+    pub annotation: RemovedAnnotation,
+    /// The action that leaves the document.
+    pub action: Action,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static JAVASCRIPT_PDF: &[u8] = include_bytes!("../test_assets/javascript.pdf");
+    static ATTACHMENTS_PDF: &[u8] = include_bytes!("../test_assets/attachments.pdf");
+    static ACTIVE_CONTENT_PDF: &[u8] = include_bytes!("../test_assets/active_content.pdf");
+    static FORMS_PDF: &[u8] = include_bytes!("../test_assets/forms.pdf");
+    static OUTLINE_PDF: &[u8] = include_bytes!("../test_assets/outline.pdf");
+    static TAGGED_PDF: &[u8] = include_bytes!("../test_assets/tagged.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn save_without_javascript() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(JAVASCRIPT_PDF).unwrap();
+
+        let mut bytes = Vec::new();
+        let dropped = document
+            .save_without_javascript(&mut bytes, StripJavaScript::default())
+            .unwrap();
+        assert_eq!(dropped, Some(DroppedParts::default()));
+
+        let copy = library.document_from_bytes(&bytes).unwrap();
+        assert!(copy.javascript_actions().is_empty());
+        assert_eq!(copy.page_count(), 1);
+        assert_eq!(copy.language().as_deref(), Some("en-US"));
+        assert!(!bytes.windows(11).any(|x| x == b"/OpenAction"));
+        assert!(!bytes.windows(11).any(|x| x == b"/JavaScript"));
+
+        assert!(copy.page(0).unwrap().has_text_layer().unwrap());
+    }
+
+    #[test]
+    fn strip_open_action() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(ATTACHMENTS_PDF).unwrap();
+
+        let mut bytes = Vec::new();
+        let options = StripJavaScript { open_action: true };
+        let dropped = document
+            .save_without_javascript(&mut bytes, options)
+            .unwrap();
+        assert!(dropped.unwrap().is_empty());
+
+        let copy = library.document_from_bytes(&bytes).unwrap();
+        assert_eq!(copy.page_count(), document.page_count());
+
+        let names = |document: &Document| {
+            document
+                .attachments()
+                .iter()
+                .map(|attachment| (attachment.name(), attachment.data()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&copy), names(&document));
+    }
+
+    #[test]
+    fn dropped_parts() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let options = StripJavaScript { open_action: true };
+        let dropped = |bytes| {
+            let document = library.document_from_bytes(bytes).unwrap();
+            document
+                .save_without_javascript(&mut Vec::new(), options)
+                .unwrap()
+                .unwrap()
+        };
+
+        let forms = dropped(FORMS_PDF);
+        assert!(forms.form);
+        assert!(!forms.outline);
+        let outline = dropped(OUTLINE_PDF);
+        assert!(outline.outline && outline.page_mode);
+        assert!(!outline.form);
+        assert!(dropped(TAGGED_PDF).struct_tree);
+        assert!(dropped(DUMMY_PDF).metadata);
+
+        // Saved unchanged
+        let document = library.document_from_bytes(FORMS_PDF).unwrap();
+        let mut bytes = Vec::new();
+        let dropped = document
+            .save_without_javascript(&mut bytes, StripJavaScript::default())
+            .unwrap();
+        assert_eq!(dropped, None);
+        let copy = library.document_from_bytes(&bytes).unwrap();
+        assert_eq!(copy.form_type(), FormType::AcroForm);
+    }
+
+    #[test]
+    fn sanitize() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let mut document = library.document_from_bytes(ACTIVE_CONTENT_PDF).unwrap();
+
+        let mut bytes = Vec::new();
+        let report = Sanitizer::default()
+            .sanitize(&mut document, &mut bytes)
+            .unwrap();
+
+        let removed = |subtype, rect: [f32; 4], contents: Option<&str>| RemovedAnnotation {
+            page_index: 0,
+            subtype,
+            rect: Some(Rect {
+                left: rect[0],
+                bottom: rect[1],
+                right: rect[2],
+                top: rect[3],
+            }),
+            contents: contents.map(String::from),
+        };
+
+        assert_eq!(report.javascript, ["init"]);
+        assert!(report.rebuilt);
+        assert_eq!(
+            report.dropped,
+            DroppedParts {
+                form: true,
+                ..DroppedParts::default()
+            }
+        );
+        assert_eq!(report.embedded_files, ["payload.exe"]);
+        assert_eq!(
+            report.external_links,
+            [
+                RemovedLink {
+                    annotation: removed(AnnotationSubtype::Link, [72.0, 700.0, 200.0, 720.0], None),
+                    action: Action::Uri {
+                        uri: "https://example.com/".to_string()
+                    },
+                },
+                RemovedLink {
+                    annotation: removed(AnnotationSubtype::Link, [72.0, 660.0, 200.0, 680.0], None),
+                    action: Action::Launch {
+                        file_path: "payload.exe".to_string()
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            report.scripts,
+            [
+                removed(AnnotationSubtype::Link, [72.0, 580.0, 200.0, 600.0], None),
+                removed(AnnotationSubtype::Widget, [72.0, 520.0, 200.0, 540.0], None),
+            ]
+        );
+        assert_eq!(
+            report.file_attachments,
+            [removed(
+                AnnotationSubtype::FileAttachment,
+                [300.0, 520.0, 320.0, 540.0],
+                Some("Invoice")
+            )]
+        );
+        assert_eq!(
+            report.hidden_annotations,
+            [removed(
+                AnnotationSubtype::Text,
+                [300.0, 700.0, 320.0, 720.0],
+                Some("Hidden")
+            )]
+        );
+        assert!(!report.is_empty());
+        assert!(!bytes.windows(11).any(|x| x == b"/JavaScript"));
+        assert!(!bytes.windows(10).any(|x| x == b"not really"));
+
+        let mut copy = library.document_from_bytes(&bytes).unwrap();
+        assert!(copy.javascript_actions().is_empty());
+        assert!(copy.attachments().is_empty());
+
+        // The internal link and the square are kept
+        let page = copy.page(0).unwrap();
+        let subtypes: Vec<_> = page
+            .annotations()
+            .iter()
+            .map(|annotation| annotation.subtype())
+            .collect();
+        assert_eq!(
+            subtypes,
+            [AnnotationSubtype::Link, AnnotationSubtype::Square]
+        );
+        drop(page);
+
+        // Nothing left to remove
+        let mut again = Vec::new();
+        let sanitizer = Sanitizer {
+            open_action: false,
+            ..Sanitizer::default()
+        };
+        let report = sanitizer.sanitize(&mut copy, &mut again).unwrap();
+        assert!(report.is_empty());
+        assert!(!report.rebuilt);
+    }
+
+    #[test]
+    fn sanitize_keeps_outline() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let mut document = library.document_from_bytes(OUTLINE_PDF).unwrap();
+        let outline = document.outline();
+        assert!(!outline.is_empty());
+
+        // Without JavaScript the document isn't rebuilt, only its external link is removed
+        let mut bytes = Vec::new();
+        let report = Sanitizer::default()
+            .sanitize(&mut document, &mut bytes)
+            .unwrap();
+        assert!(!report.rebuilt);
+        assert!(report.dropped.is_empty());
+        assert_eq!(report.external_links.len(), 1);
+
+        let copy = library.document_from_bytes(&bytes).unwrap();
+        assert_eq!(copy.outline(), outline);
+        assert_eq!(copy.page_mode(), document.page_mode());
+    }
+
+    #[test]
+    fn sanitize_scripts() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let subtypes = |bytes: &[u8]| {
+            let document = library.document_from_bytes(bytes).unwrap();
+            let page = document.page(0).unwrap();
+            let subtypes: Vec<_> = page
+                .annotations()
+                .iter()
+                .map(|annotation| annotation.subtype())
+                .collect();
+            subtypes
+        };
+
+        // The interactive form keeps the scripts of the removed form field, so it's rebuilt without it
+        let mut document = library.document_from_bytes(ACTIVE_CONTENT_PDF).unwrap();
+        let sanitizer = Sanitizer {
+            javascript: false,
+            open_action: false,
+            embedded_files: false,
+            external_links: false,
+            scripts: true,
+            hidden_annotations: false,
+        };
+        let mut bytes = Vec::new();
+        let report = sanitizer.sanitize(&mut document, &mut bytes).unwrap();
+        assert_eq!(report.scripts.len(), 2);
+        assert!(report.rebuilt);
+        assert!(report.dropped.form);
+        assert!(!subtypes(&bytes).contains(&AnnotationSubtype::Widget));
+        assert!(subtypes(&bytes).contains(&AnnotationSubtype::FileAttachment));
+
+        // Kept without `scripts`
+        let mut document = library.document_from_bytes(ACTIVE_CONTENT_PDF).unwrap();
+        let sanitizer = Sanitizer {
+            scripts: false,
+            ..sanitizer
+        };
+        let mut bytes = Vec::new();
+        let report = sanitizer.sanitize(&mut document, &mut bytes).unwrap();
+        assert!(report.is_empty());
+        assert!(!report.rebuilt);
+        assert_eq!(subtypes(&bytes).len(), 8);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Document, Page, PageObjectType, PdfiumError};
+use pdfium_core::{Library, PageObjectHandle};
+
+impl Document<'_, '_> {
+    /// The [`ScanProfile`] of every page, to decide which pages need OCR.
+    pub fn scan_profile(&self) -> Result<Vec<ScanProfile>, PdfiumError> {
+        (0..self.page_count())
+            .map(|index| self.page(index)?.scan_profile())
+            .collect()
+    }
+}
+
+impl Page<'_, '_> {
+    /// Check if the page has text that can be extracted, including invisible text added by OCR.
+    pub fn has_text_layer(&self) -> Result<bool, PdfiumError> {
+        Ok(self.scan_profile()?.has_text_layer())
+    }
+
+    /// How much text and how many images the page has, see [`ScanProfile`].
+    pub fn scan_profile(&self) -> Result<ScanProfile, PdfiumError> {
+        let text_page = self.core.load_cached_text_page(&self.handle)?;
+        let char_count = self.core.get_text_char_count(&text_page);
+
+        let image_area: f32 = (0..self.core.get_page_object_count(&self.handle))
+            .filter_map(|index| self.core.get_page_object(&self.handle, index))
+            .filter(|object| contains_image(self.core, object))
+            .filter_map(|object| self.core.get_page_object_bounds(&object))
+            .map(|rect| (rect.right - rect.left).abs() * (rect.top - rect.bottom).abs())
+            .sum();
+        let page_area = self.width() * self.height();
+
+        Ok(ScanProfile {
+            char_count,
+            image_coverage: if page_area > 0.0 {
+                (image_area / page_area).min(1.0)
+            } else {
+                0.0
+            },
+        })
+    }
+}
+
+pub(crate) fn contains_image(core: &Library, object: &PageObjectHandle) -> bool {
+    match core.get_page_object_type(object) {
+        PageObjectType::Image => true,
+        PageObjectType::Form => (0..core.get_form_object_count(object))
+            .filter_map(|index| core.get_form_object(object, index))
+            .any(|child| contains_image(core, &child)),
+        _ => false,
+    }
+}
+
+/// How much text and how many images a page has.
+///
+/// Created using [`Page::scan_profile`] and [`Document::scan_profile`].
+///
+/// Scanned pages are covered by images and have no text, unless OCR added an invisible text layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScanProfile {
+    /// The number of characters that can be extracted from the page.
+    pub char_count: usize,
+    /// The fraction of the page covered by images, from `0` to `1`.
+    ///
+    /// This is an estimate: overlapping images are counted twice
+    /// and form XObjects containing an image count with their whole bounds.
+    pub image_coverage: f32,
+}
+
+impl ScanProfile {
+    /// Pages with at least this [`image_coverage`](ScanProfile::image_coverage) are considered scanned.
+    pub const SCANNED_COVERAGE: f32 = 0.5;
+
+    /// Check if the page has text that can be extracted.
+    pub fn has_text_layer(&self) -> bool {
+        self.char_count > 0
+    }
+
+    /// Check if the page is mostly covered by images, like a scanned page.
+    pub fn is_image_based(&self) -> bool {
+        self.image_coverage >= Self::SCANNED_COVERAGE
+    }
+
+    /// Check if OCR is needed to extract text, because the page is image based and has no text layer.
+    pub fn needs_ocr(&self) -> bool {
+        self.is_image_based() && !self.has_text_layer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    static SCANNED_PDF: &[u8] = include_bytes!("../test_assets/scanned.pdf");
+    static TAGGED_PDF: &[u8] = include_bytes!("../test_assets/tagged.pdf");
+
+    #[test]
+    fn scan_profile() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let document = library.document_from_bytes(SCANNED_PDF).unwrap();
+        let profile = document.scan_profile().unwrap();
+        assert_eq!(profile.len(), 2);
+
+        // Only an image
+        assert_eq!(profile[0].char_count, 0);
+        assert_eq!(profile[0].image_coverage, 1.0);
+        assert!(profile[0].needs_ocr());
+
+        // The same image with invisible OCR text
+        assert_eq!(profile[1].char_count, "Scanned text".len());
+        assert!(profile[1].is_image_based());
+        assert!(!profile[1].needs_ocr());
+
+        let document = library.document_from_bytes(TAGGED_PDF).unwrap();
+        let profile = document.page(0).unwrap().scan_profile().unwrap();
+        assert!(profile.has_text_layer());
+        assert!(profile.image_coverage > 0.0);
+        assert!(!profile.is_image_based());
+    }
+
+    #[test]
+    fn has_text_layer() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(document.page(0).unwrap().has_text_layer().unwrap());
+
+        let document = library.document_from_bytes(SCANNED_PDF).unwrap();
+        assert!(!document.page(0).unwrap().has_text_layer().unwrap());
+        assert!(document.page(1).unwrap().has_text_layer().unwrap());
+    }
+}
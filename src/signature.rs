@@ -0,0 +1,151 @@
+use crate::{Document, PdfiumError};
+use std::ops::Range;
+
+impl Document<'_, '_> {
+    /// The digital signatures of the document, in the order of their fields.
+    ///
+    /// The signatures are not verified, use [`Signature::signed_data`] and [`Signature::contents`]
+    /// with a cryptography library to do that.
+    pub fn signatures(&self) -> Vec<Signature> {
+        (0..self.core.get_signature_count(&self.handle))
+            .filter_map(|index| self.core.get_signature(&self.handle, index))
+            .map(|handle| Signature {
+                contents: self.core.get_signature_contents(&handle),
+                byte_range: self.core.get_signature_byte_range(&handle),
+                sub_filter: Some(self.core.get_signature_sub_filter(&handle))
+                    .filter(|x| !x.is_empty()),
+            })
+            .collect()
+    }
+}
+
+/// A digital signature of a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signature {
+    /// The encoded signature value, for `adbe.pkcs7.detached` a DER encoded CMS `SignedData`
+    /// usually followed by zero padding.
+    pub contents: Vec<u8>,
+    /// The signed parts of the document file, as pairs of offset and length in bytes.
+    ///
+    /// The values are not checked, see [`Signature::signed_data`].
+    pub byte_range: Vec<(i32, i32)>,
+    /// The encoding of [`Signature::contents`], like `adbe.pkcs7.detached` or `ETSI.CAdES.detached`.
+    pub sub_filter: Option<String>,
+}
+
+impl Signature {
+    /// Extract the signed parts of `document`, the data that has to be hashed to verify the signature.
+    ///
+    /// `document` has to be the bytes the document was loaded from,
+    /// a saved copy has different offsets.
+    ///
+    /// Fails with [`PdfiumError::BadFormat`] if the byte range is empty or a part of it is
+    /// negative or out of bounds of `document`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::Library;
+    /// # static SIGNED_PDF: &[u8] = include_bytes!("../test_assets/signed.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = library.document_from_bytes(SIGNED_PDF).unwrap();
+    ///
+    /// let signature = &document.signatures()[0];
+    /// let data = signature.signed_data(SIGNED_PDF).unwrap();
+    /// assert!(data.len() < SIGNED_PDF.len());
+    /// ```
+    pub fn signed_data(&self, document: &[u8]) -> Result<Vec<u8>, PdfiumError> {
+        if self.byte_range.is_empty() {
+            return Err(PdfiumError::BadFormat);
+        }
+
+        let mut data = Vec::new();
+
+        for &(offset, length) in &self.byte_range {
+            let part = to_range(offset, length)
+                .and_then(|range| document.get(range))
+                .ok_or(PdfiumError::BadFormat)?;
+
+            data.extend_from_slice(part);
+        }
+
+        Ok(data)
+    }
+
+    /// Check if the byte range covers all of `document` except [`Signature::contents`],
+    /// it doesn't when the document was changed after it was signed.
+    pub fn covers_document(&self, document: &[u8]) -> bool {
+        let mut end = 0;
+
+        for &(offset, length) in &self.byte_range {
+            let range = match to_range(offset, length) {
+                Some(range) => range,
+                None => return false,
+            };
+
+            // Only the hexadecimal string of the contents, with its angle brackets, may be skipped
+            if range.start < end || (end != 0 && range.start - end != self.contents.len() * 2 + 2) {
+                return false;
+            }
+
+            end = range.end;
+        }
+
+        self.byte_range.len() == 2 && self.byte_range[0].0 == 0 && end == document.len()
+    }
+}
+
+/// Convert a part of a byte range to a range of indices, `None` if it's negative or overflows.
+fn to_range(offset: i32, length: i32) -> Option<Range<usize>> {
+    if offset < 0 || length < 0 {
+        return None;
+    }
+
+    let offset = offset as usize;
+    offset.checked_add(length as usize).map(|end| offset..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static SIGNED_PDF: &[u8] = include_bytes!("../test_assets/signed.pdf");
+
+    #[test]
+    fn signatures() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(SIGNED_PDF).unwrap();
+
+        let signatures = document.signatures();
+        assert_eq!(signatures.len(), 1);
+
+        let signature = &signatures[0];
+        assert_eq!(signature.sub_filter.as_deref(), Some("adbe.pkcs7.detached"));
+        assert!(signature
+            .contents
+            .starts_with(&[0x30, 0x03, 0x02, 0x01, 0x01]));
+
+        let data = signature.signed_data(SIGNED_PDF).unwrap();
+        assert!(data.starts_with(b"%PDF-1.7"));
+        assert!(data.ends_with(b"%%EOF\n"));
+        assert_eq!(data.len(), 640 + 346);
+        assert!(signature.covers_document(SIGNED_PDF));
+
+        // An incremental update after signing
+        let mut updated = SIGNED_PDF.to_vec();
+        updated.extend_from_slice(b"% update\n");
+        assert!(!signature.covers_document(&updated));
+        assert!(signature.signed_data(&updated).is_ok());
+
+        assert!(signature.signed_data(&SIGNED_PDF[..700]).is_err());
+
+        let broken = Signature {
+            byte_range: vec![(-1, 10)],
+            ..signature.clone()
+        };
+        assert!(broken.signed_data(SIGNED_PDF).is_err());
+        assert!(!broken.covers_document(SIGNED_PDF));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{Page, PageObjectType, PdfiumError};
+use pdfium_core::{
+    Library, ObjectType, PageObjectHandle, StructAttributeHandle, StructAttributeValueHandle,
+    StructElementHandle, TextPageHandle,
+};
+use std::collections::BTreeMap;
+
+impl Page<'_, '_> {
+    /// The logical structure of the content of the page in a tagged document,
+    /// like its headings, paragraphs and figures.
+    ///
+    /// Only the elements with content on the page, and their ancestors, are included.
+    /// Empty if the page doesn't have a structure tree, see [`Document::is_tagged`](crate::Document::is_tagged).
+    pub fn struct_tree(&self) -> Vec<StructElement> {
+        let tree = match self.core.get_struct_tree(&self.handle) {
+            Some(tree) => tree,
+            None => return Vec::new(),
+        };
+
+        (0..self.core.get_struct_tree_child_count(&tree))
+            .filter_map(|index| self.core.get_struct_tree_child(&tree, index))
+            .map(|element| struct_element(self.core, &element))
+            .collect()
+    }
+
+    /// The text of the objects of the page that are part of the marked content with the ID `id`,
+    /// the text of a [`StructElement`] with the ID in [`StructElement::marked_content_ids`].
+    pub fn marked_content_text(&self, id: i32) -> Result<String, PdfiumError> {
+        Ok(self.marked_content_texts()?.remove(&id).unwrap_or_default())
+    }
+
+    /// The text of all marked content of the page by ID, including the objects in form objects.
+    pub(crate) fn marked_content_texts(&self) -> Result<BTreeMap<i32, String>, PdfiumError> {
+        let text_page = self.core.load_cached_text_page(&self.handle)?;
+        let mut texts = BTreeMap::new();
+
+        for object in (0..self.core.get_page_object_count(&self.handle))
+            .filter_map(|index| self.core.get_page_object(&self.handle, index))
+        {
+            collect_marked_content_text(self.core, &object, &text_page, &mut texts);
+        }
+
+        Ok(texts)
+    }
+}
+
+fn collect_marked_content_text(
+    core: &Library,
+    object: &PageObjectHandle,
+    text_page: &TextPageHandle,
+    texts: &mut BTreeMap<i32, String>,
+) {
+    match core.get_page_object_type(object) {
+        PageObjectType::Text => {
+            if let Some(id) = core.get_page_object_marked_content_id(object) {
+                let text = core.get_text_object_text(object, text_page);
+                texts.entry(id).or_default().push_str(&text);
+            }
+        }
+        PageObjectType::Form => {
+            for child in (0..core.get_form_object_count(object))
+                .filter_map(|index| core.get_form_object(object, index))
+            {
+                collect_marked_content_text(core, &child, text_page, texts);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// An element of the structure tree of a page.
+///
+/// Created using [`Page::struct_tree`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructElement {
+    /// The structure type, like `H1`, `P`, `Table` or `Figure`.
+    ///
+    /// Documents can define their own types, which are not mapped to the standard types.
+    pub element_type: String,
+    pub title: Option<String>,
+    /// An identifier of the element that is unique in the document.
+    pub id: Option<String>,
+    /// The language of the content as a language tag, like `en-US`, if it differs from its parent.
+    pub lang: Option<String>,
+    /// The alternate description of an image or other non text content.
+    pub alt_text: Option<String>,
+    /// The exact text replacement of the content, like the word of a decorated initial letter.
+    pub actual_text: Option<String>,
+    pub attributes: Vec<StructAttributes>,
+    /// The IDs of the marked content on the page that is directly part of the element,
+    /// see [`PageObject::marked_content_id`](crate::PageObject::marked_content_id) and [`Page::marked_content_text`].
+    pub marked_content_ids: Vec<i32>,
+    /// The child elements, without the marked content and objects of the element.
+    pub children: Vec<StructElement>,
+}
+
+/// An attribute object of a structure element, attributes of one owner, like the `Layout` or `Table` attributes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructAttributes {
+    /// The owner of the attributes, the application or standard that defines them.
+    pub owner: Option<String>,
+    pub values: BTreeMap<String, StructAttributeValue>,
+}
+
+/// The value of an attribute of a structure element.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StructAttributeValue {
+    Boolean(bool),
+    Number(f32),
+    String(String),
+    Name(String),
+    Array(Vec<StructAttributeValue>),
+    /// A dictionary, stream or other value that is not supported.
+    Unsupported,
+}
+
+fn struct_element(core: &Library, handle: &StructElementHandle) -> StructElement {
+    let non_empty = |x: String| Some(x).filter(|x| !x.is_empty());
+
+    StructElement {
+        element_type: core.get_struct_element_type(handle),
+        title: non_empty(core.get_struct_element_title(handle)),
+        id: non_empty(core.get_struct_element_id(handle)),
+        lang: non_empty(core.get_struct_element_lang(handle)),
+        alt_text: non_empty(core.get_struct_element_alt_text(handle)),
+        actual_text: non_empty(core.get_struct_element_actual_text(handle)),
+        attributes: (0..core.get_struct_element_attribute_count(handle))
+            .filter_map(|index| core.get_struct_element_attribute(handle, index))
+            .map(|attribute| struct_attributes(core, &attribute))
+            .collect(),
+        marked_content_ids: (0..core.get_struct_element_child_count(handle))
+            .filter_map(|index| core.get_struct_element_child_marked_content_id(handle, index))
+            .collect(),
+        children: (0..core.get_struct_element_child_count(handle))
+            .filter_map(|index| core.get_struct_element_child(handle, index))
+            .map(|child| struct_element(core, &child))
+            .collect(),
+    }
+}
+
+fn struct_attributes(core: &Library, handle: &StructAttributeHandle) -> StructAttributes {
+    let mut owner = None;
+    let mut values = BTreeMap::new();
+
+    for index in 0..core.get_struct_attribute_count(handle) {
+        let name = match core.get_struct_attribute_name(handle, index) {
+            Some(name) => name,
+            None => continue,
+        };
+        let value = match core.get_struct_attribute_value(handle, &name) {
+            Some(value) => struct_attribute_value(core, &value),
+            None => continue,
+        };
+
+        match value {
+            StructAttributeValue::Name(value) if name == "O" => owner = Some(value),
+            value => {
+                values.insert(name, value);
+            }
+        }
+    }
+
+    StructAttributes { owner, values }
+}
+
+fn struct_attribute_value(
+    core: &Library,
+    handle: &StructAttributeValueHandle,
+) -> StructAttributeValue {
+    let value = match core.get_struct_attribute_value_type(handle) {
+        ObjectType::Boolean => core
+            .get_struct_attribute_boolean_value(handle)
+            .map(StructAttributeValue::Boolean),
+        ObjectType::Number => core
+            .get_struct_attribute_number_value(handle)
+            .map(StructAttributeValue::Number),
+        ObjectType::String => core
+            .get_struct_attribute_string_value(handle)
+            .map(StructAttributeValue::String),
+        ObjectType::Name => core
+            .get_struct_attribute_string_value(handle)
+            .map(StructAttributeValue::Name),
+        ObjectType::Array => Some(StructAttributeValue::Array(
+            (0..core.get_struct_attribute_value_child_count(handle))
+                .filter_map(|index| core.get_struct_attribute_value_child(handle, index))
+                .map(|child| struct_attribute_value(core, &child))
+                .collect(),
+        )),
+        _ => None,
+    };
+
+    value.unwrap_or(StructAttributeValue::Unsupported)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TAGGED_PDF: &[u8] = include_bytes!("../test_assets/tagged.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn struct_tree() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(TAGGED_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        let tree = page.struct_tree();
+        assert_eq!(tree.len(), 1);
+        let root = &tree[0];
+        assert_eq!(root.element_type, "Document");
+
+        let types: Vec<_> = root
+            .children
+            .iter()
+            .map(|child| child.element_type.as_str())
+            .collect();
+        assert_eq!(types, ["H1", "P", "Figure", "Figure"]);
+
+        let heading = &root.children[0];
+        assert_eq!(heading.title.as_deref(), Some("Title"));
+        assert_eq!(heading.id.as_deref(), Some("heading"));
+        assert_eq!(heading.lang.as_deref(), Some("en-GB"));
+        assert!(heading.attributes.is_empty());
+        assert!(heading.children.is_empty());
+        assert_eq!(heading.marked_content_ids, [0]);
+        assert_eq!(page.marked_content_text(0).unwrap(), "Report");
+        assert!(root.marked_content_ids.is_empty());
+
+        assert_eq!(
+            root.children[1].actual_text.as_deref(),
+            Some("Hello, world.")
+        );
+
+        let figure = &root.children[2];
+        assert_eq!(figure.alt_text.as_deref(), Some("A gray rectangle"));
+        assert_eq!(
+            figure.attributes,
+            vec![StructAttributes {
+                owner: Some("Layout".to_string()),
+                values: vec![
+                    (
+                        "Placement".to_string(),
+                        StructAttributeValue::Name("Block".to_string())
+                    ),
+                    ("Width".to_string(), StructAttributeValue::Number(100.0)),
+                ]
+                .into_iter()
+                .collect(),
+            }]
+        );
+
+        assert_eq!(root.children[3].alt_text, None);
+
+        let objects = page.objects();
+        assert_eq!(objects[2].marked_content_id(), Some(2));
+        assert!(matches!(objects[2], PageObject::Image(_)));
+        assert_eq!(page.marked_content_text(2).unwrap(), "");
+    }
+
+    #[test]
+    fn untagged_page() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(DUMMY_PDF).unwrap();
+        let page = document.page(0).unwrap();
+
+        assert!(page.struct_tree().is_empty());
+    }
+}
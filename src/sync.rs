@@ -0,0 +1,198 @@
+//! Handles that can be shared between threads, with the `sync` feature.
+//!
+//! The library and documents are only used while holding a global lock, which is why they can be
+//! sent and shared even though PDFium isn't thread safe.
+
+#![allow(unsafe_code)]
+
+use crate::{Document, Library, PdfiumError};
+use parking_lot::{const_reentrant_mutex, ReentrantMutex};
+use std::mem::ManuallyDrop;
+use std::sync::Arc;
+
+/// The lock of every call to PDFium made with a [`SyncLibrary`] or [`SyncDocument`].
+///
+/// It's reentrant so a document can be used in the closure of another one.
+static LOCK: ReentrantMutex<()> = const_reentrant_mutex(());
+
+/// A library that can be sent and shared between threads, calls to PDFium from all threads run one after another.
+///
+/// The library is used with [`SyncLibrary::with`] and the documents it opens are [`SyncDocument`]s,
+/// which can be stored in the shared state of an application. Unlike [`PdfiumWorker`](crate::PdfiumWorker)
+/// there is no thread of the library, the calls run on the calling thread while it holds a global lock.
+///
+/// ## Examples
+/// ```
+/// use pdfium_rs::SyncLibrary;
+/// use std::sync::Arc;
+/// use std::thread;
+/// # static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+///
+/// let library = SyncLibrary::init().unwrap();
+/// let document = Arc::new(library.open(DUMMY_PDF).unwrap());
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let document = document.clone();
+///         thread::spawn(move || document.page_count())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 1);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SyncLibrary {
+    shared: Arc<Shared>,
+}
+
+/// The library of a [`SyncLibrary`], destroyed with the lock when the library and all of its documents are dropped.
+struct Shared {
+    library: ManuallyDrop<Library>,
+}
+
+// The library is only used while holding `LOCK`, including when it's destroyed.
+unsafe impl Send for Shared {}
+unsafe impl Sync for Shared {}
+
+impl SyncLibrary {
+    /// Initialize the library, see [`Library::init`].
+    ///
+    /// Returns `None` if the library can't be initialized, like when a [`Library`] already exists.
+    pub fn init() -> Option<SyncLibrary> {
+        let _guard = LOCK.lock();
+
+        Some(SyncLibrary {
+            shared: Arc::new(Shared {
+                library: ManuallyDrop::new(Library::init()?),
+            }),
+        })
+    }
+
+    /// Open a document from its bytes, see [`Library::document_from_bytes`].
+    ///
+    /// The document is open until the returned [`SyncDocument`] is dropped, which keeps the library
+    /// initialized until then.
+    pub fn open(&self, buffer: impl Into<Arc<[u8]>>) -> Result<SyncDocument, PdfiumError> {
+        let buffer = buffer.into();
+        let _guard = LOCK.lock();
+
+        let document = self.shared.library.document_from_arc(buffer)?;
+        // The library is in the `Arc` the document keeps, so it outlives the document and doesn't move
+        let document = unsafe {
+            std::mem::transmute::<Document<'static, '_>, Document<'static, 'static>>(document)
+        };
+
+        Ok(SyncDocument {
+            document: ManuallyDrop::new(document),
+            _shared: self.shared.clone(),
+        })
+    }
+
+    /// Call `f` with the library while holding the lock.
+    ///
+    /// What `f` returns has to be [`Send`], so it can't keep documents or pages that would be used without the lock.
+    pub fn with<T: Send>(&self, f: impl FnOnce(&Library) -> T) -> T {
+        let _guard = LOCK.lock();
+
+        f(&self.shared.library)
+    }
+}
+
+impl Drop for Shared {
+    fn drop(&mut self) {
+        let _guard = LOCK.lock();
+
+        unsafe { ManuallyDrop::drop(&mut self.library) }
+    }
+}
+
+/// A document that can be sent and shared between threads, opened by [`SyncLibrary::open`].
+///
+/// Share it with an [`Arc`], the document is closed when it's dropped.
+pub struct SyncDocument {
+    document: ManuallyDrop<Document<'static, 'static>>,
+    /// Keeps the library of the document, it's dropped after the document is closed.
+    _shared: Arc<Shared>,
+}
+
+// The document is only used while holding `LOCK`, including when it's closed.
+unsafe impl Send for SyncDocument {}
+unsafe impl Sync for SyncDocument {}
+
+impl SyncDocument {
+    /// The number of pages in the document.
+    pub fn page_count(&self) -> usize {
+        self.with(|document| document.page_count())
+    }
+
+    /// The text of a page, see [`Page::text`](crate::Page::text).
+    pub fn page_text(&self, index: usize) -> Result<String, PdfiumError> {
+        self.with(|document| document.page(index)?.text())
+    }
+
+    /// Call `f` with the document while holding the lock.
+    ///
+    /// What `f` returns has to be [`Send`], so it can't keep pages that would be used without the lock.
+    pub fn with<T: Send>(&self, f: impl for<'a> FnOnce(&'a Document<'a, 'a>) -> T) -> T {
+        let _guard = LOCK.lock();
+
+        f(&self.document)
+    }
+}
+
+impl Drop for SyncDocument {
+    fn drop(&mut self) {
+        let _guard = LOCK.lock();
+
+        unsafe { ManuallyDrop::drop(&mut self.document) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn sync_library() {
+        assert_send_sync::<SyncLibrary>();
+        assert_send_sync::<SyncDocument>();
+
+        let _guard = TEST_LOCK.lock();
+        let library = SyncLibrary::init().unwrap();
+        assert!(Library::init().is_none());
+
+        let document = Arc::new(library.open(DUMMY_PDF).unwrap());
+        assert!(library.open(&b"not a pdf"[..]).is_err());
+        assert!(library.with(|library| library.document_from_bytes(DUMMY_PDF).is_ok()));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let document = document.clone();
+                thread::spawn(move || document.page_text(0))
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle
+                .join()
+                .unwrap()
+                .unwrap()
+                .starts_with("Dummy PDF file"));
+        }
+        assert!(document.page_text(1).is_err());
+
+        // The documents keep the library initialized after it's dropped
+        drop(library);
+        assert_eq!(document.with(|document| document.page_count()), 1);
+        assert!(Library::init().is_none());
+        drop(document);
+        assert!(Library::init().is_some());
+    }
+}
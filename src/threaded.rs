@@ -0,0 +1,336 @@
+use crate::worker::{RenderedPage, Worker};
+use crate::{Document, Library, PdfiumError};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+/// The number of calls that can wait for the thread of a [`PdfiumWorker`] by default.
+const DEFAULT_CAPACITY: usize = 64;
+
+/// A library that runs on its own thread, with a handle that can be shared between threads.
+///
+/// PDFium is not thread safe, so every call is sent to a dedicated thread that is the only one using the library,
+/// and the calling thread blocks until it's done. The worker can be cloned and used from any number
+/// of threads, like the threads of a server, the calls run one after another.
+///
+/// The queue of calls is bounded, when it's full the calling threads block until the thread catches up.
+/// The thread stops after [`PdfiumWorker::shutdown`], or when the worker and all of its documents are dropped.
+///
+/// ## Examples
+/// ```
+/// use pdfium_rs::PdfiumWorker;
+/// use std::thread;
+/// # static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+///
+/// let worker = PdfiumWorker::new().unwrap();
+/// let document = worker.open(DUMMY_PDF).unwrap();
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let document = document.clone();
+///         thread::spawn(move || document.render_page(0, 100, 140).unwrap())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap().width, 100);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct PdfiumWorker {
+    worker: Arc<Worker>,
+}
+
+impl PdfiumWorker {
+    /// Start the thread of the library with a queue of 64 calls.
+    ///
+    /// Returns `None` if the library can't be initialized, like when a [`Library`] already exists.
+    pub fn new() -> Option<PdfiumWorker> {
+        PdfiumWorker::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Start the thread of the library, calls block when `capacity` calls are waiting for the thread.
+    ///
+    /// With a capacity of 0 every call waits until the thread takes it.
+    pub fn with_capacity(capacity: usize) -> Option<PdfiumWorker> {
+        Some(PdfiumWorker {
+            worker: Arc::new(Worker::start(Some(capacity))?),
+        })
+    }
+
+    /// Open a document from its bytes, see [`Library::document_from_bytes`].
+    ///
+    /// The document is open until the returned [`WorkerDocument`] and its clones are dropped.
+    pub fn open(&self, buffer: impl Into<Arc<[u8]>>) -> Result<WorkerDocument, PdfiumError> {
+        let buffer = buffer.into();
+
+        let id = self.worker.call(move |documents| {
+            let document = documents.library.document_from_arc(buffer)?;
+
+            Ok(documents.insert(document))
+        })?;
+
+        Ok(WorkerDocument {
+            document: Arc::new(Opened {
+                id,
+                worker: self.worker.clone(),
+            }),
+        })
+    }
+
+    /// Run `job` with the library on its thread.
+    ///
+    /// Calls of the worker and its documents from `job` fail with [`PdfiumError::Unknown`], they would wait for `job`.
+    /// Documents that are dropped in `job` are closed after it.
+    pub fn run<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&Library) -> Result<T, PdfiumError> + Send + 'static,
+    ) -> Result<T, PdfiumError> {
+        self.worker.call(move |documents| job(documents.library))
+    }
+
+    /// Stop the thread after the calls that are waiting, and wait until it stopped.
+    ///
+    /// Calls of the worker, its clones and its documents fail with [`PdfiumError::Unknown`] after it's shut down.
+    pub fn shutdown(&self) {
+        self.worker.shutdown();
+    }
+}
+
+/// A document that is open on the thread of a [`PdfiumWorker`].
+///
+/// The document can be cloned and shared between threads, it's closed when all clones are dropped.
+#[derive(Clone)]
+pub struct WorkerDocument {
+    document: Arc<Opened>,
+}
+
+/// Closes the document when the last clone of a [`WorkerDocument`] is dropped.
+struct Opened {
+    id: u64,
+    worker: Arc<Worker>,
+}
+
+impl WorkerDocument {
+    /// The number of pages in the document.
+    pub fn page_count(&self) -> Result<usize, PdfiumError> {
+        self.run(|document| Ok(document.page_count()))
+    }
+
+    /// Render a page on a white background into a new buffer of `width` by `height` pixels.
+    pub fn render_page(
+        &self,
+        index: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<RenderedPage, PdfiumError> {
+        self.run(move |document| document.render_page(index, width, height))
+    }
+
+    /// Render pages one after another on the thread of the library, while `encoders` threads call `encode`
+    /// with the pages that are done, like to encode them as PNG. Returns what `encode` returned for each page,
+    /// in the order of `pages`.
+    ///
+    /// Rendering continues while the pages are encoded, at most `encoders` rendered pages wait for an encoder
+    /// and the thread of the library waits when they are all busy. `encode` gets the index of the page and
+    /// must not call the worker, the thread of the library is busy until all pages are rendered.
+    ///
+    /// ## Errors
+    /// Fails with the error of the first page that can't be rendered, the pages after it are not rendered.
+    /// Fails with [`PdfiumError::Unknown`] when it's called from a job of the worker, which would wait for itself.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::PdfiumWorker;
+    /// # static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+    ///
+    /// let worker = PdfiumWorker::new().unwrap();
+    /// let document = worker.open(DUMMY_PDF).unwrap();
+    ///
+    /// let sizes = document
+    ///     .render_pages(&[0, 0, 0], 100, 140, 2, |_index, page| page.pixels.len())
+    ///     .unwrap();
+    /// assert_eq!(sizes, vec![100 * 140 * 4; 3]);
+    /// ```
+    pub fn render_pages<T: Send>(
+        &self,
+        pages: &[usize],
+        width: usize,
+        height: usize,
+        encoders: usize,
+        encode: impl Fn(usize, RenderedPage) -> T + Sync,
+    ) -> Result<Vec<T>, PdfiumError> {
+        if self.document.worker.is_current() {
+            return Err(PdfiumError::Unknown);
+        }
+
+        let encoders = encoders.max(1);
+        let (sender, rendered) = mpsc::sync_channel(encoders);
+        let rendered = Mutex::new(rendered);
+        let id = self.document.id;
+        let indices = pages.to_vec();
+
+        self.document.worker.send(Box::new(move |documents| {
+            let document = match documents.get(id) {
+                Ok(document) => document,
+                Err(error) => {
+                    let _ = sender.send((0, 0, Err(error)));
+                    return;
+                }
+            };
+
+            for (position, &index) in indices.iter().enumerate() {
+                let page = document.render_page(index, width, height);
+                let failed = page.is_err();
+
+                // The encoders stop after an error
+                if sender.send((position, index, page)).is_err() || failed {
+                    return;
+                }
+            }
+        }));
+
+        let encoded = thread::scope(|scope| {
+            let threads: Vec<_> = (0..encoders)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut encoded = Vec::new();
+
+                        loop {
+                            let next = rendered.lock().unwrap_or_else(|x| x.into_inner()).recv();
+                            match next {
+                                Ok((position, index, page)) => {
+                                    encoded.push((position, encode(index, page?)))
+                                }
+                                Err(_) => return Ok(encoded),
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            threads
+                .into_iter()
+                .map(|thread| {
+                    thread
+                        .join()
+                        .unwrap_or_else(|x| std::panic::resume_unwind(x))
+                })
+                .collect::<Result<Vec<_>, PdfiumError>>()
+        })?;
+
+        let mut encoded: Vec<_> = encoded.into_iter().flatten().collect();
+        // The thread stopped before all pages were rendered
+        if encoded.len() != pages.len() {
+            return Err(PdfiumError::Unknown);
+        }
+        encoded.sort_by_key(|&(position, _)| position);
+
+        Ok(encoded.into_iter().map(|(_, page)| page).collect())
+    }
+
+    /// The text of a page, see [`Page::text`](crate::Page::text).
+    pub fn page_text(&self, index: usize) -> Result<String, PdfiumError> {
+        self.run(move |document| document.page(index)?.text())
+    }
+
+    /// Run `job` with the document on the thread of the library.
+    ///
+    /// Like with [`PdfiumWorker::run`], calls of the worker and its documents from `job` fail with [`PdfiumError::Unknown`].
+    pub fn run<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&Document) -> Result<T, PdfiumError> + Send + 'static,
+    ) -> Result<T, PdfiumError> {
+        let id = self.document.id;
+
+        self.document
+            .worker
+            .call(move |documents| job(documents.get(id)?))
+    }
+}
+
+impl Drop for Opened {
+    fn drop(&mut self) {
+        let id = self.id;
+
+        self.worker
+            .send(Box::new(move |documents| documents.remove(id)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::thread;
+
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn pdfium_worker() {
+        assert_send_sync::<PdfiumWorker>();
+        assert_send_sync::<WorkerDocument>();
+
+        let _guard = TEST_LOCK.lock();
+        let worker = PdfiumWorker::with_capacity(1).unwrap();
+
+        let document = worker.open(DUMMY_PDF).unwrap();
+        assert_eq!(document.page_count(), Ok(1));
+        assert!(document.page_text(0).unwrap().starts_with("Dummy PDF file"));
+        assert!(worker.open(&b"not a pdf"[..]).is_err());
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let document = document.clone();
+                thread::spawn(move || document.render_page(0, 20, 30))
+            })
+            .collect();
+        for handle in handles {
+            let page = handle.join().unwrap().unwrap();
+            assert_eq!(page.pixels.len(), 20 * 30 * 4);
+        }
+        assert!(document.render_page(1, 20, 30).is_err());
+
+        let pages = document
+            .render_pages(&[0; 5], 20, 30, 2, |index, page| (index, page.pixels.len()))
+            .unwrap();
+        assert_eq!(pages, vec![(0, 20 * 30 * 4); 5]);
+        assert!(document
+            .render_pages(&[0, 1, 0], 20, 30, 2, |_, _| ())
+            .is_err());
+
+        // The library is used on its own thread only, until it's shut down
+        assert!(Library::init().is_none());
+
+        // Calls from a job would wait for it
+        let inner = document.clone();
+        assert_eq!(
+            worker.run(move |_| Ok(inner.page_count())),
+            Ok(Err(PdfiumError::Unknown))
+        );
+        let inner = document.clone();
+        assert_eq!(
+            document.run(move |_| Ok(inner.render_pages(&[0], 20, 30, 1, |_, _| ()))),
+            Ok(Err(PdfiumError::Unknown))
+        );
+
+        // The last clone of a document can be dropped in a job, it is closed after the job
+        let dropped = worker.open(DUMMY_PDF).unwrap();
+        assert_eq!(
+            worker.run(move |_| {
+                drop(dropped);
+                Ok(())
+            }),
+            Ok(())
+        );
+        assert_eq!(document.page_count(), Ok(1));
+
+        worker.shutdown();
+        assert_eq!(document.page_count(), Err(PdfiumError::Unknown));
+        assert_eq!(
+            document.render_pages(&[0], 20, 30, 1, |_, _| ()),
+            Err(PdfiumError::Unknown)
+        );
+        assert!(Library::init().is_some());
+    }
+}
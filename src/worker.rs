@@ -0,0 +1,330 @@
+use crate::{Bitmap, BitmapFormat, Document, Library, PdfiumError};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::sync::Arc;
+use std::sync::{mpsc, Mutex};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle, ThreadId};
+
+/// The documents that are open on a worker thread, by their id.
+pub(crate) struct Documents<'library> {
+    pub(crate) library: &'library Library,
+    documents: HashMap<u64, Document<'static, 'library>>,
+    next_id: u64,
+}
+
+impl<'library> Documents<'library> {
+    pub(crate) fn insert(&mut self, document: Document<'static, 'library>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.documents.insert(id, document);
+
+        id
+    }
+
+    pub(crate) fn get(&self, id: u64) -> Result<&Document<'static, 'library>, PdfiumError> {
+        self.documents.get(&id).ok_or(PdfiumError::Unknown)
+    }
+
+    pub(crate) fn remove(&mut self, id: u64) {
+        self.documents.remove(&id);
+    }
+}
+
+type Job = Box<dyn FnOnce(&mut Documents<'_>) + Send>;
+
+thread_local! {
+    /// The jobs that a job of the worker thread sent to it, they run after the job.
+    static DEFERRED: RefCell<VecDeque<Job>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// The sender of the jobs, bounded if the queue has a capacity.
+enum Sender {
+    Unbounded(mpsc::Sender<Job>),
+    Bounded(mpsc::SyncSender<Job>),
+}
+
+/// A thread that owns the library, the only thread that calls PDFium.
+///
+/// The thread stops when the worker is dropped or shut down, after it finished the jobs that were sent before.
+pub(crate) struct Worker {
+    sender: Mutex<Option<Sender>>,
+    thread: Mutex<Option<JoinHandle<()>>>,
+    thread_id: ThreadId,
+}
+
+impl Worker {
+    /// Start the thread and initialize the library on it.
+    ///
+    /// With a `capacity` sending a job blocks while that many jobs are waiting.
+    /// Returns `None` if the library can't be initialized, like when it's already initialized.
+    pub(crate) fn start(capacity: Option<usize>) -> Option<Worker> {
+        let (sender, receiver) = match capacity {
+            Some(capacity) => {
+                let (sender, receiver) = mpsc::sync_channel::<Job>(capacity);
+                (Sender::Bounded(sender), receiver)
+            }
+            None => {
+                let (sender, receiver) = mpsc::channel::<Job>();
+                (Sender::Unbounded(sender), receiver)
+            }
+        };
+        let (initialized_sender, initialized) = mpsc::sync_channel(1);
+
+        let thread = thread::Builder::new()
+            .name("pdfium".to_string())
+            .spawn(move || {
+                let library = match Library::init() {
+                    Some(library) => library,
+                    None => {
+                        let _ = initialized_sender.send(false);
+                        return;
+                    }
+                };
+                let _ = initialized_sender.send(true);
+
+                let mut documents = Documents {
+                    library: &library,
+                    documents: HashMap::new(),
+                    next_id: 0,
+                };
+
+                for job in receiver {
+                    job(&mut documents);
+
+                    while let Some(job) =
+                        DEFERRED.with(|deferred| deferred.borrow_mut().pop_front())
+                    {
+                        job(&mut documents);
+                    }
+                }
+            })
+            .ok()?;
+
+        if !initialized.recv().unwrap_or(false) {
+            let _ = thread.join();
+            return None;
+        }
+
+        Some(Worker {
+            sender: Mutex::new(Some(sender)),
+            thread_id: thread.thread().id(),
+            thread: Mutex::new(Some(thread)),
+        })
+    }
+
+    /// Run `job` on the thread, the result is sent to the returned future.
+    #[cfg(feature = "async")]
+    pub(crate) fn run<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&mut Documents<'_>) -> Result<T, PdfiumError> + Send + 'static,
+    ) -> JobFuture<T> {
+        let (future, completer) = JobFuture::new();
+
+        let job: Job = Box::new(move |documents| completer.complete(job(documents)));
+        self.send(job);
+
+        future
+    }
+
+    /// Run `job` on the thread and wait for its result.
+    ///
+    /// Fails with [`PdfiumError::Unknown`] if the thread stopped before the job finished,
+    /// or if it's called from a job, which would wait for itself.
+    #[cfg(feature = "threaded")]
+    pub(crate) fn call<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&mut Documents<'_>) -> Result<T, PdfiumError> + Send + 'static,
+    ) -> Result<T, PdfiumError> {
+        if self.is_current() {
+            return Err(PdfiumError::Unknown);
+        }
+
+        let (result_sender, result) = mpsc::sync_channel(1);
+
+        self.send(Box::new(move |documents| {
+            let _ = result_sender.send(job(documents));
+        }));
+
+        result.recv().unwrap_or(Err(PdfiumError::Unknown))
+    }
+
+    /// Send a job without waiting for it, dropped if the thread stopped.
+    ///
+    /// Blocks while the queue is full, the lock is not held while waiting so other senders can
+    /// shut down the worker. A job that is sent from a job runs after it instead, the thread
+    /// can't wait for room in its own queue.
+    pub(crate) fn send(&self, job: Job) {
+        if self.is_current() {
+            DEFERRED.with(|deferred| deferred.borrow_mut().push_back(job));
+            return;
+        }
+
+        let sender = match &*self.sender.lock().unwrap_or_else(|x| x.into_inner()) {
+            Some(Sender::Unbounded(sender)) => {
+                let _ = sender.send(job);
+                return;
+            }
+            Some(Sender::Bounded(sender)) => sender.clone(),
+            None => return,
+        };
+
+        let _ = sender.send(job);
+    }
+
+    /// Check if this is called from a job, on the thread of the worker.
+    pub(crate) fn is_current(&self) -> bool {
+        thread::current().id() == self.thread_id
+    }
+
+    /// Stop accepting jobs and wait until the thread finished the jobs that were sent before.
+    ///
+    /// Doesn't wait when it's called from a job, the thread can't wait for itself.
+    pub(crate) fn shutdown(&self) {
+        self.sender.lock().unwrap_or_else(|x| x.into_inner()).take();
+
+        if !self.is_current() {
+            if let Some(thread) = self.thread.lock().unwrap_or_else(|x| x.into_inner()).take() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        // A job can own the last reference to the worker
+        self.shutdown();
+    }
+}
+
+/// The result of a call that runs on the PDFium thread.
+///
+/// Fails with [`PdfiumError::Unknown`] if the thread stopped before the call finished.
+#[cfg(feature = "async")]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JobFuture<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+#[cfg(feature = "async")]
+struct Shared<T> {
+    result: Option<Result<T, PdfiumError>>,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl<T> JobFuture<T> {
+    fn new() -> (JobFuture<T>, Completer<T>) {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+
+        (
+            JobFuture {
+                shared: shared.clone(),
+            },
+            Completer {
+                shared: Some(shared),
+            },
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Future for JobFuture<T> {
+    type Output = Result<T, PdfiumError>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap_or_else(|x| x.into_inner());
+
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Sends the result to a [`JobFuture`], or an error if it's dropped without a result.
+#[cfg(feature = "async")]
+struct Completer<T> {
+    shared: Option<Arc<Mutex<Shared<T>>>>,
+}
+
+#[cfg(feature = "async")]
+impl<T> Completer<T> {
+    fn complete(mut self, result: Result<T, PdfiumError>) {
+        self.send(result);
+    }
+
+    fn send(&mut self, result: Result<T, PdfiumError>) {
+        if let Some(shared) = self.shared.take() {
+            let mut shared = shared.lock().unwrap_or_else(|x| x.into_inner());
+            shared.result = Some(result);
+
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Drop for Completer<T> {
+    fn drop(&mut self) {
+        self.send(Err(PdfiumError::Unknown));
+    }
+}
+
+/// A page rendered to pixels that are owned by the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedPage {
+    pub width: usize,
+    pub height: usize,
+    /// The pixels in rows from the top, with 4 bytes in the BGRA order for each pixel.
+    pub pixels: Vec<u8>,
+}
+
+impl Document<'_, '_> {
+    /// Render a page on a white background into a new buffer of `width` by `height` pixels.
+    pub(crate) fn render_page(
+        &self,
+        index: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<RenderedPage, PdfiumError> {
+        let format = BitmapFormat::BGRA;
+        let stride = width * format.bytes_per_pixel();
+        let mut pixels = vec![0xFF; stride * height];
+
+        let page = self.page(index)?;
+        let mut bitmap = Bitmap {
+            handle: self.core.create_bitmap_from_buffer(
+                width,
+                height,
+                format,
+                &mut pixels,
+                stride,
+            )?,
+            core: self.core,
+        };
+        page.render_to(&mut bitmap);
+        drop(bitmap);
+
+        Ok(RenderedPage {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
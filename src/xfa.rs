@@ -0,0 +1,79 @@
+use crate::{Document, PdfiumError};
+
+impl Document<'_, '_> {
+    /// Load the XFA form of the document, so it's rendered instead of the pages without the form.
+    ///
+    /// Call it before pages are loaded. Fails if the document doesn't have an XFA form.
+    pub fn load_xfa(&mut self) -> Result<(), PdfiumError> {
+        self.core.load_xfa(&mut self.handle)
+    }
+
+    /// The packets of the XFA form of the document, like its `template` and its `datasets` with the
+    /// entered data.
+    ///
+    /// Empty if the document doesn't have an XFA form.
+    ///
+    /// ## Examples
+    /// ```
+    /// use pdfium_rs::Library;
+    /// # static XFA_PDF: &[u8] = include_bytes!("../test_assets/xfa.pdf");
+    ///
+    /// let library = Library::init().unwrap();
+    /// let document = library.document_from_bytes(XFA_PDF).unwrap();
+    ///
+    /// let datasets = document
+    ///     .xfa_packets()
+    ///     .into_iter()
+    ///     .find(|packet| packet.name == "datasets");
+    /// assert!(datasets.is_some());
+    /// ```
+    pub fn xfa_packets(&self) -> Vec<XfaPacket> {
+        (0..self.core.get_xfa_packet_count(&self.handle))
+            .filter_map(|index| {
+                Some(XfaPacket {
+                    name: self.core.get_xfa_packet_name(&self.handle, index),
+                    content: self.core.get_xfa_packet_content(&self.handle, index)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A part of an XFA form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XfaPacket {
+    /// The name of the packet, like `template`, `datasets` or `config`.
+    ///
+    /// Empty for forms that store all packets in one XML document.
+    pub name: String,
+    /// The XML of the packet.
+    pub content: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static XFA_PDF: &[u8] = include_bytes!("../test_assets/xfa.pdf");
+    static DUMMY_PDF: &[u8] = include_bytes!("../test_assets/dummy.pdf");
+
+    #[test]
+    fn xfa_packets() {
+        let _guard = TEST_LOCK.lock();
+        let library = Library::init().unwrap();
+        let document = library.document_from_bytes(XFA_PDF).unwrap();
+
+        let packets = document.xfa_packets();
+        let names: Vec<_> = packets.iter().map(|packet| packet.name.as_str()).collect();
+        assert_eq!(names, ["template", "datasets"]);
+        let datasets = String::from_utf8(packets[1].content.clone()).unwrap();
+        assert!(datasets.contains("<name>Jane</name>"));
+
+        let dummy = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(dummy.xfa_packets().is_empty());
+
+        let mut dummy = library.document_from_bytes(DUMMY_PDF).unwrap();
+        assert!(dummy.load_xfa().is_err());
+    }
+}